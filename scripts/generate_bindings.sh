@@ -0,0 +1,64 @@
+#!/usr/bin/env bash
+# Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+# SPDX-License-Identifier: Apache-2.0
+#
+# Generates the KVM bindings of one kernel version for one architecture.
+#
+# Usage: scripts/generate_bindings.sh <linux repo> <version> <arch>
+# Example: scripts/generate_bindings.sh ~/linux 5.10 arm64
+#
+# The bindings are written to src/<arch>/bindings_v<version>.rs, with the
+# version in the `5_10_0` form used by the `kvm-v*` features.
+
+set -euo pipefail
+
+if [ "$#" -ne 3 ]; then
+    echo "Usage: $0 <linux repo> <version> <x86|arm|arm64>" >&2
+    exit 1
+fi
+
+LINUX="$(cd "$1" && pwd)"
+VERSION="$2"
+ARCH="$3"
+CRATE="$(cd "$(dirname "$0")/.." && pwd)"
+
+case "$ARCH" in
+    x86) KERNEL_ARCH=x86; TARGET=x86_64-linux-gnu ;;
+    arm) KERNEL_ARCH=arm; TARGET=arm-linux-gnueabihf ;;
+    arm64) KERNEL_ARCH=arm64; TARGET=aarch64-linux-gnu ;;
+    *) echo "Unsupported architecture: $ARCH" >&2; exit 1 ;;
+esac
+
+# 4.20 -> 4_20_0, 5.10 -> 5_10_0
+SUFFIX="$(echo "$VERSION" | tr . _)"
+case "$SUFFIX" in
+    *_*_*) ;;
+    *) SUFFIX="${SUFFIX}_0" ;;
+esac
+
+HEADERS="$(mktemp -d)"
+trap 'rm -rf "$HEADERS"' EXIT
+
+git -C "$LINUX" checkout --quiet "v$VERSION"
+make -C "$LINUX" --quiet headers_install ARCH="$KERNEL_ARCH" \
+    INSTALL_HDR_PATH="$HEADERS"
+
+# The C types are defined for each architecture in src/<arch>/mod.rs, so the
+# layout of the bindings does not depend on the host they are compiled on.
+OUT="$CRATE/src/$ARCH/bindings_v$SUFFIX.rs"
+{
+    echo "// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved."
+    echo "// SPDX-License-Identifier: Apache-2.0"
+    bindgen "$HEADERS/include/linux/kvm.h" \
+        --with-derive-default \
+        --with-derive-partialeq \
+        --ctypes-prefix super::raw \
+        -- --target="$TARGET" -I"$HEADERS/include"
+} > "$OUT"
+
+# bindgen 0.46 measures the field offsets by dereferencing null pointers,
+# which recent toolchains reject. Use `offset_of!` like the existing bindings.
+perl -0777 -pi -e 's/unsafe\s*\{\s*&\(\*\(::std::ptr::null::<(\w+)>\(\)\)\)\.(\w+)\s+as\s+\*const\s+_\s+as\s+usize\s*\}/::std::mem::offset_of!($1, $2)/g' "$OUT"
+
+rustfmt --edition 2015 "$OUT"
+echo "Generated $OUT"
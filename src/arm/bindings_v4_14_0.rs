@@ -589,18 +589,18 @@ pub const KVM_X2APIC_API_DISABLE_BROADCAST_QUIRK: u32 = 2;
 pub const KVM_ARM_DEV_EL1_VTIMER: u32 = 1;
 pub const KVM_ARM_DEV_EL1_PTIMER: u32 = 2;
 pub const KVM_ARM_DEV_PMU: u32 = 4;
-pub type __s8 = ::std::os::raw::c_schar;
-pub type __u8 = ::std::os::raw::c_uchar;
-pub type __s16 = ::std::os::raw::c_short;
-pub type __u16 = ::std::os::raw::c_ushort;
-pub type __s32 = ::std::os::raw::c_int;
-pub type __u32 = ::std::os::raw::c_uint;
-pub type __s64 = ::std::os::raw::c_longlong;
-pub type __u64 = ::std::os::raw::c_ulonglong;
+pub type __s8 = super::raw::c_schar;
+pub type __u8 = super::raw::c_uchar;
+pub type __s16 = super::raw::c_short;
+pub type __u16 = super::raw::c_ushort;
+pub type __s32 = super::raw::c_int;
+pub type __u32 = super::raw::c_uint;
+pub type __s64 = super::raw::c_longlong;
+pub type __u64 = super::raw::c_ulonglong;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct __kernel_fd_set {
-    pub fds_bits: [::std::os::raw::c_ulong; 16usize],
+    pub fds_bits: [super::raw::c_ulong; 32usize],
 }
 #[test]
 fn bindgen_test_layout___kernel_fd_set() {
@@ -611,11 +611,11 @@ fn bindgen_test_layout___kernel_fd_set() {
     );
     assert_eq!(
         ::std::mem::align_of::<__kernel_fd_set>(),
-        8usize,
+        4usize,
         concat!("Alignment of ", stringify!(__kernel_fd_set))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__kernel_fd_set>())).fds_bits as *const _ as usize },
+        ::std::mem::offset_of!(__kernel_fd_set, fds_bits),
         0usize,
         concat!(
             "Offset of field: ",
@@ -626,31 +626,31 @@ fn bindgen_test_layout___kernel_fd_set() {
     );
 }
 pub type __kernel_sighandler_t =
-    ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>;
-pub type __kernel_key_t = ::std::os::raw::c_int;
-pub type __kernel_mqd_t = ::std::os::raw::c_int;
-pub type __kernel_mode_t = ::std::os::raw::c_ushort;
-pub type __kernel_ipc_pid_t = ::std::os::raw::c_ushort;
-pub type __kernel_uid_t = ::std::os::raw::c_ushort;
-pub type __kernel_gid_t = ::std::os::raw::c_ushort;
-pub type __kernel_old_dev_t = ::std::os::raw::c_ushort;
-pub type __kernel_long_t = ::std::os::raw::c_long;
-pub type __kernel_ulong_t = ::std::os::raw::c_ulong;
+    ::std::option::Option<unsafe extern "C" fn(arg1: super::raw::c_int)>;
+pub type __kernel_key_t = super::raw::c_int;
+pub type __kernel_mqd_t = super::raw::c_int;
+pub type __kernel_mode_t = super::raw::c_ushort;
+pub type __kernel_ipc_pid_t = super::raw::c_ushort;
+pub type __kernel_uid_t = super::raw::c_ushort;
+pub type __kernel_gid_t = super::raw::c_ushort;
+pub type __kernel_old_dev_t = super::raw::c_ushort;
+pub type __kernel_long_t = super::raw::c_long;
+pub type __kernel_ulong_t = super::raw::c_ulong;
 pub type __kernel_ino_t = __kernel_ulong_t;
-pub type __kernel_pid_t = ::std::os::raw::c_int;
+pub type __kernel_pid_t = super::raw::c_int;
 pub type __kernel_suseconds_t = __kernel_long_t;
-pub type __kernel_daddr_t = ::std::os::raw::c_int;
-pub type __kernel_uid32_t = ::std::os::raw::c_uint;
-pub type __kernel_gid32_t = ::std::os::raw::c_uint;
+pub type __kernel_daddr_t = super::raw::c_int;
+pub type __kernel_uid32_t = super::raw::c_uint;
+pub type __kernel_gid32_t = super::raw::c_uint;
 pub type __kernel_old_uid_t = __kernel_uid_t;
 pub type __kernel_old_gid_t = __kernel_gid_t;
-pub type __kernel_size_t = ::std::os::raw::c_uint;
-pub type __kernel_ssize_t = ::std::os::raw::c_int;
-pub type __kernel_ptrdiff_t = ::std::os::raw::c_int;
+pub type __kernel_size_t = super::raw::c_uint;
+pub type __kernel_ssize_t = super::raw::c_int;
+pub type __kernel_ptrdiff_t = super::raw::c_int;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct __kernel_fsid_t {
-    pub val: [::std::os::raw::c_int; 2usize],
+    pub val: [super::raw::c_int; 2usize],
 }
 #[test]
 fn bindgen_test_layout___kernel_fsid_t() {
@@ -665,7 +665,7 @@ fn bindgen_test_layout___kernel_fsid_t() {
         concat!("Alignment of ", stringify!(__kernel_fsid_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__kernel_fsid_t>())).val as *const _ as usize },
+        ::std::mem::offset_of!(__kernel_fsid_t, val),
         0usize,
         concat!(
             "Offset of field: ",
@@ -676,14 +676,14 @@ fn bindgen_test_layout___kernel_fsid_t() {
     );
 }
 pub type __kernel_off_t = __kernel_long_t;
-pub type __kernel_loff_t = ::std::os::raw::c_longlong;
+pub type __kernel_loff_t = super::raw::c_longlong;
 pub type __kernel_time_t = __kernel_long_t;
 pub type __kernel_clock_t = __kernel_long_t;
-pub type __kernel_timer_t = ::std::os::raw::c_int;
-pub type __kernel_clockid_t = ::std::os::raw::c_int;
-pub type __kernel_caddr_t = *mut ::std::os::raw::c_char;
-pub type __kernel_uid16_t = ::std::os::raw::c_ushort;
-pub type __kernel_gid16_t = ::std::os::raw::c_ushort;
+pub type __kernel_timer_t = super::raw::c_int;
+pub type __kernel_clockid_t = super::raw::c_int;
+pub type __kernel_caddr_t = *mut super::raw::c_char;
+pub type __kernel_uid16_t = super::raw::c_ushort;
+pub type __kernel_gid16_t = super::raw::c_ushort;
 pub type __le16 = __u16;
 pub type __be16 = __u16;
 pub type __le32 = __u32;
@@ -695,22 +695,22 @@ pub type __wsum = __u32;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct pt_regs {
-    pub uregs: [::std::os::raw::c_long; 18usize],
+    pub uregs: [super::raw::c_long; 18usize],
 }
 #[test]
 fn bindgen_test_layout_pt_regs() {
     assert_eq!(
         ::std::mem::size_of::<pt_regs>(),
-        144usize,
+        72usize,
         concat!("Size of: ", stringify!(pt_regs))
     );
     assert_eq!(
         ::std::mem::align_of::<pt_regs>(),
-        8usize,
+        4usize,
         concat!("Alignment of ", stringify!(pt_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pt_regs>())).uregs as *const _ as usize },
+        ::std::mem::offset_of!(pt_regs, uregs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -724,26 +724,26 @@ fn bindgen_test_layout_pt_regs() {
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct kvm_regs {
     pub usr_regs: pt_regs,
-    pub svc_regs: [::std::os::raw::c_ulong; 3usize],
-    pub abt_regs: [::std::os::raw::c_ulong; 3usize],
-    pub und_regs: [::std::os::raw::c_ulong; 3usize],
-    pub irq_regs: [::std::os::raw::c_ulong; 3usize],
-    pub fiq_regs: [::std::os::raw::c_ulong; 8usize],
+    pub svc_regs: [super::raw::c_ulong; 3usize],
+    pub abt_regs: [super::raw::c_ulong; 3usize],
+    pub und_regs: [super::raw::c_ulong; 3usize],
+    pub irq_regs: [super::raw::c_ulong; 3usize],
+    pub fiq_regs: [super::raw::c_ulong; 8usize],
 }
 #[test]
 fn bindgen_test_layout_kvm_regs() {
     assert_eq!(
         ::std::mem::size_of::<kvm_regs>(),
-        304usize,
+        152usize,
         concat!("Size of: ", stringify!(kvm_regs))
     );
     assert_eq!(
         ::std::mem::align_of::<kvm_regs>(),
-        8usize,
+        4usize,
         concat!("Alignment of ", stringify!(kvm_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).usr_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, usr_regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -753,8 +753,8 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).svc_regs as *const _ as usize },
-        144usize,
+        ::std::mem::offset_of!(kvm_regs, svc_regs),
+        72usize,
         concat!(
             "Offset of field: ",
             stringify!(kvm_regs),
@@ -763,8 +763,8 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).abt_regs as *const _ as usize },
-        168usize,
+        ::std::mem::offset_of!(kvm_regs, abt_regs),
+        84usize,
         concat!(
             "Offset of field: ",
             stringify!(kvm_regs),
@@ -773,8 +773,8 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).und_regs as *const _ as usize },
-        192usize,
+        ::std::mem::offset_of!(kvm_regs, und_regs),
+        96usize,
         concat!(
             "Offset of field: ",
             stringify!(kvm_regs),
@@ -783,8 +783,8 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).irq_regs as *const _ as usize },
-        216usize,
+        ::std::mem::offset_of!(kvm_regs, irq_regs),
+        108usize,
         concat!(
             "Offset of field: ",
             stringify!(kvm_regs),
@@ -793,8 +793,8 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).fiq_regs as *const _ as usize },
-        240usize,
+        ::std::mem::offset_of!(kvm_regs, fiq_regs),
+        120usize,
         concat!(
             "Offset of field: ",
             stringify!(kvm_regs),
@@ -822,7 +822,7 @@ fn bindgen_test_layout_kvm_vcpu_init() {
         concat!("Alignment of ", stringify!(kvm_vcpu_init))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_init>())).target as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_init, target),
         0usize,
         concat!(
             "Offset of field: ",
@@ -832,7 +832,7 @@ fn bindgen_test_layout_kvm_vcpu_init() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_init>())).features as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_init, features),
         4usize,
         concat!(
             "Offset of field: ",
@@ -924,7 +924,7 @@ fn bindgen_test_layout_kvm_sync_regs() {
         concat!("Alignment of ", stringify!(kvm_sync_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sync_regs>())).device_irq_level as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sync_regs, device_irq_level),
         0usize,
         concat!(
             "Offset of field: ",
@@ -969,7 +969,7 @@ fn bindgen_test_layout_kvm_user_trace_setup() {
         concat!("Alignment of ", stringify!(kvm_user_trace_setup))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_user_trace_setup>())).buf_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_user_trace_setup, buf_size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -979,7 +979,7 @@ fn bindgen_test_layout_kvm_user_trace_setup() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_user_trace_setup>())).buf_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_user_trace_setup, buf_nr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1009,7 +1009,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         concat!("Alignment of ", stringify!(kvm_breakpoint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1019,7 +1019,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, padding),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1029,7 +1029,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, address),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1060,7 +1060,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         concat!("Alignment of ", stringify!(kvm_debug_guest))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1070,7 +1070,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1080,7 +1080,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).breakpoints as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, breakpoints),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1090,7 +1090,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).singlestep as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, singlestep),
         72usize,
         concat!(
             "Offset of field: ",
@@ -1121,7 +1121,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         concat!("Alignment of ", stringify!(kvm_memory_region))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).slot as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1131,7 +1131,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1141,9 +1141,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_memory_region>())).guest_phys_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_memory_region, guest_phys_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1153,7 +1151,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).memory_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, memory_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1185,9 +1183,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         concat!("Alignment of ", stringify!(kvm_userspace_memory_region))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).slot as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1197,9 +1193,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1209,10 +1203,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).guest_phys_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, guest_phys_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1222,9 +1213,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).memory_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, memory_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1234,10 +1223,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).userspace_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, userspace_addr),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1273,7 +1259,7 @@ fn bindgen_test_layout_kvm_irq_level__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_irq_level__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_level__bindgen_ty_1>())).irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_level__bindgen_ty_1, irq),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1283,9 +1269,7 @@ fn bindgen_test_layout_kvm_irq_level__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_level__bindgen_ty_1>())).status as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_level__bindgen_ty_1, status),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1313,7 +1297,7 @@ fn bindgen_test_layout_kvm_irq_level() {
         concat!("Alignment of ", stringify!(kvm_irq_level))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_level>())).level as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_level, level),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1338,7 +1322,7 @@ pub struct kvm_irqchip {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union kvm_irqchip__bindgen_ty_1 {
-    pub dummy: [::std::os::raw::c_char; 512usize],
+    pub dummy: [super::raw::c_char; 512usize],
     _bindgen_union_align: [u8; 512usize],
 }
 #[test]
@@ -1354,7 +1338,7 @@ fn bindgen_test_layout_kvm_irqchip__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_irqchip__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip__bindgen_ty_1>())).dummy as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip__bindgen_ty_1, dummy),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1382,7 +1366,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         concat!("Alignment of ", stringify!(kvm_irqchip))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).chip_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, chip_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1392,7 +1376,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1402,7 +1386,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).chip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, chip),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1436,7 +1420,7 @@ fn bindgen_test_layout_kvm_pit_config() {
         concat!("Alignment of ", stringify!(kvm_pit_config))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_config>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_config, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1446,7 +1430,7 @@ fn bindgen_test_layout_kvm_pit_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_config>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_config, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1478,7 +1462,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         concat!("Alignment of ", stringify!(kvm_s390_skeys))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).start_gfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, start_gfn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1488,7 +1472,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).count as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, count),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1498,7 +1482,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).skeydata_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, skeydata_addr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1508,7 +1492,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1518,7 +1502,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, reserved),
         28usize,
         concat!(
             "Offset of field: ",
@@ -1571,10 +1555,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_s390_cmma_log__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_cmma_log__bindgen_ty_1>())).remaining as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_cmma_log__bindgen_ty_1, remaining),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1584,9 +1565,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_cmma_log__bindgen_ty_1>())).mask as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_cmma_log__bindgen_ty_1, mask),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1614,7 +1593,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         concat!("Alignment of ", stringify!(kvm_s390_cmma_log))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).start_gfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, start_gfn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1624,7 +1603,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).count as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, count),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1634,7 +1613,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -1644,7 +1623,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).values as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, values),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1699,10 +1678,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).msr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, msr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1712,10 +1688,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).control
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, control),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1725,10 +1698,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).evt_page
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, evt_page),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1738,10 +1708,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).msg_page
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, msg_page),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1777,10 +1744,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).input
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, input),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1790,10 +1754,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).result
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, result),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1803,10 +1764,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).params
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, params),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1829,9 +1787,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_hyperv_exit__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1>())).synic as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1, synic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1841,9 +1797,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1>())).hcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1, hcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1871,7 +1825,7 @@ fn bindgen_test_layout_kvm_hyperv_exit() {
         concat!("Alignment of ", stringify!(kvm_hyperv_exit))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_exit>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_exit, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1881,7 +1835,7 @@ fn bindgen_test_layout_kvm_hyperv_exit() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_exit>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_exit, u),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1937,7 +1891,7 @@ pub union kvm_run__bindgen_ty_1 {
     pub s390_stsi: kvm_run__bindgen_ty_1__bindgen_ty_18,
     pub eoi: kvm_run__bindgen_ty_1__bindgen_ty_19,
     pub hyperv: kvm_hyperv_exit,
-    pub padding: [::std::os::raw::c_char; 256usize],
+    pub padding: [super::raw::c_char; 256usize],
     _bindgen_union_align: [u64; 32usize],
 }
 #[repr(C)]
@@ -1961,10 +1915,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_1>())).hardware_exit_reason
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_1, hardware_exit_reason),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1995,10 +1946,10 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_2>()))
-                .hardware_entry_failure_reason as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            kvm_run__bindgen_ty_1__bindgen_ty_2,
+            hardware_entry_failure_reason
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2030,10 +1981,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_3>())).exception as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_3, exception),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2043,10 +1991,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_3>())).error_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_3, error_code),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2081,10 +2026,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).direction as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, direction),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2094,10 +2036,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, size),
         1usize,
         concat!(
             "Offset of field: ",
@@ -2107,10 +2046,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).port as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, port),
         2usize,
         concat!(
             "Offset of field: ",
@@ -2120,10 +2056,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).count as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, count),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2133,10 +2066,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).data_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, data_offset),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2167,10 +2097,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_5() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_5>())).arch as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_5, arch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2204,10 +2131,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).phys_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, phys_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2217,10 +2141,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2230,9 +2151,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).len as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2242,10 +2161,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, is_write),
         20usize,
         concat!(
             "Offset of field: ",
@@ -2280,9 +2196,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2292,10 +2206,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).args as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, args),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2305,9 +2216,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).ret as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, ret),
         56usize,
         concat!(
             "Offset of field: ",
@@ -2317,10 +2226,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).longmode as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, longmode),
         64usize,
         concat!(
             "Offset of field: ",
@@ -2330,9 +2236,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, pad),
         68usize,
         concat!(
             "Offset of field: ",
@@ -2365,9 +2269,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).rip as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, rip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2377,10 +2279,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, is_write),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2390,9 +2289,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2425,10 +2322,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).icptcode as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, icptcode),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2438,9 +2332,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).ipa as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, ipa),
         2usize,
         concat!(
             "Offset of field: ",
@@ -2450,9 +2342,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).ipb as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, ipb),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2487,10 +2377,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_10() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_10>())).trans_exc_code
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_10, trans_exc_code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2500,10 +2387,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_10() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_10>())).pgm_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_10, pgm_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2539,10 +2423,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).dcrn as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, dcrn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2552,10 +2433,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, data),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2565,10 +2443,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, is_write),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2604,10 +2479,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).suberror as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, suberror),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2617,10 +2489,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).ndata as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, ndata),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2630,10 +2499,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2667,10 +2533,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_13() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_13>())).gprs as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_13, gprs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2706,9 +2569,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2718,10 +2579,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).ret as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, ret),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2731,10 +2589,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).args as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, args),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2773,10 +2628,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).subchannel_id
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, subchannel_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2786,10 +2638,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).subchannel_nr
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, subchannel_nr),
         2usize,
         concat!(
             "Offset of field: ",
@@ -2799,10 +2648,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).io_int_parm as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, io_int_parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2812,10 +2658,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).io_int_word as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, io_int_word),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2825,10 +2668,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).ipb as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, ipb),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2838,10 +2678,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).dequeued as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, dequeued),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2875,10 +2712,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_16() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_16>())).epr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_16, epr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2913,10 +2747,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_17() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_17>())).type_ as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_17, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2926,10 +2757,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_17() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_17>())).flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_17, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2968,10 +2796,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2981,9 +2806,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).ar as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, ar),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2993,10 +2816,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, reserved),
         9usize,
         concat!(
             "Offset of field: ",
@@ -3006,9 +2826,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).fc as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, fc),
         10usize,
         concat!(
             "Offset of field: ",
@@ -3018,10 +2836,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).sel1 as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, sel1),
         11usize,
         concat!(
             "Offset of field: ",
@@ -3031,10 +2846,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).sel2 as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, sel2),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3068,10 +2880,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_19() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_19>())).vector as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_19, vector),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3094,7 +2903,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_run__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hw as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hw),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3104,9 +2913,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).fail_entry as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, fail_entry),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3116,7 +2923,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).ex as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, ex),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3126,7 +2933,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).io as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, io),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3136,7 +2943,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).debug as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, debug),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3146,7 +2953,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).mmio as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, mmio),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3156,7 +2963,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hypercall as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hypercall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3166,9 +2973,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).tpr_access as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, tpr_access),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3178,9 +2983,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_sieic as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_sieic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3190,9 +2993,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_reset_flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_reset_flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3202,9 +3003,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_ucontrol as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_ucontrol),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3214,7 +3013,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).dcr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, dcr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3224,7 +3023,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).internal as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, internal),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3234,7 +3033,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).osi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, osi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3244,9 +3043,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).papr_hcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, papr_hcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3256,7 +3053,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_tsch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_tsch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3266,7 +3063,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).epr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, epr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3276,9 +3073,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).system_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, system_event),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3288,7 +3083,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_stsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_stsi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3298,7 +3093,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).eoi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, eoi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3308,7 +3103,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hyperv as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hyperv),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3318,7 +3113,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, padding),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3337,7 +3132,7 @@ impl Default for kvm_run__bindgen_ty_1 {
 #[derive(Copy, Clone)]
 pub union kvm_run__bindgen_ty_2 {
     pub regs: kvm_sync_regs,
-    pub padding: [::std::os::raw::c_char; 2048usize],
+    pub padding: [super::raw::c_char; 2048usize],
     _bindgen_union_align: [u64; 256usize],
 }
 #[test]
@@ -3353,7 +3148,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_2() {
         concat!("Alignment of ", stringify!(kvm_run__bindgen_ty_2))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_2>())).regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_2, regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3363,7 +3158,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_2>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_2, padding),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3391,9 +3186,7 @@ fn bindgen_test_layout_kvm_run() {
         concat!("Alignment of ", stringify!(kvm_run))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run>())).request_interrupt_window as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run, request_interrupt_window),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3403,7 +3196,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).immediate_exit as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, immediate_exit),
         1usize,
         concat!(
             "Offset of field: ",
@@ -3413,7 +3206,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).padding1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, padding1),
         2usize,
         concat!(
             "Offset of field: ",
@@ -3423,7 +3216,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).exit_reason as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, exit_reason),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3433,9 +3226,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run>())).ready_for_interrupt_injection as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run, ready_for_interrupt_injection),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3445,7 +3236,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).if_flag as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, if_flag),
         13usize,
         concat!(
             "Offset of field: ",
@@ -3455,7 +3246,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, flags),
         14usize,
         concat!(
             "Offset of field: ",
@@ -3465,7 +3256,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).cr8 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, cr8),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3475,7 +3266,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).apic_base as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, apic_base),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3485,7 +3276,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).kvm_valid_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, kvm_valid_regs),
         288usize,
         concat!(
             "Offset of field: ",
@@ -3495,7 +3286,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).kvm_dirty_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, kvm_dirty_regs),
         296usize,
         concat!(
             "Offset of field: ",
@@ -3505,7 +3296,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).s as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, s),
         304usize,
         concat!(
             "Offset of field: ",
@@ -3540,7 +3331,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio_zone))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3550,7 +3341,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3560,7 +3351,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3591,7 +3382,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).phys_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, phys_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3601,7 +3392,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3611,7 +3402,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3621,7 +3412,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, data),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3651,7 +3442,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio_ring))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).first as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, first),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3661,7 +3452,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).last as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, last),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3671,9 +3462,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).coalesced_mmio as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, coalesced_mmio),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3706,7 +3495,7 @@ fn bindgen_test_layout_kvm_translation() {
         concat!("Alignment of ", stringify!(kvm_translation))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).linear_address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, linear_address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3716,9 +3505,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_translation>())).physical_address as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_translation, physical_address),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3728,7 +3515,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).valid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, valid),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3738,7 +3525,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).writeable as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, writeable),
         17usize,
         concat!(
             "Offset of field: ",
@@ -3748,7 +3535,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).usermode as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, usermode),
         18usize,
         concat!(
             "Offset of field: ",
@@ -3758,7 +3545,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, pad),
         19usize,
         concat!(
             "Offset of field: ",
@@ -3792,7 +3579,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         concat!("Alignment of ", stringify!(kvm_s390_mem_op))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).gaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, gaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3802,7 +3589,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3812,7 +3599,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3822,7 +3609,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).op as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, op),
         20usize,
         concat!(
             "Offset of field: ",
@@ -3832,7 +3619,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).buf as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, buf),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3842,7 +3629,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).ar as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, ar),
         32usize,
         concat!(
             "Offset of field: ",
@@ -3852,7 +3639,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, reserved),
         33usize,
         concat!(
             "Offset of field: ",
@@ -3880,7 +3667,7 @@ fn bindgen_test_layout_kvm_interrupt() {
         concat!("Alignment of ", stringify!(kvm_interrupt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_interrupt>())).irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_interrupt, irq),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3900,7 +3687,7 @@ pub struct kvm_dirty_log {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union kvm_dirty_log__bindgen_ty_1 {
-    pub dirty_bitmap: *mut ::std::os::raw::c_void,
+    pub dirty_bitmap: *mut super::raw::c_void,
     pub padding2: __u64,
     _bindgen_union_align: u64,
 }
@@ -3917,10 +3704,7 @@ fn bindgen_test_layout_kvm_dirty_log__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_dirty_log__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_dirty_log__bindgen_ty_1>())).dirty_bitmap as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_dirty_log__bindgen_ty_1, dirty_bitmap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3930,9 +3714,7 @@ fn bindgen_test_layout_kvm_dirty_log__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_dirty_log__bindgen_ty_1>())).padding2 as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_dirty_log__bindgen_ty_1, padding2),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3960,7 +3742,7 @@ fn bindgen_test_layout_kvm_dirty_log() {
         concat!("Alignment of ", stringify!(kvm_dirty_log))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_log>())).slot as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_log, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3970,7 +3752,7 @@ fn bindgen_test_layout_kvm_dirty_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_log>())).padding1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_log, padding1),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4004,7 +3786,7 @@ fn bindgen_test_layout_kvm_signal_mask() {
         concat!("Alignment of ", stringify!(kvm_signal_mask))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_signal_mask>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_signal_mask, len),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4014,7 +3796,7 @@ fn bindgen_test_layout_kvm_signal_mask() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_signal_mask>())).sigset as *const _ as usize },
+        ::std::mem::offset_of!(kvm_signal_mask, sigset),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4044,7 +3826,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         concat!("Alignment of ", stringify!(kvm_tpr_access_ctl))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4054,7 +3836,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4064,7 +3846,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, reserved),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4092,7 +3874,7 @@ fn bindgen_test_layout_kvm_vapic_addr() {
         concat!("Alignment of ", stringify!(kvm_vapic_addr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vapic_addr>())).vapic_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vapic_addr, vapic_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4120,7 +3902,7 @@ fn bindgen_test_layout_kvm_mp_state() {
         concat!("Alignment of ", stringify!(kvm_mp_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_mp_state>())).mp_state as *const _ as usize },
+        ::std::mem::offset_of!(kvm_mp_state, mp_state),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4149,7 +3931,7 @@ fn bindgen_test_layout_kvm_s390_psw() {
         concat!("Alignment of ", stringify!(kvm_s390_psw))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_psw>())).mask as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_psw, mask),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4159,7 +3941,7 @@ fn bindgen_test_layout_kvm_s390_psw() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_psw>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_psw, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4189,7 +3971,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         concat!("Alignment of ", stringify!(kvm_s390_interrupt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4199,7 +3981,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).parm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4209,7 +3991,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).parm64 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, parm64),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4240,7 +4022,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         concat!("Alignment of ", stringify!(kvm_s390_io_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).subchannel_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, subchannel_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4250,7 +4032,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).subchannel_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, subchannel_nr),
         2usize,
         concat!(
             "Offset of field: ",
@@ -4260,7 +4042,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).io_int_parm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, io_int_parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4270,7 +4052,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).io_int_word as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, io_int_word),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4300,7 +4082,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         concat!("Alignment of ", stringify!(kvm_s390_ext_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).ext_params as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, ext_params),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4310,7 +4092,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4320,7 +4102,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).ext_params2 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, ext_params2),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4360,9 +4142,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         concat!("Alignment of ", stringify!(kvm_s390_pgm_info))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_pgm_info>())).trans_exc_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, trans_exc_code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4372,7 +4152,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).mon_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, mon_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4382,7 +4162,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_address),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4392,7 +4172,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).data_exc_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, data_exc_code),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4402,7 +4182,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, code),
         28usize,
         concat!(
             "Offset of field: ",
@@ -4412,7 +4192,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).mon_class_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, mon_class_nr),
         30usize,
         concat!(
             "Offset of field: ",
@@ -4422,7 +4202,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_code),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4432,7 +4212,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_atmid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_atmid),
         33usize,
         concat!(
             "Offset of field: ",
@@ -4442,7 +4222,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).exc_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, exc_access_id),
         34usize,
         concat!(
             "Offset of field: ",
@@ -4452,7 +4232,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_access_id),
         35usize,
         concat!(
             "Offset of field: ",
@@ -4462,7 +4242,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).op_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, op_access_id),
         36usize,
         concat!(
             "Offset of field: ",
@@ -4472,7 +4252,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, flags),
         37usize,
         concat!(
             "Offset of field: ",
@@ -4482,7 +4262,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, pad),
         38usize,
         concat!(
             "Offset of field: ",
@@ -4510,7 +4290,7 @@ fn bindgen_test_layout_kvm_s390_prefix_info() {
         concat!("Alignment of ", stringify!(kvm_s390_prefix_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_prefix_info>())).address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_prefix_info, address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4538,7 +4318,7 @@ fn bindgen_test_layout_kvm_s390_extcall_info() {
         concat!("Alignment of ", stringify!(kvm_s390_extcall_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_extcall_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_extcall_info, code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4566,7 +4346,7 @@ fn bindgen_test_layout_kvm_s390_emerg_info() {
         concat!("Alignment of ", stringify!(kvm_s390_emerg_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_emerg_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_emerg_info, code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4594,7 +4374,7 @@ fn bindgen_test_layout_kvm_s390_stop_info() {
         concat!("Alignment of ", stringify!(kvm_s390_stop_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_stop_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_stop_info, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4627,7 +4407,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         concat!("Alignment of ", stringify!(kvm_s390_mchk_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).cr14 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, cr14),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4637,7 +4417,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).mcic as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, mcic),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4647,10 +4427,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_mchk_info>())).failing_storage_address as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, failing_storage_address),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4660,9 +4437,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_mchk_info>())).ext_damage_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, ext_damage_code),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4672,7 +4447,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, pad),
         28usize,
         concat!(
             "Offset of field: ",
@@ -4682,7 +4457,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).fixed_logout as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, fixed_logout),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4709,7 +4484,7 @@ pub union kvm_s390_irq__bindgen_ty_1 {
     pub prefix: kvm_s390_prefix_info,
     pub stop: kvm_s390_stop_info,
     pub mchk: kvm_s390_mchk_info,
-    pub reserved: [::std::os::raw::c_char; 64usize],
+    pub reserved: [super::raw::c_char; 64usize],
     _bindgen_union_align: [u64; 8usize],
 }
 #[test]
@@ -4725,7 +4500,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_s390_irq__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).io as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, io),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4735,7 +4510,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).ext as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, ext),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4745,7 +4520,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).pgm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, pgm),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4755,9 +4530,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).emerg as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, emerg),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4767,9 +4540,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).extcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, extcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4779,9 +4550,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).prefix as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, prefix),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4791,7 +4560,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).stop as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, stop),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4801,7 +4570,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).mchk as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, mchk),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4811,9 +4580,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4841,7 +4608,7 @@ fn bindgen_test_layout_kvm_s390_irq() {
         concat!("Alignment of ", stringify!(kvm_s390_irq))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4851,7 +4618,7 @@ fn bindgen_test_layout_kvm_s390_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq, u),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4887,7 +4654,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         concat!("Alignment of ", stringify!(kvm_s390_irq_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).buf as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, buf),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4897,7 +4664,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4907,7 +4674,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, len),
         12usize,
         concat!(
             "Offset of field: ",
@@ -4917,7 +4684,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, reserved),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4947,7 +4714,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         concat!("Alignment of ", stringify!(kvm_guest_debug))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).control as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, control),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4957,7 +4724,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4967,7 +4734,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).arch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, arch),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5007,7 +4774,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         concat!("Alignment of ", stringify!(kvm_ioeventfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).datamatch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, datamatch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5017,7 +4784,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5027,7 +4794,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5037,7 +4804,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, fd),
         20usize,
         concat!(
             "Offset of field: ",
@@ -5047,7 +4814,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5057,7 +4824,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, pad),
         28usize,
         concat!(
             "Offset of field: ",
@@ -5093,7 +4860,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         concat!("Alignment of ", stringify!(kvm_enable_cap))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).cap as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, cap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5103,7 +4870,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5113,7 +4880,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).args as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, args),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5123,7 +4890,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, pad),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5158,7 +4925,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         concat!("Alignment of ", stringify!(kvm_ppc_pvinfo))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5168,7 +4935,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).hcall as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, hcall),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5178,7 +4945,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, pad),
         20usize,
         concat!(
             "Offset of field: ",
@@ -5212,9 +4979,7 @@ fn bindgen_test_layout_kvm_ppc_one_page_size() {
         concat!("Alignment of ", stringify!(kvm_ppc_one_page_size))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_page_size>())).page_shift as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_page_size, page_shift),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5224,7 +4989,7 @@ fn bindgen_test_layout_kvm_ppc_one_page_size() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_one_page_size>())).pte_enc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_one_page_size, pte_enc),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5254,9 +5019,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         concat!("Alignment of ", stringify!(kvm_ppc_one_seg_page_size))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).page_shift as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, page_shift),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5266,9 +5029,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).slb_enc as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, slb_enc),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5278,7 +5039,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).enc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, enc),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5310,7 +5071,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         concat!("Alignment of ", stringify!(kvm_ppc_smmu_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5320,7 +5081,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).slb_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, slb_size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5330,7 +5091,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).data_keys as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, data_keys),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5340,7 +5101,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).instr_keys as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, instr_keys),
         14usize,
         concat!(
             "Offset of field: ",
@@ -5350,7 +5111,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).sps as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, sps),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5380,7 +5141,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         concat!("Alignment of ", stringify!(kvm_ppc_resize_hpt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5390,7 +5151,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).shift as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, shift),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5400,7 +5161,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5429,7 +5190,7 @@ fn bindgen_test_layout_kvm_irq_routing_irqchip() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_irqchip))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_irqchip>())).irqchip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_irqchip, irqchip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5439,7 +5200,7 @@ fn bindgen_test_layout_kvm_irq_routing_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_irqchip>())).pin as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_irqchip, pin),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5480,9 +5241,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_msi__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_msi__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5492,9 +5251,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_msi__bindgen_ty_1>())).devid as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_msi__bindgen_ty_1, devid),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5522,7 +5279,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_msi))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).address_lo as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, address_lo),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5532,7 +5289,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).address_hi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, address_hi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5542,7 +5299,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5579,9 +5336,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_s390_adapter))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).ind_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, ind_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5591,10 +5346,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).summary_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, summary_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5604,9 +5356,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).ind_offset as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, ind_offset),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5616,10 +5366,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).summary_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, summary_offset),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5629,9 +5376,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).adapter_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, adapter_id),
         28usize,
         concat!(
             "Offset of field: ",
@@ -5660,7 +5405,7 @@ fn bindgen_test_layout_kvm_irq_routing_hv_sint() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_hv_sint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_hv_sint>())).vcpu as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_hv_sint, vcpu),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5670,7 +5415,7 @@ fn bindgen_test_layout_kvm_irq_routing_hv_sint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_hv_sint>())).sint as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_hv_sint, sint),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5715,10 +5460,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).irqchip as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, irqchip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5728,9 +5470,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).msi as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, msi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5740,10 +5480,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).adapter as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, adapter),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5753,10 +5490,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).hv_sint as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, hv_sint),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5766,9 +5500,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5796,7 +5528,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_entry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, gsi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5806,7 +5538,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, type_),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5816,7 +5548,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5826,7 +5558,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5836,7 +5568,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, u),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5870,7 +5602,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         concat!("Alignment of ", stringify!(kvm_irq_routing))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5880,7 +5612,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5890,7 +5622,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).entries as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, entries),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5927,7 +5659,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         concat!("Alignment of ", stringify!(kvm_irqfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, fd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5937,7 +5669,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, gsi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5947,7 +5679,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5957,7 +5689,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).resamplefd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, resamplefd),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5967,7 +5699,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, pad),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5997,7 +5729,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         concat!("Alignment of ", stringify!(kvm_clock_data))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).clock as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, clock),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6007,7 +5739,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6017,7 +5749,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6048,7 +5780,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         concat!("Alignment of ", stringify!(kvm_config_tlb))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).params as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, params),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6058,7 +5790,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).array as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, array),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6068,7 +5800,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).mmu_type as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, mmu_type),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6078,7 +5810,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).array_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, array_len),
         20usize,
         concat!(
             "Offset of field: ",
@@ -6107,7 +5839,7 @@ fn bindgen_test_layout_kvm_dirty_tlb() {
         concat!("Alignment of ", stringify!(kvm_dirty_tlb))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_tlb>())).bitmap as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_tlb, bitmap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6117,7 +5849,7 @@ fn bindgen_test_layout_kvm_dirty_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_tlb>())).num_dirty as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_tlb, num_dirty),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6146,7 +5878,7 @@ fn bindgen_test_layout_kvm_reg_list() {
         concat!("Alignment of ", stringify!(kvm_reg_list))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_reg_list>())).n as *const _ as usize },
+        ::std::mem::offset_of!(kvm_reg_list, n),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6156,7 +5888,7 @@ fn bindgen_test_layout_kvm_reg_list() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_reg_list>())).reg as *const _ as usize },
+        ::std::mem::offset_of!(kvm_reg_list, reg),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6185,7 +5917,7 @@ fn bindgen_test_layout_kvm_one_reg() {
         concat!("Alignment of ", stringify!(kvm_one_reg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_one_reg>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_one_reg, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6195,7 +5927,7 @@ fn bindgen_test_layout_kvm_one_reg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_one_reg>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_one_reg, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6228,7 +5960,7 @@ fn bindgen_test_layout_kvm_msi() {
         concat!("Alignment of ", stringify!(kvm_msi))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).address_lo as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, address_lo),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6238,7 +5970,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).address_hi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, address_hi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6248,7 +5980,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6258,7 +5990,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6268,7 +6000,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).devid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, devid),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6278,7 +6010,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, pad),
         20usize,
         concat!(
             "Offset of field: ",
@@ -6307,7 +6039,7 @@ fn bindgen_test_layout_kvm_arm_device_addr() {
         concat!("Alignment of ", stringify!(kvm_arm_device_addr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_arm_device_addr>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_arm_device_addr, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6317,7 +6049,7 @@ fn bindgen_test_layout_kvm_arm_device_addr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_arm_device_addr>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_arm_device_addr, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6347,7 +6079,7 @@ fn bindgen_test_layout_kvm_create_device() {
         concat!("Alignment of ", stringify!(kvm_create_device))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6357,7 +6089,7 @@ fn bindgen_test_layout_kvm_create_device() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, fd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6367,7 +6099,7 @@ fn bindgen_test_layout_kvm_create_device() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6398,7 +6130,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         concat!("Alignment of ", stringify!(kvm_device_attr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6408,7 +6140,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).group as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, group),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6418,7 +6150,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).attr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, attr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6428,7 +6160,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, addr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6467,7 +6199,7 @@ fn bindgen_test_layout_kvm_vfio_spapr_tce() {
         concat!("Alignment of ", stringify!(kvm_vfio_spapr_tce))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vfio_spapr_tce>())).groupfd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vfio_spapr_tce, groupfd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6477,7 +6209,7 @@ fn bindgen_test_layout_kvm_vfio_spapr_tce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vfio_spapr_tce>())).tablefd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vfio_spapr_tce, tablefd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6507,7 +6239,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         concat!("Alignment of ", stringify!(kvm_s390_ucas_mapping))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).user_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, user_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6517,7 +6249,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).vcpu_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, vcpu_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6527,7 +6259,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).length as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, length),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6569,10 +6301,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_pci_dev__bindgen_ty_1>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6600,9 +6329,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         concat!("Alignment of ", stringify!(kvm_assigned_pci_dev))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6612,7 +6339,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).busnr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, busnr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6622,7 +6349,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).devfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, devfn),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6632,7 +6359,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6642,7 +6369,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).segnr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, segnr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6685,9 +6412,7 @@ fn bindgen_test_layout_kvm_assigned_irq__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_assigned_irq__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_irq__bindgen_ty_1>())).reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_irq__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6715,9 +6440,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         concat!("Alignment of ", stringify!(kvm_assigned_irq))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_irq>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_irq, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6727,7 +6450,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).host_irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, host_irq),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6737,7 +6460,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).guest_irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, guest_irq),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6747,7 +6470,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6782,9 +6505,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         concat!("Alignment of ", stringify!(kvm_assigned_msix_nr))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6794,7 +6515,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).entry_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, entry_nr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6804,7 +6525,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, padding),
         6usize,
         concat!(
             "Offset of field: ",
@@ -6835,9 +6556,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         concat!("Alignment of ", stringify!(kvm_assigned_msix_entry))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6847,7 +6566,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, gsi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6857,7 +6576,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).entry as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, entry),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6867,7 +6586,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, padding),
         10usize,
         concat!(
             "Offset of field: ",
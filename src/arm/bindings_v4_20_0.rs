@@ -624,18 +624,18 @@ pub const KVM_ARM_DEV_EL1_PTIMER: u32 = 2;
 pub const KVM_ARM_DEV_PMU: u32 = 4;
 pub const KVM_HYPERV_CONN_ID_MASK: u32 = 16777215;
 pub const KVM_HYPERV_EVENTFD_DEASSIGN: u32 = 1;
-pub type __s8 = ::std::os::raw::c_schar;
-pub type __u8 = ::std::os::raw::c_uchar;
-pub type __s16 = ::std::os::raw::c_short;
-pub type __u16 = ::std::os::raw::c_ushort;
-pub type __s32 = ::std::os::raw::c_int;
-pub type __u32 = ::std::os::raw::c_uint;
-pub type __s64 = ::std::os::raw::c_longlong;
-pub type __u64 = ::std::os::raw::c_ulonglong;
+pub type __s8 = super::raw::c_schar;
+pub type __u8 = super::raw::c_uchar;
+pub type __s16 = super::raw::c_short;
+pub type __u16 = super::raw::c_ushort;
+pub type __s32 = super::raw::c_int;
+pub type __u32 = super::raw::c_uint;
+pub type __s64 = super::raw::c_longlong;
+pub type __u64 = super::raw::c_ulonglong;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct __kernel_fd_set {
-    pub fds_bits: [::std::os::raw::c_ulong; 16usize],
+    pub fds_bits: [super::raw::c_ulong; 32usize],
 }
 #[test]
 fn bindgen_test_layout___kernel_fd_set() {
@@ -646,11 +646,11 @@ fn bindgen_test_layout___kernel_fd_set() {
     );
     assert_eq!(
         ::std::mem::align_of::<__kernel_fd_set>(),
-        8usize,
+        4usize,
         concat!("Alignment of ", stringify!(__kernel_fd_set))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__kernel_fd_set>())).fds_bits as *const _ as usize },
+        ::std::mem::offset_of!(__kernel_fd_set, fds_bits),
         0usize,
         concat!(
             "Offset of field: ",
@@ -661,31 +661,31 @@ fn bindgen_test_layout___kernel_fd_set() {
     );
 }
 pub type __kernel_sighandler_t =
-    ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>;
-pub type __kernel_key_t = ::std::os::raw::c_int;
-pub type __kernel_mqd_t = ::std::os::raw::c_int;
-pub type __kernel_mode_t = ::std::os::raw::c_ushort;
-pub type __kernel_ipc_pid_t = ::std::os::raw::c_ushort;
-pub type __kernel_uid_t = ::std::os::raw::c_ushort;
-pub type __kernel_gid_t = ::std::os::raw::c_ushort;
-pub type __kernel_old_dev_t = ::std::os::raw::c_ushort;
-pub type __kernel_long_t = ::std::os::raw::c_long;
-pub type __kernel_ulong_t = ::std::os::raw::c_ulong;
+    ::std::option::Option<unsafe extern "C" fn(arg1: super::raw::c_int)>;
+pub type __kernel_key_t = super::raw::c_int;
+pub type __kernel_mqd_t = super::raw::c_int;
+pub type __kernel_mode_t = super::raw::c_ushort;
+pub type __kernel_ipc_pid_t = super::raw::c_ushort;
+pub type __kernel_uid_t = super::raw::c_ushort;
+pub type __kernel_gid_t = super::raw::c_ushort;
+pub type __kernel_old_dev_t = super::raw::c_ushort;
+pub type __kernel_long_t = super::raw::c_long;
+pub type __kernel_ulong_t = super::raw::c_ulong;
 pub type __kernel_ino_t = __kernel_ulong_t;
-pub type __kernel_pid_t = ::std::os::raw::c_int;
+pub type __kernel_pid_t = super::raw::c_int;
 pub type __kernel_suseconds_t = __kernel_long_t;
-pub type __kernel_daddr_t = ::std::os::raw::c_int;
-pub type __kernel_uid32_t = ::std::os::raw::c_uint;
-pub type __kernel_gid32_t = ::std::os::raw::c_uint;
+pub type __kernel_daddr_t = super::raw::c_int;
+pub type __kernel_uid32_t = super::raw::c_uint;
+pub type __kernel_gid32_t = super::raw::c_uint;
 pub type __kernel_old_uid_t = __kernel_uid_t;
 pub type __kernel_old_gid_t = __kernel_gid_t;
-pub type __kernel_size_t = ::std::os::raw::c_uint;
-pub type __kernel_ssize_t = ::std::os::raw::c_int;
-pub type __kernel_ptrdiff_t = ::std::os::raw::c_int;
+pub type __kernel_size_t = super::raw::c_uint;
+pub type __kernel_ssize_t = super::raw::c_int;
+pub type __kernel_ptrdiff_t = super::raw::c_int;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct __kernel_fsid_t {
-    pub val: [::std::os::raw::c_int; 2usize],
+    pub val: [super::raw::c_int; 2usize],
 }
 #[test]
 fn bindgen_test_layout___kernel_fsid_t() {
@@ -700,7 +700,7 @@ fn bindgen_test_layout___kernel_fsid_t() {
         concat!("Alignment of ", stringify!(__kernel_fsid_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__kernel_fsid_t>())).val as *const _ as usize },
+        ::std::mem::offset_of!(__kernel_fsid_t, val),
         0usize,
         concat!(
             "Offset of field: ",
@@ -711,15 +711,15 @@ fn bindgen_test_layout___kernel_fsid_t() {
     );
 }
 pub type __kernel_off_t = __kernel_long_t;
-pub type __kernel_loff_t = ::std::os::raw::c_longlong;
+pub type __kernel_loff_t = super::raw::c_longlong;
 pub type __kernel_time_t = __kernel_long_t;
-pub type __kernel_time64_t = ::std::os::raw::c_longlong;
+pub type __kernel_time64_t = super::raw::c_longlong;
 pub type __kernel_clock_t = __kernel_long_t;
-pub type __kernel_timer_t = ::std::os::raw::c_int;
-pub type __kernel_clockid_t = ::std::os::raw::c_int;
-pub type __kernel_caddr_t = *mut ::std::os::raw::c_char;
-pub type __kernel_uid16_t = ::std::os::raw::c_ushort;
-pub type __kernel_gid16_t = ::std::os::raw::c_ushort;
+pub type __kernel_timer_t = super::raw::c_int;
+pub type __kernel_clockid_t = super::raw::c_int;
+pub type __kernel_caddr_t = *mut super::raw::c_char;
+pub type __kernel_uid16_t = super::raw::c_ushort;
+pub type __kernel_gid16_t = super::raw::c_ushort;
 pub type __le16 = __u16;
 pub type __be16 = __u16;
 pub type __le32 = __u32;
@@ -728,26 +728,26 @@ pub type __le64 = __u64;
 pub type __be64 = __u64;
 pub type __sum16 = __u16;
 pub type __wsum = __u32;
-pub type __poll_t = ::std::os::raw::c_uint;
+pub type __poll_t = super::raw::c_uint;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct pt_regs {
-    pub uregs: [::std::os::raw::c_long; 18usize],
+    pub uregs: [super::raw::c_long; 18usize],
 }
 #[test]
 fn bindgen_test_layout_pt_regs() {
     assert_eq!(
         ::std::mem::size_of::<pt_regs>(),
-        144usize,
+        72usize,
         concat!("Size of: ", stringify!(pt_regs))
     );
     assert_eq!(
         ::std::mem::align_of::<pt_regs>(),
-        8usize,
+        4usize,
         concat!("Alignment of ", stringify!(pt_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pt_regs>())).uregs as *const _ as usize },
+        ::std::mem::offset_of!(pt_regs, uregs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -761,26 +761,26 @@ fn bindgen_test_layout_pt_regs() {
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct kvm_regs {
     pub usr_regs: pt_regs,
-    pub svc_regs: [::std::os::raw::c_ulong; 3usize],
-    pub abt_regs: [::std::os::raw::c_ulong; 3usize],
-    pub und_regs: [::std::os::raw::c_ulong; 3usize],
-    pub irq_regs: [::std::os::raw::c_ulong; 3usize],
-    pub fiq_regs: [::std::os::raw::c_ulong; 8usize],
+    pub svc_regs: [super::raw::c_ulong; 3usize],
+    pub abt_regs: [super::raw::c_ulong; 3usize],
+    pub und_regs: [super::raw::c_ulong; 3usize],
+    pub irq_regs: [super::raw::c_ulong; 3usize],
+    pub fiq_regs: [super::raw::c_ulong; 8usize],
 }
 #[test]
 fn bindgen_test_layout_kvm_regs() {
     assert_eq!(
         ::std::mem::size_of::<kvm_regs>(),
-        304usize,
+        152usize,
         concat!("Size of: ", stringify!(kvm_regs))
     );
     assert_eq!(
         ::std::mem::align_of::<kvm_regs>(),
-        8usize,
+        4usize,
         concat!("Alignment of ", stringify!(kvm_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).usr_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, usr_regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -790,8 +790,8 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).svc_regs as *const _ as usize },
-        144usize,
+        ::std::mem::offset_of!(kvm_regs, svc_regs),
+        72usize,
         concat!(
             "Offset of field: ",
             stringify!(kvm_regs),
@@ -800,8 +800,8 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).abt_regs as *const _ as usize },
-        168usize,
+        ::std::mem::offset_of!(kvm_regs, abt_regs),
+        84usize,
         concat!(
             "Offset of field: ",
             stringify!(kvm_regs),
@@ -810,8 +810,8 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).und_regs as *const _ as usize },
-        192usize,
+        ::std::mem::offset_of!(kvm_regs, und_regs),
+        96usize,
         concat!(
             "Offset of field: ",
             stringify!(kvm_regs),
@@ -820,8 +820,8 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).irq_regs as *const _ as usize },
-        216usize,
+        ::std::mem::offset_of!(kvm_regs, irq_regs),
+        108usize,
         concat!(
             "Offset of field: ",
             stringify!(kvm_regs),
@@ -830,8 +830,8 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).fiq_regs as *const _ as usize },
-        240usize,
+        ::std::mem::offset_of!(kvm_regs, fiq_regs),
+        120usize,
         concat!(
             "Offset of field: ",
             stringify!(kvm_regs),
@@ -859,7 +859,7 @@ fn bindgen_test_layout_kvm_vcpu_init() {
         concat!("Alignment of ", stringify!(kvm_vcpu_init))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_init>())).target as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_init, target),
         0usize,
         concat!(
             "Offset of field: ",
@@ -869,7 +869,7 @@ fn bindgen_test_layout_kvm_vcpu_init() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_init>())).features as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_init, features),
         4usize,
         concat!(
             "Offset of field: ",
@@ -961,7 +961,7 @@ fn bindgen_test_layout_kvm_sync_regs() {
         concat!("Alignment of ", stringify!(kvm_sync_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sync_regs>())).device_irq_level as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sync_regs, device_irq_level),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1014,10 +1014,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_vcpu_events__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).serror_pending as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, serror_pending),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1027,10 +1024,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).serror_has_esr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, serror_has_esr),
         1usize,
         concat!(
             "Offset of field: ",
@@ -1040,9 +1034,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, pad),
         2usize,
         concat!(
             "Offset of field: ",
@@ -1052,10 +1044,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).serror_esr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, serror_esr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1078,7 +1067,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         concat!("Alignment of ", stringify!(kvm_vcpu_events))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).exception as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, exception),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1088,7 +1077,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, reserved),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1117,7 +1106,7 @@ fn bindgen_test_layout_kvm_user_trace_setup() {
         concat!("Alignment of ", stringify!(kvm_user_trace_setup))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_user_trace_setup>())).buf_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_user_trace_setup, buf_size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1127,7 +1116,7 @@ fn bindgen_test_layout_kvm_user_trace_setup() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_user_trace_setup>())).buf_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_user_trace_setup, buf_nr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1157,7 +1146,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         concat!("Alignment of ", stringify!(kvm_breakpoint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1167,7 +1156,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, padding),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1177,7 +1166,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, address),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1208,7 +1197,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         concat!("Alignment of ", stringify!(kvm_debug_guest))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1218,7 +1207,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1228,7 +1217,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).breakpoints as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, breakpoints),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1238,7 +1227,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).singlestep as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, singlestep),
         72usize,
         concat!(
             "Offset of field: ",
@@ -1269,7 +1258,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         concat!("Alignment of ", stringify!(kvm_memory_region))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).slot as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1279,7 +1268,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1289,9 +1278,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_memory_region>())).guest_phys_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_memory_region, guest_phys_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1301,7 +1288,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).memory_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, memory_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1333,9 +1320,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         concat!("Alignment of ", stringify!(kvm_userspace_memory_region))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).slot as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1345,9 +1330,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1357,10 +1340,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).guest_phys_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, guest_phys_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1370,9 +1350,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).memory_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, memory_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1382,10 +1360,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).userspace_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, userspace_addr),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1421,7 +1396,7 @@ fn bindgen_test_layout_kvm_irq_level__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_irq_level__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_level__bindgen_ty_1>())).irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_level__bindgen_ty_1, irq),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1431,9 +1406,7 @@ fn bindgen_test_layout_kvm_irq_level__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_level__bindgen_ty_1>())).status as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_level__bindgen_ty_1, status),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1461,7 +1434,7 @@ fn bindgen_test_layout_kvm_irq_level() {
         concat!("Alignment of ", stringify!(kvm_irq_level))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_level>())).level as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_level, level),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1486,7 +1459,7 @@ pub struct kvm_irqchip {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union kvm_irqchip__bindgen_ty_1 {
-    pub dummy: [::std::os::raw::c_char; 512usize],
+    pub dummy: [super::raw::c_char; 512usize],
     _bindgen_union_align: [u8; 512usize],
 }
 #[test]
@@ -1502,7 +1475,7 @@ fn bindgen_test_layout_kvm_irqchip__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_irqchip__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip__bindgen_ty_1>())).dummy as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip__bindgen_ty_1, dummy),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1530,7 +1503,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         concat!("Alignment of ", stringify!(kvm_irqchip))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).chip_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, chip_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1540,7 +1513,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1550,7 +1523,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).chip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, chip),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1584,7 +1557,7 @@ fn bindgen_test_layout_kvm_pit_config() {
         concat!("Alignment of ", stringify!(kvm_pit_config))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_config>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_config, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1594,7 +1567,7 @@ fn bindgen_test_layout_kvm_pit_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_config>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_config, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1626,7 +1599,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         concat!("Alignment of ", stringify!(kvm_s390_skeys))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).start_gfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, start_gfn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1636,7 +1609,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).count as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, count),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1646,7 +1619,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).skeydata_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, skeydata_addr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1656,7 +1629,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1666,7 +1639,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, reserved),
         28usize,
         concat!(
             "Offset of field: ",
@@ -1719,10 +1692,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_s390_cmma_log__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_cmma_log__bindgen_ty_1>())).remaining as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_cmma_log__bindgen_ty_1, remaining),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1732,9 +1702,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_cmma_log__bindgen_ty_1>())).mask as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_cmma_log__bindgen_ty_1, mask),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1762,7 +1730,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         concat!("Alignment of ", stringify!(kvm_s390_cmma_log))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).start_gfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, start_gfn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1772,7 +1740,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).count as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, count),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1782,7 +1750,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -1792,7 +1760,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).values as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, values),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1847,10 +1815,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).msr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, msr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1860,10 +1825,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).control
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, control),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1873,10 +1835,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).evt_page
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, evt_page),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1886,10 +1845,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).msg_page
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, msg_page),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1925,10 +1881,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).input
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, input),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1938,10 +1891,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).result
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, result),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1951,10 +1901,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).params
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, params),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1977,9 +1924,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_hyperv_exit__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1>())).synic as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1, synic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1989,9 +1934,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1>())).hcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1, hcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2019,7 +1962,7 @@ fn bindgen_test_layout_kvm_hyperv_exit() {
         concat!("Alignment of ", stringify!(kvm_hyperv_exit))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_exit>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_exit, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2029,7 +1972,7 @@ fn bindgen_test_layout_kvm_hyperv_exit() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_exit>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_exit, u),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2085,7 +2028,7 @@ pub union kvm_run__bindgen_ty_1 {
     pub s390_stsi: kvm_run__bindgen_ty_1__bindgen_ty_18,
     pub eoi: kvm_run__bindgen_ty_1__bindgen_ty_19,
     pub hyperv: kvm_hyperv_exit,
-    pub padding: [::std::os::raw::c_char; 256usize],
+    pub padding: [super::raw::c_char; 256usize],
     _bindgen_union_align: [u64; 32usize],
 }
 #[repr(C)]
@@ -2109,10 +2052,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_1>())).hardware_exit_reason
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_1, hardware_exit_reason),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2143,10 +2083,10 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_2>()))
-                .hardware_entry_failure_reason as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            kvm_run__bindgen_ty_1__bindgen_ty_2,
+            hardware_entry_failure_reason
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2178,10 +2118,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_3>())).exception as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_3, exception),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2191,10 +2128,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_3>())).error_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_3, error_code),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2229,10 +2163,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).direction as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, direction),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2242,10 +2173,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, size),
         1usize,
         concat!(
             "Offset of field: ",
@@ -2255,10 +2183,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).port as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, port),
         2usize,
         concat!(
             "Offset of field: ",
@@ -2268,10 +2193,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).count as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, count),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2281,10 +2203,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).data_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, data_offset),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2315,10 +2234,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_5() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_5>())).arch as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_5, arch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2352,10 +2268,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).phys_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, phys_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2365,10 +2278,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2378,9 +2288,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).len as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2390,10 +2298,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, is_write),
         20usize,
         concat!(
             "Offset of field: ",
@@ -2428,9 +2333,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2440,10 +2343,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).args as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, args),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2453,9 +2353,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).ret as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, ret),
         56usize,
         concat!(
             "Offset of field: ",
@@ -2465,10 +2363,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).longmode as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, longmode),
         64usize,
         concat!(
             "Offset of field: ",
@@ -2478,9 +2373,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, pad),
         68usize,
         concat!(
             "Offset of field: ",
@@ -2513,9 +2406,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).rip as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, rip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2525,10 +2416,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, is_write),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2538,9 +2426,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2573,10 +2459,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).icptcode as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, icptcode),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2586,9 +2469,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).ipa as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, ipa),
         2usize,
         concat!(
             "Offset of field: ",
@@ -2598,9 +2479,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).ipb as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, ipb),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2635,10 +2514,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_10() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_10>())).trans_exc_code
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_10, trans_exc_code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2648,10 +2524,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_10() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_10>())).pgm_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_10, pgm_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2687,10 +2560,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).dcrn as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, dcrn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2700,10 +2570,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, data),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2713,10 +2580,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, is_write),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2752,10 +2616,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).suberror as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, suberror),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2765,10 +2626,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).ndata as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, ndata),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2778,10 +2636,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2815,10 +2670,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_13() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_13>())).gprs as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_13, gprs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2854,9 +2706,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2866,10 +2716,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).ret as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, ret),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2879,10 +2726,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).args as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, args),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2921,10 +2765,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).subchannel_id
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, subchannel_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2934,10 +2775,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).subchannel_nr
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, subchannel_nr),
         2usize,
         concat!(
             "Offset of field: ",
@@ -2947,10 +2785,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).io_int_parm as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, io_int_parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2960,10 +2795,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).io_int_word as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, io_int_word),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2973,10 +2805,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).ipb as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, ipb),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2986,10 +2815,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).dequeued as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, dequeued),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3023,10 +2849,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_16() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_16>())).epr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_16, epr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3061,10 +2884,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_17() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_17>())).type_ as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_17, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3074,10 +2894,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_17() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_17>())).flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_17, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3116,10 +2933,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3129,9 +2943,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).ar as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, ar),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3141,10 +2953,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, reserved),
         9usize,
         concat!(
             "Offset of field: ",
@@ -3154,9 +2963,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).fc as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, fc),
         10usize,
         concat!(
             "Offset of field: ",
@@ -3166,10 +2973,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).sel1 as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, sel1),
         11usize,
         concat!(
             "Offset of field: ",
@@ -3179,10 +2983,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).sel2 as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, sel2),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3216,10 +3017,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_19() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_19>())).vector as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_19, vector),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3242,7 +3040,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_run__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hw as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hw),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3252,9 +3050,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).fail_entry as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, fail_entry),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3264,7 +3060,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).ex as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, ex),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3274,7 +3070,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).io as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, io),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3284,7 +3080,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).debug as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, debug),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3294,7 +3090,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).mmio as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, mmio),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3304,7 +3100,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hypercall as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hypercall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3314,9 +3110,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).tpr_access as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, tpr_access),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3326,9 +3120,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_sieic as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_sieic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3338,9 +3130,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_reset_flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_reset_flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3350,9 +3140,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_ucontrol as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_ucontrol),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3362,7 +3150,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).dcr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, dcr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3372,7 +3160,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).internal as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, internal),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3382,7 +3170,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).osi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, osi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3392,9 +3180,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).papr_hcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, papr_hcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3404,7 +3190,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_tsch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_tsch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3414,7 +3200,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).epr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, epr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3424,9 +3210,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).system_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, system_event),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3436,7 +3220,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_stsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_stsi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3446,7 +3230,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).eoi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, eoi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3456,7 +3240,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hyperv as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hyperv),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3466,7 +3250,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, padding),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3485,7 +3269,7 @@ impl Default for kvm_run__bindgen_ty_1 {
 #[derive(Copy, Clone)]
 pub union kvm_run__bindgen_ty_2 {
     pub regs: kvm_sync_regs,
-    pub padding: [::std::os::raw::c_char; 2048usize],
+    pub padding: [super::raw::c_char; 2048usize],
     _bindgen_union_align: [u64; 256usize],
 }
 #[test]
@@ -3501,7 +3285,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_2() {
         concat!("Alignment of ", stringify!(kvm_run__bindgen_ty_2))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_2>())).regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_2, regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3511,7 +3295,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_2>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_2, padding),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3539,9 +3323,7 @@ fn bindgen_test_layout_kvm_run() {
         concat!("Alignment of ", stringify!(kvm_run))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run>())).request_interrupt_window as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run, request_interrupt_window),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3551,7 +3333,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).immediate_exit as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, immediate_exit),
         1usize,
         concat!(
             "Offset of field: ",
@@ -3561,7 +3343,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).padding1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, padding1),
         2usize,
         concat!(
             "Offset of field: ",
@@ -3571,7 +3353,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).exit_reason as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, exit_reason),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3581,9 +3363,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run>())).ready_for_interrupt_injection as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run, ready_for_interrupt_injection),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3593,7 +3373,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).if_flag as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, if_flag),
         13usize,
         concat!(
             "Offset of field: ",
@@ -3603,7 +3383,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, flags),
         14usize,
         concat!(
             "Offset of field: ",
@@ -3613,7 +3393,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).cr8 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, cr8),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3623,7 +3403,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).apic_base as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, apic_base),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3633,7 +3413,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).kvm_valid_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, kvm_valid_regs),
         288usize,
         concat!(
             "Offset of field: ",
@@ -3643,7 +3423,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).kvm_dirty_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, kvm_dirty_regs),
         296usize,
         concat!(
             "Offset of field: ",
@@ -3653,7 +3433,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).s as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, s),
         304usize,
         concat!(
             "Offset of field: ",
@@ -3701,10 +3481,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio_zone__bindgen_ty_1>())).pad as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3714,10 +3491,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio_zone__bindgen_ty_1>())).pio as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone__bindgen_ty_1, pio),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3745,7 +3519,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio_zone))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3755,7 +3529,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3801,9 +3575,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3813,9 +3585,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio__bindgen_ty_1>())).pio as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio__bindgen_ty_1, pio),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3843,7 +3613,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).phys_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, phys_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3853,7 +3623,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3863,7 +3633,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, data),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3897,7 +3667,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio_ring))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).first as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, first),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3907,7 +3677,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).last as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, last),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3917,9 +3687,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).coalesced_mmio as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, coalesced_mmio),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3957,7 +3725,7 @@ fn bindgen_test_layout_kvm_translation() {
         concat!("Alignment of ", stringify!(kvm_translation))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).linear_address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, linear_address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3967,9 +3735,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_translation>())).physical_address as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_translation, physical_address),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3979,7 +3745,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).valid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, valid),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3989,7 +3755,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).writeable as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, writeable),
         17usize,
         concat!(
             "Offset of field: ",
@@ -3999,7 +3765,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).usermode as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, usermode),
         18usize,
         concat!(
             "Offset of field: ",
@@ -4009,7 +3775,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, pad),
         19usize,
         concat!(
             "Offset of field: ",
@@ -4043,7 +3809,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         concat!("Alignment of ", stringify!(kvm_s390_mem_op))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).gaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, gaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4053,7 +3819,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4063,7 +3829,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4073,7 +3839,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).op as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, op),
         20usize,
         concat!(
             "Offset of field: ",
@@ -4083,7 +3849,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).buf as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, buf),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4093,7 +3859,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).ar as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, ar),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4103,7 +3869,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, reserved),
         33usize,
         concat!(
             "Offset of field: ",
@@ -4131,7 +3897,7 @@ fn bindgen_test_layout_kvm_interrupt() {
         concat!("Alignment of ", stringify!(kvm_interrupt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_interrupt>())).irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_interrupt, irq),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4151,7 +3917,7 @@ pub struct kvm_dirty_log {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union kvm_dirty_log__bindgen_ty_1 {
-    pub dirty_bitmap: *mut ::std::os::raw::c_void,
+    pub dirty_bitmap: *mut super::raw::c_void,
     pub padding2: __u64,
     _bindgen_union_align: u64,
 }
@@ -4168,10 +3934,7 @@ fn bindgen_test_layout_kvm_dirty_log__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_dirty_log__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_dirty_log__bindgen_ty_1>())).dirty_bitmap as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_dirty_log__bindgen_ty_1, dirty_bitmap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4181,9 +3944,7 @@ fn bindgen_test_layout_kvm_dirty_log__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_dirty_log__bindgen_ty_1>())).padding2 as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_dirty_log__bindgen_ty_1, padding2),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4211,7 +3972,7 @@ fn bindgen_test_layout_kvm_dirty_log() {
         concat!("Alignment of ", stringify!(kvm_dirty_log))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_log>())).slot as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_log, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4221,7 +3982,7 @@ fn bindgen_test_layout_kvm_dirty_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_log>())).padding1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_log, padding1),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4255,7 +4016,7 @@ fn bindgen_test_layout_kvm_signal_mask() {
         concat!("Alignment of ", stringify!(kvm_signal_mask))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_signal_mask>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_signal_mask, len),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4265,7 +4026,7 @@ fn bindgen_test_layout_kvm_signal_mask() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_signal_mask>())).sigset as *const _ as usize },
+        ::std::mem::offset_of!(kvm_signal_mask, sigset),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4295,7 +4056,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         concat!("Alignment of ", stringify!(kvm_tpr_access_ctl))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4305,7 +4066,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4315,7 +4076,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, reserved),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4343,7 +4104,7 @@ fn bindgen_test_layout_kvm_vapic_addr() {
         concat!("Alignment of ", stringify!(kvm_vapic_addr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vapic_addr>())).vapic_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vapic_addr, vapic_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4371,7 +4132,7 @@ fn bindgen_test_layout_kvm_mp_state() {
         concat!("Alignment of ", stringify!(kvm_mp_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_mp_state>())).mp_state as *const _ as usize },
+        ::std::mem::offset_of!(kvm_mp_state, mp_state),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4400,7 +4161,7 @@ fn bindgen_test_layout_kvm_s390_psw() {
         concat!("Alignment of ", stringify!(kvm_s390_psw))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_psw>())).mask as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_psw, mask),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4410,7 +4171,7 @@ fn bindgen_test_layout_kvm_s390_psw() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_psw>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_psw, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4440,7 +4201,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         concat!("Alignment of ", stringify!(kvm_s390_interrupt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4450,7 +4211,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).parm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4460,7 +4221,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).parm64 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, parm64),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4491,7 +4252,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         concat!("Alignment of ", stringify!(kvm_s390_io_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).subchannel_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, subchannel_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4501,7 +4262,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).subchannel_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, subchannel_nr),
         2usize,
         concat!(
             "Offset of field: ",
@@ -4511,7 +4272,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).io_int_parm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, io_int_parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4521,7 +4282,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).io_int_word as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, io_int_word),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4551,7 +4312,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         concat!("Alignment of ", stringify!(kvm_s390_ext_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).ext_params as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, ext_params),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4561,7 +4322,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4571,7 +4332,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).ext_params2 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, ext_params2),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4611,9 +4372,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         concat!("Alignment of ", stringify!(kvm_s390_pgm_info))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_pgm_info>())).trans_exc_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, trans_exc_code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4623,7 +4382,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).mon_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, mon_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4633,7 +4392,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_address),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4643,7 +4402,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).data_exc_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, data_exc_code),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4653,7 +4412,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, code),
         28usize,
         concat!(
             "Offset of field: ",
@@ -4663,7 +4422,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).mon_class_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, mon_class_nr),
         30usize,
         concat!(
             "Offset of field: ",
@@ -4673,7 +4432,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_code),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4683,7 +4442,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_atmid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_atmid),
         33usize,
         concat!(
             "Offset of field: ",
@@ -4693,7 +4452,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).exc_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, exc_access_id),
         34usize,
         concat!(
             "Offset of field: ",
@@ -4703,7 +4462,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_access_id),
         35usize,
         concat!(
             "Offset of field: ",
@@ -4713,7 +4472,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).op_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, op_access_id),
         36usize,
         concat!(
             "Offset of field: ",
@@ -4723,7 +4482,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, flags),
         37usize,
         concat!(
             "Offset of field: ",
@@ -4733,7 +4492,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, pad),
         38usize,
         concat!(
             "Offset of field: ",
@@ -4761,7 +4520,7 @@ fn bindgen_test_layout_kvm_s390_prefix_info() {
         concat!("Alignment of ", stringify!(kvm_s390_prefix_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_prefix_info>())).address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_prefix_info, address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4789,7 +4548,7 @@ fn bindgen_test_layout_kvm_s390_extcall_info() {
         concat!("Alignment of ", stringify!(kvm_s390_extcall_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_extcall_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_extcall_info, code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4817,7 +4576,7 @@ fn bindgen_test_layout_kvm_s390_emerg_info() {
         concat!("Alignment of ", stringify!(kvm_s390_emerg_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_emerg_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_emerg_info, code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4845,7 +4604,7 @@ fn bindgen_test_layout_kvm_s390_stop_info() {
         concat!("Alignment of ", stringify!(kvm_s390_stop_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_stop_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_stop_info, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4878,7 +4637,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         concat!("Alignment of ", stringify!(kvm_s390_mchk_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).cr14 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, cr14),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4888,7 +4647,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).mcic as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, mcic),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4898,10 +4657,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_mchk_info>())).failing_storage_address as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, failing_storage_address),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4911,9 +4667,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_mchk_info>())).ext_damage_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, ext_damage_code),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4923,7 +4677,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, pad),
         28usize,
         concat!(
             "Offset of field: ",
@@ -4933,7 +4687,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).fixed_logout as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, fixed_logout),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4960,7 +4714,7 @@ pub union kvm_s390_irq__bindgen_ty_1 {
     pub prefix: kvm_s390_prefix_info,
     pub stop: kvm_s390_stop_info,
     pub mchk: kvm_s390_mchk_info,
-    pub reserved: [::std::os::raw::c_char; 64usize],
+    pub reserved: [super::raw::c_char; 64usize],
     _bindgen_union_align: [u64; 8usize],
 }
 #[test]
@@ -4976,7 +4730,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_s390_irq__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).io as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, io),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4986,7 +4740,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).ext as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, ext),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4996,7 +4750,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).pgm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, pgm),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5006,9 +4760,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).emerg as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, emerg),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5018,9 +4770,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).extcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, extcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5030,9 +4780,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).prefix as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, prefix),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5042,7 +4790,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).stop as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, stop),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5052,7 +4800,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).mchk as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, mchk),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5062,9 +4810,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5092,7 +4838,7 @@ fn bindgen_test_layout_kvm_s390_irq() {
         concat!("Alignment of ", stringify!(kvm_s390_irq))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5102,7 +4848,7 @@ fn bindgen_test_layout_kvm_s390_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq, u),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5138,7 +4884,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         concat!("Alignment of ", stringify!(kvm_s390_irq_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).buf as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, buf),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5148,7 +4894,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5158,7 +4904,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, len),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5168,7 +4914,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, reserved),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5198,7 +4944,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         concat!("Alignment of ", stringify!(kvm_guest_debug))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).control as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, control),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5208,7 +4954,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5218,7 +4964,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).arch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, arch),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5258,7 +5004,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         concat!("Alignment of ", stringify!(kvm_ioeventfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).datamatch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, datamatch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5268,7 +5014,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5278,7 +5024,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5288,7 +5034,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, fd),
         20usize,
         concat!(
             "Offset of field: ",
@@ -5298,7 +5044,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5308,7 +5054,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, pad),
         28usize,
         concat!(
             "Offset of field: ",
@@ -5344,7 +5090,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         concat!("Alignment of ", stringify!(kvm_enable_cap))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).cap as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, cap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5354,7 +5100,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5364,7 +5110,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).args as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, args),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5374,7 +5120,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, pad),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5409,7 +5155,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         concat!("Alignment of ", stringify!(kvm_ppc_pvinfo))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5419,7 +5165,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).hcall as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, hcall),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5429,7 +5175,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, pad),
         20usize,
         concat!(
             "Offset of field: ",
@@ -5463,9 +5209,7 @@ fn bindgen_test_layout_kvm_ppc_one_page_size() {
         concat!("Alignment of ", stringify!(kvm_ppc_one_page_size))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_page_size>())).page_shift as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_page_size, page_shift),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5475,7 +5219,7 @@ fn bindgen_test_layout_kvm_ppc_one_page_size() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_one_page_size>())).pte_enc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_one_page_size, pte_enc),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5505,9 +5249,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         concat!("Alignment of ", stringify!(kvm_ppc_one_seg_page_size))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).page_shift as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, page_shift),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5517,9 +5259,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).slb_enc as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, slb_enc),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5529,7 +5269,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).enc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, enc),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5561,7 +5301,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         concat!("Alignment of ", stringify!(kvm_ppc_smmu_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5571,7 +5311,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).slb_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, slb_size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5581,7 +5321,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).data_keys as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, data_keys),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5591,7 +5331,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).instr_keys as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, instr_keys),
         14usize,
         concat!(
             "Offset of field: ",
@@ -5601,7 +5341,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).sps as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, sps),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5631,7 +5371,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         concat!("Alignment of ", stringify!(kvm_ppc_resize_hpt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5641,7 +5381,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).shift as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, shift),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5651,7 +5391,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5680,7 +5420,7 @@ fn bindgen_test_layout_kvm_irq_routing_irqchip() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_irqchip))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_irqchip>())).irqchip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_irqchip, irqchip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5690,7 +5430,7 @@ fn bindgen_test_layout_kvm_irq_routing_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_irqchip>())).pin as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_irqchip, pin),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5731,9 +5471,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_msi__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_msi__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5743,9 +5481,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_msi__bindgen_ty_1>())).devid as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_msi__bindgen_ty_1, devid),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5773,7 +5509,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_msi))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).address_lo as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, address_lo),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5783,7 +5519,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).address_hi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, address_hi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5793,7 +5529,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5830,9 +5566,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_s390_adapter))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).ind_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, ind_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5842,10 +5576,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).summary_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, summary_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5855,9 +5586,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).ind_offset as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, ind_offset),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5867,10 +5596,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).summary_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, summary_offset),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5880,9 +5606,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).adapter_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, adapter_id),
         28usize,
         concat!(
             "Offset of field: ",
@@ -5911,7 +5635,7 @@ fn bindgen_test_layout_kvm_irq_routing_hv_sint() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_hv_sint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_hv_sint>())).vcpu as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_hv_sint, vcpu),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5921,7 +5645,7 @@ fn bindgen_test_layout_kvm_irq_routing_hv_sint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_hv_sint>())).sint as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_hv_sint, sint),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5966,10 +5690,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).irqchip as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, irqchip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5979,9 +5700,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).msi as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, msi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5991,10 +5710,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).adapter as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, adapter),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6004,10 +5720,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).hv_sint as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, hv_sint),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6017,9 +5730,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6047,7 +5758,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_entry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, gsi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6057,7 +5768,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, type_),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6067,7 +5778,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6077,7 +5788,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6087,7 +5798,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, u),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6121,7 +5832,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         concat!("Alignment of ", stringify!(kvm_irq_routing))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6131,7 +5842,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6141,7 +5852,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).entries as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, entries),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6178,7 +5889,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         concat!("Alignment of ", stringify!(kvm_irqfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, fd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6188,7 +5899,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, gsi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6198,7 +5909,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6208,7 +5919,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).resamplefd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, resamplefd),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6218,7 +5929,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, pad),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6248,7 +5959,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         concat!("Alignment of ", stringify!(kvm_clock_data))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).clock as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, clock),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6258,7 +5969,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6268,7 +5979,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6299,7 +6010,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         concat!("Alignment of ", stringify!(kvm_config_tlb))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).params as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, params),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6309,7 +6020,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).array as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, array),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6319,7 +6030,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).mmu_type as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, mmu_type),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6329,7 +6040,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).array_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, array_len),
         20usize,
         concat!(
             "Offset of field: ",
@@ -6358,7 +6069,7 @@ fn bindgen_test_layout_kvm_dirty_tlb() {
         concat!("Alignment of ", stringify!(kvm_dirty_tlb))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_tlb>())).bitmap as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_tlb, bitmap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6368,7 +6079,7 @@ fn bindgen_test_layout_kvm_dirty_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_tlb>())).num_dirty as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_tlb, num_dirty),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6397,7 +6108,7 @@ fn bindgen_test_layout_kvm_reg_list() {
         concat!("Alignment of ", stringify!(kvm_reg_list))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_reg_list>())).n as *const _ as usize },
+        ::std::mem::offset_of!(kvm_reg_list, n),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6407,7 +6118,7 @@ fn bindgen_test_layout_kvm_reg_list() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_reg_list>())).reg as *const _ as usize },
+        ::std::mem::offset_of!(kvm_reg_list, reg),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6436,7 +6147,7 @@ fn bindgen_test_layout_kvm_one_reg() {
         concat!("Alignment of ", stringify!(kvm_one_reg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_one_reg>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_one_reg, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6446,7 +6157,7 @@ fn bindgen_test_layout_kvm_one_reg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_one_reg>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_one_reg, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6479,7 +6190,7 @@ fn bindgen_test_layout_kvm_msi() {
         concat!("Alignment of ", stringify!(kvm_msi))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).address_lo as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, address_lo),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6489,7 +6200,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).address_hi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, address_hi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6499,7 +6210,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6509,7 +6220,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6519,7 +6230,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).devid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, devid),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6529,7 +6240,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, pad),
         20usize,
         concat!(
             "Offset of field: ",
@@ -6558,7 +6269,7 @@ fn bindgen_test_layout_kvm_arm_device_addr() {
         concat!("Alignment of ", stringify!(kvm_arm_device_addr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_arm_device_addr>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_arm_device_addr, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6568,7 +6279,7 @@ fn bindgen_test_layout_kvm_arm_device_addr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_arm_device_addr>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_arm_device_addr, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6598,7 +6309,7 @@ fn bindgen_test_layout_kvm_create_device() {
         concat!("Alignment of ", stringify!(kvm_create_device))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6608,7 +6319,7 @@ fn bindgen_test_layout_kvm_create_device() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, fd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6618,7 +6329,7 @@ fn bindgen_test_layout_kvm_create_device() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6649,7 +6360,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         concat!("Alignment of ", stringify!(kvm_device_attr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6659,7 +6370,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).group as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, group),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6669,7 +6380,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).attr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, attr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6679,7 +6390,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, addr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6718,7 +6429,7 @@ fn bindgen_test_layout_kvm_vfio_spapr_tce() {
         concat!("Alignment of ", stringify!(kvm_vfio_spapr_tce))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vfio_spapr_tce>())).groupfd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vfio_spapr_tce, groupfd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6728,7 +6439,7 @@ fn bindgen_test_layout_kvm_vfio_spapr_tce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vfio_spapr_tce>())).tablefd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vfio_spapr_tce, tablefd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6758,7 +6469,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         concat!("Alignment of ", stringify!(kvm_s390_ucas_mapping))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).user_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, user_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6768,7 +6479,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).vcpu_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, vcpu_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6778,7 +6489,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).length as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, length),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6807,7 +6518,7 @@ fn bindgen_test_layout_kvm_enc_region() {
         concat!("Alignment of ", stringify!(kvm_enc_region))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enc_region>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enc_region, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6817,7 +6528,7 @@ fn bindgen_test_layout_kvm_enc_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enc_region>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enc_region, size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6870,7 +6581,7 @@ fn bindgen_test_layout_kvm_sev_cmd() {
         concat!("Alignment of ", stringify!(kvm_sev_cmd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_cmd>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_cmd, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6880,7 +6591,7 @@ fn bindgen_test_layout_kvm_sev_cmd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_cmd>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_cmd, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6890,7 +6601,7 @@ fn bindgen_test_layout_kvm_sev_cmd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_cmd>())).error as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_cmd, error),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6900,7 +6611,7 @@ fn bindgen_test_layout_kvm_sev_cmd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_cmd>())).sev_fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_cmd, sev_fd),
         20usize,
         concat!(
             "Offset of field: ",
@@ -6933,7 +6644,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         concat!("Alignment of ", stringify!(kvm_sev_launch_start))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_start>())).handle as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_start, handle),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6943,7 +6654,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_start>())).policy as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_start, policy),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6953,7 +6664,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_start>())).dh_uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_start, dh_uaddr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6963,7 +6674,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_start>())).dh_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_start, dh_len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6973,9 +6684,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_start>())).session_uaddr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_start, session_uaddr),
         24usize,
         concat!(
             "Offset of field: ",
@@ -6985,9 +6694,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_start>())).session_len as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_start, session_len),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7016,9 +6723,7 @@ fn bindgen_test_layout_kvm_sev_launch_update_data() {
         concat!("Alignment of ", stringify!(kvm_sev_launch_update_data))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_update_data>())).uaddr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_update_data, uaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7028,7 +6733,7 @@ fn bindgen_test_layout_kvm_sev_launch_update_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_update_data>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_update_data, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7061,7 +6766,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         concat!("Alignment of ", stringify!(kvm_sev_launch_secret))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_secret>())).hdr_uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, hdr_uaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7071,7 +6776,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_secret>())).hdr_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, hdr_len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7081,9 +6786,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_secret>())).guest_uaddr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, guest_uaddr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7093,7 +6796,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_secret>())).guest_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, guest_len),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7103,9 +6806,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_secret>())).trans_uaddr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, trans_uaddr),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7115,7 +6816,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_secret>())).trans_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, trans_len),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7144,7 +6845,7 @@ fn bindgen_test_layout_kvm_sev_launch_measure() {
         concat!("Alignment of ", stringify!(kvm_sev_launch_measure))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_measure>())).uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_measure, uaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7154,7 +6855,7 @@ fn bindgen_test_layout_kvm_sev_launch_measure() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_measure>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_measure, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7184,7 +6885,7 @@ fn bindgen_test_layout_kvm_sev_guest_status() {
         concat!("Alignment of ", stringify!(kvm_sev_guest_status))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_guest_status>())).handle as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_guest_status, handle),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7194,7 +6895,7 @@ fn bindgen_test_layout_kvm_sev_guest_status() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_guest_status>())).policy as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_guest_status, policy),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7204,7 +6905,7 @@ fn bindgen_test_layout_kvm_sev_guest_status() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_guest_status>())).state as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_guest_status, state),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7234,7 +6935,7 @@ fn bindgen_test_layout_kvm_sev_dbg() {
         concat!("Alignment of ", stringify!(kvm_sev_dbg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_dbg>())).src_uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_dbg, src_uaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7244,7 +6945,7 @@ fn bindgen_test_layout_kvm_sev_dbg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_dbg>())).dst_uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_dbg, dst_uaddr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7254,7 +6955,7 @@ fn bindgen_test_layout_kvm_sev_dbg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_dbg>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_dbg, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7296,10 +6997,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_pci_dev__bindgen_ty_1>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7327,9 +7025,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         concat!("Alignment of ", stringify!(kvm_assigned_pci_dev))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7339,7 +7035,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).busnr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, busnr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7349,7 +7045,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).devfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, devfn),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7359,7 +7055,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -7369,7 +7065,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).segnr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, segnr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7412,9 +7108,7 @@ fn bindgen_test_layout_kvm_assigned_irq__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_assigned_irq__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_irq__bindgen_ty_1>())).reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_irq__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7442,9 +7136,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         concat!("Alignment of ", stringify!(kvm_assigned_irq))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_irq>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_irq, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7454,7 +7146,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).host_irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, host_irq),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7464,7 +7156,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).guest_irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, guest_irq),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7474,7 +7166,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -7509,9 +7201,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         concat!("Alignment of ", stringify!(kvm_assigned_msix_nr))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7521,7 +7211,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).entry_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, entry_nr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7531,7 +7221,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, padding),
         6usize,
         concat!(
             "Offset of field: ",
@@ -7562,9 +7252,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         concat!("Alignment of ", stringify!(kvm_assigned_msix_entry))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7574,7 +7262,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, gsi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7584,7 +7272,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).entry as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, entry),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7594,7 +7282,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, padding),
         10usize,
         concat!(
             "Offset of field: ",
@@ -7625,7 +7313,7 @@ fn bindgen_test_layout_kvm_hyperv_eventfd() {
         concat!("Alignment of ", stringify!(kvm_hyperv_eventfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_eventfd>())).conn_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_eventfd, conn_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7635,7 +7323,7 @@ fn bindgen_test_layout_kvm_hyperv_eventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_eventfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_eventfd, fd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7645,7 +7333,7 @@ fn bindgen_test_layout_kvm_hyperv_eventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_eventfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_eventfd, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7655,7 +7343,7 @@ fn bindgen_test_layout_kvm_hyperv_eventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_eventfd>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_eventfd, padding),
         12usize,
         concat!(
             "Offset of field: ",
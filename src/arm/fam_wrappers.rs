@@ -0,0 +1,76 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Maximum number of GSI routes KVM accepts.
+pub const KVM_MAX_IRQ_ROUTES: usize = 4096;
+/// The kernel does not bound the register list; this keeps the allocation sane.
+pub const KVM_MAX_REG_LIST_ENTRIES: usize = 65536;
+/// Size in bytes of the kernel `sigset_t` expected by `KVM_SET_SIGNAL_MASK`.
+pub const KVM_SIGSET_SIZE: usize = 8;
+
+/// Implements `FamStruct` for the flexible array member structs of one bindings
+/// version and defines the wrappers over them. The bindings of that version must
+/// be in scope.
+macro_rules! fam_wrappers {
+    () => {
+        generate_fam_struct_impl!(
+            kvm_irq_routing,
+            kvm_irq_routing_entry,
+            entries,
+            nr,
+            __u32,
+            KVM_MAX_IRQ_ROUTES
+        );
+        generate_fam_struct_impl!(kvm_reg_list, __u64, reg, n, __u64, KVM_MAX_REG_LIST_ENTRIES);
+        generate_fam_struct_impl!(kvm_signal_mask, __u8, sigset, len, __u32, KVM_SIGSET_SIZE);
+
+        /// Wrapper over `kvm_irq_routing`, used by `KVM_SET_GSI_ROUTING`.
+        pub type IrqRouting = $crate::fam::FamStructWrapper<kvm_irq_routing>;
+        /// Wrapper over `kvm_reg_list`, used by `KVM_GET_REG_LIST`.
+        pub type RegList = $crate::fam::FamStructWrapper<kvm_reg_list>;
+        /// Wrapper over `kvm_signal_mask`, used by `KVM_SET_SIGNAL_MASK`.
+        pub type SignalMask = $crate::fam::FamStructWrapper<kvm_signal_mask>;
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bindings::*;
+    use super::*;
+    use std::mem::align_of;
+
+    #[test]
+    fn test_reg_list() {
+        let mut reg_list = RegList::new(0).unwrap();
+        reg_list.push(KVM_REG_ARM_CORE as u64).unwrap();
+        assert_eq!(reg_list.as_fam_struct_ref().n, 1);
+        assert_eq!(reg_list.as_slice(), &[KVM_REG_ARM_CORE as u64]);
+        assert_eq!(
+            reg_list.as_fam_struct_ptr() as usize % align_of::<kvm_reg_list>(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_irq_routing() {
+        let mut routing = IrqRouting::new(0).unwrap();
+        let mut entry = kvm_irq_routing_entry {
+            gsi: 5,
+            type_: KVM_IRQ_ROUTING_IRQCHIP,
+            flags: 0,
+            pad: 0,
+            u: unsafe { ::std::mem::zeroed() },
+        };
+        entry.u.irqchip = kvm_irq_routing_irqchip { irqchip: 0, pin: 5 };
+        routing.push(entry).unwrap();
+        assert_eq!(routing.as_fam_struct_ref().nr, 1);
+        assert_eq!(routing.as_slice()[0].gsi, 5);
+    }
+
+    #[test]
+    fn test_signal_mask() {
+        let mask = SignalMask::from_entries(&[0xff; KVM_SIGSET_SIZE]).unwrap();
+        assert_eq!(mask.as_fam_struct_ref().len, KVM_SIGSET_SIZE as u32);
+        assert!(SignalMask::new(KVM_SIGSET_SIZE + 1).is_err());
+    }
+}
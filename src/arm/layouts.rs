@@ -0,0 +1,1506 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Layouts of the `kvm_*` structs of every bindings version, generated from the
+//! bindgen layout tests.
+
+use kernel_abi::StructLayout;
+
+pub const V4_14_0: &[StructLayout] = &[
+    StructLayout {
+        name: "kvm_arch_memory_slot",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_arm_device_addr",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_assigned_irq",
+        size: 64,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_assigned_msix_entry",
+        size: 16,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_assigned_msix_nr",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_assigned_pci_dev",
+        size: 64,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_breakpoint",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_clock_data",
+        size: 48,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_coalesced_mmio",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_coalesced_mmio_ring",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_coalesced_mmio_zone",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_config_tlb",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_create_device",
+        size: 12,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_debug_exit_arch",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_debug_guest",
+        size: 80,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_device_attr",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_dirty_log",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_dirty_tlb",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_enable_cap",
+        size: 104,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_fpu",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_guest_debug",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_guest_debug_arch",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_hyperv_exit",
+        size: 40,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_interrupt",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ioeventfd",
+        size: 64,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_irq_level",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irq_routing",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_entry",
+        size: 48,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_hv_sint",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_irqchip",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_msi",
+        size: 16,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_s390_adapter",
+        size: 32,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_irqchip",
+        size: 520,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irqfd",
+        size: 32,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_memory_region",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_mp_state",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_msi",
+        size: 32,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_one_reg",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_pit_config",
+        size: 64,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ppc_one_page_size",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ppc_one_seg_page_size",
+        size: 72,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ppc_pvinfo",
+        size: 128,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ppc_resize_hpt",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_ppc_smmu_info",
+        size: 592,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_reg_list",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_regs",
+        size: 152,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_run",
+        size: 2352,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_cmma_log",
+        size: 32,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_emerg_info",
+        size: 2,
+        align: 2,
+    },
+    StructLayout {
+        name: "kvm_s390_ext_info",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_extcall_info",
+        size: 2,
+        align: 2,
+    },
+    StructLayout {
+        name: "kvm_s390_interrupt",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_io_info",
+        size: 12,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_s390_irq",
+        size: 72,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_irq_state",
+        size: 32,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_mchk_info",
+        size: 48,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_mem_op",
+        size: 64,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_pgm_info",
+        size: 40,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_prefix_info",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_s390_psw",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_skeys",
+        size: 64,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_stop_info",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_s390_ucas_mapping",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_signal_mask",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_sregs",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_sync_regs",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_tpr_access_ctl",
+        size: 40,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_translation",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_user_trace_setup",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_userspace_memory_region",
+        size: 32,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_vapic_addr",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_vcpu_init",
+        size: 32,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_vfio_spapr_tce",
+        size: 8,
+        align: 4,
+    },
+];
+
+pub const V4_20_0: &[StructLayout] = &[
+    StructLayout {
+        name: "kvm_arch_memory_slot",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_arm_device_addr",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_assigned_irq",
+        size: 64,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_assigned_msix_entry",
+        size: 16,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_assigned_msix_nr",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_assigned_pci_dev",
+        size: 64,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_breakpoint",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_clock_data",
+        size: 48,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_coalesced_mmio",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_coalesced_mmio_ring",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_coalesced_mmio_zone",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_config_tlb",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_create_device",
+        size: 12,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_debug_exit_arch",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_debug_guest",
+        size: 80,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_device_attr",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_dirty_log",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_dirty_tlb",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_enable_cap",
+        size: 104,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_enc_region",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_fpu",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_guest_debug",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_guest_debug_arch",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_hyperv_eventfd",
+        size: 24,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_hyperv_exit",
+        size: 40,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_interrupt",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ioeventfd",
+        size: 64,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_irq_level",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irq_routing",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_entry",
+        size: 48,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_hv_sint",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_irqchip",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_msi",
+        size: 16,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_s390_adapter",
+        size: 32,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_irqchip",
+        size: 520,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irqfd",
+        size: 32,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_memory_region",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_mp_state",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_msi",
+        size: 32,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_one_reg",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_pit_config",
+        size: 64,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ppc_one_page_size",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ppc_one_seg_page_size",
+        size: 72,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ppc_pvinfo",
+        size: 128,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ppc_resize_hpt",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_ppc_smmu_info",
+        size: 592,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_reg_list",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_regs",
+        size: 152,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_run",
+        size: 2352,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_cmma_log",
+        size: 32,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_emerg_info",
+        size: 2,
+        align: 2,
+    },
+    StructLayout {
+        name: "kvm_s390_ext_info",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_extcall_info",
+        size: 2,
+        align: 2,
+    },
+    StructLayout {
+        name: "kvm_s390_interrupt",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_io_info",
+        size: 12,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_s390_irq",
+        size: 72,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_irq_state",
+        size: 32,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_mchk_info",
+        size: 48,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_mem_op",
+        size: 64,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_pgm_info",
+        size: 40,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_prefix_info",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_s390_psw",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_skeys",
+        size: 64,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_stop_info",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_s390_ucas_mapping",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_sev_cmd",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_sev_dbg",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_sev_guest_status",
+        size: 12,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_sev_launch_measure",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_sev_launch_secret",
+        size: 48,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_sev_launch_start",
+        size: 40,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_sev_launch_update_data",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_signal_mask",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_sregs",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_sync_regs",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_tpr_access_ctl",
+        size: 40,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_translation",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_user_trace_setup",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_userspace_memory_region",
+        size: 32,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_vapic_addr",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_vcpu_events",
+        size: 64,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_vcpu_init",
+        size: 32,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_vfio_spapr_tce",
+        size: 8,
+        align: 4,
+    },
+];
+
+pub const V5_4_0: &[StructLayout] = &[
+    StructLayout {
+        name: "kvm_arch_memory_slot",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_arm_device_addr",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_assigned_irq",
+        size: 64,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_assigned_msix_entry",
+        size: 16,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_assigned_msix_nr",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_assigned_pci_dev",
+        size: 64,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_breakpoint",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_clear_dirty_log",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_clock_data",
+        size: 48,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_coalesced_mmio",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_coalesced_mmio_ring",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_coalesced_mmio_zone",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_config_tlb",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_create_device",
+        size: 12,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_debug_exit_arch",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_debug_guest",
+        size: 80,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_device_attr",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_dirty_log",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_dirty_tlb",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_enable_cap",
+        size: 104,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_enc_region",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_fpu",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_guest_debug",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_guest_debug_arch",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_hyperv_eventfd",
+        size: 24,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_hyperv_exit",
+        size: 40,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_interrupt",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ioeventfd",
+        size: 64,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_irq_level",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irq_routing",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_entry",
+        size: 48,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_hv_sint",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_irqchip",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_msi",
+        size: 16,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irq_routing_s390_adapter",
+        size: 32,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_irqchip",
+        size: 520,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_irqfd",
+        size: 32,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_memory_region",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_mp_state",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_msi",
+        size: 32,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_one_reg",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_pit_config",
+        size: 64,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ppc_one_page_size",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ppc_one_seg_page_size",
+        size: 72,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ppc_pvinfo",
+        size: 128,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_ppc_resize_hpt",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_ppc_smmu_info",
+        size: 592,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_reg_list",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_regs",
+        size: 152,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_run",
+        size: 2352,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_cmma_log",
+        size: 32,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_emerg_info",
+        size: 2,
+        align: 2,
+    },
+    StructLayout {
+        name: "kvm_s390_ext_info",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_extcall_info",
+        size: 2,
+        align: 2,
+    },
+    StructLayout {
+        name: "kvm_s390_interrupt",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_io_info",
+        size: 12,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_s390_irq",
+        size: 72,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_irq_state",
+        size: 32,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_mchk_info",
+        size: 48,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_mem_op",
+        size: 64,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_pgm_info",
+        size: 40,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_prefix_info",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_s390_psw",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_skeys",
+        size: 64,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_s390_stop_info",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_s390_ucas_mapping",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_sev_cmd",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_sev_dbg",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_sev_guest_status",
+        size: 12,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_sev_launch_measure",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_sev_launch_secret",
+        size: 48,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_sev_launch_start",
+        size: 40,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_sev_launch_update_data",
+        size: 16,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_signal_mask",
+        size: 4,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_sregs",
+        size: 0,
+        align: 1,
+    },
+    StructLayout {
+        name: "kvm_sync_regs",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_tpr_access_ctl",
+        size: 40,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_translation",
+        size: 24,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_user_trace_setup",
+        size: 8,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_userspace_memory_region",
+        size: 32,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_vapic_addr",
+        size: 8,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_vcpu_events",
+        size: 64,
+        align: 8,
+    },
+    StructLayout {
+        name: "kvm_vcpu_init",
+        size: 32,
+        align: 4,
+    },
+    StructLayout {
+        name: "kvm_vfio_spapr_tce",
+        size: 8,
+        align: 4,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::{align_of, size_of};
+
+    macro_rules! check_layouts {
+        ($table:expr, $($t:ident),* $(,)*) => {
+            $(
+                let layout = $table.iter().find(|l| l.name == stringify!($t)).unwrap();
+                assert_eq!(layout.size, size_of::<$t>(), stringify!($t));
+                assert_eq!(layout.align, align_of::<$t>(), stringify!($t));
+            )*
+            assert_eq!($table.len(), [$(stringify!($t)),*].len());
+        };
+    }
+
+    #[cfg(feature = "kvm-v4_14_0")]
+    #[test]
+    fn test_v4_14_0() {
+        use super::super::v4_14_0::*;
+
+        check_layouts!(
+            V4_14_0,
+            kvm_arch_memory_slot,
+            kvm_arm_device_addr,
+            kvm_assigned_irq,
+            kvm_assigned_msix_entry,
+            kvm_assigned_msix_nr,
+            kvm_assigned_pci_dev,
+            kvm_breakpoint,
+            kvm_clock_data,
+            kvm_coalesced_mmio,
+            kvm_coalesced_mmio_ring,
+            kvm_coalesced_mmio_zone,
+            kvm_config_tlb,
+            kvm_create_device,
+            kvm_debug_exit_arch,
+            kvm_debug_guest,
+            kvm_device_attr,
+            kvm_dirty_log,
+            kvm_dirty_tlb,
+            kvm_enable_cap,
+            kvm_fpu,
+            kvm_guest_debug,
+            kvm_guest_debug_arch,
+            kvm_hyperv_exit,
+            kvm_interrupt,
+            kvm_ioeventfd,
+            kvm_irq_level,
+            kvm_irq_routing,
+            kvm_irq_routing_entry,
+            kvm_irq_routing_hv_sint,
+            kvm_irq_routing_irqchip,
+            kvm_irq_routing_msi,
+            kvm_irq_routing_s390_adapter,
+            kvm_irqchip,
+            kvm_irqfd,
+            kvm_memory_region,
+            kvm_mp_state,
+            kvm_msi,
+            kvm_one_reg,
+            kvm_pit_config,
+            kvm_ppc_one_page_size,
+            kvm_ppc_one_seg_page_size,
+            kvm_ppc_pvinfo,
+            kvm_ppc_resize_hpt,
+            kvm_ppc_smmu_info,
+            kvm_reg_list,
+            kvm_regs,
+            kvm_run,
+            kvm_s390_cmma_log,
+            kvm_s390_emerg_info,
+            kvm_s390_ext_info,
+            kvm_s390_extcall_info,
+            kvm_s390_interrupt,
+            kvm_s390_io_info,
+            kvm_s390_irq,
+            kvm_s390_irq_state,
+            kvm_s390_mchk_info,
+            kvm_s390_mem_op,
+            kvm_s390_pgm_info,
+            kvm_s390_prefix_info,
+            kvm_s390_psw,
+            kvm_s390_skeys,
+            kvm_s390_stop_info,
+            kvm_s390_ucas_mapping,
+            kvm_signal_mask,
+            kvm_sregs,
+            kvm_sync_regs,
+            kvm_tpr_access_ctl,
+            kvm_translation,
+            kvm_user_trace_setup,
+            kvm_userspace_memory_region,
+            kvm_vapic_addr,
+            kvm_vcpu_init,
+            kvm_vfio_spapr_tce,
+        );
+    }
+
+    #[cfg(feature = "kvm-v4_20_0")]
+    #[test]
+    fn test_v4_20_0() {
+        use super::super::v4_20_0::*;
+
+        check_layouts!(
+            V4_20_0,
+            kvm_arch_memory_slot,
+            kvm_arm_device_addr,
+            kvm_assigned_irq,
+            kvm_assigned_msix_entry,
+            kvm_assigned_msix_nr,
+            kvm_assigned_pci_dev,
+            kvm_breakpoint,
+            kvm_clock_data,
+            kvm_coalesced_mmio,
+            kvm_coalesced_mmio_ring,
+            kvm_coalesced_mmio_zone,
+            kvm_config_tlb,
+            kvm_create_device,
+            kvm_debug_exit_arch,
+            kvm_debug_guest,
+            kvm_device_attr,
+            kvm_dirty_log,
+            kvm_dirty_tlb,
+            kvm_enable_cap,
+            kvm_enc_region,
+            kvm_fpu,
+            kvm_guest_debug,
+            kvm_guest_debug_arch,
+            kvm_hyperv_eventfd,
+            kvm_hyperv_exit,
+            kvm_interrupt,
+            kvm_ioeventfd,
+            kvm_irq_level,
+            kvm_irq_routing,
+            kvm_irq_routing_entry,
+            kvm_irq_routing_hv_sint,
+            kvm_irq_routing_irqchip,
+            kvm_irq_routing_msi,
+            kvm_irq_routing_s390_adapter,
+            kvm_irqchip,
+            kvm_irqfd,
+            kvm_memory_region,
+            kvm_mp_state,
+            kvm_msi,
+            kvm_one_reg,
+            kvm_pit_config,
+            kvm_ppc_one_page_size,
+            kvm_ppc_one_seg_page_size,
+            kvm_ppc_pvinfo,
+            kvm_ppc_resize_hpt,
+            kvm_ppc_smmu_info,
+            kvm_reg_list,
+            kvm_regs,
+            kvm_run,
+            kvm_s390_cmma_log,
+            kvm_s390_emerg_info,
+            kvm_s390_ext_info,
+            kvm_s390_extcall_info,
+            kvm_s390_interrupt,
+            kvm_s390_io_info,
+            kvm_s390_irq,
+            kvm_s390_irq_state,
+            kvm_s390_mchk_info,
+            kvm_s390_mem_op,
+            kvm_s390_pgm_info,
+            kvm_s390_prefix_info,
+            kvm_s390_psw,
+            kvm_s390_skeys,
+            kvm_s390_stop_info,
+            kvm_s390_ucas_mapping,
+            kvm_sev_cmd,
+            kvm_sev_dbg,
+            kvm_sev_guest_status,
+            kvm_sev_launch_measure,
+            kvm_sev_launch_secret,
+            kvm_sev_launch_start,
+            kvm_sev_launch_update_data,
+            kvm_signal_mask,
+            kvm_sregs,
+            kvm_sync_regs,
+            kvm_tpr_access_ctl,
+            kvm_translation,
+            kvm_user_trace_setup,
+            kvm_userspace_memory_region,
+            kvm_vapic_addr,
+            kvm_vcpu_events,
+            kvm_vcpu_init,
+            kvm_vfio_spapr_tce,
+        );
+    }
+
+    #[cfg(any(
+        feature = "kvm-v5_4_0",
+        not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+    ))]
+    #[test]
+    fn test_v5_4_0() {
+        use super::super::v5_4_0::*;
+
+        check_layouts!(
+            V5_4_0,
+            kvm_arch_memory_slot,
+            kvm_arm_device_addr,
+            kvm_assigned_irq,
+            kvm_assigned_msix_entry,
+            kvm_assigned_msix_nr,
+            kvm_assigned_pci_dev,
+            kvm_breakpoint,
+            kvm_clear_dirty_log,
+            kvm_clock_data,
+            kvm_coalesced_mmio,
+            kvm_coalesced_mmio_ring,
+            kvm_coalesced_mmio_zone,
+            kvm_config_tlb,
+            kvm_create_device,
+            kvm_debug_exit_arch,
+            kvm_debug_guest,
+            kvm_device_attr,
+            kvm_dirty_log,
+            kvm_dirty_tlb,
+            kvm_enable_cap,
+            kvm_enc_region,
+            kvm_fpu,
+            kvm_guest_debug,
+            kvm_guest_debug_arch,
+            kvm_hyperv_eventfd,
+            kvm_hyperv_exit,
+            kvm_interrupt,
+            kvm_ioeventfd,
+            kvm_irq_level,
+            kvm_irq_routing,
+            kvm_irq_routing_entry,
+            kvm_irq_routing_hv_sint,
+            kvm_irq_routing_irqchip,
+            kvm_irq_routing_msi,
+            kvm_irq_routing_s390_adapter,
+            kvm_irqchip,
+            kvm_irqfd,
+            kvm_memory_region,
+            kvm_mp_state,
+            kvm_msi,
+            kvm_one_reg,
+            kvm_pit_config,
+            kvm_ppc_one_page_size,
+            kvm_ppc_one_seg_page_size,
+            kvm_ppc_pvinfo,
+            kvm_ppc_resize_hpt,
+            kvm_ppc_smmu_info,
+            kvm_reg_list,
+            kvm_regs,
+            kvm_run,
+            kvm_s390_cmma_log,
+            kvm_s390_emerg_info,
+            kvm_s390_ext_info,
+            kvm_s390_extcall_info,
+            kvm_s390_interrupt,
+            kvm_s390_io_info,
+            kvm_s390_irq,
+            kvm_s390_irq_state,
+            kvm_s390_mchk_info,
+            kvm_s390_mem_op,
+            kvm_s390_pgm_info,
+            kvm_s390_prefix_info,
+            kvm_s390_psw,
+            kvm_s390_skeys,
+            kvm_s390_stop_info,
+            kvm_s390_ucas_mapping,
+            kvm_sev_cmd,
+            kvm_sev_dbg,
+            kvm_sev_guest_status,
+            kvm_sev_launch_measure,
+            kvm_sev_launch_secret,
+            kvm_sev_launch_start,
+            kvm_sev_launch_update_data,
+            kvm_signal_mask,
+            kvm_sregs,
+            kvm_sync_regs,
+            kvm_tpr_access_ctl,
+            kvm_translation,
+            kvm_user_trace_setup,
+            kvm_userspace_memory_region,
+            kvm_vapic_addr,
+            kvm_vcpu_events,
+            kvm_vcpu_init,
+            kvm_vfio_spapr_tce,
+        );
+    }
+}
@@ -1,24 +1,137 @@
 // Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
+// Each `kvm-v*` feature compiles the bindings of one kernel version in their
+// own module, so several versions can be used side by side.
+//
+// Major hack to have a default version in case no feature is specified:
+// If no version is specified by using the features, just use the latest one
+// which currently is 5.4.
+//
+// 32-bit arm KVM was removed in Linux 5.7, so there are no newer bindings: the
+// `kvm-v5_10_0`, `kvm-v5_15_0`, `kvm-v6_1_0` and `kvm-v6_6_0` features have no
+// effect on arm.
+
 #[cfg(feature = "kvm-v4_14_0")]
+#[allow(clippy::all)]
 mod bindings_v4_14_0;
+#[cfg(feature = "kvm-v4_14_0")]
+mod ioctls_v4_14_0;
+#[cfg(all(feature = "serde", feature = "kvm-v4_14_0"))]
+mod serialize_v4_14_0;
+
 #[cfg(feature = "kvm-v4_20_0")]
+#[allow(clippy::all)]
 mod bindings_v4_20_0;
+#[cfg(feature = "kvm-v4_20_0")]
+mod ioctls_v4_20_0;
+#[cfg(all(feature = "serde", feature = "kvm-v4_20_0"))]
+mod serialize_v4_20_0;
 
-// Major hack to have a default version in case no feature is specified:
-// If no version is specified by using the features, just use the latest one
-// which currently is 4.20.
-#[cfg(all(not(feature = "kvm-v4_14_0"), not(feature = "kvm-v4_20_0")))]
-mod bindings_v4_20_0;
+#[cfg(any(
+    feature = "kvm-v5_4_0",
+    not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+))]
+#[allow(clippy::all)]
+mod bindings_v5_4_0;
+#[cfg(any(
+    feature = "kvm-v5_4_0",
+    not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+))]
+mod ioctls_v5_4_0;
+#[cfg(all(
+    feature = "serde",
+    any(
+        feature = "kvm-v5_4_0",
+        not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+    )
+))]
+mod serialize_v5_4_0;
 
-pub mod bindings {
-    #[cfg(feature = "kvm-v4_14_0")]
+#[macro_use]
+mod fam_wrappers;
+// The layouts are only used by `kernel_abi` for the host architecture.
+#[cfg_attr(not(target_arch = "arm"), allow(dead_code))]
+pub(crate) mod layouts;
+
+// The C types of the 32-bit ARM ABI. The bindings are generated with
+// `--ctypes-prefix super::raw` so their layout does not depend on the host.
+mod raw {
+    pub type c_char = u8;
+    pub type c_schar = i8;
+    pub type c_uchar = u8;
+    pub type c_short = i16;
+    pub type c_ushort = u16;
+    pub type c_int = i32;
+    pub type c_uint = u32;
+    pub type c_long = i32;
+    pub type c_ulong = u32;
+    pub type c_longlong = i64;
+    pub type c_ulonglong = u64;
+    pub use std::os::raw::c_void;
+}
+
+pub mod reg_id;
+
+/// Bindings for Linux 4.14.
+#[cfg(feature = "kvm-v4_14_0")]
+pub mod v4_14_0 {
     pub use super::bindings_v4_14_0::*;
+    pub use super::fam_wrappers::*;
 
-    #[cfg(feature = "kvm-v4_20_0")]
-    pub use super::bindings_v4_20_0::*;
+    fam_wrappers!();
+
+    /// Ioctl request numbers for Linux 4.14.
+    pub mod ioctls {
+        pub use super::super::ioctls_v4_14_0::*;
+    }
+}
 
-    #[cfg(all(not(feature = "kvm-v4_14_0"), not(feature = "kvm-v4_20_0")))]
+/// Bindings for Linux 4.20.
+#[cfg(feature = "kvm-v4_20_0")]
+pub mod v4_20_0 {
     pub use super::bindings_v4_20_0::*;
+    pub use super::fam_wrappers::*;
+
+    fam_wrappers!();
+
+    /// Ioctl request numbers for Linux 4.20.
+    pub mod ioctls {
+        pub use super::super::ioctls_v4_20_0::*;
+    }
+}
+
+/// Bindings for Linux 5.4.
+#[cfg(any(
+    feature = "kvm-v5_4_0",
+    not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+))]
+pub mod v5_4_0 {
+    pub use super::bindings_v5_4_0::*;
+    pub use super::fam_wrappers::*;
+
+    fam_wrappers!();
+
+    /// Ioctl request numbers for Linux 5.4.
+    pub mod ioctls {
+        pub use super::super::ioctls_v5_4_0::*;
+    }
+}
+
+/// The default bindings: the newest version compiled in.
+pub mod bindings {
+    #[cfg(all(
+        feature = "kvm-v4_14_0",
+        not(any(feature = "kvm-v4_20_0", feature = "kvm-v5_4_0"))
+    ))]
+    pub use super::v4_14_0::*;
+
+    #[cfg(all(feature = "kvm-v4_20_0", not(feature = "kvm-v5_4_0")))]
+    pub use super::v4_20_0::*;
+
+    #[cfg(any(
+        feature = "kvm-v5_4_0",
+        not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+    ))]
+    pub use super::v5_4_0::*;
 }
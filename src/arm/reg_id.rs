@@ -0,0 +1,351 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Register IDs for `KVM_GET_ONE_REG`, `KVM_SET_ONE_REG` and `KVM_GET_REG_LIST`.
+//!
+//! A 32-bit arm register ID is made of the architecture, the size of the
+//! register, the register group and an index in that group.
+//! [`ArmRegId`](struct.ArmRegId.html) builds the IDs of the core, CP15 and VFP
+//! registers and decodes the IDs returned in `kvm_reg_list`.
+
+use std::fmt;
+use std::mem::{offset_of, size_of};
+
+use super::bindings::*;
+
+// Not in the 4.14 bindings.
+const KVM_REG_ARM_SECURE_MASK: u64 = 0x1000_0000;
+const KVM_REG_ARM_FW: u64 = 0x0014 << KVM_REG_ARM_COPROC_SHIFT;
+
+const KVM_REG_ARM_COPROC: u64 = KVM_REG_ARM_COPROC_MASK as u64;
+const CORE: u64 = KVM_REG_ARM_CORE as u64;
+const DEMUX: u64 = KVM_REG_ARM_DEMUX as u64;
+const VFP: u64 = KVM_REG_ARM_VFP as u64;
+const CP15: u64 = 15 << KVM_REG_ARM_COPROC_SHIFT;
+
+/// Errors associated with 32-bit arm register IDs.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The register ID is not a 32-bit arm register ID.
+    NotArm(u64),
+    /// The offset is not the one of a field of `kvm_regs`.
+    InvalidCoreReg(usize),
+    /// The register ID belongs to a group unknown to these bindings.
+    UnknownGroup(u64),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::NotArm(id) => write!(f, "{:#x} is not an arm register ID", id),
+            Error::InvalidCoreReg(offset) => {
+                write!(f, "no core register at offset {} of kvm_regs", offset)
+            }
+            Error::UnknownGroup(id) => write!(f, "{:#x} belongs to an unknown register group", id),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// The register an [`ArmRegId`](struct.ArmRegId.html) refers to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ArmReg {
+    /// A field of `kvm_regs`, at `offset` bytes from its start.
+    Core { offset: usize },
+    /// A 32-bit CP15 register, accessed with `MRC p15, opc1, <Rt>, crn, crm, opc2`.
+    Cp15 {
+        crn: u8,
+        crm: u8,
+        opc1: u8,
+        opc2: u8,
+    },
+    /// A 64-bit CP15 register, accessed with `MRRC p15, opc1, <Rt>, <Rt2>, crm`.
+    Cp15Wide { crm: u8, opc1: u8 },
+    /// The VFP data register `Dn`.
+    VfpData(u8),
+    /// A VFP control register, like `KVM_REG_ARM_VFP_FPSCR`.
+    VfpControl(u16),
+    /// A `CCSIDR` value, for the `CSSELR` cache selector.
+    Ccsidr(u8),
+    /// A firmware pseudo-register, such as the PSCI version.
+    Firmware(u16),
+}
+
+/// A 32-bit arm KVM register ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ArmRegId(u64);
+
+impl ArmRegId {
+    /// The program counter, `r15` in `kvm_regs::usr_regs`.
+    pub const PC: ArmRegId = ArmRegId::core_reg(offset_of!(kvm_regs, usr_regs) + 15 * 4);
+    /// The current program status register in `kvm_regs::usr_regs`.
+    pub const CPSR: ArmRegId = ArmRegId::core_reg(offset_of!(kvm_regs, usr_regs) + 16 * 4);
+
+    /// The VFP system ID register.
+    pub const FPSID: ArmRegId = ArmRegId::vfp(KVM_REG_ARM_VFP_FPSID as u16, 4);
+    /// The VFP status and control register.
+    pub const FPSCR: ArmRegId = ArmRegId::vfp(KVM_REG_ARM_VFP_FPSCR as u16, 4);
+    /// The media and VFP feature register 0.
+    pub const MVFR0: ArmRegId = ArmRegId::vfp(KVM_REG_ARM_VFP_MVFR0 as u16, 4);
+    /// The media and VFP feature register 1.
+    pub const MVFR1: ArmRegId = ArmRegId::vfp(KVM_REG_ARM_VFP_MVFR1 as u16, 4);
+    /// The VFP exception register.
+    pub const FPEXC: ArmRegId = ArmRegId::vfp(KVM_REG_ARM_VFP_FPEXC as u16, 4);
+    /// The VFP instruction register.
+    pub const FPINST: ArmRegId = ArmRegId::vfp(KVM_REG_ARM_VFP_FPINST as u16, 4);
+    /// The second VFP instruction register.
+    pub const FPINST2: ArmRegId = ArmRegId::vfp(KVM_REG_ARM_VFP_FPINST2 as u16, 4);
+
+    /// The PSCI version implemented for the guest.
+    pub const PSCI_VERSION: ArmRegId = ArmRegId::firmware(0);
+
+    /// Builds the ID of a `size` bytes register, `size` being a power of two.
+    const fn new(size: usize, group: u64, index: u64) -> Self {
+        ArmRegId(
+            KVM_REG_ARM | ((size.trailing_zeros() as u64) << KVM_REG_SIZE_SHIFT) | group | index,
+        )
+    }
+
+    // Like `KVM_REG_ARM_CORE_REG()`, the index is the offset in 32-bit words.
+    const fn core_reg(offset: usize) -> Self {
+        ArmRegId::new(4, CORE, (offset / size_of::<u32>()) as u64)
+    }
+
+    const fn vfp(index: u16, size: usize) -> Self {
+        ArmRegId::new(size, VFP, index as u64)
+    }
+
+    /// Builds the ID of the field at `offset` bytes in `kvm_regs`.
+    ///
+    /// `offset` is typically computed with `offset_of!`, for instance
+    /// `offset_of!(kvm_regs, svc_regs) + 4` for `LR_svc`.
+    pub fn core(offset: usize) -> Result<Self, Error> {
+        if !offset.is_multiple_of(size_of::<u32>()) || offset >= size_of::<kvm_regs>() {
+            return Err(Error::InvalidCoreReg(offset));
+        }
+        Ok(ArmRegId::core_reg(offset))
+    }
+
+    /// Returns the ID of the user mode register `Rn`, `pt_regs::uregs[n]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is larger than 15.
+    pub fn r(n: usize) -> Self {
+        assert!(n < 16, "there is no R{} register", n);
+        ArmRegId::core_reg(offset_of!(kvm_regs, usr_regs) + n * size_of::<u32>())
+    }
+
+    /// Builds the ID of a 32-bit CP15 register.
+    ///
+    /// The fields are truncated to their width: 4 bits for `crn`, `crm` and
+    /// `opc1` and 3 bits for `opc2`.
+    pub const fn cp15(crn: u8, crm: u8, opc1: u8, opc2: u8) -> Self {
+        let index = (((crn as u32) << KVM_REG_ARM_32_CRN_SHIFT) & KVM_REG_ARM_32_CRN_MASK)
+            | (((crm as u32) << KVM_REG_ARM_CRM_SHIFT) & KVM_REG_ARM_CRM_MASK)
+            | (((opc1 as u32) << KVM_REG_ARM_OPC1_SHIFT) & KVM_REG_ARM_OPC1_MASK)
+            | (((opc2 as u32) << KVM_REG_ARM_32_OPC2_SHIFT) & KVM_REG_ARM_32_OPC2_MASK);
+        ArmRegId::new(4, CP15, index as u64)
+    }
+
+    /// Builds the ID of a 64-bit CP15 register.
+    ///
+    /// The fields are truncated to 4 bits.
+    pub const fn cp15_wide(crm: u8, opc1: u8) -> Self {
+        let index = (((crm as u32) << KVM_REG_ARM_CRM_SHIFT) & KVM_REG_ARM_CRM_MASK)
+            | (((opc1 as u32) << KVM_REG_ARM_OPC1_SHIFT) & KVM_REG_ARM_OPC1_MASK);
+        ArmRegId::new(8, CP15, index as u64)
+    }
+
+    /// Returns the ID of the VFP data register `Dn`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is larger than 31.
+    pub fn d(n: u8) -> Self {
+        assert!(n < 32, "there is no D{} register", n);
+        ArmRegId::vfp(KVM_REG_ARM_VFP_BASE_REG as u16 + u16::from(n), 8)
+    }
+
+    /// Builds the ID of the `CCSIDR` value for the `CSSELR` cache selector.
+    pub const fn ccsidr(selector: u8) -> Self {
+        ArmRegId::new(
+            4,
+            DEMUX,
+            ((KVM_REG_ARM_DEMUX_ID_CCSIDR << KVM_REG_ARM_DEMUX_ID_SHIFT)
+                | ((selector as u32) << KVM_REG_ARM_DEMUX_VAL_SHIFT)) as u64,
+        )
+    }
+
+    /// Builds the ID of the firmware pseudo-register `index`.
+    pub const fn firmware(index: u16) -> Self {
+        ArmRegId::new(8, KVM_REG_ARM_FW, index as u64)
+    }
+
+    /// Returns the ID of the secure bank of the register.
+    pub const fn secure(self) -> Self {
+        ArmRegId(self.0 | KVM_REG_ARM_SECURE_MASK)
+    }
+
+    /// Returns `true` if the ID refers to the secure bank of the register.
+    pub const fn is_secure(self) -> bool {
+        self.0 & KVM_REG_ARM_SECURE_MASK != 0
+    }
+
+    /// Wraps a raw register ID, such as one returned in `kvm_reg_list`.
+    pub fn from_raw(id: u64) -> Result<Self, Error> {
+        if id & KVM_REG_ARCH_MASK as u64 != KVM_REG_ARM {
+            return Err(Error::NotArm(id));
+        }
+        Ok(ArmRegId(id))
+    }
+
+    /// Returns the raw register ID, to be stored in `kvm_one_reg::id`.
+    pub const fn raw(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the size of the register in bytes.
+    pub const fn size(self) -> usize {
+        1 << ((self.0 & KVM_REG_SIZE_MASK) >> KVM_REG_SIZE_SHIFT)
+    }
+
+    /// Decodes the register the ID refers to.
+    pub fn decode(self) -> Result<ArmReg, Error> {
+        let index = (self.0 & 0xffff) as u32;
+        let field = |mask: u32, shift: u32| ((index & mask) >> shift) as u8;
+        match self.0 & KVM_REG_ARM_COPROC {
+            CORE => Ok(ArmReg::Core {
+                offset: index as usize * size_of::<u32>(),
+            }),
+            CP15 if self.size() == 8 => Ok(ArmReg::Cp15Wide {
+                crm: field(KVM_REG_ARM_CRM_MASK, KVM_REG_ARM_CRM_SHIFT),
+                opc1: field(KVM_REG_ARM_OPC1_MASK, KVM_REG_ARM_OPC1_SHIFT),
+            }),
+            CP15 => Ok(ArmReg::Cp15 {
+                crn: field(KVM_REG_ARM_32_CRN_MASK, KVM_REG_ARM_32_CRN_SHIFT),
+                crm: field(KVM_REG_ARM_CRM_MASK, KVM_REG_ARM_CRM_SHIFT),
+                opc1: field(KVM_REG_ARM_OPC1_MASK, KVM_REG_ARM_OPC1_SHIFT),
+                opc2: field(KVM_REG_ARM_32_OPC2_MASK, KVM_REG_ARM_32_OPC2_SHIFT),
+            }),
+            VFP if index < KVM_REG_ARM_VFP_FPSID => {
+                Ok(ArmReg::VfpData((index - KVM_REG_ARM_VFP_BASE_REG) as u8))
+            }
+            VFP => Ok(ArmReg::VfpControl(index as u16)),
+            DEMUX => {
+                if field(KVM_REG_ARM_DEMUX_ID_MASK, KVM_REG_ARM_DEMUX_ID_SHIFT) as u32
+                    != KVM_REG_ARM_DEMUX_ID_CCSIDR
+                {
+                    return Err(Error::UnknownGroup(self.0));
+                }
+                Ok(ArmReg::Ccsidr(field(
+                    KVM_REG_ARM_DEMUX_VAL_MASK,
+                    KVM_REG_ARM_DEMUX_VAL_SHIFT,
+                )))
+            }
+            KVM_REG_ARM_FW => Ok(ArmReg::Firmware(index as u16)),
+            _ => Err(Error::UnknownGroup(self.0)),
+        }
+    }
+}
+
+impl From<ArmRegId> for u64 {
+    fn from(id: ArmRegId) -> u64 {
+        id.raw()
+    }
+}
+
+impl fmt::Display for ArmRegId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_core_regs() {
+        assert_eq!(ArmRegId::r(0).raw(), 0x4020_0000_0010_0000);
+        assert_eq!(ArmRegId::PC.raw(), 0x4020_0000_0010_000f);
+        assert_eq!(ArmRegId::CPSR.raw(), 0x4020_0000_0010_0010);
+        // SP_svc
+        let sp_svc = ArmRegId::core(offset_of!(kvm_regs, svc_regs)).unwrap();
+        assert_eq!(sp_svc.raw(), 0x4020_0000_0010_0012);
+        assert_eq!(
+            sp_svc.decode().unwrap(),
+            ArmReg::Core {
+                offset: offset_of!(kvm_regs, svc_regs)
+            }
+        );
+        assert!(ArmRegId::core(2).is_err());
+        assert!(ArmRegId::core(size_of::<kvm_regs>()).is_err());
+    }
+
+    #[test]
+    fn test_cp15_regs() {
+        // MIDR: MRC p15, 0, <Rt>, c0, c0, 0
+        assert_eq!(ArmRegId::cp15(0, 0, 0, 0).raw(), 0x4020_0000_000f_0000);
+        // SCTLR: MRC p15, 0, <Rt>, c1, c0, 0
+        let sctlr = ArmRegId::cp15(1, 0, 0, 0);
+        assert_eq!(sctlr.raw(), 0x4020_0000_000f_0800);
+        assert_eq!(sctlr.size(), 4);
+        // MPIDR: MRC p15, 0, <Rt>, c0, c0, 5
+        assert_eq!(
+            ArmRegId::cp15(0, 0, 0, 5).decode().unwrap(),
+            ArmReg::Cp15 {
+                crn: 0,
+                crm: 0,
+                opc1: 0,
+                opc2: 5
+            }
+        );
+        // TTBR1: MRRC p15, 1, <Rt>, <Rt2>, c2
+        let ttbr1 = ArmRegId::cp15_wide(2, 1);
+        assert_eq!(ttbr1.raw(), 0x4030_0000_000f_0108);
+        assert_eq!(ttbr1.size(), 8);
+        assert_eq!(
+            ttbr1.decode().unwrap(),
+            ArmReg::Cp15Wide { crm: 2, opc1: 1 }
+        );
+
+        assert!(!sctlr.is_secure());
+        assert!(sctlr.secure().is_secure());
+        assert_eq!(sctlr.secure().decode(), sctlr.decode());
+    }
+
+    #[test]
+    fn test_vfp_regs() {
+        assert_eq!(ArmRegId::d(0).raw(), 0x4030_0000_0012_0000);
+        assert_eq!(ArmRegId::d(31).decode().unwrap(), ArmReg::VfpData(31));
+        assert_eq!(ArmRegId::FPSCR.raw(), 0x4020_0000_0012_1001);
+        assert_eq!(ArmRegId::FPEXC.size(), 4);
+        assert_eq!(
+            ArmRegId::MVFR0.decode().unwrap(),
+            ArmReg::VfpControl(KVM_REG_ARM_VFP_MVFR0 as u16)
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(ArmRegId::ccsidr(1).raw(), 0x4020_0000_0011_0001);
+        assert_eq!(ArmRegId::ccsidr(1).decode().unwrap(), ArmReg::Ccsidr(1));
+        assert_eq!(ArmRegId::PSCI_VERSION.raw(), 0x4030_0000_0014_0000);
+        assert_eq!(
+            ArmRegId::PSCI_VERSION.decode().unwrap(),
+            ArmReg::Firmware(0)
+        );
+
+        let pc = ArmRegId::from_raw(0x4020_0000_0010_000f).unwrap();
+        assert_eq!(pc, ArmRegId::PC);
+        assert_eq!(u64::from(pc), 0x4020_0000_0010_000f);
+        assert_eq!(
+            ArmRegId::from_raw(0x6030_0000_0010_0000),
+            Err(Error::NotArm(0x6030_0000_0010_0000))
+        );
+        let id = ArmRegId::from_raw(0x4020_0000_000e_0000).unwrap();
+        assert_eq!(id.decode(), Err(Error::UnknownGroup(id.raw())));
+    }
+}
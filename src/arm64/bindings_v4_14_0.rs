@@ -556,18 +556,18 @@ pub const KVM_X2APIC_API_DISABLE_BROADCAST_QUIRK: u32 = 2;
 pub const KVM_ARM_DEV_EL1_VTIMER: u32 = 1;
 pub const KVM_ARM_DEV_EL1_PTIMER: u32 = 2;
 pub const KVM_ARM_DEV_PMU: u32 = 4;
-pub type __s8 = ::std::os::raw::c_schar;
-pub type __u8 = ::std::os::raw::c_uchar;
-pub type __s16 = ::std::os::raw::c_short;
-pub type __u16 = ::std::os::raw::c_ushort;
-pub type __s32 = ::std::os::raw::c_int;
-pub type __u32 = ::std::os::raw::c_uint;
-pub type __s64 = ::std::os::raw::c_longlong;
-pub type __u64 = ::std::os::raw::c_ulonglong;
+pub type __s8 = super::raw::c_schar;
+pub type __u8 = super::raw::c_uchar;
+pub type __s16 = super::raw::c_short;
+pub type __u16 = super::raw::c_ushort;
+pub type __s32 = super::raw::c_int;
+pub type __u32 = super::raw::c_uint;
+pub type __s64 = super::raw::c_longlong;
+pub type __u64 = super::raw::c_ulonglong;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct __kernel_fd_set {
-    pub fds_bits: [::std::os::raw::c_ulong; 16usize],
+    pub fds_bits: [super::raw::c_ulong; 16usize],
 }
 #[test]
 fn bindgen_test_layout___kernel_fd_set() {
@@ -582,7 +582,7 @@ fn bindgen_test_layout___kernel_fd_set() {
         concat!("Alignment of ", stringify!(__kernel_fd_set))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__kernel_fd_set>())).fds_bits as *const _ as usize },
+        ::std::mem::offset_of!(__kernel_fd_set, fds_bits),
         0usize,
         concat!(
             "Offset of field: ",
@@ -593,31 +593,31 @@ fn bindgen_test_layout___kernel_fd_set() {
     );
 }
 pub type __kernel_sighandler_t =
-    ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>;
-pub type __kernel_key_t = ::std::os::raw::c_int;
-pub type __kernel_mqd_t = ::std::os::raw::c_int;
-pub type __kernel_old_uid_t = ::std::os::raw::c_ushort;
-pub type __kernel_old_gid_t = ::std::os::raw::c_ushort;
-pub type __kernel_long_t = ::std::os::raw::c_long;
-pub type __kernel_ulong_t = ::std::os::raw::c_ulong;
+    ::std::option::Option<unsafe extern "C" fn(arg1: super::raw::c_int)>;
+pub type __kernel_key_t = super::raw::c_int;
+pub type __kernel_mqd_t = super::raw::c_int;
+pub type __kernel_old_uid_t = super::raw::c_ushort;
+pub type __kernel_old_gid_t = super::raw::c_ushort;
+pub type __kernel_long_t = super::raw::c_long;
+pub type __kernel_ulong_t = super::raw::c_ulong;
 pub type __kernel_ino_t = __kernel_ulong_t;
-pub type __kernel_mode_t = ::std::os::raw::c_uint;
-pub type __kernel_pid_t = ::std::os::raw::c_int;
-pub type __kernel_ipc_pid_t = ::std::os::raw::c_int;
-pub type __kernel_uid_t = ::std::os::raw::c_uint;
-pub type __kernel_gid_t = ::std::os::raw::c_uint;
+pub type __kernel_mode_t = super::raw::c_uint;
+pub type __kernel_pid_t = super::raw::c_int;
+pub type __kernel_ipc_pid_t = super::raw::c_int;
+pub type __kernel_uid_t = super::raw::c_uint;
+pub type __kernel_gid_t = super::raw::c_uint;
 pub type __kernel_suseconds_t = __kernel_long_t;
-pub type __kernel_daddr_t = ::std::os::raw::c_int;
-pub type __kernel_uid32_t = ::std::os::raw::c_uint;
-pub type __kernel_gid32_t = ::std::os::raw::c_uint;
-pub type __kernel_old_dev_t = ::std::os::raw::c_uint;
+pub type __kernel_daddr_t = super::raw::c_int;
+pub type __kernel_uid32_t = super::raw::c_uint;
+pub type __kernel_gid32_t = super::raw::c_uint;
+pub type __kernel_old_dev_t = super::raw::c_uint;
 pub type __kernel_size_t = __kernel_ulong_t;
 pub type __kernel_ssize_t = __kernel_long_t;
 pub type __kernel_ptrdiff_t = __kernel_long_t;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct __kernel_fsid_t {
-    pub val: [::std::os::raw::c_int; 2usize],
+    pub val: [super::raw::c_int; 2usize],
 }
 #[test]
 fn bindgen_test_layout___kernel_fsid_t() {
@@ -632,7 +632,7 @@ fn bindgen_test_layout___kernel_fsid_t() {
         concat!("Alignment of ", stringify!(__kernel_fsid_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__kernel_fsid_t>())).val as *const _ as usize },
+        ::std::mem::offset_of!(__kernel_fsid_t, val),
         0usize,
         concat!(
             "Offset of field: ",
@@ -643,14 +643,14 @@ fn bindgen_test_layout___kernel_fsid_t() {
     );
 }
 pub type __kernel_off_t = __kernel_long_t;
-pub type __kernel_loff_t = ::std::os::raw::c_longlong;
+pub type __kernel_loff_t = super::raw::c_longlong;
 pub type __kernel_time_t = __kernel_long_t;
 pub type __kernel_clock_t = __kernel_long_t;
-pub type __kernel_timer_t = ::std::os::raw::c_int;
-pub type __kernel_clockid_t = ::std::os::raw::c_int;
-pub type __kernel_caddr_t = *mut ::std::os::raw::c_char;
-pub type __kernel_uid16_t = ::std::os::raw::c_ushort;
-pub type __kernel_gid16_t = ::std::os::raw::c_ushort;
+pub type __kernel_timer_t = super::raw::c_int;
+pub type __kernel_clockid_t = super::raw::c_int;
+pub type __kernel_caddr_t = *mut super::raw::c_char;
+pub type __kernel_uid16_t = super::raw::c_ushort;
+pub type __kernel_gid16_t = super::raw::c_ushort;
 pub type __le16 = __u16;
 pub type __be16 = __u16;
 pub type __le32 = __u32;
@@ -680,7 +680,7 @@ fn bindgen_test_layout_user_pt_regs() {
         concat!("Alignment of ", stringify!(user_pt_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_pt_regs>())).regs as *const _ as usize },
+        ::std::mem::offset_of!(user_pt_regs, regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -690,7 +690,7 @@ fn bindgen_test_layout_user_pt_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_pt_regs>())).sp as *const _ as usize },
+        ::std::mem::offset_of!(user_pt_regs, sp),
         248usize,
         concat!(
             "Offset of field: ",
@@ -700,7 +700,7 @@ fn bindgen_test_layout_user_pt_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_pt_regs>())).pc as *const _ as usize },
+        ::std::mem::offset_of!(user_pt_regs, pc),
         256usize,
         concat!(
             "Offset of field: ",
@@ -710,7 +710,7 @@ fn bindgen_test_layout_user_pt_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_pt_regs>())).pstate as *const _ as usize },
+        ::std::mem::offset_of!(user_pt_regs, pstate),
         264usize,
         concat!(
             "Offset of field: ",
@@ -736,7 +736,7 @@ fn bindgen_test_layout_user_fpsimd_state() {
         concat!("Size of: ", stringify!(user_fpsimd_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_fpsimd_state>())).vregs as *const _ as usize },
+        ::std::mem::offset_of!(user_fpsimd_state, vregs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -746,7 +746,7 @@ fn bindgen_test_layout_user_fpsimd_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_fpsimd_state>())).fpsr as *const _ as usize },
+        ::std::mem::offset_of!(user_fpsimd_state, fpsr),
         512usize,
         concat!(
             "Offset of field: ",
@@ -756,7 +756,7 @@ fn bindgen_test_layout_user_fpsimd_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_fpsimd_state>())).fpcr as *const _ as usize },
+        ::std::mem::offset_of!(user_fpsimd_state, fpcr),
         516usize,
         concat!(
             "Offset of field: ",
@@ -766,7 +766,7 @@ fn bindgen_test_layout_user_fpsimd_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_fpsimd_state>())).__reserved as *const _ as usize },
+        ::std::mem::offset_of!(user_fpsimd_state, __reserved),
         520usize,
         concat!(
             "Offset of field: ",
@@ -806,9 +806,7 @@ fn bindgen_test_layout_user_hwdebug_state__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<user_hwdebug_state__bindgen_ty_1>())).addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(user_hwdebug_state__bindgen_ty_1, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -818,9 +816,7 @@ fn bindgen_test_layout_user_hwdebug_state__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<user_hwdebug_state__bindgen_ty_1>())).ctrl as *const _ as usize
-        },
+        ::std::mem::offset_of!(user_hwdebug_state__bindgen_ty_1, ctrl),
         8usize,
         concat!(
             "Offset of field: ",
@@ -830,9 +826,7 @@ fn bindgen_test_layout_user_hwdebug_state__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<user_hwdebug_state__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(user_hwdebug_state__bindgen_ty_1, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -855,7 +849,7 @@ fn bindgen_test_layout_user_hwdebug_state() {
         concat!("Alignment of ", stringify!(user_hwdebug_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_hwdebug_state>())).dbg_info as *const _ as usize },
+        ::std::mem::offset_of!(user_hwdebug_state, dbg_info),
         0usize,
         concat!(
             "Offset of field: ",
@@ -865,7 +859,7 @@ fn bindgen_test_layout_user_hwdebug_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_hwdebug_state>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(user_hwdebug_state, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -875,7 +869,7 @@ fn bindgen_test_layout_user_hwdebug_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_hwdebug_state>())).dbg_regs as *const _ as usize },
+        ::std::mem::offset_of!(user_hwdebug_state, dbg_regs),
         8usize,
         concat!(
             "Offset of field: ",
@@ -903,7 +897,7 @@ fn bindgen_test_layout_kvm_regs() {
         concat!("Size of: ", stringify!(kvm_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -913,7 +907,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).sp_el1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, sp_el1),
         272usize,
         concat!(
             "Offset of field: ",
@@ -923,7 +917,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).elr_el1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, elr_el1),
         280usize,
         concat!(
             "Offset of field: ",
@@ -933,7 +927,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).spsr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, spsr),
         288usize,
         concat!(
             "Offset of field: ",
@@ -943,7 +937,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).fp_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, fp_regs),
         336usize,
         concat!(
             "Offset of field: ",
@@ -972,7 +966,7 @@ fn bindgen_test_layout_kvm_vcpu_init() {
         concat!("Alignment of ", stringify!(kvm_vcpu_init))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_init>())).target as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_init, target),
         0usize,
         concat!(
             "Offset of field: ",
@@ -982,7 +976,7 @@ fn bindgen_test_layout_kvm_vcpu_init() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_init>())).features as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_init, features),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1045,7 +1039,7 @@ fn bindgen_test_layout_kvm_guest_debug_arch() {
         concat!("Alignment of ", stringify!(kvm_guest_debug_arch))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug_arch>())).dbg_bcr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug_arch, dbg_bcr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1055,7 +1049,7 @@ fn bindgen_test_layout_kvm_guest_debug_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug_arch>())).dbg_bvr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug_arch, dbg_bvr),
         128usize,
         concat!(
             "Offset of field: ",
@@ -1065,7 +1059,7 @@ fn bindgen_test_layout_kvm_guest_debug_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug_arch>())).dbg_wcr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug_arch, dbg_wcr),
         256usize,
         concat!(
             "Offset of field: ",
@@ -1075,7 +1069,7 @@ fn bindgen_test_layout_kvm_guest_debug_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug_arch>())).dbg_wvr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug_arch, dbg_wvr),
         384usize,
         concat!(
             "Offset of field: ",
@@ -1104,7 +1098,7 @@ fn bindgen_test_layout_kvm_debug_exit_arch() {
         concat!("Alignment of ", stringify!(kvm_debug_exit_arch))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_exit_arch>())).hsr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_exit_arch, hsr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1114,7 +1108,7 @@ fn bindgen_test_layout_kvm_debug_exit_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_exit_arch>())).far as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_exit_arch, far),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1142,7 +1136,7 @@ fn bindgen_test_layout_kvm_sync_regs() {
         concat!("Alignment of ", stringify!(kvm_sync_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sync_regs>())).device_irq_level as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sync_regs, device_irq_level),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1187,7 +1181,7 @@ fn bindgen_test_layout_kvm_user_trace_setup() {
         concat!("Alignment of ", stringify!(kvm_user_trace_setup))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_user_trace_setup>())).buf_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_user_trace_setup, buf_size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1197,7 +1191,7 @@ fn bindgen_test_layout_kvm_user_trace_setup() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_user_trace_setup>())).buf_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_user_trace_setup, buf_nr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1227,7 +1221,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         concat!("Alignment of ", stringify!(kvm_breakpoint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1237,7 +1231,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, padding),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1247,7 +1241,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, address),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1278,7 +1272,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         concat!("Alignment of ", stringify!(kvm_debug_guest))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1288,7 +1282,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1298,7 +1292,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).breakpoints as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, breakpoints),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1308,7 +1302,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).singlestep as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, singlestep),
         72usize,
         concat!(
             "Offset of field: ",
@@ -1339,7 +1333,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         concat!("Alignment of ", stringify!(kvm_memory_region))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).slot as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1349,7 +1343,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1359,9 +1353,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_memory_region>())).guest_phys_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_memory_region, guest_phys_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1371,7 +1363,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).memory_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, memory_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1403,9 +1395,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         concat!("Alignment of ", stringify!(kvm_userspace_memory_region))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).slot as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1415,9 +1405,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1427,10 +1415,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).guest_phys_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, guest_phys_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1440,9 +1425,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).memory_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, memory_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1452,10 +1435,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).userspace_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, userspace_addr),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1491,7 +1471,7 @@ fn bindgen_test_layout_kvm_irq_level__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_irq_level__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_level__bindgen_ty_1>())).irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_level__bindgen_ty_1, irq),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1501,9 +1481,7 @@ fn bindgen_test_layout_kvm_irq_level__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_level__bindgen_ty_1>())).status as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_level__bindgen_ty_1, status),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1531,7 +1509,7 @@ fn bindgen_test_layout_kvm_irq_level() {
         concat!("Alignment of ", stringify!(kvm_irq_level))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_level>())).level as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_level, level),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1556,7 +1534,7 @@ pub struct kvm_irqchip {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union kvm_irqchip__bindgen_ty_1 {
-    pub dummy: [::std::os::raw::c_char; 512usize],
+    pub dummy: [super::raw::c_char; 512usize],
     _bindgen_union_align: [u8; 512usize],
 }
 #[test]
@@ -1572,7 +1550,7 @@ fn bindgen_test_layout_kvm_irqchip__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_irqchip__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip__bindgen_ty_1>())).dummy as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip__bindgen_ty_1, dummy),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1600,7 +1578,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         concat!("Alignment of ", stringify!(kvm_irqchip))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).chip_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, chip_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1610,7 +1588,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1620,7 +1598,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).chip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, chip),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1654,7 +1632,7 @@ fn bindgen_test_layout_kvm_pit_config() {
         concat!("Alignment of ", stringify!(kvm_pit_config))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_config>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_config, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1664,7 +1642,7 @@ fn bindgen_test_layout_kvm_pit_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_config>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_config, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1696,7 +1674,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         concat!("Alignment of ", stringify!(kvm_s390_skeys))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).start_gfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, start_gfn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1706,7 +1684,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).count as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, count),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1716,7 +1694,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).skeydata_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, skeydata_addr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1726,7 +1704,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1736,7 +1714,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, reserved),
         28usize,
         concat!(
             "Offset of field: ",
@@ -1789,10 +1767,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_s390_cmma_log__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_cmma_log__bindgen_ty_1>())).remaining as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_cmma_log__bindgen_ty_1, remaining),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1802,9 +1777,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_cmma_log__bindgen_ty_1>())).mask as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_cmma_log__bindgen_ty_1, mask),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1832,7 +1805,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         concat!("Alignment of ", stringify!(kvm_s390_cmma_log))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).start_gfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, start_gfn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1842,7 +1815,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).count as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, count),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1852,7 +1825,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -1862,7 +1835,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).values as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, values),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1917,10 +1890,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).msr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, msr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1930,10 +1900,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).control
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, control),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1943,10 +1910,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).evt_page
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, evt_page),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1956,10 +1920,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).msg_page
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, msg_page),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1995,10 +1956,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).input
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, input),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2008,10 +1966,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).result
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, result),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2021,10 +1976,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).params
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, params),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2047,9 +1999,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_hyperv_exit__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1>())).synic as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1, synic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2059,9 +2009,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1>())).hcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1, hcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2089,7 +2037,7 @@ fn bindgen_test_layout_kvm_hyperv_exit() {
         concat!("Alignment of ", stringify!(kvm_hyperv_exit))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_exit>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_exit, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2099,7 +2047,7 @@ fn bindgen_test_layout_kvm_hyperv_exit() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_exit>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_exit, u),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2155,7 +2103,7 @@ pub union kvm_run__bindgen_ty_1 {
     pub s390_stsi: kvm_run__bindgen_ty_1__bindgen_ty_18,
     pub eoi: kvm_run__bindgen_ty_1__bindgen_ty_19,
     pub hyperv: kvm_hyperv_exit,
-    pub padding: [::std::os::raw::c_char; 256usize],
+    pub padding: [super::raw::c_char; 256usize],
     _bindgen_union_align: [u64; 32usize],
 }
 #[repr(C)]
@@ -2179,10 +2127,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_1>())).hardware_exit_reason
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_1, hardware_exit_reason),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2213,10 +2158,10 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_2>()))
-                .hardware_entry_failure_reason as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            kvm_run__bindgen_ty_1__bindgen_ty_2,
+            hardware_entry_failure_reason
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2248,10 +2193,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_3>())).exception as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_3, exception),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2261,10 +2203,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_3>())).error_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_3, error_code),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2299,10 +2238,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).direction as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, direction),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2312,10 +2248,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, size),
         1usize,
         concat!(
             "Offset of field: ",
@@ -2325,10 +2258,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).port as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, port),
         2usize,
         concat!(
             "Offset of field: ",
@@ -2338,10 +2268,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).count as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, count),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2351,10 +2278,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).data_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, data_offset),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2385,10 +2309,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_5() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_5>())).arch as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_5, arch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2422,10 +2343,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).phys_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, phys_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2435,10 +2353,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2448,9 +2363,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).len as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2460,10 +2373,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, is_write),
         20usize,
         concat!(
             "Offset of field: ",
@@ -2498,9 +2408,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2510,10 +2418,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).args as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, args),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2523,9 +2428,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).ret as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, ret),
         56usize,
         concat!(
             "Offset of field: ",
@@ -2535,10 +2438,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).longmode as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, longmode),
         64usize,
         concat!(
             "Offset of field: ",
@@ -2548,9 +2448,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, pad),
         68usize,
         concat!(
             "Offset of field: ",
@@ -2583,9 +2481,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).rip as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, rip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2595,10 +2491,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, is_write),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2608,9 +2501,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2643,10 +2534,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).icptcode as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, icptcode),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2656,9 +2544,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).ipa as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, ipa),
         2usize,
         concat!(
             "Offset of field: ",
@@ -2668,9 +2554,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).ipb as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, ipb),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2705,10 +2589,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_10() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_10>())).trans_exc_code
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_10, trans_exc_code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2718,10 +2599,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_10() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_10>())).pgm_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_10, pgm_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2757,10 +2635,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).dcrn as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, dcrn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2770,10 +2645,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, data),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2783,10 +2655,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, is_write),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2822,10 +2691,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).suberror as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, suberror),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2835,10 +2701,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).ndata as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, ndata),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2848,10 +2711,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2885,10 +2745,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_13() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_13>())).gprs as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_13, gprs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2924,9 +2781,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2936,10 +2791,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).ret as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, ret),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2949,10 +2801,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).args as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, args),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2991,10 +2840,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).subchannel_id
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, subchannel_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3004,10 +2850,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).subchannel_nr
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, subchannel_nr),
         2usize,
         concat!(
             "Offset of field: ",
@@ -3017,10 +2860,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).io_int_parm as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, io_int_parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3030,10 +2870,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).io_int_word as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, io_int_word),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3043,10 +2880,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).ipb as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, ipb),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3056,10 +2890,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).dequeued as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, dequeued),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3093,10 +2924,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_16() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_16>())).epr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_16, epr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3131,10 +2959,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_17() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_17>())).type_ as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_17, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3144,10 +2969,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_17() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_17>())).flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_17, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3186,10 +3008,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3199,9 +3018,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).ar as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, ar),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3211,10 +3028,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, reserved),
         9usize,
         concat!(
             "Offset of field: ",
@@ -3224,9 +3038,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).fc as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, fc),
         10usize,
         concat!(
             "Offset of field: ",
@@ -3236,10 +3048,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).sel1 as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, sel1),
         11usize,
         concat!(
             "Offset of field: ",
@@ -3249,10 +3058,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).sel2 as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, sel2),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3286,10 +3092,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_19() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_19>())).vector as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_19, vector),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3312,7 +3115,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_run__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hw as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hw),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3322,9 +3125,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).fail_entry as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, fail_entry),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3334,7 +3135,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).ex as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, ex),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3344,7 +3145,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).io as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, io),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3354,7 +3155,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).debug as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, debug),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3364,7 +3165,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).mmio as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, mmio),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3374,7 +3175,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hypercall as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hypercall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3384,9 +3185,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).tpr_access as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, tpr_access),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3396,9 +3195,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_sieic as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_sieic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3408,9 +3205,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_reset_flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_reset_flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3420,9 +3215,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_ucontrol as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_ucontrol),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3432,7 +3225,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).dcr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, dcr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3442,7 +3235,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).internal as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, internal),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3452,7 +3245,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).osi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, osi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3462,9 +3255,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).papr_hcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, papr_hcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3474,7 +3265,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_tsch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_tsch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3484,7 +3275,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).epr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, epr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3494,9 +3285,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).system_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, system_event),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3506,7 +3295,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_stsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_stsi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3516,7 +3305,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).eoi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, eoi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3526,7 +3315,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hyperv as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hyperv),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3536,7 +3325,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, padding),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3555,7 +3344,7 @@ impl Default for kvm_run__bindgen_ty_1 {
 #[derive(Copy, Clone)]
 pub union kvm_run__bindgen_ty_2 {
     pub regs: kvm_sync_regs,
-    pub padding: [::std::os::raw::c_char; 2048usize],
+    pub padding: [super::raw::c_char; 2048usize],
     _bindgen_union_align: [u64; 256usize],
 }
 #[test]
@@ -3571,7 +3360,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_2() {
         concat!("Alignment of ", stringify!(kvm_run__bindgen_ty_2))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_2>())).regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_2, regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3581,7 +3370,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_2>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_2, padding),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3609,9 +3398,7 @@ fn bindgen_test_layout_kvm_run() {
         concat!("Alignment of ", stringify!(kvm_run))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run>())).request_interrupt_window as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run, request_interrupt_window),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3621,7 +3408,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).immediate_exit as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, immediate_exit),
         1usize,
         concat!(
             "Offset of field: ",
@@ -3631,7 +3418,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).padding1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, padding1),
         2usize,
         concat!(
             "Offset of field: ",
@@ -3641,7 +3428,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).exit_reason as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, exit_reason),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3651,9 +3438,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run>())).ready_for_interrupt_injection as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run, ready_for_interrupt_injection),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3663,7 +3448,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).if_flag as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, if_flag),
         13usize,
         concat!(
             "Offset of field: ",
@@ -3673,7 +3458,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, flags),
         14usize,
         concat!(
             "Offset of field: ",
@@ -3683,7 +3468,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).cr8 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, cr8),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3693,7 +3478,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).apic_base as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, apic_base),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3703,7 +3488,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).kvm_valid_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, kvm_valid_regs),
         288usize,
         concat!(
             "Offset of field: ",
@@ -3713,7 +3498,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).kvm_dirty_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, kvm_dirty_regs),
         296usize,
         concat!(
             "Offset of field: ",
@@ -3723,7 +3508,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).s as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, s),
         304usize,
         concat!(
             "Offset of field: ",
@@ -3758,7 +3543,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio_zone))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3768,7 +3553,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3778,7 +3563,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3809,7 +3594,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).phys_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, phys_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3819,7 +3604,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3829,7 +3614,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3839,7 +3624,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, data),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3869,7 +3654,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio_ring))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).first as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, first),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3879,7 +3664,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).last as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, last),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3889,9 +3674,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).coalesced_mmio as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, coalesced_mmio),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3924,7 +3707,7 @@ fn bindgen_test_layout_kvm_translation() {
         concat!("Alignment of ", stringify!(kvm_translation))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).linear_address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, linear_address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3934,9 +3717,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_translation>())).physical_address as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_translation, physical_address),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3946,7 +3727,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).valid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, valid),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3956,7 +3737,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).writeable as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, writeable),
         17usize,
         concat!(
             "Offset of field: ",
@@ -3966,7 +3747,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).usermode as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, usermode),
         18usize,
         concat!(
             "Offset of field: ",
@@ -3976,7 +3757,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, pad),
         19usize,
         concat!(
             "Offset of field: ",
@@ -4010,7 +3791,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         concat!("Alignment of ", stringify!(kvm_s390_mem_op))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).gaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, gaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4020,7 +3801,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4030,7 +3811,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4040,7 +3821,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).op as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, op),
         20usize,
         concat!(
             "Offset of field: ",
@@ -4050,7 +3831,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).buf as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, buf),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4060,7 +3841,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).ar as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, ar),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4070,7 +3851,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, reserved),
         33usize,
         concat!(
             "Offset of field: ",
@@ -4098,7 +3879,7 @@ fn bindgen_test_layout_kvm_interrupt() {
         concat!("Alignment of ", stringify!(kvm_interrupt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_interrupt>())).irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_interrupt, irq),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4118,7 +3899,7 @@ pub struct kvm_dirty_log {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union kvm_dirty_log__bindgen_ty_1 {
-    pub dirty_bitmap: *mut ::std::os::raw::c_void,
+    pub dirty_bitmap: *mut super::raw::c_void,
     pub padding2: __u64,
     _bindgen_union_align: u64,
 }
@@ -4135,10 +3916,7 @@ fn bindgen_test_layout_kvm_dirty_log__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_dirty_log__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_dirty_log__bindgen_ty_1>())).dirty_bitmap as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_dirty_log__bindgen_ty_1, dirty_bitmap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4148,9 +3926,7 @@ fn bindgen_test_layout_kvm_dirty_log__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_dirty_log__bindgen_ty_1>())).padding2 as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_dirty_log__bindgen_ty_1, padding2),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4178,7 +3954,7 @@ fn bindgen_test_layout_kvm_dirty_log() {
         concat!("Alignment of ", stringify!(kvm_dirty_log))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_log>())).slot as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_log, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4188,7 +3964,7 @@ fn bindgen_test_layout_kvm_dirty_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_log>())).padding1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_log, padding1),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4222,7 +3998,7 @@ fn bindgen_test_layout_kvm_signal_mask() {
         concat!("Alignment of ", stringify!(kvm_signal_mask))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_signal_mask>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_signal_mask, len),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4232,7 +4008,7 @@ fn bindgen_test_layout_kvm_signal_mask() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_signal_mask>())).sigset as *const _ as usize },
+        ::std::mem::offset_of!(kvm_signal_mask, sigset),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4262,7 +4038,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         concat!("Alignment of ", stringify!(kvm_tpr_access_ctl))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4272,7 +4048,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4282,7 +4058,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, reserved),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4310,7 +4086,7 @@ fn bindgen_test_layout_kvm_vapic_addr() {
         concat!("Alignment of ", stringify!(kvm_vapic_addr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vapic_addr>())).vapic_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vapic_addr, vapic_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4338,7 +4114,7 @@ fn bindgen_test_layout_kvm_mp_state() {
         concat!("Alignment of ", stringify!(kvm_mp_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_mp_state>())).mp_state as *const _ as usize },
+        ::std::mem::offset_of!(kvm_mp_state, mp_state),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4367,7 +4143,7 @@ fn bindgen_test_layout_kvm_s390_psw() {
         concat!("Alignment of ", stringify!(kvm_s390_psw))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_psw>())).mask as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_psw, mask),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4377,7 +4153,7 @@ fn bindgen_test_layout_kvm_s390_psw() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_psw>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_psw, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4407,7 +4183,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         concat!("Alignment of ", stringify!(kvm_s390_interrupt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4417,7 +4193,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).parm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4427,7 +4203,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).parm64 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, parm64),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4458,7 +4234,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         concat!("Alignment of ", stringify!(kvm_s390_io_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).subchannel_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, subchannel_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4468,7 +4244,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).subchannel_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, subchannel_nr),
         2usize,
         concat!(
             "Offset of field: ",
@@ -4478,7 +4254,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).io_int_parm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, io_int_parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4488,7 +4264,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).io_int_word as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, io_int_word),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4518,7 +4294,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         concat!("Alignment of ", stringify!(kvm_s390_ext_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).ext_params as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, ext_params),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4528,7 +4304,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4538,7 +4314,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).ext_params2 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, ext_params2),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4578,9 +4354,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         concat!("Alignment of ", stringify!(kvm_s390_pgm_info))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_pgm_info>())).trans_exc_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, trans_exc_code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4590,7 +4364,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).mon_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, mon_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4600,7 +4374,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_address),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4610,7 +4384,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).data_exc_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, data_exc_code),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4620,7 +4394,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, code),
         28usize,
         concat!(
             "Offset of field: ",
@@ -4630,7 +4404,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).mon_class_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, mon_class_nr),
         30usize,
         concat!(
             "Offset of field: ",
@@ -4640,7 +4414,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_code),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4650,7 +4424,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_atmid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_atmid),
         33usize,
         concat!(
             "Offset of field: ",
@@ -4660,7 +4434,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).exc_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, exc_access_id),
         34usize,
         concat!(
             "Offset of field: ",
@@ -4670,7 +4444,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_access_id),
         35usize,
         concat!(
             "Offset of field: ",
@@ -4680,7 +4454,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).op_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, op_access_id),
         36usize,
         concat!(
             "Offset of field: ",
@@ -4690,7 +4464,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, flags),
         37usize,
         concat!(
             "Offset of field: ",
@@ -4700,7 +4474,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, pad),
         38usize,
         concat!(
             "Offset of field: ",
@@ -4728,7 +4502,7 @@ fn bindgen_test_layout_kvm_s390_prefix_info() {
         concat!("Alignment of ", stringify!(kvm_s390_prefix_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_prefix_info>())).address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_prefix_info, address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4756,7 +4530,7 @@ fn bindgen_test_layout_kvm_s390_extcall_info() {
         concat!("Alignment of ", stringify!(kvm_s390_extcall_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_extcall_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_extcall_info, code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4784,7 +4558,7 @@ fn bindgen_test_layout_kvm_s390_emerg_info() {
         concat!("Alignment of ", stringify!(kvm_s390_emerg_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_emerg_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_emerg_info, code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4812,7 +4586,7 @@ fn bindgen_test_layout_kvm_s390_stop_info() {
         concat!("Alignment of ", stringify!(kvm_s390_stop_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_stop_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_stop_info, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4845,7 +4619,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         concat!("Alignment of ", stringify!(kvm_s390_mchk_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).cr14 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, cr14),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4855,7 +4629,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).mcic as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, mcic),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4865,10 +4639,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_mchk_info>())).failing_storage_address as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, failing_storage_address),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4878,9 +4649,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_mchk_info>())).ext_damage_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, ext_damage_code),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4890,7 +4659,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, pad),
         28usize,
         concat!(
             "Offset of field: ",
@@ -4900,7 +4669,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).fixed_logout as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, fixed_logout),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4927,7 +4696,7 @@ pub union kvm_s390_irq__bindgen_ty_1 {
     pub prefix: kvm_s390_prefix_info,
     pub stop: kvm_s390_stop_info,
     pub mchk: kvm_s390_mchk_info,
-    pub reserved: [::std::os::raw::c_char; 64usize],
+    pub reserved: [super::raw::c_char; 64usize],
     _bindgen_union_align: [u64; 8usize],
 }
 #[test]
@@ -4943,7 +4712,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_s390_irq__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).io as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, io),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4953,7 +4722,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).ext as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, ext),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4963,7 +4732,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).pgm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, pgm),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4973,9 +4742,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).emerg as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, emerg),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4985,9 +4752,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).extcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, extcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4997,9 +4762,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).prefix as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, prefix),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5009,7 +4772,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).stop as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, stop),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5019,7 +4782,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).mchk as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, mchk),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5029,9 +4792,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5059,7 +4820,7 @@ fn bindgen_test_layout_kvm_s390_irq() {
         concat!("Alignment of ", stringify!(kvm_s390_irq))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5069,7 +4830,7 @@ fn bindgen_test_layout_kvm_s390_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq, u),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5105,7 +4866,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         concat!("Alignment of ", stringify!(kvm_s390_irq_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).buf as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, buf),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5115,7 +4876,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5125,7 +4886,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, len),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5135,7 +4896,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, reserved),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5165,7 +4926,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         concat!("Alignment of ", stringify!(kvm_guest_debug))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).control as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, control),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5175,7 +4936,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5185,7 +4946,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).arch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, arch),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5225,7 +4986,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         concat!("Alignment of ", stringify!(kvm_ioeventfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).datamatch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, datamatch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5235,7 +4996,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5245,7 +5006,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5255,7 +5016,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, fd),
         20usize,
         concat!(
             "Offset of field: ",
@@ -5265,7 +5026,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5275,7 +5036,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, pad),
         28usize,
         concat!(
             "Offset of field: ",
@@ -5311,7 +5072,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         concat!("Alignment of ", stringify!(kvm_enable_cap))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).cap as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, cap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5321,7 +5082,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5331,7 +5092,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).args as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, args),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5341,7 +5102,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, pad),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5376,7 +5137,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         concat!("Alignment of ", stringify!(kvm_ppc_pvinfo))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5386,7 +5147,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).hcall as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, hcall),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5396,7 +5157,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, pad),
         20usize,
         concat!(
             "Offset of field: ",
@@ -5430,9 +5191,7 @@ fn bindgen_test_layout_kvm_ppc_one_page_size() {
         concat!("Alignment of ", stringify!(kvm_ppc_one_page_size))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_page_size>())).page_shift as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_page_size, page_shift),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5442,7 +5201,7 @@ fn bindgen_test_layout_kvm_ppc_one_page_size() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_one_page_size>())).pte_enc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_one_page_size, pte_enc),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5472,9 +5231,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         concat!("Alignment of ", stringify!(kvm_ppc_one_seg_page_size))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).page_shift as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, page_shift),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5484,9 +5241,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).slb_enc as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, slb_enc),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5496,7 +5251,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).enc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, enc),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5528,7 +5283,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         concat!("Alignment of ", stringify!(kvm_ppc_smmu_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5538,7 +5293,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).slb_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, slb_size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5548,7 +5303,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).data_keys as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, data_keys),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5558,7 +5313,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).instr_keys as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, instr_keys),
         14usize,
         concat!(
             "Offset of field: ",
@@ -5568,7 +5323,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).sps as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, sps),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5598,7 +5353,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         concat!("Alignment of ", stringify!(kvm_ppc_resize_hpt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5608,7 +5363,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).shift as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, shift),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5618,7 +5373,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5647,7 +5402,7 @@ fn bindgen_test_layout_kvm_irq_routing_irqchip() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_irqchip))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_irqchip>())).irqchip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_irqchip, irqchip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5657,7 +5412,7 @@ fn bindgen_test_layout_kvm_irq_routing_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_irqchip>())).pin as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_irqchip, pin),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5698,9 +5453,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_msi__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_msi__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5710,9 +5463,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_msi__bindgen_ty_1>())).devid as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_msi__bindgen_ty_1, devid),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5740,7 +5491,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_msi))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).address_lo as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, address_lo),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5750,7 +5501,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).address_hi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, address_hi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5760,7 +5511,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5797,9 +5548,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_s390_adapter))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).ind_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, ind_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5809,10 +5558,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).summary_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, summary_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5822,9 +5568,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).ind_offset as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, ind_offset),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5834,10 +5578,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).summary_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, summary_offset),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5847,9 +5588,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).adapter_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, adapter_id),
         28usize,
         concat!(
             "Offset of field: ",
@@ -5878,7 +5617,7 @@ fn bindgen_test_layout_kvm_irq_routing_hv_sint() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_hv_sint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_hv_sint>())).vcpu as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_hv_sint, vcpu),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5888,7 +5627,7 @@ fn bindgen_test_layout_kvm_irq_routing_hv_sint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_hv_sint>())).sint as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_hv_sint, sint),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5933,10 +5672,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).irqchip as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, irqchip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5946,9 +5682,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).msi as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, msi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5958,10 +5692,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).adapter as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, adapter),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5971,10 +5702,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).hv_sint as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, hv_sint),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5984,9 +5712,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6014,7 +5740,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_entry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, gsi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6024,7 +5750,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, type_),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6034,7 +5760,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6044,7 +5770,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6054,7 +5780,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, u),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6088,7 +5814,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         concat!("Alignment of ", stringify!(kvm_irq_routing))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6098,7 +5824,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6108,7 +5834,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).entries as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, entries),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6145,7 +5871,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         concat!("Alignment of ", stringify!(kvm_irqfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, fd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6155,7 +5881,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, gsi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6165,7 +5891,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6175,7 +5901,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).resamplefd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, resamplefd),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6185,7 +5911,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, pad),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6215,7 +5941,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         concat!("Alignment of ", stringify!(kvm_clock_data))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).clock as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, clock),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6225,7 +5951,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6235,7 +5961,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6266,7 +5992,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         concat!("Alignment of ", stringify!(kvm_config_tlb))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).params as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, params),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6276,7 +6002,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).array as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, array),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6286,7 +6012,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).mmu_type as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, mmu_type),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6296,7 +6022,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).array_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, array_len),
         20usize,
         concat!(
             "Offset of field: ",
@@ -6325,7 +6051,7 @@ fn bindgen_test_layout_kvm_dirty_tlb() {
         concat!("Alignment of ", stringify!(kvm_dirty_tlb))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_tlb>())).bitmap as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_tlb, bitmap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6335,7 +6061,7 @@ fn bindgen_test_layout_kvm_dirty_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_tlb>())).num_dirty as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_tlb, num_dirty),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6364,7 +6090,7 @@ fn bindgen_test_layout_kvm_reg_list() {
         concat!("Alignment of ", stringify!(kvm_reg_list))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_reg_list>())).n as *const _ as usize },
+        ::std::mem::offset_of!(kvm_reg_list, n),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6374,7 +6100,7 @@ fn bindgen_test_layout_kvm_reg_list() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_reg_list>())).reg as *const _ as usize },
+        ::std::mem::offset_of!(kvm_reg_list, reg),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6403,7 +6129,7 @@ fn bindgen_test_layout_kvm_one_reg() {
         concat!("Alignment of ", stringify!(kvm_one_reg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_one_reg>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_one_reg, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6413,7 +6139,7 @@ fn bindgen_test_layout_kvm_one_reg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_one_reg>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_one_reg, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6446,7 +6172,7 @@ fn bindgen_test_layout_kvm_msi() {
         concat!("Alignment of ", stringify!(kvm_msi))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).address_lo as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, address_lo),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6456,7 +6182,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).address_hi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, address_hi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6466,7 +6192,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6476,7 +6202,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6486,7 +6212,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).devid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, devid),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6496,7 +6222,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, pad),
         20usize,
         concat!(
             "Offset of field: ",
@@ -6525,7 +6251,7 @@ fn bindgen_test_layout_kvm_arm_device_addr() {
         concat!("Alignment of ", stringify!(kvm_arm_device_addr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_arm_device_addr>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_arm_device_addr, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6535,7 +6261,7 @@ fn bindgen_test_layout_kvm_arm_device_addr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_arm_device_addr>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_arm_device_addr, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6565,7 +6291,7 @@ fn bindgen_test_layout_kvm_create_device() {
         concat!("Alignment of ", stringify!(kvm_create_device))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6575,7 +6301,7 @@ fn bindgen_test_layout_kvm_create_device() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, fd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6585,7 +6311,7 @@ fn bindgen_test_layout_kvm_create_device() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6616,7 +6342,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         concat!("Alignment of ", stringify!(kvm_device_attr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6626,7 +6352,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).group as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, group),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6636,7 +6362,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).attr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, attr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6646,7 +6372,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, addr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6685,7 +6411,7 @@ fn bindgen_test_layout_kvm_vfio_spapr_tce() {
         concat!("Alignment of ", stringify!(kvm_vfio_spapr_tce))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vfio_spapr_tce>())).groupfd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vfio_spapr_tce, groupfd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6695,7 +6421,7 @@ fn bindgen_test_layout_kvm_vfio_spapr_tce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vfio_spapr_tce>())).tablefd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vfio_spapr_tce, tablefd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6725,7 +6451,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         concat!("Alignment of ", stringify!(kvm_s390_ucas_mapping))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).user_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, user_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6735,7 +6461,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).vcpu_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, vcpu_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6745,7 +6471,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).length as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, length),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6787,10 +6513,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_pci_dev__bindgen_ty_1>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6818,9 +6541,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         concat!("Alignment of ", stringify!(kvm_assigned_pci_dev))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6830,7 +6551,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).busnr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, busnr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6840,7 +6561,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).devfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, devfn),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6850,7 +6571,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6860,7 +6581,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).segnr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, segnr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6903,9 +6624,7 @@ fn bindgen_test_layout_kvm_assigned_irq__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_assigned_irq__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_irq__bindgen_ty_1>())).reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_irq__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6933,9 +6652,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         concat!("Alignment of ", stringify!(kvm_assigned_irq))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_irq>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_irq, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6945,7 +6662,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).host_irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, host_irq),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6955,7 +6672,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).guest_irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, guest_irq),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6965,7 +6682,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -7000,9 +6717,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         concat!("Alignment of ", stringify!(kvm_assigned_msix_nr))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7012,7 +6727,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).entry_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, entry_nr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7022,7 +6737,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, padding),
         6usize,
         concat!(
             "Offset of field: ",
@@ -7053,9 +6768,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         concat!("Alignment of ", stringify!(kvm_assigned_msix_entry))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7065,7 +6778,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, gsi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7075,7 +6788,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).entry as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, entry),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7085,7 +6798,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, padding),
         10usize,
         concat!(
             "Offset of field: ",
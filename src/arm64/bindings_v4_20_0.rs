@@ -720,18 +720,18 @@ pub const KVM_ARM_DEV_EL1_PTIMER: u32 = 2;
 pub const KVM_ARM_DEV_PMU: u32 = 4;
 pub const KVM_HYPERV_CONN_ID_MASK: u32 = 16777215;
 pub const KVM_HYPERV_EVENTFD_DEASSIGN: u32 = 1;
-pub type __s8 = ::std::os::raw::c_schar;
-pub type __u8 = ::std::os::raw::c_uchar;
-pub type __s16 = ::std::os::raw::c_short;
-pub type __u16 = ::std::os::raw::c_ushort;
-pub type __s32 = ::std::os::raw::c_int;
-pub type __u32 = ::std::os::raw::c_uint;
-pub type __s64 = ::std::os::raw::c_longlong;
-pub type __u64 = ::std::os::raw::c_ulonglong;
+pub type __s8 = super::raw::c_schar;
+pub type __u8 = super::raw::c_uchar;
+pub type __s16 = super::raw::c_short;
+pub type __u16 = super::raw::c_ushort;
+pub type __s32 = super::raw::c_int;
+pub type __u32 = super::raw::c_uint;
+pub type __s64 = super::raw::c_longlong;
+pub type __u64 = super::raw::c_ulonglong;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct __kernel_fd_set {
-    pub fds_bits: [::std::os::raw::c_ulong; 16usize],
+    pub fds_bits: [super::raw::c_ulong; 16usize],
 }
 #[test]
 fn bindgen_test_layout___kernel_fd_set() {
@@ -746,7 +746,7 @@ fn bindgen_test_layout___kernel_fd_set() {
         concat!("Alignment of ", stringify!(__kernel_fd_set))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__kernel_fd_set>())).fds_bits as *const _ as usize },
+        ::std::mem::offset_of!(__kernel_fd_set, fds_bits),
         0usize,
         concat!(
             "Offset of field: ",
@@ -757,31 +757,31 @@ fn bindgen_test_layout___kernel_fd_set() {
     );
 }
 pub type __kernel_sighandler_t =
-    ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>;
-pub type __kernel_key_t = ::std::os::raw::c_int;
-pub type __kernel_mqd_t = ::std::os::raw::c_int;
-pub type __kernel_old_uid_t = ::std::os::raw::c_ushort;
-pub type __kernel_old_gid_t = ::std::os::raw::c_ushort;
-pub type __kernel_long_t = ::std::os::raw::c_long;
-pub type __kernel_ulong_t = ::std::os::raw::c_ulong;
+    ::std::option::Option<unsafe extern "C" fn(arg1: super::raw::c_int)>;
+pub type __kernel_key_t = super::raw::c_int;
+pub type __kernel_mqd_t = super::raw::c_int;
+pub type __kernel_old_uid_t = super::raw::c_ushort;
+pub type __kernel_old_gid_t = super::raw::c_ushort;
+pub type __kernel_long_t = super::raw::c_long;
+pub type __kernel_ulong_t = super::raw::c_ulong;
 pub type __kernel_ino_t = __kernel_ulong_t;
-pub type __kernel_mode_t = ::std::os::raw::c_uint;
-pub type __kernel_pid_t = ::std::os::raw::c_int;
-pub type __kernel_ipc_pid_t = ::std::os::raw::c_int;
-pub type __kernel_uid_t = ::std::os::raw::c_uint;
-pub type __kernel_gid_t = ::std::os::raw::c_uint;
+pub type __kernel_mode_t = super::raw::c_uint;
+pub type __kernel_pid_t = super::raw::c_int;
+pub type __kernel_ipc_pid_t = super::raw::c_int;
+pub type __kernel_uid_t = super::raw::c_uint;
+pub type __kernel_gid_t = super::raw::c_uint;
 pub type __kernel_suseconds_t = __kernel_long_t;
-pub type __kernel_daddr_t = ::std::os::raw::c_int;
-pub type __kernel_uid32_t = ::std::os::raw::c_uint;
-pub type __kernel_gid32_t = ::std::os::raw::c_uint;
-pub type __kernel_old_dev_t = ::std::os::raw::c_uint;
+pub type __kernel_daddr_t = super::raw::c_int;
+pub type __kernel_uid32_t = super::raw::c_uint;
+pub type __kernel_gid32_t = super::raw::c_uint;
+pub type __kernel_old_dev_t = super::raw::c_uint;
 pub type __kernel_size_t = __kernel_ulong_t;
 pub type __kernel_ssize_t = __kernel_long_t;
 pub type __kernel_ptrdiff_t = __kernel_long_t;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct __kernel_fsid_t {
-    pub val: [::std::os::raw::c_int; 2usize],
+    pub val: [super::raw::c_int; 2usize],
 }
 #[test]
 fn bindgen_test_layout___kernel_fsid_t() {
@@ -796,7 +796,7 @@ fn bindgen_test_layout___kernel_fsid_t() {
         concat!("Alignment of ", stringify!(__kernel_fsid_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__kernel_fsid_t>())).val as *const _ as usize },
+        ::std::mem::offset_of!(__kernel_fsid_t, val),
         0usize,
         concat!(
             "Offset of field: ",
@@ -807,15 +807,15 @@ fn bindgen_test_layout___kernel_fsid_t() {
     );
 }
 pub type __kernel_off_t = __kernel_long_t;
-pub type __kernel_loff_t = ::std::os::raw::c_longlong;
+pub type __kernel_loff_t = super::raw::c_longlong;
 pub type __kernel_time_t = __kernel_long_t;
-pub type __kernel_time64_t = ::std::os::raw::c_longlong;
+pub type __kernel_time64_t = super::raw::c_longlong;
 pub type __kernel_clock_t = __kernel_long_t;
-pub type __kernel_timer_t = ::std::os::raw::c_int;
-pub type __kernel_clockid_t = ::std::os::raw::c_int;
-pub type __kernel_caddr_t = *mut ::std::os::raw::c_char;
-pub type __kernel_uid16_t = ::std::os::raw::c_ushort;
-pub type __kernel_gid16_t = ::std::os::raw::c_ushort;
+pub type __kernel_timer_t = super::raw::c_int;
+pub type __kernel_clockid_t = super::raw::c_int;
+pub type __kernel_caddr_t = *mut super::raw::c_char;
+pub type __kernel_uid16_t = super::raw::c_ushort;
+pub type __kernel_gid16_t = super::raw::c_ushort;
 pub type __le16 = __u16;
 pub type __be16 = __u16;
 pub type __le32 = __u32;
@@ -824,7 +824,7 @@ pub type __le64 = __u64;
 pub type __be64 = __u64;
 pub type __sum16 = __u16;
 pub type __wsum = __u32;
-pub type __poll_t = ::std::os::raw::c_uint;
+pub type __poll_t = super::raw::c_uint;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct sigcontext {
@@ -844,7 +844,7 @@ fn bindgen_test_layout_sigcontext() {
         concat!("Size of: ", stringify!(sigcontext))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).fault_address as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, fault_address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -854,7 +854,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).regs as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, regs),
         8usize,
         concat!(
             "Offset of field: ",
@@ -864,7 +864,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).sp as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, sp),
         256usize,
         concat!(
             "Offset of field: ",
@@ -874,7 +874,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).pc as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, pc),
         264usize,
         concat!(
             "Offset of field: ",
@@ -884,7 +884,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).pstate as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, pstate),
         272usize,
         concat!(
             "Offset of field: ",
@@ -894,7 +894,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).__reserved as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, __reserved),
         288usize,
         concat!(
             "Offset of field: ",
@@ -928,7 +928,7 @@ fn bindgen_test_layout__aarch64_ctx() {
         concat!("Alignment of ", stringify!(_aarch64_ctx))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_aarch64_ctx>())).magic as *const _ as usize },
+        ::std::mem::offset_of!(_aarch64_ctx, magic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -938,7 +938,7 @@ fn bindgen_test_layout__aarch64_ctx() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_aarch64_ctx>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_aarch64_ctx, size),
         4usize,
         concat!(
             "Offset of field: ",
@@ -964,7 +964,7 @@ fn bindgen_test_layout_fpsimd_context() {
         concat!("Size of: ", stringify!(fpsimd_context))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<fpsimd_context>())).head as *const _ as usize },
+        ::std::mem::offset_of!(fpsimd_context, head),
         0usize,
         concat!(
             "Offset of field: ",
@@ -974,7 +974,7 @@ fn bindgen_test_layout_fpsimd_context() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<fpsimd_context>())).fpsr as *const _ as usize },
+        ::std::mem::offset_of!(fpsimd_context, fpsr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -984,7 +984,7 @@ fn bindgen_test_layout_fpsimd_context() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<fpsimd_context>())).fpcr as *const _ as usize },
+        ::std::mem::offset_of!(fpsimd_context, fpcr),
         12usize,
         concat!(
             "Offset of field: ",
@@ -994,7 +994,7 @@ fn bindgen_test_layout_fpsimd_context() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<fpsimd_context>())).vregs as *const _ as usize },
+        ::std::mem::offset_of!(fpsimd_context, vregs),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1023,7 +1023,7 @@ fn bindgen_test_layout_esr_context() {
         concat!("Alignment of ", stringify!(esr_context))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<esr_context>())).head as *const _ as usize },
+        ::std::mem::offset_of!(esr_context, head),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1033,7 +1033,7 @@ fn bindgen_test_layout_esr_context() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<esr_context>())).esr as *const _ as usize },
+        ::std::mem::offset_of!(esr_context, esr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1064,7 +1064,7 @@ fn bindgen_test_layout_extra_context() {
         concat!("Alignment of ", stringify!(extra_context))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<extra_context>())).head as *const _ as usize },
+        ::std::mem::offset_of!(extra_context, head),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1074,7 +1074,7 @@ fn bindgen_test_layout_extra_context() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<extra_context>())).datap as *const _ as usize },
+        ::std::mem::offset_of!(extra_context, datap),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1084,7 +1084,7 @@ fn bindgen_test_layout_extra_context() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<extra_context>())).size as *const _ as usize },
+        ::std::mem::offset_of!(extra_context, size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1094,7 +1094,7 @@ fn bindgen_test_layout_extra_context() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<extra_context>())).__reserved as *const _ as usize },
+        ::std::mem::offset_of!(extra_context, __reserved),
         20usize,
         concat!(
             "Offset of field: ",
@@ -1124,7 +1124,7 @@ fn bindgen_test_layout_sve_context() {
         concat!("Alignment of ", stringify!(sve_context))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sve_context>())).head as *const _ as usize },
+        ::std::mem::offset_of!(sve_context, head),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1134,7 +1134,7 @@ fn bindgen_test_layout_sve_context() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sve_context>())).vl as *const _ as usize },
+        ::std::mem::offset_of!(sve_context, vl),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1144,7 +1144,7 @@ fn bindgen_test_layout_sve_context() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sve_context>())).__reserved as *const _ as usize },
+        ::std::mem::offset_of!(sve_context, __reserved),
         10usize,
         concat!(
             "Offset of field: ",
@@ -1185,7 +1185,7 @@ fn bindgen_test_layout_prctl_mm_map() {
         concat!("Alignment of ", stringify!(prctl_mm_map))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<prctl_mm_map>())).start_code as *const _ as usize },
+        ::std::mem::offset_of!(prctl_mm_map, start_code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1195,7 +1195,7 @@ fn bindgen_test_layout_prctl_mm_map() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<prctl_mm_map>())).end_code as *const _ as usize },
+        ::std::mem::offset_of!(prctl_mm_map, end_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1205,7 +1205,7 @@ fn bindgen_test_layout_prctl_mm_map() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<prctl_mm_map>())).start_data as *const _ as usize },
+        ::std::mem::offset_of!(prctl_mm_map, start_data),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1215,7 +1215,7 @@ fn bindgen_test_layout_prctl_mm_map() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<prctl_mm_map>())).end_data as *const _ as usize },
+        ::std::mem::offset_of!(prctl_mm_map, end_data),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1225,7 +1225,7 @@ fn bindgen_test_layout_prctl_mm_map() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<prctl_mm_map>())).start_brk as *const _ as usize },
+        ::std::mem::offset_of!(prctl_mm_map, start_brk),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1235,7 +1235,7 @@ fn bindgen_test_layout_prctl_mm_map() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<prctl_mm_map>())).brk as *const _ as usize },
+        ::std::mem::offset_of!(prctl_mm_map, brk),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1245,7 +1245,7 @@ fn bindgen_test_layout_prctl_mm_map() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<prctl_mm_map>())).start_stack as *const _ as usize },
+        ::std::mem::offset_of!(prctl_mm_map, start_stack),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1255,7 +1255,7 @@ fn bindgen_test_layout_prctl_mm_map() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<prctl_mm_map>())).arg_start as *const _ as usize },
+        ::std::mem::offset_of!(prctl_mm_map, arg_start),
         56usize,
         concat!(
             "Offset of field: ",
@@ -1265,7 +1265,7 @@ fn bindgen_test_layout_prctl_mm_map() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<prctl_mm_map>())).arg_end as *const _ as usize },
+        ::std::mem::offset_of!(prctl_mm_map, arg_end),
         64usize,
         concat!(
             "Offset of field: ",
@@ -1275,7 +1275,7 @@ fn bindgen_test_layout_prctl_mm_map() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<prctl_mm_map>())).env_start as *const _ as usize },
+        ::std::mem::offset_of!(prctl_mm_map, env_start),
         72usize,
         concat!(
             "Offset of field: ",
@@ -1285,7 +1285,7 @@ fn bindgen_test_layout_prctl_mm_map() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<prctl_mm_map>())).env_end as *const _ as usize },
+        ::std::mem::offset_of!(prctl_mm_map, env_end),
         80usize,
         concat!(
             "Offset of field: ",
@@ -1295,7 +1295,7 @@ fn bindgen_test_layout_prctl_mm_map() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<prctl_mm_map>())).auxv as *const _ as usize },
+        ::std::mem::offset_of!(prctl_mm_map, auxv),
         88usize,
         concat!(
             "Offset of field: ",
@@ -1305,7 +1305,7 @@ fn bindgen_test_layout_prctl_mm_map() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<prctl_mm_map>())).auxv_size as *const _ as usize },
+        ::std::mem::offset_of!(prctl_mm_map, auxv_size),
         96usize,
         concat!(
             "Offset of field: ",
@@ -1315,7 +1315,7 @@ fn bindgen_test_layout_prctl_mm_map() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<prctl_mm_map>())).exe_fd as *const _ as usize },
+        ::std::mem::offset_of!(prctl_mm_map, exe_fd),
         100usize,
         concat!(
             "Offset of field: ",
@@ -1351,7 +1351,7 @@ fn bindgen_test_layout_user_pt_regs() {
         concat!("Alignment of ", stringify!(user_pt_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_pt_regs>())).regs as *const _ as usize },
+        ::std::mem::offset_of!(user_pt_regs, regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1361,7 +1361,7 @@ fn bindgen_test_layout_user_pt_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_pt_regs>())).sp as *const _ as usize },
+        ::std::mem::offset_of!(user_pt_regs, sp),
         248usize,
         concat!(
             "Offset of field: ",
@@ -1371,7 +1371,7 @@ fn bindgen_test_layout_user_pt_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_pt_regs>())).pc as *const _ as usize },
+        ::std::mem::offset_of!(user_pt_regs, pc),
         256usize,
         concat!(
             "Offset of field: ",
@@ -1381,7 +1381,7 @@ fn bindgen_test_layout_user_pt_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_pt_regs>())).pstate as *const _ as usize },
+        ::std::mem::offset_of!(user_pt_regs, pstate),
         264usize,
         concat!(
             "Offset of field: ",
@@ -1407,7 +1407,7 @@ fn bindgen_test_layout_user_fpsimd_state() {
         concat!("Size of: ", stringify!(user_fpsimd_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_fpsimd_state>())).vregs as *const _ as usize },
+        ::std::mem::offset_of!(user_fpsimd_state, vregs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1417,7 +1417,7 @@ fn bindgen_test_layout_user_fpsimd_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_fpsimd_state>())).fpsr as *const _ as usize },
+        ::std::mem::offset_of!(user_fpsimd_state, fpsr),
         512usize,
         concat!(
             "Offset of field: ",
@@ -1427,7 +1427,7 @@ fn bindgen_test_layout_user_fpsimd_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_fpsimd_state>())).fpcr as *const _ as usize },
+        ::std::mem::offset_of!(user_fpsimd_state, fpcr),
         516usize,
         concat!(
             "Offset of field: ",
@@ -1437,7 +1437,7 @@ fn bindgen_test_layout_user_fpsimd_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_fpsimd_state>())).__reserved as *const _ as usize },
+        ::std::mem::offset_of!(user_fpsimd_state, __reserved),
         520usize,
         concat!(
             "Offset of field: ",
@@ -1477,9 +1477,7 @@ fn bindgen_test_layout_user_hwdebug_state__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<user_hwdebug_state__bindgen_ty_1>())).addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(user_hwdebug_state__bindgen_ty_1, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1489,9 +1487,7 @@ fn bindgen_test_layout_user_hwdebug_state__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<user_hwdebug_state__bindgen_ty_1>())).ctrl as *const _ as usize
-        },
+        ::std::mem::offset_of!(user_hwdebug_state__bindgen_ty_1, ctrl),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1501,9 +1497,7 @@ fn bindgen_test_layout_user_hwdebug_state__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<user_hwdebug_state__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(user_hwdebug_state__bindgen_ty_1, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -1526,7 +1520,7 @@ fn bindgen_test_layout_user_hwdebug_state() {
         concat!("Alignment of ", stringify!(user_hwdebug_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_hwdebug_state>())).dbg_info as *const _ as usize },
+        ::std::mem::offset_of!(user_hwdebug_state, dbg_info),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1536,7 +1530,7 @@ fn bindgen_test_layout_user_hwdebug_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_hwdebug_state>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(user_hwdebug_state, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1546,7 +1540,7 @@ fn bindgen_test_layout_user_hwdebug_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_hwdebug_state>())).dbg_regs as *const _ as usize },
+        ::std::mem::offset_of!(user_hwdebug_state, dbg_regs),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1579,7 +1573,7 @@ fn bindgen_test_layout_user_sve_header() {
         concat!("Alignment of ", stringify!(user_sve_header))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_sve_header>())).size as *const _ as usize },
+        ::std::mem::offset_of!(user_sve_header, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1589,7 +1583,7 @@ fn bindgen_test_layout_user_sve_header() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_sve_header>())).max_size as *const _ as usize },
+        ::std::mem::offset_of!(user_sve_header, max_size),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1599,7 +1593,7 @@ fn bindgen_test_layout_user_sve_header() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_sve_header>())).vl as *const _ as usize },
+        ::std::mem::offset_of!(user_sve_header, vl),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1609,7 +1603,7 @@ fn bindgen_test_layout_user_sve_header() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_sve_header>())).max_vl as *const _ as usize },
+        ::std::mem::offset_of!(user_sve_header, max_vl),
         10usize,
         concat!(
             "Offset of field: ",
@@ -1619,7 +1613,7 @@ fn bindgen_test_layout_user_sve_header() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_sve_header>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(user_sve_header, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -1629,7 +1623,7 @@ fn bindgen_test_layout_user_sve_header() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<user_sve_header>())).__reserved as *const _ as usize },
+        ::std::mem::offset_of!(user_sve_header, __reserved),
         14usize,
         concat!(
             "Offset of field: ",
@@ -1657,7 +1651,7 @@ fn bindgen_test_layout_kvm_regs() {
         concat!("Size of: ", stringify!(kvm_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1667,7 +1661,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).sp_el1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, sp_el1),
         272usize,
         concat!(
             "Offset of field: ",
@@ -1677,7 +1671,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).elr_el1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, elr_el1),
         280usize,
         concat!(
             "Offset of field: ",
@@ -1687,7 +1681,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).spsr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, spsr),
         288usize,
         concat!(
             "Offset of field: ",
@@ -1697,7 +1691,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).fp_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, fp_regs),
         336usize,
         concat!(
             "Offset of field: ",
@@ -1726,7 +1720,7 @@ fn bindgen_test_layout_kvm_vcpu_init() {
         concat!("Alignment of ", stringify!(kvm_vcpu_init))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_init>())).target as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_init, target),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1736,7 +1730,7 @@ fn bindgen_test_layout_kvm_vcpu_init() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_init>())).features as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_init, features),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1799,7 +1793,7 @@ fn bindgen_test_layout_kvm_guest_debug_arch() {
         concat!("Alignment of ", stringify!(kvm_guest_debug_arch))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug_arch>())).dbg_bcr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug_arch, dbg_bcr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1809,7 +1803,7 @@ fn bindgen_test_layout_kvm_guest_debug_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug_arch>())).dbg_bvr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug_arch, dbg_bvr),
         128usize,
         concat!(
             "Offset of field: ",
@@ -1819,7 +1813,7 @@ fn bindgen_test_layout_kvm_guest_debug_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug_arch>())).dbg_wcr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug_arch, dbg_wcr),
         256usize,
         concat!(
             "Offset of field: ",
@@ -1829,7 +1823,7 @@ fn bindgen_test_layout_kvm_guest_debug_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug_arch>())).dbg_wvr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug_arch, dbg_wvr),
         384usize,
         concat!(
             "Offset of field: ",
@@ -1858,7 +1852,7 @@ fn bindgen_test_layout_kvm_debug_exit_arch() {
         concat!("Alignment of ", stringify!(kvm_debug_exit_arch))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_exit_arch>())).hsr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_exit_arch, hsr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1868,7 +1862,7 @@ fn bindgen_test_layout_kvm_debug_exit_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_exit_arch>())).far as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_exit_arch, far),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1896,7 +1890,7 @@ fn bindgen_test_layout_kvm_sync_regs() {
         concat!("Alignment of ", stringify!(kvm_sync_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sync_regs>())).device_irq_level as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sync_regs, device_irq_level),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1949,10 +1943,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_vcpu_events__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).serror_pending as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, serror_pending),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1962,10 +1953,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).serror_has_esr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, serror_has_esr),
         1usize,
         concat!(
             "Offset of field: ",
@@ -1975,9 +1963,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, pad),
         2usize,
         concat!(
             "Offset of field: ",
@@ -1987,10 +1973,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).serror_esr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, serror_esr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2013,7 +1996,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         concat!("Alignment of ", stringify!(kvm_vcpu_events))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).exception as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, exception),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2023,7 +2006,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, reserved),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2052,7 +2035,7 @@ fn bindgen_test_layout_kvm_user_trace_setup() {
         concat!("Alignment of ", stringify!(kvm_user_trace_setup))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_user_trace_setup>())).buf_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_user_trace_setup, buf_size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2062,7 +2045,7 @@ fn bindgen_test_layout_kvm_user_trace_setup() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_user_trace_setup>())).buf_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_user_trace_setup, buf_nr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2092,7 +2075,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         concat!("Alignment of ", stringify!(kvm_breakpoint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2102,7 +2085,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, padding),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2112,7 +2095,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, address),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2143,7 +2126,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         concat!("Alignment of ", stringify!(kvm_debug_guest))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2153,7 +2136,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2163,7 +2146,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).breakpoints as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, breakpoints),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2173,7 +2156,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).singlestep as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, singlestep),
         72usize,
         concat!(
             "Offset of field: ",
@@ -2204,7 +2187,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         concat!("Alignment of ", stringify!(kvm_memory_region))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).slot as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2214,7 +2197,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2224,9 +2207,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_memory_region>())).guest_phys_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_memory_region, guest_phys_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2236,7 +2217,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).memory_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, memory_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2268,9 +2249,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         concat!("Alignment of ", stringify!(kvm_userspace_memory_region))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).slot as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2280,9 +2259,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2292,10 +2269,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).guest_phys_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, guest_phys_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2305,9 +2279,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).memory_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, memory_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2317,10 +2289,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).userspace_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, userspace_addr),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2356,7 +2325,7 @@ fn bindgen_test_layout_kvm_irq_level__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_irq_level__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_level__bindgen_ty_1>())).irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_level__bindgen_ty_1, irq),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2366,9 +2335,7 @@ fn bindgen_test_layout_kvm_irq_level__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_level__bindgen_ty_1>())).status as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_level__bindgen_ty_1, status),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2396,7 +2363,7 @@ fn bindgen_test_layout_kvm_irq_level() {
         concat!("Alignment of ", stringify!(kvm_irq_level))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_level>())).level as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_level, level),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2421,7 +2388,7 @@ pub struct kvm_irqchip {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union kvm_irqchip__bindgen_ty_1 {
-    pub dummy: [::std::os::raw::c_char; 512usize],
+    pub dummy: [super::raw::c_char; 512usize],
     _bindgen_union_align: [u8; 512usize],
 }
 #[test]
@@ -2437,7 +2404,7 @@ fn bindgen_test_layout_kvm_irqchip__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_irqchip__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip__bindgen_ty_1>())).dummy as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip__bindgen_ty_1, dummy),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2465,7 +2432,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         concat!("Alignment of ", stringify!(kvm_irqchip))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).chip_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, chip_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2475,7 +2442,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2485,7 +2452,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).chip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, chip),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2519,7 +2486,7 @@ fn bindgen_test_layout_kvm_pit_config() {
         concat!("Alignment of ", stringify!(kvm_pit_config))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_config>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_config, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2529,7 +2496,7 @@ fn bindgen_test_layout_kvm_pit_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_config>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_config, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2561,7 +2528,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         concat!("Alignment of ", stringify!(kvm_s390_skeys))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).start_gfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, start_gfn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2571,7 +2538,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).count as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, count),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2581,7 +2548,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).skeydata_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, skeydata_addr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2591,7 +2558,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2601,7 +2568,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, reserved),
         28usize,
         concat!(
             "Offset of field: ",
@@ -2654,10 +2621,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_s390_cmma_log__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_cmma_log__bindgen_ty_1>())).remaining as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_cmma_log__bindgen_ty_1, remaining),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2667,9 +2631,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_cmma_log__bindgen_ty_1>())).mask as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_cmma_log__bindgen_ty_1, mask),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2697,7 +2659,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         concat!("Alignment of ", stringify!(kvm_s390_cmma_log))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).start_gfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, start_gfn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2707,7 +2669,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).count as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, count),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2717,7 +2679,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2727,7 +2689,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).values as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, values),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2782,10 +2744,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).msr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, msr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2795,10 +2754,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).control
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, control),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2808,10 +2764,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).evt_page
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, evt_page),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2821,10 +2774,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).msg_page
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, msg_page),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2860,10 +2810,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).input
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, input),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2873,10 +2820,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).result
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, result),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2886,10 +2830,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).params
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, params),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2912,9 +2853,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_hyperv_exit__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1>())).synic as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1, synic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2924,9 +2863,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1>())).hcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1, hcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2954,7 +2891,7 @@ fn bindgen_test_layout_kvm_hyperv_exit() {
         concat!("Alignment of ", stringify!(kvm_hyperv_exit))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_exit>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_exit, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2964,7 +2901,7 @@ fn bindgen_test_layout_kvm_hyperv_exit() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_exit>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_exit, u),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3020,7 +2957,7 @@ pub union kvm_run__bindgen_ty_1 {
     pub s390_stsi: kvm_run__bindgen_ty_1__bindgen_ty_18,
     pub eoi: kvm_run__bindgen_ty_1__bindgen_ty_19,
     pub hyperv: kvm_hyperv_exit,
-    pub padding: [::std::os::raw::c_char; 256usize],
+    pub padding: [super::raw::c_char; 256usize],
     _bindgen_union_align: [u64; 32usize],
 }
 #[repr(C)]
@@ -3044,10 +2981,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_1>())).hardware_exit_reason
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_1, hardware_exit_reason),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3078,10 +3012,10 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_2>()))
-                .hardware_entry_failure_reason as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            kvm_run__bindgen_ty_1__bindgen_ty_2,
+            hardware_entry_failure_reason
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3113,10 +3047,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_3>())).exception as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_3, exception),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3126,10 +3057,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_3>())).error_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_3, error_code),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3164,10 +3092,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).direction as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, direction),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3177,10 +3102,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, size),
         1usize,
         concat!(
             "Offset of field: ",
@@ -3190,10 +3112,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).port as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, port),
         2usize,
         concat!(
             "Offset of field: ",
@@ -3203,10 +3122,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).count as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, count),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3216,10 +3132,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).data_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, data_offset),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3250,10 +3163,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_5() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_5>())).arch as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_5, arch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3287,10 +3197,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).phys_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, phys_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3300,10 +3207,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3313,9 +3217,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).len as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3325,10 +3227,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, is_write),
         20usize,
         concat!(
             "Offset of field: ",
@@ -3363,9 +3262,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3375,10 +3272,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).args as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, args),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3388,9 +3282,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).ret as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, ret),
         56usize,
         concat!(
             "Offset of field: ",
@@ -3400,10 +3292,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).longmode as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, longmode),
         64usize,
         concat!(
             "Offset of field: ",
@@ -3413,9 +3302,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, pad),
         68usize,
         concat!(
             "Offset of field: ",
@@ -3448,9 +3335,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).rip as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, rip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3460,10 +3345,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, is_write),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3473,9 +3355,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3508,10 +3388,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).icptcode as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, icptcode),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3521,9 +3398,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).ipa as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, ipa),
         2usize,
         concat!(
             "Offset of field: ",
@@ -3533,9 +3408,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).ipb as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, ipb),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3570,10 +3443,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_10() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_10>())).trans_exc_code
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_10, trans_exc_code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3583,10 +3453,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_10() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_10>())).pgm_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_10, pgm_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3622,10 +3489,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).dcrn as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, dcrn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3635,10 +3499,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, data),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3648,10 +3509,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, is_write),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3687,10 +3545,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).suberror as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, suberror),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3700,10 +3555,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).ndata as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, ndata),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3713,10 +3565,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3750,10 +3599,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_13() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_13>())).gprs as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_13, gprs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3789,9 +3635,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3801,10 +3645,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).ret as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, ret),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3814,10 +3655,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).args as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, args),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3856,10 +3694,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).subchannel_id
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, subchannel_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3869,10 +3704,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).subchannel_nr
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, subchannel_nr),
         2usize,
         concat!(
             "Offset of field: ",
@@ -3882,10 +3714,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).io_int_parm as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, io_int_parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3895,10 +3724,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).io_int_word as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, io_int_word),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3908,10 +3734,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).ipb as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, ipb),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3921,10 +3744,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).dequeued as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, dequeued),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3958,10 +3778,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_16() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_16>())).epr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_16, epr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3996,10 +3813,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_17() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_17>())).type_ as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_17, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4009,10 +3823,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_17() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_17>())).flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_17, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4051,10 +3862,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4064,9 +3872,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).ar as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, ar),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4076,10 +3882,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, reserved),
         9usize,
         concat!(
             "Offset of field: ",
@@ -4089,9 +3892,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).fc as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, fc),
         10usize,
         concat!(
             "Offset of field: ",
@@ -4101,10 +3902,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).sel1 as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, sel1),
         11usize,
         concat!(
             "Offset of field: ",
@@ -4114,10 +3912,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).sel2 as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, sel2),
         12usize,
         concat!(
             "Offset of field: ",
@@ -4151,10 +3946,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_19() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_19>())).vector as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_19, vector),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4177,7 +3969,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_run__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hw as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hw),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4187,9 +3979,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).fail_entry as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, fail_entry),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4199,7 +3989,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).ex as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, ex),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4209,7 +3999,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).io as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, io),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4219,7 +4009,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).debug as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, debug),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4229,7 +4019,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).mmio as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, mmio),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4239,7 +4029,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hypercall as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hypercall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4249,9 +4039,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).tpr_access as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, tpr_access),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4261,9 +4049,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_sieic as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_sieic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4273,9 +4059,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_reset_flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_reset_flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4285,9 +4069,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_ucontrol as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_ucontrol),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4297,7 +4079,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).dcr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, dcr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4307,7 +4089,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).internal as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, internal),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4317,7 +4099,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).osi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, osi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4327,9 +4109,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).papr_hcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, papr_hcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4339,7 +4119,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_tsch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_tsch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4349,7 +4129,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).epr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, epr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4359,9 +4139,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).system_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, system_event),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4371,7 +4149,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_stsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_stsi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4381,7 +4159,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).eoi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, eoi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4391,7 +4169,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hyperv as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hyperv),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4401,7 +4179,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, padding),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4420,7 +4198,7 @@ impl Default for kvm_run__bindgen_ty_1 {
 #[derive(Copy, Clone)]
 pub union kvm_run__bindgen_ty_2 {
     pub regs: kvm_sync_regs,
-    pub padding: [::std::os::raw::c_char; 2048usize],
+    pub padding: [super::raw::c_char; 2048usize],
     _bindgen_union_align: [u64; 256usize],
 }
 #[test]
@@ -4436,7 +4214,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_2() {
         concat!("Alignment of ", stringify!(kvm_run__bindgen_ty_2))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_2>())).regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_2, regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4446,7 +4224,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_2>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_2, padding),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4474,9 +4252,7 @@ fn bindgen_test_layout_kvm_run() {
         concat!("Alignment of ", stringify!(kvm_run))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run>())).request_interrupt_window as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run, request_interrupt_window),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4486,7 +4262,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).immediate_exit as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, immediate_exit),
         1usize,
         concat!(
             "Offset of field: ",
@@ -4496,7 +4272,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).padding1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, padding1),
         2usize,
         concat!(
             "Offset of field: ",
@@ -4506,7 +4282,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).exit_reason as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, exit_reason),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4516,9 +4292,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run>())).ready_for_interrupt_injection as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run, ready_for_interrupt_injection),
         12usize,
         concat!(
             "Offset of field: ",
@@ -4528,7 +4302,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).if_flag as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, if_flag),
         13usize,
         concat!(
             "Offset of field: ",
@@ -4538,7 +4312,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, flags),
         14usize,
         concat!(
             "Offset of field: ",
@@ -4548,7 +4322,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).cr8 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, cr8),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4558,7 +4332,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).apic_base as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, apic_base),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4568,7 +4342,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).kvm_valid_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, kvm_valid_regs),
         288usize,
         concat!(
             "Offset of field: ",
@@ -4578,7 +4352,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).kvm_dirty_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, kvm_dirty_regs),
         296usize,
         concat!(
             "Offset of field: ",
@@ -4588,7 +4362,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).s as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, s),
         304usize,
         concat!(
             "Offset of field: ",
@@ -4636,10 +4410,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio_zone__bindgen_ty_1>())).pad as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4649,10 +4420,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio_zone__bindgen_ty_1>())).pio as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone__bindgen_ty_1, pio),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4680,7 +4448,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio_zone))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4690,7 +4458,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4736,9 +4504,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4748,9 +4514,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio__bindgen_ty_1>())).pio as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio__bindgen_ty_1, pio),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4778,7 +4542,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).phys_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, phys_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4788,7 +4552,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4798,7 +4562,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, data),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4832,7 +4596,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio_ring))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).first as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, first),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4842,7 +4606,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).last as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, last),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4852,9 +4616,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).coalesced_mmio as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, coalesced_mmio),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4892,7 +4654,7 @@ fn bindgen_test_layout_kvm_translation() {
         concat!("Alignment of ", stringify!(kvm_translation))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).linear_address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, linear_address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4902,9 +4664,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_translation>())).physical_address as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_translation, physical_address),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4914,7 +4674,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).valid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, valid),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4924,7 +4684,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).writeable as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, writeable),
         17usize,
         concat!(
             "Offset of field: ",
@@ -4934,7 +4694,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).usermode as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, usermode),
         18usize,
         concat!(
             "Offset of field: ",
@@ -4944,7 +4704,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, pad),
         19usize,
         concat!(
             "Offset of field: ",
@@ -4978,7 +4738,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         concat!("Alignment of ", stringify!(kvm_s390_mem_op))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).gaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, gaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4988,7 +4748,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4998,7 +4758,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5008,7 +4768,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).op as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, op),
         20usize,
         concat!(
             "Offset of field: ",
@@ -5018,7 +4778,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).buf as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, buf),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5028,7 +4788,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).ar as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, ar),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5038,7 +4798,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, reserved),
         33usize,
         concat!(
             "Offset of field: ",
@@ -5066,7 +4826,7 @@ fn bindgen_test_layout_kvm_interrupt() {
         concat!("Alignment of ", stringify!(kvm_interrupt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_interrupt>())).irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_interrupt, irq),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5086,7 +4846,7 @@ pub struct kvm_dirty_log {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union kvm_dirty_log__bindgen_ty_1 {
-    pub dirty_bitmap: *mut ::std::os::raw::c_void,
+    pub dirty_bitmap: *mut super::raw::c_void,
     pub padding2: __u64,
     _bindgen_union_align: u64,
 }
@@ -5103,10 +4863,7 @@ fn bindgen_test_layout_kvm_dirty_log__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_dirty_log__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_dirty_log__bindgen_ty_1>())).dirty_bitmap as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_dirty_log__bindgen_ty_1, dirty_bitmap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5116,9 +4873,7 @@ fn bindgen_test_layout_kvm_dirty_log__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_dirty_log__bindgen_ty_1>())).padding2 as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_dirty_log__bindgen_ty_1, padding2),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5146,7 +4901,7 @@ fn bindgen_test_layout_kvm_dirty_log() {
         concat!("Alignment of ", stringify!(kvm_dirty_log))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_log>())).slot as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_log, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5156,7 +4911,7 @@ fn bindgen_test_layout_kvm_dirty_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_log>())).padding1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_log, padding1),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5190,7 +4945,7 @@ fn bindgen_test_layout_kvm_signal_mask() {
         concat!("Alignment of ", stringify!(kvm_signal_mask))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_signal_mask>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_signal_mask, len),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5200,7 +4955,7 @@ fn bindgen_test_layout_kvm_signal_mask() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_signal_mask>())).sigset as *const _ as usize },
+        ::std::mem::offset_of!(kvm_signal_mask, sigset),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5230,7 +4985,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         concat!("Alignment of ", stringify!(kvm_tpr_access_ctl))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5240,7 +4995,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5250,7 +5005,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, reserved),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5278,7 +5033,7 @@ fn bindgen_test_layout_kvm_vapic_addr() {
         concat!("Alignment of ", stringify!(kvm_vapic_addr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vapic_addr>())).vapic_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vapic_addr, vapic_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5306,7 +5061,7 @@ fn bindgen_test_layout_kvm_mp_state() {
         concat!("Alignment of ", stringify!(kvm_mp_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_mp_state>())).mp_state as *const _ as usize },
+        ::std::mem::offset_of!(kvm_mp_state, mp_state),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5335,7 +5090,7 @@ fn bindgen_test_layout_kvm_s390_psw() {
         concat!("Alignment of ", stringify!(kvm_s390_psw))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_psw>())).mask as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_psw, mask),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5345,7 +5100,7 @@ fn bindgen_test_layout_kvm_s390_psw() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_psw>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_psw, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5375,7 +5130,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         concat!("Alignment of ", stringify!(kvm_s390_interrupt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5385,7 +5140,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).parm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5395,7 +5150,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).parm64 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, parm64),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5426,7 +5181,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         concat!("Alignment of ", stringify!(kvm_s390_io_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).subchannel_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, subchannel_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5436,7 +5191,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).subchannel_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, subchannel_nr),
         2usize,
         concat!(
             "Offset of field: ",
@@ -5446,7 +5201,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).io_int_parm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, io_int_parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5456,7 +5211,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).io_int_word as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, io_int_word),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5486,7 +5241,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         concat!("Alignment of ", stringify!(kvm_s390_ext_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).ext_params as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, ext_params),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5496,7 +5251,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5506,7 +5261,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).ext_params2 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, ext_params2),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5546,9 +5301,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         concat!("Alignment of ", stringify!(kvm_s390_pgm_info))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_pgm_info>())).trans_exc_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, trans_exc_code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5558,7 +5311,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).mon_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, mon_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5568,7 +5321,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_address),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5578,7 +5331,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).data_exc_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, data_exc_code),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5588,7 +5341,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, code),
         28usize,
         concat!(
             "Offset of field: ",
@@ -5598,7 +5351,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).mon_class_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, mon_class_nr),
         30usize,
         concat!(
             "Offset of field: ",
@@ -5608,7 +5361,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_code),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5618,7 +5371,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_atmid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_atmid),
         33usize,
         concat!(
             "Offset of field: ",
@@ -5628,7 +5381,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).exc_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, exc_access_id),
         34usize,
         concat!(
             "Offset of field: ",
@@ -5638,7 +5391,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_access_id),
         35usize,
         concat!(
             "Offset of field: ",
@@ -5648,7 +5401,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).op_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, op_access_id),
         36usize,
         concat!(
             "Offset of field: ",
@@ -5658,7 +5411,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, flags),
         37usize,
         concat!(
             "Offset of field: ",
@@ -5668,7 +5421,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, pad),
         38usize,
         concat!(
             "Offset of field: ",
@@ -5696,7 +5449,7 @@ fn bindgen_test_layout_kvm_s390_prefix_info() {
         concat!("Alignment of ", stringify!(kvm_s390_prefix_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_prefix_info>())).address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_prefix_info, address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5724,7 +5477,7 @@ fn bindgen_test_layout_kvm_s390_extcall_info() {
         concat!("Alignment of ", stringify!(kvm_s390_extcall_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_extcall_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_extcall_info, code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5752,7 +5505,7 @@ fn bindgen_test_layout_kvm_s390_emerg_info() {
         concat!("Alignment of ", stringify!(kvm_s390_emerg_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_emerg_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_emerg_info, code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5780,7 +5533,7 @@ fn bindgen_test_layout_kvm_s390_stop_info() {
         concat!("Alignment of ", stringify!(kvm_s390_stop_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_stop_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_stop_info, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5813,7 +5566,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         concat!("Alignment of ", stringify!(kvm_s390_mchk_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).cr14 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, cr14),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5823,7 +5576,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).mcic as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, mcic),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5833,10 +5586,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_mchk_info>())).failing_storage_address as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, failing_storage_address),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5846,9 +5596,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_mchk_info>())).ext_damage_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, ext_damage_code),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5858,7 +5606,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, pad),
         28usize,
         concat!(
             "Offset of field: ",
@@ -5868,7 +5616,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).fixed_logout as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, fixed_logout),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5895,7 +5643,7 @@ pub union kvm_s390_irq__bindgen_ty_1 {
     pub prefix: kvm_s390_prefix_info,
     pub stop: kvm_s390_stop_info,
     pub mchk: kvm_s390_mchk_info,
-    pub reserved: [::std::os::raw::c_char; 64usize],
+    pub reserved: [super::raw::c_char; 64usize],
     _bindgen_union_align: [u64; 8usize],
 }
 #[test]
@@ -5911,7 +5659,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_s390_irq__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).io as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, io),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5921,7 +5669,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).ext as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, ext),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5931,7 +5679,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).pgm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, pgm),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5941,9 +5689,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).emerg as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, emerg),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5953,9 +5699,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).extcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, extcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5965,9 +5709,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).prefix as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, prefix),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5977,7 +5719,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).stop as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, stop),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5987,7 +5729,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).mchk as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, mchk),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5997,9 +5739,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6027,7 +5767,7 @@ fn bindgen_test_layout_kvm_s390_irq() {
         concat!("Alignment of ", stringify!(kvm_s390_irq))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6037,7 +5777,7 @@ fn bindgen_test_layout_kvm_s390_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq, u),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6073,7 +5813,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         concat!("Alignment of ", stringify!(kvm_s390_irq_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).buf as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, buf),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6083,7 +5823,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6093,7 +5833,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, len),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6103,7 +5843,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, reserved),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6133,7 +5873,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         concat!("Alignment of ", stringify!(kvm_guest_debug))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).control as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, control),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6143,7 +5883,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6153,7 +5893,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).arch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, arch),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6193,7 +5933,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         concat!("Alignment of ", stringify!(kvm_ioeventfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).datamatch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, datamatch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6203,7 +5943,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6213,7 +5953,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6223,7 +5963,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, fd),
         20usize,
         concat!(
             "Offset of field: ",
@@ -6233,7 +5973,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -6243,7 +5983,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, pad),
         28usize,
         concat!(
             "Offset of field: ",
@@ -6279,7 +6019,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         concat!("Alignment of ", stringify!(kvm_enable_cap))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).cap as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, cap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6289,7 +6029,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6299,7 +6039,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).args as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, args),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6309,7 +6049,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, pad),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6344,7 +6084,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         concat!("Alignment of ", stringify!(kvm_ppc_pvinfo))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6354,7 +6094,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).hcall as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, hcall),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6364,7 +6104,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, pad),
         20usize,
         concat!(
             "Offset of field: ",
@@ -6398,9 +6138,7 @@ fn bindgen_test_layout_kvm_ppc_one_page_size() {
         concat!("Alignment of ", stringify!(kvm_ppc_one_page_size))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_page_size>())).page_shift as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_page_size, page_shift),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6410,7 +6148,7 @@ fn bindgen_test_layout_kvm_ppc_one_page_size() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_one_page_size>())).pte_enc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_one_page_size, pte_enc),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6440,9 +6178,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         concat!("Alignment of ", stringify!(kvm_ppc_one_seg_page_size))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).page_shift as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, page_shift),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6452,9 +6188,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).slb_enc as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, slb_enc),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6464,7 +6198,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).enc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, enc),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6496,7 +6230,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         concat!("Alignment of ", stringify!(kvm_ppc_smmu_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6506,7 +6240,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).slb_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, slb_size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6516,7 +6250,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).data_keys as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, data_keys),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6526,7 +6260,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).instr_keys as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, instr_keys),
         14usize,
         concat!(
             "Offset of field: ",
@@ -6536,7 +6270,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).sps as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, sps),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6566,7 +6300,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         concat!("Alignment of ", stringify!(kvm_ppc_resize_hpt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6576,7 +6310,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).shift as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, shift),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6586,7 +6320,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6615,7 +6349,7 @@ fn bindgen_test_layout_kvm_irq_routing_irqchip() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_irqchip))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_irqchip>())).irqchip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_irqchip, irqchip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6625,7 +6359,7 @@ fn bindgen_test_layout_kvm_irq_routing_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_irqchip>())).pin as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_irqchip, pin),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6666,9 +6400,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_msi__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_msi__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6678,9 +6410,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_msi__bindgen_ty_1>())).devid as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_msi__bindgen_ty_1, devid),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6708,7 +6438,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_msi))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).address_lo as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, address_lo),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6718,7 +6448,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).address_hi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, address_hi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6728,7 +6458,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6765,9 +6495,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_s390_adapter))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).ind_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, ind_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6777,10 +6505,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).summary_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, summary_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6790,9 +6515,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).ind_offset as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, ind_offset),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6802,10 +6525,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).summary_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, summary_offset),
         24usize,
         concat!(
             "Offset of field: ",
@@ -6815,9 +6535,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).adapter_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, adapter_id),
         28usize,
         concat!(
             "Offset of field: ",
@@ -6846,7 +6564,7 @@ fn bindgen_test_layout_kvm_irq_routing_hv_sint() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_hv_sint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_hv_sint>())).vcpu as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_hv_sint, vcpu),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6856,7 +6574,7 @@ fn bindgen_test_layout_kvm_irq_routing_hv_sint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_hv_sint>())).sint as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_hv_sint, sint),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6901,10 +6619,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).irqchip as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, irqchip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6914,9 +6629,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).msi as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, msi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6926,10 +6639,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).adapter as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, adapter),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6939,10 +6649,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).hv_sint as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, hv_sint),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6952,9 +6659,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6982,7 +6687,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_entry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, gsi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6992,7 +6697,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, type_),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7002,7 +6707,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7012,7 +6717,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -7022,7 +6727,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, u),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7056,7 +6761,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         concat!("Alignment of ", stringify!(kvm_irq_routing))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7066,7 +6771,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7076,7 +6781,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).entries as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, entries),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7113,7 +6818,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         concat!("Alignment of ", stringify!(kvm_irqfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, fd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7123,7 +6828,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, gsi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7133,7 +6838,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7143,7 +6848,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).resamplefd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, resamplefd),
         12usize,
         concat!(
             "Offset of field: ",
@@ -7153,7 +6858,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, pad),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7183,7 +6888,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         concat!("Alignment of ", stringify!(kvm_clock_data))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).clock as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, clock),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7193,7 +6898,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7203,7 +6908,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -7234,7 +6939,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         concat!("Alignment of ", stringify!(kvm_config_tlb))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).params as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, params),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7244,7 +6949,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).array as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, array),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7254,7 +6959,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).mmu_type as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, mmu_type),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7264,7 +6969,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).array_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, array_len),
         20usize,
         concat!(
             "Offset of field: ",
@@ -7293,7 +6998,7 @@ fn bindgen_test_layout_kvm_dirty_tlb() {
         concat!("Alignment of ", stringify!(kvm_dirty_tlb))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_tlb>())).bitmap as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_tlb, bitmap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7303,7 +7008,7 @@ fn bindgen_test_layout_kvm_dirty_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_tlb>())).num_dirty as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_tlb, num_dirty),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7332,7 +7037,7 @@ fn bindgen_test_layout_kvm_reg_list() {
         concat!("Alignment of ", stringify!(kvm_reg_list))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_reg_list>())).n as *const _ as usize },
+        ::std::mem::offset_of!(kvm_reg_list, n),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7342,7 +7047,7 @@ fn bindgen_test_layout_kvm_reg_list() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_reg_list>())).reg as *const _ as usize },
+        ::std::mem::offset_of!(kvm_reg_list, reg),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7371,7 +7076,7 @@ fn bindgen_test_layout_kvm_one_reg() {
         concat!("Alignment of ", stringify!(kvm_one_reg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_one_reg>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_one_reg, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7381,7 +7086,7 @@ fn bindgen_test_layout_kvm_one_reg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_one_reg>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_one_reg, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7414,7 +7119,7 @@ fn bindgen_test_layout_kvm_msi() {
         concat!("Alignment of ", stringify!(kvm_msi))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).address_lo as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, address_lo),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7424,7 +7129,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).address_hi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, address_hi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7434,7 +7139,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7444,7 +7149,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -7454,7 +7159,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).devid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, devid),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7464,7 +7169,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, pad),
         20usize,
         concat!(
             "Offset of field: ",
@@ -7493,7 +7198,7 @@ fn bindgen_test_layout_kvm_arm_device_addr() {
         concat!("Alignment of ", stringify!(kvm_arm_device_addr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_arm_device_addr>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_arm_device_addr, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7503,7 +7208,7 @@ fn bindgen_test_layout_kvm_arm_device_addr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_arm_device_addr>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_arm_device_addr, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7533,7 +7238,7 @@ fn bindgen_test_layout_kvm_create_device() {
         concat!("Alignment of ", stringify!(kvm_create_device))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7543,7 +7248,7 @@ fn bindgen_test_layout_kvm_create_device() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, fd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7553,7 +7258,7 @@ fn bindgen_test_layout_kvm_create_device() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7584,7 +7289,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         concat!("Alignment of ", stringify!(kvm_device_attr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7594,7 +7299,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).group as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, group),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7604,7 +7309,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).attr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, attr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7614,7 +7319,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, addr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7653,7 +7358,7 @@ fn bindgen_test_layout_kvm_vfio_spapr_tce() {
         concat!("Alignment of ", stringify!(kvm_vfio_spapr_tce))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vfio_spapr_tce>())).groupfd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vfio_spapr_tce, groupfd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7663,7 +7368,7 @@ fn bindgen_test_layout_kvm_vfio_spapr_tce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vfio_spapr_tce>())).tablefd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vfio_spapr_tce, tablefd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7693,7 +7398,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         concat!("Alignment of ", stringify!(kvm_s390_ucas_mapping))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).user_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, user_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7703,7 +7408,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).vcpu_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, vcpu_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7713,7 +7418,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).length as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, length),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7742,7 +7447,7 @@ fn bindgen_test_layout_kvm_enc_region() {
         concat!("Alignment of ", stringify!(kvm_enc_region))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enc_region>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enc_region, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7752,7 +7457,7 @@ fn bindgen_test_layout_kvm_enc_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enc_region>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enc_region, size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7805,7 +7510,7 @@ fn bindgen_test_layout_kvm_sev_cmd() {
         concat!("Alignment of ", stringify!(kvm_sev_cmd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_cmd>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_cmd, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7815,7 +7520,7 @@ fn bindgen_test_layout_kvm_sev_cmd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_cmd>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_cmd, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7825,7 +7530,7 @@ fn bindgen_test_layout_kvm_sev_cmd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_cmd>())).error as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_cmd, error),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7835,7 +7540,7 @@ fn bindgen_test_layout_kvm_sev_cmd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_cmd>())).sev_fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_cmd, sev_fd),
         20usize,
         concat!(
             "Offset of field: ",
@@ -7868,7 +7573,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         concat!("Alignment of ", stringify!(kvm_sev_launch_start))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_start>())).handle as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_start, handle),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7878,7 +7583,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_start>())).policy as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_start, policy),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7888,7 +7593,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_start>())).dh_uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_start, dh_uaddr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7898,7 +7603,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_start>())).dh_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_start, dh_len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7908,9 +7613,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_start>())).session_uaddr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_start, session_uaddr),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7920,9 +7623,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_start>())).session_len as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_start, session_len),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7951,9 +7652,7 @@ fn bindgen_test_layout_kvm_sev_launch_update_data() {
         concat!("Alignment of ", stringify!(kvm_sev_launch_update_data))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_update_data>())).uaddr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_update_data, uaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7963,7 +7662,7 @@ fn bindgen_test_layout_kvm_sev_launch_update_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_update_data>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_update_data, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7996,7 +7695,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         concat!("Alignment of ", stringify!(kvm_sev_launch_secret))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_secret>())).hdr_uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, hdr_uaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8006,7 +7705,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_secret>())).hdr_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, hdr_len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8016,9 +7715,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_secret>())).guest_uaddr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, guest_uaddr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8028,7 +7725,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_secret>())).guest_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, guest_len),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8038,9 +7735,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_secret>())).trans_uaddr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, trans_uaddr),
         32usize,
         concat!(
             "Offset of field: ",
@@ -8050,7 +7745,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_secret>())).trans_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, trans_len),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8079,7 +7774,7 @@ fn bindgen_test_layout_kvm_sev_launch_measure() {
         concat!("Alignment of ", stringify!(kvm_sev_launch_measure))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_measure>())).uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_measure, uaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8089,7 +7784,7 @@ fn bindgen_test_layout_kvm_sev_launch_measure() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_measure>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_measure, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8119,7 +7814,7 @@ fn bindgen_test_layout_kvm_sev_guest_status() {
         concat!("Alignment of ", stringify!(kvm_sev_guest_status))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_guest_status>())).handle as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_guest_status, handle),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8129,7 +7824,7 @@ fn bindgen_test_layout_kvm_sev_guest_status() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_guest_status>())).policy as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_guest_status, policy),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8139,7 +7834,7 @@ fn bindgen_test_layout_kvm_sev_guest_status() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_guest_status>())).state as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_guest_status, state),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8169,7 +7864,7 @@ fn bindgen_test_layout_kvm_sev_dbg() {
         concat!("Alignment of ", stringify!(kvm_sev_dbg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_dbg>())).src_uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_dbg, src_uaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8179,7 +7874,7 @@ fn bindgen_test_layout_kvm_sev_dbg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_dbg>())).dst_uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_dbg, dst_uaddr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8189,7 +7884,7 @@ fn bindgen_test_layout_kvm_sev_dbg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_dbg>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_dbg, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8231,10 +7926,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_pci_dev__bindgen_ty_1>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8262,9 +7954,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         concat!("Alignment of ", stringify!(kvm_assigned_pci_dev))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8274,7 +7964,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).busnr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, busnr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8284,7 +7974,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).devfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, devfn),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8294,7 +7984,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -8304,7 +7994,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).segnr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, segnr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8347,9 +8037,7 @@ fn bindgen_test_layout_kvm_assigned_irq__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_assigned_irq__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_irq__bindgen_ty_1>())).reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_irq__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8377,9 +8065,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         concat!("Alignment of ", stringify!(kvm_assigned_irq))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_irq>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_irq, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8389,7 +8075,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).host_irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, host_irq),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8399,7 +8085,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).guest_irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, guest_irq),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8409,7 +8095,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -8444,9 +8130,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         concat!("Alignment of ", stringify!(kvm_assigned_msix_nr))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8456,7 +8140,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).entry_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, entry_nr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8466,7 +8150,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, padding),
         6usize,
         concat!(
             "Offset of field: ",
@@ -8497,9 +8181,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         concat!("Alignment of ", stringify!(kvm_assigned_msix_entry))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8509,7 +8191,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, gsi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8519,7 +8201,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).entry as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, entry),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8529,7 +8211,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, padding),
         10usize,
         concat!(
             "Offset of field: ",
@@ -8560,7 +8242,7 @@ fn bindgen_test_layout_kvm_hyperv_eventfd() {
         concat!("Alignment of ", stringify!(kvm_hyperv_eventfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_eventfd>())).conn_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_eventfd, conn_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8570,7 +8252,7 @@ fn bindgen_test_layout_kvm_hyperv_eventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_eventfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_eventfd, fd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8580,7 +8262,7 @@ fn bindgen_test_layout_kvm_hyperv_eventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_eventfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_eventfd, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8590,7 +8272,7 @@ fn bindgen_test_layout_kvm_hyperv_eventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_eventfd>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_eventfd, padding),
         12usize,
         concat!(
             "Offset of field: ",
@@ -0,0 +1,384 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Guest debugging with `KVM_SET_GUEST_DEBUG` and `KVM_EXIT_DEBUG`.
+//!
+//! [`GuestDebugBuilder`](struct.GuestDebugBuilder.html) programs the hardware
+//! breakpoints and watchpoints of `kvm_guest_debug_arch` in the layout of the
+//! `DBGBCR<n>_EL1` and `DBGWCR<n>_EL1` registers, and
+//! [`DebugExit`](enum.DebugExit.html) decodes the exception class of the
+//! `kvm_debug_exit_arch` returned by `KVM_EXIT_DEBUG`.
+
+use std::fmt;
+
+use super::bindings::*;
+
+// Fields of DBGBCR<n>_EL1 and DBGWCR<n>_EL1.
+const DBG_CR_ENABLE: u64 = 1;
+const DBG_CR_PRIV_SHIFT: u64 = 1;
+const DBG_BCR_BAS_A64: u64 = 0xf << 5;
+const DBG_WCR_LSC_SHIFT: u64 = 3;
+const DBG_WCR_BAS_SHIFT: u64 = 5;
+const DBG_WCR_MASK_SHIFT: u64 = 24;
+
+// The largest region a single watchpoint can cover, with DBGWCR.MASK = 31.
+const MAX_WATCHPOINT_LEN: u64 = 1 << 31;
+
+// Exception classes of the debug exceptions, in ESR_ELx.EC.
+const ESR_EC_SHIFT: u32 = 26;
+const ESR_EC_BREAKPT_LOW: u32 = 0x30;
+const ESR_EC_BREAKPT_CUR: u32 = 0x31;
+const ESR_EC_SOFTSTP_LOW: u32 = 0x32;
+const ESR_EC_SOFTSTP_CUR: u32 = 0x33;
+const ESR_EC_WATCHPT_LOW: u32 = 0x34;
+const ESR_EC_WATCHPT_CUR: u32 = 0x35;
+const ESR_EC_BKPT32: u32 = 0x38;
+const ESR_EC_BRK64: u32 = 0x3c;
+
+/// Errors associated with programming the debug registers.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// All the hardware breakpoints are used.
+    TooManyBreakpoints,
+    /// All the hardware watchpoints are used.
+    TooManyWatchpoints,
+    /// The breakpoint address is not aligned to an instruction.
+    UnalignedBreakpoint(u64),
+    /// No watchpoint can cover `len` bytes at `addr`.
+    InvalidWatchpoint { addr: u64, len: u64 },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::TooManyBreakpoints => write!(f, "no hardware breakpoint left"),
+            Error::TooManyWatchpoints => write!(f, "no hardware watchpoint left"),
+            Error::UnalignedBreakpoint(addr) => {
+                write!(f, "breakpoint address {:#x} is not 4 bytes aligned", addr)
+            }
+            Error::InvalidWatchpoint { addr, len } => {
+                write!(f, "a watchpoint cannot cover {} bytes at {:#x}", len, addr)
+            }
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// The exception levels a breakpoint or watchpoint triggers at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DebugPrivilege {
+    /// The guest kernel.
+    El1,
+    /// The guest user space.
+    El0,
+    /// Both the guest kernel and user space.
+    El1El0,
+}
+
+impl DebugPrivilege {
+    // The DBGBCR.PMC and DBGWCR.PAC encoding.
+    fn bits(self) -> u64 {
+        match self {
+            DebugPrivilege::El1 => 0b01,
+            DebugPrivilege::El0 => 0b10,
+            DebugPrivilege::El1El0 => 0b11,
+        }
+    }
+}
+
+/// The accesses a watchpoint triggers on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WatchpointAccess {
+    /// Loads.
+    Load,
+    /// Stores.
+    Store,
+    /// Loads and stores.
+    LoadStore,
+}
+
+impl WatchpointAccess {
+    // The DBGWCR.LSC encoding.
+    fn bits(self) -> u64 {
+        match self {
+            WatchpointAccess::Load => 0b01,
+            WatchpointAccess::Store => 0b10,
+            WatchpointAccess::LoadStore => 0b11,
+        }
+    }
+}
+
+/// Returns the `DBGBCR` and `DBGBVR` values of a breakpoint on the A64
+/// instruction at `addr`.
+pub fn breakpoint_regs(addr: u64, privilege: DebugPrivilege) -> Result<(u64, u64), Error> {
+    if !addr.is_multiple_of(4) {
+        return Err(Error::UnalignedBreakpoint(addr));
+    }
+    let bcr = DBG_CR_ENABLE | (privilege.bits() << DBG_CR_PRIV_SHIFT) | DBG_BCR_BAS_A64;
+    Ok((bcr, addr))
+}
+
+/// Returns the `DBGWCR` and `DBGWVR` values of a watchpoint on the `len` bytes
+/// at `addr`.
+///
+/// Up to 8 bytes within an aligned doubleword are selected with the byte
+/// address select field. Larger regions must be a power of two in size, up to
+/// 2GiB, and aligned to their size.
+pub fn watchpoint_regs(
+    addr: u64,
+    len: u64,
+    access: WatchpointAccess,
+    privilege: DebugPrivilege,
+) -> Result<(u64, u64), Error> {
+    let wcr = DBG_CR_ENABLE
+        | (privilege.bits() << DBG_CR_PRIV_SHIFT)
+        | (access.bits() << DBG_WCR_LSC_SHIFT);
+    let offset = addr & 7;
+    if len > 0 && offset + len <= 8 {
+        let bas = ((1 << len) - 1) << offset;
+        return Ok((wcr | (bas << DBG_WCR_BAS_SHIFT), addr - offset));
+    }
+    if len.is_power_of_two() && len <= MAX_WATCHPOINT_LEN && addr.is_multiple_of(len) {
+        let mask = u64::from(len.trailing_zeros());
+        return Ok((
+            wcr | (0xff << DBG_WCR_BAS_SHIFT) | (mask << DBG_WCR_MASK_SHIFT),
+            addr,
+        ));
+    }
+    Err(Error::InvalidWatchpoint { addr, len })
+}
+
+/// A builder for the `kvm_guest_debug` passed to `KVM_SET_GUEST_DEBUG`.
+#[derive(Clone, Debug)]
+pub struct GuestDebugBuilder {
+    debug: kvm_guest_debug,
+    breakpoints: usize,
+    watchpoints: usize,
+    max_breakpoints: usize,
+    max_watchpoints: usize,
+}
+
+impl Default for GuestDebugBuilder {
+    fn default() -> Self {
+        GuestDebugBuilder::new()
+    }
+}
+
+impl GuestDebugBuilder {
+    /// Starts a `kvm_guest_debug` enabling guest debugging.
+    pub fn new() -> Self {
+        GuestDebugBuilder {
+            debug: kvm_guest_debug {
+                control: KVM_GUESTDBG_ENABLE,
+                ..Default::default()
+            },
+            breakpoints: 0,
+            watchpoints: 0,
+            max_breakpoints: KVM_ARM_MAX_DBG_REGS as usize,
+            max_watchpoints: KVM_ARM_MAX_DBG_REGS as usize,
+        }
+    }
+
+    /// Limits the hardware breakpoints and watchpoints to the numbers the host
+    /// implements, as reported by `KVM_CAP_GUEST_DEBUG_HW_BPS` and
+    /// `KVM_CAP_GUEST_DEBUG_HW_WPS`.
+    pub fn limits(mut self, breakpoints: usize, watchpoints: usize) -> Self {
+        let max = KVM_ARM_MAX_DBG_REGS as usize;
+        self.max_breakpoints = breakpoints.min(max);
+        self.max_watchpoints = watchpoints.min(max);
+        self
+    }
+
+    /// Exits after each instruction, with `KVM_GUESTDBG_SINGLESTEP`.
+    pub fn single_step(mut self) -> Self {
+        self.debug.control |= KVM_GUESTDBG_SINGLESTEP;
+        self
+    }
+
+    /// Exits on the `BRK` instructions of the guest, with
+    /// `KVM_GUESTDBG_USE_SW_BP`.
+    pub fn sw_breakpoints(mut self) -> Self {
+        self.debug.control |= KVM_GUESTDBG_USE_SW_BP;
+        self
+    }
+
+    /// Adds a hardware breakpoint on the instruction at `addr`.
+    pub fn hw_breakpoint(mut self, addr: u64, privilege: DebugPrivilege) -> Result<Self, Error> {
+        if self.breakpoints == self.max_breakpoints {
+            return Err(Error::TooManyBreakpoints);
+        }
+        let (bcr, bvr) = breakpoint_regs(addr, privilege)?;
+        self.debug.arch.dbg_bcr[self.breakpoints] = bcr;
+        self.debug.arch.dbg_bvr[self.breakpoints] = bvr;
+        self.breakpoints += 1;
+        self.debug.control |= KVM_GUESTDBG_USE_HW;
+        Ok(self)
+    }
+
+    /// Adds a hardware watchpoint on the `len` bytes at `addr`.
+    pub fn hw_watchpoint(
+        mut self,
+        addr: u64,
+        len: u64,
+        access: WatchpointAccess,
+        privilege: DebugPrivilege,
+    ) -> Result<Self, Error> {
+        if self.watchpoints == self.max_watchpoints {
+            return Err(Error::TooManyWatchpoints);
+        }
+        let (wcr, wvr) = watchpoint_regs(addr, len, access, privilege)?;
+        self.debug.arch.dbg_wcr[self.watchpoints] = wcr;
+        self.debug.arch.dbg_wvr[self.watchpoints] = wvr;
+        self.watchpoints += 1;
+        self.debug.control |= KVM_GUESTDBG_USE_HW;
+        Ok(self)
+    }
+
+    /// Returns the `kvm_guest_debug`.
+    pub fn build(&self) -> kvm_guest_debug {
+        self.debug
+    }
+}
+
+/// The cause of a `KVM_EXIT_DEBUG` exit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DebugExit {
+    /// A hardware breakpoint hit.
+    HwBreakpoint,
+    /// A hardware watchpoint hit on the access to `far`.
+    Watchpoint { far: u64 },
+    /// A single step completed.
+    SoftwareStep,
+    /// A `BRK` or AArch32 `BKPT` instruction, with its immediate.
+    SoftwareBreakpoint { immediate: u16 },
+    /// Another exception class, unexpected for a debug exit.
+    Other { ec: u32 },
+}
+
+impl DebugExit {
+    /// Decodes the `hsr` and `far` of `kvm_debug_exit_arch`.
+    pub fn new(arch: &kvm_debug_exit_arch) -> Self {
+        match arch.hsr >> ESR_EC_SHIFT {
+            ESR_EC_BREAKPT_LOW | ESR_EC_BREAKPT_CUR => DebugExit::HwBreakpoint,
+            ESR_EC_WATCHPT_LOW | ESR_EC_WATCHPT_CUR => DebugExit::Watchpoint { far: arch.far },
+            ESR_EC_SOFTSTP_LOW | ESR_EC_SOFTSTP_CUR => DebugExit::SoftwareStep,
+            ESR_EC_BKPT32 | ESR_EC_BRK64 => DebugExit::SoftwareBreakpoint {
+                immediate: arch.hsr as u16,
+            },
+            ec => DebugExit::Other { ec },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_breakpoints() {
+        assert_eq!(
+            breakpoint_regs(0x4008_0000, DebugPrivilege::El1),
+            Ok((0x1e3, 0x4008_0000))
+        );
+        assert_eq!(
+            breakpoint_regs(0x1000, DebugPrivilege::El1El0).unwrap().0,
+            0x1e7
+        );
+        assert_eq!(
+            breakpoint_regs(0x1002, DebugPrivilege::El1),
+            Err(Error::UnalignedBreakpoint(0x1002))
+        );
+    }
+
+    #[test]
+    fn test_watchpoints() {
+        // 4 bytes at offset 4 of the doubleword: BAS = 0xf0.
+        assert_eq!(
+            watchpoint_regs(0x1004, 4, WatchpointAccess::Store, DebugPrivilege::El1),
+            Ok((0x1e13, 0x1000))
+        );
+        assert_eq!(
+            watchpoint_regs(
+                0x1000,
+                8,
+                WatchpointAccess::LoadStore,
+                DebugPrivilege::El1El0
+            ),
+            Ok((0x1fff, 0x1000))
+        );
+        // 4KiB: MASK = 12.
+        assert_eq!(
+            watchpoint_regs(0x2000, 0x1000, WatchpointAccess::Load, DebugPrivilege::El0),
+            Ok((0x0c00_1fed, 0x2000))
+        );
+        for &(addr, len) in [(0x1006, 4), (0x1000, 0), (0x1000, 24), (0x1800, 0x1000)].iter() {
+            assert_eq!(
+                watchpoint_regs(addr, len, WatchpointAccess::Load, DebugPrivilege::El1),
+                Err(Error::InvalidWatchpoint { addr, len })
+            );
+        }
+    }
+
+    #[test]
+    fn test_builder() {
+        let debug = GuestDebugBuilder::new().single_step().build();
+        assert_eq!(debug.control, KVM_GUESTDBG_ENABLE | KVM_GUESTDBG_SINGLESTEP);
+
+        let debug = GuestDebugBuilder::new()
+            .sw_breakpoints()
+            .hw_breakpoint(0x4000, DebugPrivilege::El1)
+            .unwrap()
+            .hw_breakpoint(0x5000, DebugPrivilege::El1)
+            .unwrap()
+            .hw_watchpoint(0x8000, 8, WatchpointAccess::Store, DebugPrivilege::El1)
+            .unwrap()
+            .build();
+        assert_eq!(
+            debug.control,
+            KVM_GUESTDBG_ENABLE | KVM_GUESTDBG_USE_SW_BP | KVM_GUESTDBG_USE_HW
+        );
+        assert_eq!(debug.arch.dbg_bvr[..3], [0x4000, 0x5000, 0]);
+        assert_eq!(debug.arch.dbg_bcr[2], 0);
+        assert_eq!(debug.arch.dbg_wvr[0], 0x8000);
+
+        let builder = GuestDebugBuilder::new()
+            .limits(1, 0)
+            .hw_breakpoint(0x4000, DebugPrivilege::El1)
+            .unwrap();
+        assert_eq!(
+            builder
+                .clone()
+                .hw_breakpoint(0x5000, DebugPrivilege::El1)
+                .unwrap_err(),
+            Error::TooManyBreakpoints
+        );
+        assert_eq!(
+            builder
+                .hw_watchpoint(0x8000, 8, WatchpointAccess::Store, DebugPrivilege::El1)
+                .unwrap_err(),
+            Error::TooManyWatchpoints
+        );
+    }
+
+    #[test]
+    // `hsr_high` is only in the 6.1 bindings and later.
+    #[allow(clippy::needless_update)]
+    fn test_debug_exit() {
+        let exit = |hsr: u32| {
+            DebugExit::new(&kvm_debug_exit_arch {
+                hsr,
+                far: 0x8004,
+                ..Default::default()
+            })
+        };
+        assert_eq!(exit(0xc000_0000), DebugExit::HwBreakpoint);
+        assert_eq!(exit(0xd000_0052), DebugExit::Watchpoint { far: 0x8004 });
+        assert_eq!(exit(0xca00_0022), DebugExit::SoftwareStep);
+        assert_eq!(
+            exit(0xf200_0003),
+            DebugExit::SoftwareBreakpoint { immediate: 3 }
+        );
+        assert_eq!(exit(0x5a00_0000), DebugExit::Other { ec: 0x16 });
+    }
+}
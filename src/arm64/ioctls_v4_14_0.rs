@@ -0,0 +1,100 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! KVM ioctl request numbers for Linux 4.14.
+
+use super::bindings_v4_14_0::*;
+
+// Ioctls for /dev/kvm.
+ioctl_io_nr!(KVM_GET_API_VERSION, KVMIO, 0x00);
+ioctl_io_nr!(KVM_CREATE_VM, KVMIO, 0x01);
+ioctl_io_nr!(KVM_CHECK_EXTENSION, KVMIO, 0x03);
+ioctl_io_nr!(KVM_GET_VCPU_MMAP_SIZE, KVMIO, 0x04);
+
+// Ioctls for VM fds.
+ioctl_io_nr!(KVM_CREATE_VCPU, KVMIO, 0x41);
+ioctl_iow_nr!(KVM_GET_DIRTY_LOG, KVMIO, 0x42, kvm_dirty_log);
+ioctl_iow_nr!(
+    KVM_SET_USER_MEMORY_REGION,
+    KVMIO,
+    0x46,
+    kvm_userspace_memory_region
+);
+ioctl_io_nr!(KVM_CREATE_IRQCHIP, KVMIO, 0x60);
+ioctl_iow_nr!(KVM_IRQ_LINE, KVMIO, 0x61, kvm_irq_level);
+ioctl_iowr_nr!(KVM_IRQ_LINE_STATUS, KVMIO, 0x67, kvm_irq_level);
+ioctl_iow_nr!(
+    KVM_REGISTER_COALESCED_MMIO,
+    KVMIO,
+    0x67,
+    kvm_coalesced_mmio_zone
+);
+ioctl_iow_nr!(
+    KVM_UNREGISTER_COALESCED_MMIO,
+    KVMIO,
+    0x68,
+    kvm_coalesced_mmio_zone
+);
+ioctl_iow_nr!(KVM_SET_GSI_ROUTING, KVMIO, 0x6a, kvm_irq_routing);
+ioctl_iow_nr!(KVM_IRQFD, KVMIO, 0x76, kvm_irqfd);
+ioctl_iow_nr!(KVM_IOEVENTFD, KVMIO, 0x79, kvm_ioeventfd);
+ioctl_iow_nr!(KVM_SIGNAL_MSI, KVMIO, 0xa5, kvm_msi);
+ioctl_iow_nr!(KVM_ARM_SET_DEVICE_ADDR, KVMIO, 0xab, kvm_arm_device_addr);
+ioctl_ior_nr!(KVM_ARM_PREFERRED_TARGET, KVMIO, 0xaf, kvm_vcpu_init);
+ioctl_iowr_nr!(KVM_CREATE_DEVICE, KVMIO, 0xe0, kvm_create_device);
+ioctl_iow_nr!(KVM_SET_DEVICE_ATTR, KVMIO, 0xe1, kvm_device_attr);
+ioctl_iow_nr!(KVM_GET_DEVICE_ATTR, KVMIO, 0xe2, kvm_device_attr);
+ioctl_iow_nr!(KVM_HAS_DEVICE_ATTR, KVMIO, 0xe3, kvm_device_attr);
+
+// Ioctls for VCPU fds.
+ioctl_io_nr!(KVM_RUN, KVMIO, 0x80);
+ioctl_iow_nr!(KVM_SET_SIGNAL_MASK, KVMIO, 0x8b, kvm_signal_mask);
+ioctl_ior_nr!(KVM_GET_MP_STATE, KVMIO, 0x98, kvm_mp_state);
+ioctl_iow_nr!(KVM_SET_MP_STATE, KVMIO, 0x99, kvm_mp_state);
+ioctl_iow_nr!(KVM_SET_GUEST_DEBUG, KVMIO, 0x9b, kvm_guest_debug);
+ioctl_iow_nr!(KVM_ENABLE_CAP, KVMIO, 0xa3, kvm_enable_cap);
+ioctl_iow_nr!(KVM_GET_ONE_REG, KVMIO, 0xab, kvm_one_reg);
+ioctl_iow_nr!(KVM_SET_ONE_REG, KVMIO, 0xac, kvm_one_reg);
+ioctl_iow_nr!(KVM_ARM_VCPU_INIT, KVMIO, 0xae, kvm_vcpu_init);
+ioctl_iowr_nr!(KVM_GET_REG_LIST, KVMIO, 0xb0, kvm_reg_list);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ioctl_numbers() {
+        assert_eq!(KVM_GET_API_VERSION, 0xae00);
+        assert_eq!(KVM_CREATE_VM, 0xae01);
+        assert_eq!(KVM_CHECK_EXTENSION, 0xae03);
+        assert_eq!(KVM_GET_VCPU_MMAP_SIZE, 0xae04);
+        assert_eq!(KVM_CREATE_VCPU, 0xae41);
+        assert_eq!(KVM_GET_DIRTY_LOG, 0x4010ae42);
+        assert_eq!(KVM_SET_USER_MEMORY_REGION, 0x4020ae46);
+        assert_eq!(KVM_CREATE_IRQCHIP, 0xae60);
+        assert_eq!(KVM_IRQ_LINE, 0x4008ae61);
+        assert_eq!(KVM_IRQ_LINE_STATUS, 0xc008ae67);
+        assert_eq!(KVM_REGISTER_COALESCED_MMIO, 0x4010ae67);
+        assert_eq!(KVM_UNREGISTER_COALESCED_MMIO, 0x4010ae68);
+        assert_eq!(KVM_SET_GSI_ROUTING, 0x4008ae6a);
+        assert_eq!(KVM_IRQFD, 0x4020ae76);
+        assert_eq!(KVM_IOEVENTFD, 0x4040ae79);
+        assert_eq!(KVM_SIGNAL_MSI, 0x4020aea5);
+        assert_eq!(KVM_ARM_SET_DEVICE_ADDR, 0x4010aeab);
+        assert_eq!(KVM_ARM_PREFERRED_TARGET, 0x8020aeaf);
+        assert_eq!(KVM_CREATE_DEVICE, 0xc00caee0);
+        assert_eq!(KVM_SET_DEVICE_ATTR, 0x4018aee1);
+        assert_eq!(KVM_GET_DEVICE_ATTR, 0x4018aee2);
+        assert_eq!(KVM_HAS_DEVICE_ATTR, 0x4018aee3);
+        assert_eq!(KVM_RUN, 0xae80);
+        assert_eq!(KVM_SET_SIGNAL_MASK, 0x4004ae8b);
+        assert_eq!(KVM_GET_MP_STATE, 0x8004ae98);
+        assert_eq!(KVM_SET_MP_STATE, 0x4004ae99);
+        assert_eq!(KVM_SET_GUEST_DEBUG, 0x4208ae9b);
+        assert_eq!(KVM_ENABLE_CAP, 0x4068aea3);
+        assert_eq!(KVM_GET_ONE_REG, 0x4010aeab);
+        assert_eq!(KVM_SET_ONE_REG, 0x4010aeac);
+        assert_eq!(KVM_ARM_VCPU_INIT, 0x4020aeae);
+        assert_eq!(KVM_GET_REG_LIST, 0xc008aeb0);
+    }
+}
@@ -0,0 +1,260 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! The `irq` field of the `kvm_irq_level` passed to `KVM_IRQ_LINE`.
+//!
+//! On arm64 the field packs the interrupt type, the target vcpu index and the
+//! interrupt number. [`ArmIrq`](enum.ArmIrq.html) encodes and validates it,
+//! and decodes it back for tracing. SPIs are checked against the number of
+//! interrupts of the VGIC, [`LEGACY_NR_IRQS`](constant.LEGACY_NR_IRQS.html)
+//! or the one set with `KVM_DEV_ARM_VGIC_GRP_NR_IRQS`.
+
+use std::fmt;
+
+use super::bindings::*;
+
+// The interrupt IDs of the GIC: 16 SGIs, then 16 PPIs, then up to 988 SPIs.
+const VGIC_NR_SGIS: u32 = 16;
+const VGIC_NR_PRIVATE_IRQS: u32 = 32;
+
+/// The number of interrupts up to `KVM_ARM_IRQ_GIC_MAX`, SGIs and PPIs
+/// included, for a VGIC without `KVM_DEV_ARM_VGIC_GRP_NR_IRQS`.
+pub const LEGACY_NR_IRQS: u32 = KVM_ARM_IRQ_GIC_MAX + 1;
+
+/// The largest number of interrupts `KVM_DEV_ARM_VGIC_GRP_NR_IRQS` accepts.
+pub const MAX_NR_IRQS: u32 = 1020;
+
+/// Errors associated with the `irq` field of `kvm_irq_level`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The vcpu index does not fit in the `KVM_ARM_IRQ_VCPU` field.
+    InvalidVcpu(u32),
+    /// The CPU line is neither `KVM_ARM_IRQ_CPU_IRQ` nor `KVM_ARM_IRQ_CPU_FIQ`.
+    InvalidCpuLine(u32),
+    /// The interrupt ID is not the one of an SPI.
+    InvalidSpi(u32),
+    /// The interrupt ID is not the one of a PPI.
+    InvalidPpi(u32),
+    /// The `KVM_ARM_IRQ_TYPE` field is unknown to these bindings.
+    UnknownType(u32),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::InvalidVcpu(vcpu) => write!(f, "vcpu index {} does not fit in 8 bits", vcpu),
+            Error::InvalidCpuLine(line) => write!(f, "invalid CPU interrupt line {}", line),
+            Error::InvalidSpi(intid) => write!(f, "interrupt {} is not an SPI", intid),
+            Error::InvalidPpi(intid) => write!(f, "interrupt {} is not a PPI", intid),
+            Error::UnknownType(irq) => write!(f, "{:#x} has an unknown interrupt type", irq),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// An interrupt line raised or lowered with `KVM_IRQ_LINE`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ArmIrq {
+    /// The IRQ line of the vcpu, without in-kernel interrupt controller.
+    CpuIrq { vcpu: u32 },
+    /// The FIQ line of the vcpu, without in-kernel interrupt controller.
+    CpuFiq { vcpu: u32 },
+    /// The shared peripheral interrupt with the interrupt ID `intid`, from 32
+    /// to the number of interrupts of the VGIC, excluded.
+    Spi(u32),
+    /// The private peripheral interrupt `intid`, from 16 to 31, of the vcpu.
+    Ppi { vcpu: u32, intid: u32 },
+}
+
+impl ArmIrq {
+    /// Decodes the `irq` field of a `kvm_irq_level` for a VGIC with `nr_irqs`
+    /// interrupts.
+    pub fn from_raw(irq: u32, nr_irqs: u32) -> Result<Self, Error> {
+        let field = |shift: u32, mask: u32| (irq >> shift) & mask;
+        let vcpu = field(KVM_ARM_IRQ_VCPU_SHIFT, KVM_ARM_IRQ_VCPU_MASK);
+        let num = field(KVM_ARM_IRQ_NUM_SHIFT, KVM_ARM_IRQ_NUM_MASK);
+        let decoded = match field(KVM_ARM_IRQ_TYPE_SHIFT, KVM_ARM_IRQ_TYPE_MASK) {
+            KVM_ARM_IRQ_TYPE_CPU => match num {
+                KVM_ARM_IRQ_CPU_IRQ => ArmIrq::CpuIrq { vcpu },
+                KVM_ARM_IRQ_CPU_FIQ => ArmIrq::CpuFiq { vcpu },
+                _ => return Err(Error::InvalidCpuLine(num)),
+            },
+            KVM_ARM_IRQ_TYPE_SPI => ArmIrq::Spi(num),
+            KVM_ARM_IRQ_TYPE_PPI => ArmIrq::Ppi { vcpu, intid: num },
+            _ => return Err(Error::UnknownType(irq)),
+        };
+        decoded.validate(nr_irqs)?;
+        Ok(decoded)
+    }
+
+    /// Checks the vcpu index and interrupt ID against the field widths and
+    /// the interrupt ranges of a VGIC with `nr_irqs` interrupts.
+    ///
+    /// `nr_irqs` is [`LEGACY_NR_IRQS`](constant.LEGACY_NR_IRQS.html), or the
+    /// value set with `KVM_DEV_ARM_VGIC_GRP_NR_IRQS`, SGIs and PPIs included.
+    /// It is capped to [`MAX_NR_IRQS`](constant.MAX_NR_IRQS.html).
+    pub fn validate(self, nr_irqs: u32) -> Result<(), Error> {
+        match self {
+            ArmIrq::CpuIrq { vcpu } | ArmIrq::CpuFiq { vcpu } => Self::validate_vcpu(vcpu),
+            ArmIrq::Spi(intid) => {
+                if !(VGIC_NR_PRIVATE_IRQS..nr_irqs.min(MAX_NR_IRQS)).contains(&intid) {
+                    return Err(Error::InvalidSpi(intid));
+                }
+                Ok(())
+            }
+            ArmIrq::Ppi { vcpu, intid } => {
+                if !(VGIC_NR_SGIS..VGIC_NR_PRIVATE_IRQS).contains(&intid) {
+                    return Err(Error::InvalidPpi(intid));
+                }
+                Self::validate_vcpu(vcpu)
+            }
+        }
+    }
+
+    fn validate_vcpu(vcpu: u32) -> Result<(), Error> {
+        if vcpu > KVM_ARM_IRQ_VCPU_MASK {
+            return Err(Error::InvalidVcpu(vcpu));
+        }
+        Ok(())
+    }
+
+    /// Validates the interrupt for a VGIC with `nr_irqs` interrupts and
+    /// encodes the `irq` field of `kvm_irq_level`.
+    pub fn raw(self, nr_irqs: u32) -> Result<u32, Error> {
+        self.validate(nr_irqs)?;
+        let (irq_type, vcpu, num) = match self {
+            ArmIrq::CpuIrq { vcpu } => (KVM_ARM_IRQ_TYPE_CPU, vcpu, KVM_ARM_IRQ_CPU_IRQ),
+            ArmIrq::CpuFiq { vcpu } => (KVM_ARM_IRQ_TYPE_CPU, vcpu, KVM_ARM_IRQ_CPU_FIQ),
+            ArmIrq::Spi(intid) => (KVM_ARM_IRQ_TYPE_SPI, 0, intid),
+            ArmIrq::Ppi { vcpu, intid } => (KVM_ARM_IRQ_TYPE_PPI, vcpu, intid),
+        };
+        Ok((irq_type << KVM_ARM_IRQ_TYPE_SHIFT)
+            | (vcpu << KVM_ARM_IRQ_VCPU_SHIFT)
+            | (num << KVM_ARM_IRQ_NUM_SHIFT))
+    }
+
+    /// Returns the `kvm_irq_level` setting the line to `level`, for a VGIC
+    /// with `nr_irqs` interrupts.
+    pub fn kvm_irq_level(self, level: bool, nr_irqs: u32) -> Result<kvm_irq_level, Error> {
+        Ok(kvm_irq_level {
+            __bindgen_anon_1: kvm_irq_level__bindgen_ty_1 {
+                irq: self.raw(nr_irqs)?,
+            },
+            level: u32::from(level),
+        })
+    }
+}
+
+impl fmt::Display for ArmIrq {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ArmIrq::CpuIrq { vcpu } => write!(f, "IRQ of vcpu {}", vcpu),
+            ArmIrq::CpuFiq { vcpu } => write!(f, "FIQ of vcpu {}", vcpu),
+            ArmIrq::Spi(intid) => write!(f, "SPI {}", intid),
+            ArmIrq::Ppi { vcpu, intid } => write!(f, "PPI {} of vcpu {}", intid, vcpu),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(ArmIrq::Spi(32).raw(LEGACY_NR_IRQS).unwrap(), 0x0100_0020);
+        assert_eq!(
+            ArmIrq::Ppi { vcpu: 3, intid: 27 }
+                .raw(LEGACY_NR_IRQS)
+                .unwrap(),
+            0x0203_001b
+        );
+        assert_eq!(
+            ArmIrq::CpuIrq { vcpu: 1 }.raw(LEGACY_NR_IRQS).unwrap(),
+            0x0001_0000
+        );
+        assert_eq!(
+            ArmIrq::CpuFiq { vcpu: 255 }.raw(LEGACY_NR_IRQS).unwrap(),
+            0x00ff_0001
+        );
+
+        let level = ArmIrq::Spi(40).kvm_irq_level(true, LEGACY_NR_IRQS).unwrap();
+        assert_eq!(unsafe { level.__bindgen_anon_1.irq }, 0x0100_0028);
+        assert_eq!(level.level, 1);
+        assert_eq!(
+            ArmIrq::Spi(40)
+                .kvm_irq_level(false, LEGACY_NR_IRQS)
+                .unwrap()
+                .level,
+            0
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            ArmIrq::Spi(31).raw(LEGACY_NR_IRQS),
+            Err(Error::InvalidSpi(31))
+        );
+        assert!(ArmIrq::Spi(KVM_ARM_IRQ_GIC_MAX)
+            .validate(LEGACY_NR_IRQS)
+            .is_ok());
+        assert_eq!(
+            ArmIrq::Spi(KVM_ARM_IRQ_GIC_MAX + 1).validate(LEGACY_NR_IRQS),
+            Err(Error::InvalidSpi(KVM_ARM_IRQ_GIC_MAX + 1))
+        );
+        // A VGIC with more interrupts set with KVM_DEV_ARM_VGIC_GRP_NR_IRQS.
+        assert!(ArmIrq::Spi(KVM_ARM_IRQ_GIC_MAX + 1).validate(256).is_ok());
+        assert_eq!(ArmIrq::Spi(256).validate(256), Err(Error::InvalidSpi(256)));
+        assert!(ArmIrq::Spi(1019).validate(MAX_NR_IRQS).is_ok());
+        assert_eq!(ArmIrq::Spi(1020).raw(2048), Err(Error::InvalidSpi(1020)));
+        assert_eq!(
+            ArmIrq::Ppi { vcpu: 0, intid: 15 }.raw(LEGACY_NR_IRQS),
+            Err(Error::InvalidPpi(15))
+        );
+        assert_eq!(
+            ArmIrq::Ppi { vcpu: 0, intid: 32 }.raw(LEGACY_NR_IRQS),
+            Err(Error::InvalidPpi(32))
+        );
+        assert_eq!(
+            ArmIrq::CpuIrq { vcpu: 256 }.raw(LEGACY_NR_IRQS),
+            Err(Error::InvalidVcpu(256))
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        for irq in [
+            ArmIrq::CpuIrq { vcpu: 2 },
+            ArmIrq::CpuFiq { vcpu: 0 },
+            ArmIrq::Spi(100),
+            ArmIrq::Ppi { vcpu: 7, intid: 30 },
+        ]
+        .iter()
+        {
+            assert_eq!(
+                ArmIrq::from_raw(irq.raw(LEGACY_NR_IRQS).unwrap(), LEGACY_NR_IRQS).unwrap(),
+                *irq
+            );
+        }
+        assert_eq!(
+            ArmIrq::from_raw(0x0000_0002, LEGACY_NR_IRQS),
+            Err(Error::InvalidCpuLine(2))
+        );
+        assert_eq!(
+            ArmIrq::from_raw(0x0300_0000, LEGACY_NR_IRQS),
+            Err(Error::UnknownType(0x0300_0000))
+        );
+        assert_eq!(
+            ArmIrq::from_raw(0x0100_0001, LEGACY_NR_IRQS),
+            Err(Error::InvalidSpi(1))
+        );
+
+        assert_eq!(
+            format!("{}", ArmIrq::from_raw(0x0201_001b, LEGACY_NR_IRQS).unwrap()),
+            "PPI 27 of vcpu 1"
+        );
+        assert_eq!(format!("{}", ArmIrq::Spi(42)), "SPI 42");
+    }
+}
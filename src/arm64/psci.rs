@@ -0,0 +1,443 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! PSCI function IDs, power events and the PSCI version register.
+//!
+//! KVM implements PSCI for the guest and reports the calls that need the VMM,
+//! like `SYSTEM_OFF`, as `KVM_EXIT_SYSTEM_EVENT` exits.
+//! [`PsciEvent`](enum.PsciEvent.html) decodes these exits and the PSCI calls
+//! of VMMs emulating PSCI themselves, and
+//! [`PsciVersion`](struct.PsciVersion.html) pins the version seen by the guest.
+
+use std::fmt;
+
+use super::bindings::*;
+use super::reg_id::Arm64RegId;
+use one_reg::OneRegValue;
+use vcpu_exit::VcpuExit;
+
+/// Errors associated with PSCI calls and events.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The function ID is not the one of a PSCI function.
+    UnknownFunction(u32),
+    /// The system event type is unknown to these bindings.
+    UnknownSystemEvent(u32),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::UnknownFunction(id) => write!(f, "{:#x} is not a PSCI function ID", id),
+            Error::UnknownSystemEvent(type_) => write!(f, "unknown system event type {}", type_),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// A PSCI version, as returned by `PSCI_VERSION`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PsciVersion {
+    /// The major version.
+    pub major: u16,
+    /// The minor version.
+    pub minor: u16,
+}
+
+impl PsciVersion {
+    /// PSCI 0.1, with the KVM specific function IDs.
+    pub const V0_1: PsciVersion = PsciVersion { major: 0, minor: 1 };
+    /// PSCI 0.2, selected with `KVM_ARM_VCPU_PSCI_0_2`.
+    pub const V0_2: PsciVersion = PsciVersion { major: 0, minor: 2 };
+    /// PSCI 1.0, the default with `KVM_ARM_VCPU_PSCI_0_2` since 4.16.
+    pub const V1_0: PsciVersion = PsciVersion { major: 1, minor: 0 };
+
+    /// Decodes the value of `PSCI_VERSION` or of the PSCI version register.
+    pub fn from_raw(raw: u32) -> Self {
+        PsciVersion {
+            major: (raw >> PSCI_VERSION_MAJOR_SHIFT) as u16,
+            minor: (raw & PSCI_VERSION_MINOR_MASK) as u16,
+        }
+    }
+
+    /// Returns the value of `PSCI_VERSION` for this version.
+    pub fn raw(self) -> u32 {
+        (u32::from(self.major) << PSCI_VERSION_MAJOR_SHIFT) | u32::from(self.minor)
+    }
+
+    /// Returns the value to write with `KVM_SET_ONE_REG` to the PSCI version
+    /// firmware register so the guest sees this version, for instance after a
+    /// migration to a newer kernel.
+    pub fn firmware_reg(self) -> OneRegValue {
+        OneRegValue::from_u64(Arm64RegId::PSCI_VERSION, u64::from(self.raw()))
+            .expect("the PSCI version register is 64-bit wide")
+    }
+}
+
+impl fmt::Display for PsciVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// A PSCI function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PsciFunction {
+    /// `PSCI_VERSION`.
+    Version,
+    /// `CPU_SUSPEND`.
+    CpuSuspend,
+    /// `CPU_OFF`.
+    CpuOff,
+    /// `CPU_ON`.
+    CpuOn,
+    /// `AFFINITY_INFO`.
+    AffinityInfo,
+    /// `MIGRATE`.
+    Migrate,
+    /// `MIGRATE_INFO_TYPE`.
+    MigrateInfoType,
+    /// `MIGRATE_INFO_UP_CPU`.
+    MigrateInfoUpCpu,
+    /// `SYSTEM_OFF`.
+    SystemOff,
+    /// `SYSTEM_RESET`.
+    SystemReset,
+    /// `PSCI_FEATURES`.
+    Features,
+    /// `CPU_FREEZE`.
+    CpuFreeze,
+    /// `CPU_DEFAULT_SUSPEND`.
+    CpuDefaultSuspend,
+    /// `NODE_HW_STATE`.
+    NodeHwState,
+    /// `SYSTEM_SUSPEND`.
+    SystemSuspend,
+    /// `PSCI_SET_SUSPEND_MODE`.
+    SetSuspendMode,
+    /// `PSCI_STAT_RESIDENCY`.
+    StatResidency,
+    /// `PSCI_STAT_COUNT`.
+    StatCount,
+}
+
+impl PsciFunction {
+    /// The PSCI functions, in function number order.
+    pub const ALL: [PsciFunction; 18] = [
+        PsciFunction::Version,
+        PsciFunction::CpuSuspend,
+        PsciFunction::CpuOff,
+        PsciFunction::CpuOn,
+        PsciFunction::AffinityInfo,
+        PsciFunction::Migrate,
+        PsciFunction::MigrateInfoType,
+        PsciFunction::MigrateInfoUpCpu,
+        PsciFunction::SystemOff,
+        PsciFunction::SystemReset,
+        PsciFunction::Features,
+        PsciFunction::CpuFreeze,
+        PsciFunction::CpuDefaultSuspend,
+        PsciFunction::NodeHwState,
+        PsciFunction::SystemSuspend,
+        PsciFunction::SetSuspendMode,
+        PsciFunction::StatResidency,
+        PsciFunction::StatCount,
+    ];
+
+    /// Returns the name of the function in the PSCI specification.
+    pub fn name(self) -> &'static str {
+        match self {
+            PsciFunction::Version => "PSCI_VERSION",
+            PsciFunction::CpuSuspend => "CPU_SUSPEND",
+            PsciFunction::CpuOff => "CPU_OFF",
+            PsciFunction::CpuOn => "CPU_ON",
+            PsciFunction::AffinityInfo => "AFFINITY_INFO",
+            PsciFunction::Migrate => "MIGRATE",
+            PsciFunction::MigrateInfoType => "MIGRATE_INFO_TYPE",
+            PsciFunction::MigrateInfoUpCpu => "MIGRATE_INFO_UP_CPU",
+            PsciFunction::SystemOff => "SYSTEM_OFF",
+            PsciFunction::SystemReset => "SYSTEM_RESET",
+            PsciFunction::Features => "PSCI_FEATURES",
+            PsciFunction::CpuFreeze => "CPU_FREEZE",
+            PsciFunction::CpuDefaultSuspend => "CPU_DEFAULT_SUSPEND",
+            PsciFunction::NodeHwState => "NODE_HW_STATE",
+            PsciFunction::SystemSuspend => "SYSTEM_SUSPEND",
+            PsciFunction::SetSuspendMode => "PSCI_SET_SUSPEND_MODE",
+            PsciFunction::StatResidency => "PSCI_STAT_RESIDENCY",
+            PsciFunction::StatCount => "PSCI_STAT_COUNT",
+        }
+    }
+
+    /// Returns the first PSCI version with the function.
+    ///
+    /// PSCI 0.1 only has `CPU_SUSPEND`, `CPU_OFF`, `CPU_ON` and `MIGRATE`,
+    /// with the IDs returned by [`v0_1_id`](#method.v0_1_id).
+    pub fn since(self) -> PsciVersion {
+        if self.v0_1_id().is_some() {
+            return PsciVersion::V0_1;
+        }
+        if self.number() <= 9 {
+            PsciVersion::V0_2
+        } else {
+            PsciVersion::V1_0
+        }
+    }
+
+    /// Returns `true` if the function has an SMC64 ID, because one of its
+    /// arguments is an address or an MPIDR.
+    pub fn has_smc64(self) -> bool {
+        matches!(
+            self,
+            PsciFunction::CpuSuspend
+                | PsciFunction::CpuOn
+                | PsciFunction::AffinityInfo
+                | PsciFunction::Migrate
+                | PsciFunction::MigrateInfoUpCpu
+                | PsciFunction::CpuDefaultSuspend
+                | PsciFunction::NodeHwState
+                | PsciFunction::SystemSuspend
+                | PsciFunction::StatResidency
+                | PsciFunction::StatCount
+        )
+    }
+
+    fn number(self) -> u32 {
+        Self::ALL.iter().position(|&f| f == self).unwrap() as u32
+    }
+
+    /// Returns the SMC32 function ID, from PSCI 0.2 on.
+    pub fn id(self) -> u32 {
+        PSCI_0_2_FN_BASE + self.number()
+    }
+
+    /// Returns the SMC64 function ID, if the function has one.
+    pub fn id64(self) -> Option<u32> {
+        if self.has_smc64() {
+            Some(PSCI_0_2_FN64_BASE + self.number())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the KVM specific function ID of PSCI 0.1, if the function
+    /// exists in that version.
+    pub fn v0_1_id(self) -> Option<u32> {
+        let index = match self {
+            PsciFunction::CpuSuspend => 0,
+            PsciFunction::CpuOff => 1,
+            PsciFunction::CpuOn => 2,
+            PsciFunction::Migrate => 3,
+            _ => return None,
+        };
+        Some(KVM_PSCI_FN_BASE + index)
+    }
+
+    /// Returns the function with the SMC32, SMC64 or PSCI 0.1 ID `id`.
+    pub fn from_id(id: u32) -> Option<PsciFunction> {
+        Self::ALL
+            .iter()
+            .cloned()
+            .find(|f| f.id() == id || f.id64() == Some(id) || f.v0_1_id() == Some(id))
+    }
+}
+
+impl fmt::Display for PsciFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A PSCI call or power event of the guest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PsciEvent {
+    /// `CPU_ON`: starts the vcpu `target_mpidr` at `entry_point`, with
+    /// `context_id` in `x0`.
+    CpuOn {
+        target_mpidr: u64,
+        entry_point: u64,
+        context_id: u64,
+    },
+    /// `CPU_OFF`: the calling vcpu powers itself off.
+    CpuOff,
+    /// `SYSTEM_OFF`, or a `KVM_SYSTEM_EVENT_SHUTDOWN` exit.
+    SystemOff,
+    /// `SYSTEM_RESET`, or a `KVM_SYSTEM_EVENT_RESET` exit.
+    SystemReset,
+    /// A `KVM_SYSTEM_EVENT_CRASH` exit: the guest reported a crash.
+    Crash,
+    /// Any other PSCI function.
+    Call(PsciFunction),
+}
+
+impl PsciEvent {
+    /// Decodes the PSCI call `function_id`, `args` holding the values of
+    /// `x1` to `x3`.
+    pub fn from_call(function_id: u32, args: &[u64; 3]) -> Result<Self, Error> {
+        let function =
+            PsciFunction::from_id(function_id).ok_or(Error::UnknownFunction(function_id))?;
+        // The SMC32 calls only use the low 32 bits of the registers. The SMC64
+        // and PSCI 0.1 calls use all 64 bits.
+        let arg = |i: usize| {
+            if function.id() == function_id {
+                args[i] & 0xffff_ffff
+            } else {
+                args[i]
+            }
+        };
+        Ok(match function {
+            PsciFunction::CpuOn => PsciEvent::CpuOn {
+                target_mpidr: arg(0),
+                entry_point: arg(1),
+                context_id: arg(2),
+            },
+            PsciFunction::CpuOff => PsciEvent::CpuOff,
+            PsciFunction::SystemOff => PsciEvent::SystemOff,
+            PsciFunction::SystemReset => PsciEvent::SystemReset,
+            other => PsciEvent::Call(other),
+        })
+    }
+
+    /// Decodes the `type_` of a `KVM_EXIT_SYSTEM_EVENT` exit.
+    pub fn from_system_event(type_: u32) -> Result<Self, Error> {
+        match type_ {
+            KVM_SYSTEM_EVENT_SHUTDOWN => Ok(PsciEvent::SystemOff),
+            KVM_SYSTEM_EVENT_RESET => Ok(PsciEvent::SystemReset),
+            KVM_SYSTEM_EVENT_CRASH => Ok(PsciEvent::Crash),
+            _ => Err(Error::UnknownSystemEvent(type_)),
+        }
+    }
+
+    /// Decodes a `KVM_EXIT_SYSTEM_EVENT` or `KVM_EXIT_HYPERCALL` exit, returning
+    /// `None` for the other exits.
+    ///
+    /// For `KVM_EXIT_HYPERCALL`, `nr` is the function ID and `args` start with
+    /// the values of `x1` to `x3`.
+    pub fn from_exit(exit: &VcpuExit) -> Option<Result<Self, Error>> {
+        match *exit {
+            VcpuExit::SystemEvent { type_, .. } => Some(PsciEvent::from_system_event(type_)),
+            VcpuExit::Hypercall { nr, args, .. } => Some(PsciEvent::from_call(
+                nr as u32,
+                &[args[0], args[1], args[2]],
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_versions() {
+        assert_eq!(PsciVersion::V1_0.raw(), 0x1_0000);
+        assert_eq!(PsciVersion::from_raw(2), PsciVersion::V0_2);
+        assert!(PsciVersion::V0_2 < PsciVersion::V1_0);
+        assert_eq!(format!("{}", PsciVersion::V1_0), "1.0");
+
+        let reg = PsciVersion::V0_2.firmware_reg();
+        assert_eq!(reg.id(), 0x6030_0000_0014_0000);
+        assert_eq!(reg.to_u64().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_functions() {
+        assert_eq!(PsciFunction::Version.id(), 0x8400_0000);
+        assert_eq!(PsciFunction::CpuOn.id(), 0x8400_0003);
+        assert_eq!(PsciFunction::CpuOn.id64(), Some(0xc400_0003));
+        assert_eq!(PsciFunction::SystemOff.id(), 0x8400_0008);
+        assert_eq!(PsciFunction::SystemOff.id64(), None);
+        assert_eq!(PsciFunction::Features.id(), 0x8400_000a);
+        assert_eq!(PsciFunction::CpuOn.v0_1_id(), Some(0x95c1_ba60));
+
+        assert_eq!(PsciFunction::CpuOn.since(), PsciVersion::V0_1);
+        assert_eq!(PsciFunction::SystemReset.since(), PsciVersion::V0_2);
+        assert_eq!(PsciFunction::Features.since(), PsciVersion::V1_0);
+
+        for function in PsciFunction::ALL.iter() {
+            assert_eq!(PsciFunction::from_id(function.id()), Some(*function));
+            if let Some(id) = function.id64() {
+                assert_eq!(PsciFunction::from_id(id), Some(*function));
+            }
+        }
+        assert_eq!(
+            PsciFunction::from_id(0x95c1_ba5f),
+            Some(PsciFunction::CpuOff)
+        );
+        assert_eq!(PsciFunction::from_id(0x8400_0020), None);
+    }
+
+    #[test]
+    fn test_events() {
+        let args = [0xffff_ffff_8000_0001, 0x4008_0000, 0x1234];
+        assert_eq!(
+            PsciEvent::from_call(0xc400_0003, &args),
+            Ok(PsciEvent::CpuOn {
+                target_mpidr: 0xffff_ffff_8000_0001,
+                entry_point: 0x4008_0000,
+                context_id: 0x1234
+            })
+        );
+        assert_eq!(
+            PsciEvent::from_call(0x8400_0003, &args),
+            Ok(PsciEvent::CpuOn {
+                target_mpidr: 0x8000_0001,
+                entry_point: 0x4008_0000,
+                context_id: 0x1234
+            })
+        );
+        // KVM passes the 64-bit registers to the PSCI 0.1 functions.
+        let args = [0x1_0000_0101, 0x1_4008_0000, 0x1_0000_1234];
+        assert_eq!(
+            PsciEvent::from_call(PsciFunction::CpuOn.v0_1_id().unwrap(), &args),
+            Ok(PsciEvent::CpuOn {
+                target_mpidr: 0x1_0000_0101,
+                entry_point: 0x1_4008_0000,
+                context_id: 0x1_0000_1234
+            })
+        );
+        assert_eq!(
+            PsciEvent::from_call(0x8400_0009, &[0; 3]),
+            Ok(PsciEvent::SystemReset)
+        );
+        assert_eq!(
+            PsciEvent::from_call(0x8400_0000, &[0; 3]),
+            Ok(PsciEvent::Call(PsciFunction::Version))
+        );
+        assert_eq!(
+            PsciEvent::from_call(0x1234, &[0; 3]),
+            Err(Error::UnknownFunction(0x1234))
+        );
+
+        assert_eq!(
+            PsciEvent::from_system_event(KVM_SYSTEM_EVENT_SHUTDOWN),
+            Ok(PsciEvent::SystemOff)
+        );
+        assert_eq!(
+            PsciEvent::from_system_event(KVM_SYSTEM_EVENT_CRASH),
+            Ok(PsciEvent::Crash)
+        );
+        assert_eq!(
+            PsciEvent::from_system_event(4),
+            Err(Error::UnknownSystemEvent(4))
+        );
+
+        let exit = VcpuExit::SystemEvent {
+            type_: KVM_SYSTEM_EVENT_RESET,
+            flags: 0,
+        };
+        assert_eq!(
+            PsciEvent::from_exit(&exit),
+            Some(Ok(PsciEvent::SystemReset))
+        );
+        let exit = VcpuExit::Hypercall {
+            nr: 0x8400_0008,
+            args: &[0; 6],
+            ret: 0,
+            longmode: 0,
+        };
+        assert_eq!(PsciEvent::from_exit(&exit), Some(Ok(PsciEvent::SystemOff)));
+        assert_eq!(PsciEvent::from_exit(&VcpuExit::Hlt), None);
+    }
+}
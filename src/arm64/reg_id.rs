@@ -0,0 +1,379 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Register IDs for `KVM_GET_ONE_REG`, `KVM_SET_ONE_REG` and `KVM_GET_REG_LIST`.
+//!
+//! An arm64 register ID is made of the architecture, the size of the register,
+//! the register group and an index in that group. [`Arm64RegId`](struct.Arm64RegId.html)
+//! builds the IDs the kernel headers build with `KVM_REG_ARM_CORE_REG()`,
+//! `ARM64_SYS_REG()` and friends, and decodes the IDs returned in
+//! `kvm_reg_list`.
+
+use std::fmt;
+use std::mem::{offset_of, size_of};
+
+use super::bindings::*;
+
+// Not in the 4.14 bindings.
+const KVM_REG_ARM_FW: u64 = 0x0014 << KVM_REG_ARM_COPROC_SHIFT;
+
+const KVM_REG_ARM_COPROC: u64 = KVM_REG_ARM_COPROC_MASK as u64;
+const CORE: u64 = KVM_REG_ARM_CORE as u64;
+const DEMUX: u64 = KVM_REG_ARM_DEMUX as u64;
+const SYSREG: u64 = KVM_REG_ARM64_SYSREG as u64;
+
+/// Errors associated with arm64 register IDs.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The register ID is not an arm64 register ID.
+    NotArm64(u64),
+    /// The core register is not within `kvm_regs` or has an invalid size.
+    InvalidCoreReg { offset: usize, size: usize },
+    /// The register ID belongs to a group unknown to these bindings.
+    UnknownGroup(u64),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::NotArm64(id) => write!(f, "{:#x} is not an arm64 register ID", id),
+            Error::InvalidCoreReg { offset, size } => write!(
+                f,
+                "no {} bytes core register at offset {} of kvm_regs",
+                size, offset
+            ),
+            Error::UnknownGroup(id) => write!(f, "{:#x} belongs to an unknown register group", id),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// The register an [`Arm64RegId`](struct.Arm64RegId.html) refers to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Arm64Reg {
+    /// A field of `kvm_regs`, at `offset` bytes from its start.
+    Core { offset: usize },
+    /// A system register, identified by its `MRS`/`MSR` encoding.
+    SysReg {
+        op0: u8,
+        op1: u8,
+        crn: u8,
+        crm: u8,
+        op2: u8,
+    },
+    /// A `CCSIDR_EL1` value, for the `CSSELR_EL1` cache selector.
+    Ccsidr(u8),
+    /// A firmware pseudo-register, such as the PSCI version.
+    Firmware(u16),
+}
+
+/// An arm64 KVM register ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Arm64RegId(u64);
+
+impl Arm64RegId {
+    // Offsets of `X0` and `V0` in `kvm_regs`.
+    const X0: usize = offset_of!(kvm_regs, regs) + offset_of!(user_pt_regs, regs);
+    const V0: usize = offset_of!(kvm_regs, fp_regs) + offset_of!(user_fpsimd_state, vregs);
+
+    /// The stack pointer, `user_pt_regs::sp`.
+    pub const SP: Arm64RegId =
+        Arm64RegId::core_reg(offset_of!(kvm_regs, regs) + offset_of!(user_pt_regs, sp), 8);
+    /// The program counter, `user_pt_regs::pc`.
+    pub const PC: Arm64RegId =
+        Arm64RegId::core_reg(offset_of!(kvm_regs, regs) + offset_of!(user_pt_regs, pc), 8);
+    /// The processor state, `user_pt_regs::pstate`.
+    pub const PSTATE: Arm64RegId = Arm64RegId::core_reg(
+        offset_of!(kvm_regs, regs) + offset_of!(user_pt_regs, pstate),
+        8,
+    );
+    /// `SP_EL1`, `kvm_regs::sp_el1`.
+    pub const SP_EL1: Arm64RegId = Arm64RegId::core_reg(offset_of!(kvm_regs, sp_el1), 8);
+    /// `ELR_EL1`, `kvm_regs::elr_el1`.
+    pub const ELR_EL1: Arm64RegId = Arm64RegId::core_reg(offset_of!(kvm_regs, elr_el1), 8);
+    /// `FPSR`, `user_fpsimd_state::fpsr`.
+    pub const FPSR: Arm64RegId = Arm64RegId::core_reg(
+        offset_of!(kvm_regs, fp_regs) + offset_of!(user_fpsimd_state, fpsr),
+        4,
+    );
+    /// `FPCR`, `user_fpsimd_state::fpcr`.
+    pub const FPCR: Arm64RegId = Arm64RegId::core_reg(
+        offset_of!(kvm_regs, fp_regs) + offset_of!(user_fpsimd_state, fpcr),
+        4,
+    );
+
+    /// The virtual timer control register, `CNTV_CTL_EL0`.
+    pub const TIMER_CTL: Arm64RegId = Arm64RegId::sys_reg(3, 3, 14, 3, 1);
+    /// The virtual counter, `CNTVCT_EL0`.
+    ///
+    /// The kernel swaps the encodings of `TIMER_CNT` and `TIMER_CVAL`, and this
+    /// is part of the ABI: this is the encoding of `CNTV_CVAL_EL0`.
+    pub const TIMER_CNT: Arm64RegId = Arm64RegId::sys_reg(3, 3, 14, 3, 2);
+    /// The virtual timer compare value, `CNTV_CVAL_EL0`.
+    ///
+    /// This is the encoding of `CNTVCT_EL0`, see [`TIMER_CNT`](#associatedconstant.TIMER_CNT).
+    pub const TIMER_CVAL: Arm64RegId = Arm64RegId::sys_reg(3, 3, 14, 0, 2);
+
+    /// The PSCI version implemented for the guest.
+    pub const PSCI_VERSION: Arm64RegId = Arm64RegId::firmware(0);
+
+    /// Builds the ID of a `size` bytes register, `size` being a power of two.
+    const fn new(size: usize, group: u64, index: u64) -> Self {
+        Arm64RegId(
+            KVM_REG_ARM64 | ((size.trailing_zeros() as u64) << KVM_REG_SIZE_SHIFT) | group | index,
+        )
+    }
+
+    // Like `KVM_REG_ARM_CORE_REG()`, the index is the offset in 32-bit words.
+    const fn core_reg(offset: usize, size: usize) -> Self {
+        Arm64RegId::new(size, CORE, (offset / size_of::<u32>()) as u64)
+    }
+
+    /// Builds the ID of the `size` bytes field at `offset` bytes in `kvm_regs`.
+    ///
+    /// `offset` is typically computed with `offset_of!`, for instance
+    /// `offset_of!(kvm_regs, spsr) + 2 * 8` for `SPSR_IRQ`.
+    pub fn core(offset: usize, size: usize) -> Result<Self, Error> {
+        let valid_size = size == 4 || size == 8 || size == 16;
+        let in_regs = match offset.checked_add(size) {
+            Some(end) => end <= size_of::<kvm_regs>(),
+            None => false,
+        };
+        if !valid_size || !offset.is_multiple_of(size_of::<u32>()) || !in_regs {
+            return Err(Error::InvalidCoreReg { offset, size });
+        }
+        Ok(Arm64RegId::core_reg(offset, size))
+    }
+
+    /// Returns the ID of the general purpose register `Xn`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is larger than 30.
+    pub fn x(n: usize) -> Self {
+        assert!(n < 31, "there is no X{} register", n);
+        Arm64RegId::core_reg(Arm64RegId::X0 + n * size_of::<u64>(), 8)
+    }
+
+    /// Returns the ID of the FP/SIMD register `Vn`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is larger than 31.
+    pub fn v(n: usize) -> Self {
+        assert!(n < 32, "there is no V{} register", n);
+        Arm64RegId::core_reg(Arm64RegId::V0 + n * 16, 16)
+    }
+
+    /// Returns the ID of `SPSR_EL1` (`n` = 0) or one of the `SPSR` of the
+    /// AArch32 modes: `abt`, `und`, `irq` and `fiq` (`n` = 1 to 4).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is larger than 4.
+    pub fn spsr(n: usize) -> Self {
+        assert!(n < 5, "there is no SPSR {}", n);
+        Arm64RegId::core_reg(offset_of!(kvm_regs, spsr) + n * size_of::<u64>(), 8)
+    }
+
+    /// Builds the ID of a 64-bit system register, like `ARM64_SYS_REG()`.
+    ///
+    /// The fields are truncated to their width: 2 bits for `op0`, 3 bits for
+    /// `op1` and `op2` and 4 bits for `crn` and `crm`.
+    pub const fn sys_reg(op0: u8, op1: u8, crn: u8, crm: u8, op2: u8) -> Self {
+        let index = (((op0 as u32) << KVM_REG_ARM64_SYSREG_OP0_SHIFT)
+            & KVM_REG_ARM64_SYSREG_OP0_MASK)
+            | (((op1 as u32) << KVM_REG_ARM64_SYSREG_OP1_SHIFT) & KVM_REG_ARM64_SYSREG_OP1_MASK)
+            | (((crn as u32) << KVM_REG_ARM64_SYSREG_CRN_SHIFT) & KVM_REG_ARM64_SYSREG_CRN_MASK)
+            | (((crm as u32) << KVM_REG_ARM64_SYSREG_CRM_SHIFT) & KVM_REG_ARM64_SYSREG_CRM_MASK)
+            | (((op2 as u32) << KVM_REG_ARM64_SYSREG_OP2_SHIFT) & KVM_REG_ARM64_SYSREG_OP2_MASK);
+        Arm64RegId::new(8, SYSREG, index as u64)
+    }
+
+    /// Builds the ID of the `CCSIDR_EL1` value for the `CSSELR_EL1` cache selector.
+    pub const fn ccsidr(selector: u8) -> Self {
+        Arm64RegId::new(
+            4,
+            DEMUX,
+            ((KVM_REG_ARM_DEMUX_ID_CCSIDR << KVM_REG_ARM_DEMUX_ID_SHIFT)
+                | ((selector as u32) << KVM_REG_ARM_DEMUX_VAL_SHIFT)) as u64,
+        )
+    }
+
+    /// Builds the ID of the firmware pseudo-register `index`.
+    pub const fn firmware(index: u16) -> Self {
+        Arm64RegId::new(8, KVM_REG_ARM_FW, index as u64)
+    }
+
+    /// Wraps a raw register ID, such as one returned in `kvm_reg_list`.
+    pub fn from_raw(id: u64) -> Result<Self, Error> {
+        if id & KVM_REG_ARCH_MASK as u64 != KVM_REG_ARM64 {
+            return Err(Error::NotArm64(id));
+        }
+        Ok(Arm64RegId(id))
+    }
+
+    /// Returns the raw register ID, to be stored in `kvm_one_reg::id`.
+    pub const fn raw(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the size of the register in bytes.
+    pub const fn size(self) -> usize {
+        1 << ((self.0 & KVM_REG_SIZE_MASK) >> KVM_REG_SIZE_SHIFT)
+    }
+
+    /// Decodes the register the ID refers to.
+    pub fn decode(self) -> Result<Arm64Reg, Error> {
+        let index = self.0 & 0xffff;
+        match self.0 & KVM_REG_ARM_COPROC {
+            CORE => Ok(Arm64Reg::Core {
+                offset: index as usize * size_of::<u32>(),
+            }),
+            SYSREG => {
+                let field = |mask: u32, shift: u32| ((index as u32 & mask) >> shift) as u8;
+                Ok(Arm64Reg::SysReg {
+                    op0: field(
+                        KVM_REG_ARM64_SYSREG_OP0_MASK,
+                        KVM_REG_ARM64_SYSREG_OP0_SHIFT,
+                    ),
+                    op1: field(
+                        KVM_REG_ARM64_SYSREG_OP1_MASK,
+                        KVM_REG_ARM64_SYSREG_OP1_SHIFT,
+                    ),
+                    crn: field(
+                        KVM_REG_ARM64_SYSREG_CRN_MASK,
+                        KVM_REG_ARM64_SYSREG_CRN_SHIFT,
+                    ),
+                    crm: field(
+                        KVM_REG_ARM64_SYSREG_CRM_MASK,
+                        KVM_REG_ARM64_SYSREG_CRM_SHIFT,
+                    ),
+                    op2: field(
+                        KVM_REG_ARM64_SYSREG_OP2_MASK,
+                        KVM_REG_ARM64_SYSREG_OP2_SHIFT,
+                    ),
+                })
+            }
+            DEMUX => {
+                let id = (index as u32 & KVM_REG_ARM_DEMUX_ID_MASK) >> KVM_REG_ARM_DEMUX_ID_SHIFT;
+                if id != KVM_REG_ARM_DEMUX_ID_CCSIDR {
+                    return Err(Error::UnknownGroup(self.0));
+                }
+                Ok(Arm64Reg::Ccsidr(
+                    ((index as u32 & KVM_REG_ARM_DEMUX_VAL_MASK) >> KVM_REG_ARM_DEMUX_VAL_SHIFT)
+                        as u8,
+                ))
+            }
+            KVM_REG_ARM_FW => Ok(Arm64Reg::Firmware(index as u16)),
+            _ => Err(Error::UnknownGroup(self.0)),
+        }
+    }
+}
+
+impl From<Arm64RegId> for u64 {
+    fn from(id: Arm64RegId) -> u64 {
+        id.raw()
+    }
+}
+
+impl fmt::Display for Arm64RegId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_core_regs() {
+        assert_eq!(Arm64RegId::x(0).raw(), 0x6030_0000_0010_0000);
+        assert_eq!(Arm64RegId::x(30).raw(), 0x6030_0000_0010_003c);
+        assert_eq!(Arm64RegId::SP.raw(), 0x6030_0000_0010_003e);
+        assert_eq!(Arm64RegId::PC.raw(), 0x6030_0000_0010_0040);
+        assert_eq!(Arm64RegId::PSTATE.raw(), 0x6030_0000_0010_0042);
+        assert_eq!(Arm64RegId::SP_EL1.raw(), 0x6030_0000_0010_0044);
+        assert_eq!(Arm64RegId::ELR_EL1.raw(), 0x6030_0000_0010_0046);
+        assert_eq!(Arm64RegId::spsr(0).raw(), 0x6030_0000_0010_0048);
+        assert_eq!(Arm64RegId::v(0).raw(), 0x6040_0000_0010_0054);
+        assert_eq!(Arm64RegId::v(31).raw(), 0x6040_0000_0010_00d0);
+        assert_eq!(Arm64RegId::FPSR.raw(), 0x6020_0000_0010_00d4);
+        assert_eq!(Arm64RegId::FPCR.raw(), 0x6020_0000_0010_00d5);
+
+        assert_eq!(
+            Arm64RegId::core(offset_of!(kvm_regs, elr_el1), 8).unwrap(),
+            Arm64RegId::ELR_EL1
+        );
+        assert!(Arm64RegId::core(2, 8).is_err());
+        assert!(Arm64RegId::core(0, 2).is_err());
+        assert!(Arm64RegId::core(size_of::<kvm_regs>() - 4, 8).is_err());
+        assert_eq!(
+            Arm64RegId::core(usize::MAX - 3, 8),
+            Err(Error::InvalidCoreReg {
+                offset: usize::MAX - 3,
+                size: 8
+            })
+        );
+    }
+
+    #[test]
+    fn test_sys_regs() {
+        // MPIDR_EL1
+        let mpidr = Arm64RegId::sys_reg(3, 0, 0, 0, 5);
+        assert_eq!(mpidr.raw(), 0x6030_0000_0013_c005);
+        assert_eq!(
+            mpidr.decode().unwrap(),
+            Arm64Reg::SysReg {
+                op0: 3,
+                op1: 0,
+                crn: 0,
+                crm: 0,
+                op2: 5
+            }
+        );
+        assert_eq!(Arm64RegId::TIMER_CTL.raw(), 0x6030_0000_0013_df19);
+        assert_eq!(Arm64RegId::TIMER_CNT.raw(), 0x6030_0000_0013_df1a);
+        assert_eq!(Arm64RegId::TIMER_CVAL.raw(), 0x6030_0000_0013_df02);
+    }
+
+    #[test]
+    fn test_demux_and_firmware() {
+        assert_eq!(Arm64RegId::ccsidr(0).raw(), 0x6020_0000_0011_0000);
+        assert_eq!(Arm64RegId::ccsidr(3).decode().unwrap(), Arm64Reg::Ccsidr(3));
+        assert_eq!(Arm64RegId::PSCI_VERSION.raw(), 0x6030_0000_0014_0000);
+        assert_eq!(
+            Arm64RegId::firmware(1).decode().unwrap(),
+            Arm64Reg::Firmware(1)
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        let pc = Arm64RegId::from_raw(0x6030_0000_0010_0040).unwrap();
+        assert_eq!(pc, Arm64RegId::PC);
+        assert_eq!(pc.size(), 8);
+        assert_eq!(
+            pc.decode().unwrap(),
+            Arm64Reg::Core {
+                offset: offset_of!(kvm_regs, regs) + offset_of!(user_pt_regs, pc)
+            }
+        );
+        assert_eq!(Arm64RegId::v(1).size(), 16);
+        assert_eq!(Arm64RegId::FPCR.size(), 4);
+        assert_eq!(u64::from(Arm64RegId::SP), 0x6030_0000_0010_003e);
+
+        // A 32-bit arm register.
+        assert_eq!(
+            Arm64RegId::from_raw(0x4020_0000_0010_0000),
+            Err(Error::NotArm64(0x4020_0000_0010_0000))
+        );
+        // A demux register other than CCSIDR.
+        let id = Arm64RegId::from_raw(0x6020_0000_0011_0100).unwrap();
+        assert_eq!(id.decode(), Err(Error::UnknownGroup(id.raw())));
+        let id = Arm64RegId::from_raw(0x6030_0000_0015_0000).unwrap();
+        assert_eq!(id.decode(), Err(Error::UnknownGroup(id.raw())));
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::bindings_v4_14_0::*;
+use serialize::Pod;
+
+// Safe because the array is empty.
+unsafe impl<T> Pod for __IncompleteArrayField<T> {}
+
+serde_impls! {
+    struct __kernel_fd_set { fds_bits }
+    struct __kernel_fsid_t { val }
+    struct user_pt_regs { regs, sp, pc, pstate }
+    struct user_fpsimd_state { vregs, fpsr, fpcr, __reserved }
+    struct user_hwdebug_state { dbg_info, pad, dbg_regs }
+    struct user_hwdebug_state__bindgen_ty_1 { addr, ctrl, pad }
+    struct kvm_regs { regs, sp_el1, elr_el1, spsr, __bindgen_padding_0, fp_regs }
+    struct kvm_vcpu_init { target, features }
+    struct kvm_sregs {}
+    struct kvm_fpu {}
+    struct kvm_guest_debug_arch { dbg_bcr, dbg_bvr, dbg_wcr, dbg_wvr }
+    fields kvm_debug_exit_arch { hsr, far }
+    struct kvm_sync_regs { device_irq_level }
+    struct kvm_arch_memory_slot {}
+    struct kvm_user_trace_setup { buf_size, buf_nr }
+    struct kvm_breakpoint { enabled, padding, address }
+    fields kvm_debug_guest { enabled, pad, breakpoints, singlestep }
+    struct kvm_memory_region { slot, flags, guest_phys_addr, memory_size }
+    struct kvm_userspace_memory_region { slot, flags, guest_phys_addr, memory_size, userspace_addr }
+    fields kvm_irq_level { __bindgen_anon_1, level }
+    union kvm_irq_level__bindgen_ty_1 { irq, status }
+    fields kvm_irqchip { chip_id, pad, chip }
+    union kvm_irqchip__bindgen_ty_1 { dummy }
+    struct kvm_pit_config { flags, pad }
+    struct kvm_s390_skeys { start_gfn, count, skeydata_addr, flags, reserved }
+    fields kvm_s390_cmma_log { start_gfn, count, flags, __bindgen_anon_1, values }
+    union kvm_s390_cmma_log__bindgen_ty_1 { remaining, mask }
+    fields kvm_hyperv_exit {
+        type_,
+        u(type_) {
+            KVM_EXIT_HYPERV_SYNIC => synic,
+            KVM_EXIT_HYPERV_HCALL => hcall,
+        },
+    }
+    fields kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1 { msr, control, evt_page, msg_page }
+    struct kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2 { input, result, params }
+    fields kvm_run {
+        request_interrupt_window, immediate_exit, padding1, exit_reason,
+        ready_for_interrupt_injection, if_flag, flags, cr8, apic_base,
+        __bindgen_anon_1(exit_reason) {
+            KVM_EXIT_UNKNOWN => hw,
+            KVM_EXIT_FAIL_ENTRY => fail_entry,
+            KVM_EXIT_EXCEPTION => ex,
+            KVM_EXIT_IO => io,
+            KVM_EXIT_DEBUG => debug,
+            KVM_EXIT_MMIO => mmio,
+            KVM_EXIT_HYPERCALL => hypercall,
+            KVM_EXIT_TPR_ACCESS => tpr_access,
+            KVM_EXIT_S390_SIEIC => s390_sieic,
+            KVM_EXIT_S390_RESET => s390_reset_flags,
+            KVM_EXIT_S390_UCONTROL => s390_ucontrol,
+            KVM_EXIT_DCR => dcr,
+            KVM_EXIT_INTERNAL_ERROR => internal,
+            KVM_EXIT_OSI => osi,
+            KVM_EXIT_PAPR_HCALL => papr_hcall,
+            KVM_EXIT_S390_TSCH => s390_tsch,
+            KVM_EXIT_EPR => epr,
+            KVM_EXIT_SYSTEM_EVENT => system_event,
+            KVM_EXIT_S390_STSI => s390_stsi,
+            KVM_EXIT_IOAPIC_EOI => eoi,
+            KVM_EXIT_HYPERV => hyperv,
+        },
+        kvm_valid_regs, kvm_dirty_regs,
+        s(kvm_valid_regs) {
+            _ => regs,
+        },
+    }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_1 { hardware_exit_reason }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_2 { hardware_entry_failure_reason }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_3 { exception, error_code }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_4 { direction, size, port, count, data_offset }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_5 { arch }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_6 { phys_addr, data, len, is_write }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_7 { nr, args, ret, longmode, pad }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_8 { rip, is_write, pad }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_9 { icptcode, ipa, ipb }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_10 { trans_exc_code, pgm_code }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_11 { dcrn, data, is_write }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_12 { suberror, ndata, data }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_13 { gprs }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_14 { nr, ret, args }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_15 {
+        subchannel_id, subchannel_nr, io_int_parm, io_int_word, ipb, dequeued,
+    }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_16 { epr }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_17 { type_, flags }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_18 { addr, ar, reserved, fc, sel1, sel2 }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_19 { vector }
+    struct kvm_coalesced_mmio_zone { addr, size, pad }
+    struct kvm_coalesced_mmio { phys_addr, len, pad, data }
+    struct kvm_coalesced_mmio_ring { first, last, coalesced_mmio }
+    struct kvm_translation { linear_address, physical_address, valid, writeable, usermode, pad }
+    struct kvm_s390_mem_op { gaddr, flags, size, op, buf, ar, reserved }
+    struct kvm_interrupt { irq }
+    struct kvm_signal_mask { len, sigset }
+    struct kvm_tpr_access_ctl { enabled, flags, reserved }
+    struct kvm_vapic_addr { vapic_addr }
+    struct kvm_mp_state { mp_state }
+    struct kvm_s390_psw { mask, addr }
+    struct kvm_s390_interrupt { type_, parm, parm64 }
+    struct kvm_s390_io_info { subchannel_id, subchannel_nr, io_int_parm, io_int_word }
+    struct kvm_s390_ext_info { ext_params, pad, ext_params2 }
+    struct kvm_s390_pgm_info {
+        trans_exc_code, mon_code, per_address, data_exc_code, code, mon_class_nr, per_code,
+        per_atmid, exc_access_id, per_access_id, op_access_id, flags, pad,
+    }
+    struct kvm_s390_prefix_info { address }
+    struct kvm_s390_extcall_info { code }
+    struct kvm_s390_emerg_info { code }
+    struct kvm_s390_stop_info { flags }
+    struct kvm_s390_mchk_info {
+        cr14, mcic, failing_storage_address, ext_damage_code, pad, fixed_logout,
+    }
+    fields kvm_s390_irq {
+        type_,
+        u(type_ as u32) {
+            KVM_S390_INT_IO_MIN..=KVM_S390_INT_IO_MAX => io,
+            KVM_S390_INT_SERVICE => ext,
+            KVM_S390_INT_VIRTIO => ext,
+            KVM_S390_INT_PFAULT_INIT => ext,
+            KVM_S390_INT_PFAULT_DONE => ext,
+            KVM_S390_PROGRAM_INT => pgm,
+            KVM_S390_INT_EMERGENCY => emerg,
+            KVM_S390_INT_EXTERNAL_CALL => extcall,
+            KVM_S390_SIGP_SET_PREFIX => prefix,
+            KVM_S390_SIGP_STOP => stop,
+            KVM_S390_MCHK => mchk,
+        },
+    }
+    struct kvm_s390_irq_state { buf, flags, len, reserved }
+    struct kvm_guest_debug { control, pad, arch }
+    struct kvm_ioeventfd { datamatch, addr, len, fd, flags, pad }
+    struct kvm_enable_cap { cap, flags, args, pad }
+    struct kvm_ppc_pvinfo { flags, hcall, pad }
+    struct kvm_ppc_one_page_size { page_shift, pte_enc }
+    struct kvm_ppc_one_seg_page_size { page_shift, slb_enc, enc }
+    struct kvm_ppc_smmu_info { flags, slb_size, data_keys, instr_keys, sps }
+    struct kvm_ppc_resize_hpt { flags, shift, pad }
+    struct kvm_irq_routing_irqchip { irqchip, pin }
+    fields kvm_irq_routing_msi { address_lo, address_hi, data, __bindgen_anon_1 }
+    union kvm_irq_routing_msi__bindgen_ty_1 { pad, devid }
+    struct kvm_irq_routing_s390_adapter {
+        ind_addr, summary_addr, ind_offset, summary_offset, adapter_id,
+    }
+    struct kvm_irq_routing_hv_sint { vcpu, sint }
+    fields kvm_irq_routing_entry {
+        gsi, type_, flags, pad,
+        u(type_) {
+            KVM_IRQ_ROUTING_IRQCHIP => irqchip,
+            KVM_IRQ_ROUTING_MSI => msi,
+            KVM_IRQ_ROUTING_S390_ADAPTER => adapter,
+            KVM_IRQ_ROUTING_HV_SINT => hv_sint,
+        },
+    }
+    struct kvm_irq_routing { nr, flags, entries }
+    struct kvm_irqfd { fd, gsi, flags, resamplefd, pad }
+    struct kvm_clock_data { clock, flags, pad }
+    struct kvm_config_tlb { params, array, mmu_type, array_len }
+    fields kvm_dirty_tlb { bitmap, num_dirty }
+    struct kvm_reg_list { n, reg }
+    struct kvm_one_reg { id, addr }
+    struct kvm_msi { address_lo, address_hi, data, flags, devid, pad }
+    struct kvm_arm_device_addr { id, addr }
+    struct kvm_create_device { type_, fd, flags }
+    struct kvm_device_attr { flags, group, attr, addr }
+    struct kvm_vfio_spapr_tce { groupfd, tablefd }
+    struct kvm_s390_ucas_mapping { user_addr, vcpu_addr, length }
+    fields kvm_assigned_pci_dev { assigned_dev_id, busnr, devfn, flags, segnr, __bindgen_anon_1 }
+    union kvm_assigned_pci_dev__bindgen_ty_1 { reserved }
+    fields kvm_assigned_irq { assigned_dev_id, host_irq, guest_irq, flags, __bindgen_anon_1 }
+    union kvm_assigned_irq__bindgen_ty_1 { reserved }
+    struct kvm_assigned_msix_nr { assigned_dev_id, entry_nr, padding }
+    struct kvm_assigned_msix_entry { assigned_dev_id, gsi, entry, padding }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::v4_14_0::RegList;
+    use super::*;
+    use serialize::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip::<kvm_regs>();
+        assert_round_trip::<user_pt_regs>();
+        assert_round_trip::<kvm_mp_state>();
+        assert_round_trip::<kvm_one_reg>();
+    }
+
+    #[test]
+    fn test_tagged_round_trip() {
+        let mut run = kvm_run {
+            exit_reason: KVM_EXIT_MMIO,
+            ..Default::default()
+        };
+        run.__bindgen_anon_1.mmio.phys_addr = 0x0900_0000;
+        run.__bindgen_anon_1.mmio.len = 4;
+        let bin = ::bincode::serialize(&run).unwrap();
+        let from_bin: kvm_run = ::bincode::deserialize(&bin).unwrap();
+        assert_eq!(from_bin.exit_reason, KVM_EXIT_MMIO);
+        let mmio = unsafe { from_bin.__bindgen_anon_1.mmio };
+        assert_eq!((mmio.phys_addr, mmio.len), (0x0900_0000, 4));
+    }
+
+    #[test]
+    fn test_fam_round_trip() {
+        let reg_list = RegList::from_entries(&[0x6030_0000_0010_0000]).unwrap();
+        let json = ::serde_json::to_string(&reg_list).unwrap();
+        assert_eq!(::serde_json::from_str::<RegList>(&json).unwrap(), reg_list);
+    }
+}
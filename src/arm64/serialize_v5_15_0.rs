@@ -0,0 +1,271 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::bindings_v5_15_0::*;
+use serialize::Pod;
+
+// Safe because the array is empty.
+unsafe impl<T> Pod for __IncompleteArrayField<T> {}
+
+serde_impls! {
+    struct __kernel_fd_set { fds_bits }
+    struct __kernel_fsid_t { val }
+    struct user_pt_regs { regs, sp, pc, pstate }
+    struct user_fpsimd_state { vregs, fpsr, fpcr, __reserved }
+    struct user_hwdebug_state { dbg_info, pad, dbg_regs }
+    struct user_hwdebug_state__bindgen_ty_1 { addr, ctrl, pad }
+    struct user_sve_header { size, max_size, vl, max_vl, flags, __reserved }
+    struct user_pac_mask { data_mask, insn_mask }
+    struct user_pac_address_keys { apiakey, apibkey, apdakey, apdbkey }
+    struct user_pac_generic_keys { apgakey }
+    struct kvm_regs { regs, sp_el1, elr_el1, spsr, __bindgen_padding_0, fp_regs }
+    struct kvm_vcpu_init { target, features }
+    struct kvm_sregs {}
+    struct kvm_fpu {}
+    struct kvm_guest_debug_arch { dbg_bcr, dbg_bvr, dbg_wcr, dbg_wvr }
+    fields kvm_debug_exit_arch { hsr, far }
+    struct kvm_sync_regs { device_irq_level }
+    struct kvm_arch_memory_slot {}
+    struct kvm_vcpu_events { exception, reserved }
+    struct kvm_vcpu_events__bindgen_ty_1 {
+        serror_pending, serror_has_esr, ext_dabt_pending, pad, serror_esr,
+    }
+    struct kvm_pmu_event_filter { base_event, nevents, action, pad }
+    struct kvm_user_trace_setup { buf_size, buf_nr }
+    struct kvm_breakpoint { enabled, padding, address }
+    fields kvm_debug_guest { enabled, pad, breakpoints, singlestep }
+    struct kvm_memory_region { slot, flags, guest_phys_addr, memory_size }
+    struct kvm_userspace_memory_region { slot, flags, guest_phys_addr, memory_size, userspace_addr }
+    fields kvm_irq_level { __bindgen_anon_1, level }
+    union kvm_irq_level__bindgen_ty_1 { irq, status }
+    fields kvm_irqchip { chip_id, pad, chip }
+    union kvm_irqchip__bindgen_ty_1 { dummy }
+    struct kvm_pit_config { flags, pad }
+    struct kvm_s390_skeys { start_gfn, count, skeydata_addr, flags, reserved }
+    fields kvm_s390_cmma_log { start_gfn, count, flags, __bindgen_anon_1, values }
+    union kvm_s390_cmma_log__bindgen_ty_1 { remaining, mask }
+    fields kvm_hyperv_exit {
+        type_, pad1,
+        u(type_) {
+            KVM_EXIT_HYPERV_SYNIC => synic,
+            KVM_EXIT_HYPERV_HCALL => hcall,
+            KVM_EXIT_HYPERV_SYNDBG => syndbg,
+        },
+    }
+    struct kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1 { msr, pad2, control, evt_page, msg_page }
+    struct kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2 { input, result, params }
+    struct kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_3 {
+        msr, pad2, control, status, send_page, recv_page, pending_page,
+    }
+    fields kvm_xen_exit { type_, u }
+    union kvm_xen_exit__bindgen_ty_1 { hcall }
+    struct kvm_xen_exit__bindgen_ty_1__bindgen_ty_1 { longmode, cpl, input, result, params }
+    fields kvm_run {
+        request_interrupt_window, immediate_exit, padding1, exit_reason,
+        ready_for_interrupt_injection, if_flag, flags, cr8, apic_base,
+        __bindgen_anon_1(exit_reason) {
+            KVM_EXIT_UNKNOWN => hw,
+            KVM_EXIT_FAIL_ENTRY => fail_entry,
+            KVM_EXIT_EXCEPTION => ex,
+            KVM_EXIT_IO => io,
+            KVM_EXIT_DEBUG => debug,
+            KVM_EXIT_MMIO => mmio,
+            KVM_EXIT_HYPERCALL => hypercall,
+            KVM_EXIT_TPR_ACCESS => tpr_access,
+            KVM_EXIT_S390_SIEIC => s390_sieic,
+            KVM_EXIT_S390_RESET => s390_reset_flags,
+            KVM_EXIT_S390_UCONTROL => s390_ucontrol,
+            KVM_EXIT_DCR => dcr,
+            KVM_EXIT_INTERNAL_ERROR => internal,
+            KVM_EXIT_OSI => osi,
+            KVM_EXIT_PAPR_HCALL => papr_hcall,
+            KVM_EXIT_S390_TSCH => s390_tsch,
+            KVM_EXIT_EPR => epr,
+            KVM_EXIT_SYSTEM_EVENT => system_event,
+            KVM_EXIT_S390_STSI => s390_stsi,
+            KVM_EXIT_IOAPIC_EOI => eoi,
+            KVM_EXIT_HYPERV => hyperv,
+            KVM_EXIT_ARM_NISV => arm_nisv,
+            KVM_EXIT_X86_RDMSR => msr,
+            KVM_EXIT_X86_WRMSR => msr,
+            KVM_EXIT_XEN => xen,
+        },
+        kvm_valid_regs, kvm_dirty_regs,
+        s(kvm_valid_regs) {
+            _ => regs,
+        },
+    }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_1 { hardware_exit_reason }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_2 { hardware_entry_failure_reason, cpu }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_3 { exception, error_code }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_4 { direction, size, port, count, data_offset }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_5 { arch }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_6 { phys_addr, data, len, is_write }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_7 { nr, args, ret, longmode, pad }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_8 { rip, is_write, pad }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_9 { icptcode, ipa, ipb }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_10 { trans_exc_code, pgm_code }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_11 { dcrn, data, is_write }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_12 { suberror, ndata, data }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_13 { suberror, ndata, flags, __bindgen_anon_1 }
+    union kvm_run__bindgen_ty_1__bindgen_ty_13__bindgen_ty_1 { __bindgen_anon_1 }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_13__bindgen_ty_1__bindgen_ty_1 {
+        insn_size, insn_bytes,
+    }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_14 { gprs }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_15 { nr, ret, args }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_16 {
+        subchannel_id, subchannel_nr, io_int_parm, io_int_word, ipb, dequeued,
+    }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_17 { epr }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_18 { type_, flags }
+    fields kvm_run__bindgen_ty_1__bindgen_ty_19 { addr, ar, reserved, fc, sel1, sel2 }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_20 { vector }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_21 { esr_iss, fault_ipa }
+    struct kvm_run__bindgen_ty_1__bindgen_ty_22 { error, pad, reason, index, data }
+    fields kvm_coalesced_mmio_zone { addr, size, __bindgen_anon_1 }
+    union kvm_coalesced_mmio_zone__bindgen_ty_1 { pad, pio }
+    fields kvm_coalesced_mmio { phys_addr, len, __bindgen_anon_1, data }
+    union kvm_coalesced_mmio__bindgen_ty_1 { pad, pio }
+    struct kvm_coalesced_mmio_ring { first, last, coalesced_mmio }
+    struct kvm_translation { linear_address, physical_address, valid, writeable, usermode, pad }
+    fields kvm_s390_mem_op {
+        gaddr, flags, size, op, buf,
+        __bindgen_anon_1(op) {
+            KVM_S390_MEMOP_SIDA_READ => sida_offset,
+            KVM_S390_MEMOP_SIDA_WRITE => sida_offset,
+        },
+    }
+    struct kvm_interrupt { irq }
+    struct kvm_signal_mask { len, sigset }
+    struct kvm_tpr_access_ctl { enabled, flags, reserved }
+    struct kvm_vapic_addr { vapic_addr }
+    struct kvm_mp_state { mp_state }
+    struct kvm_s390_psw { mask, addr }
+    struct kvm_s390_interrupt { type_, parm, parm64 }
+    struct kvm_s390_io_info { subchannel_id, subchannel_nr, io_int_parm, io_int_word }
+    struct kvm_s390_ext_info { ext_params, pad, ext_params2 }
+    struct kvm_s390_pgm_info {
+        trans_exc_code, mon_code, per_address, data_exc_code, code, mon_class_nr, per_code,
+        per_atmid, exc_access_id, per_access_id, op_access_id, flags, pad,
+    }
+    struct kvm_s390_prefix_info { address }
+    struct kvm_s390_extcall_info { code }
+    struct kvm_s390_emerg_info { code }
+    struct kvm_s390_stop_info { flags }
+    struct kvm_s390_mchk_info {
+        cr14, mcic, failing_storage_address, ext_damage_code, pad, fixed_logout,
+    }
+    fields kvm_s390_irq {
+        type_,
+        u(type_ as u32) {
+            KVM_S390_INT_IO_MIN..=KVM_S390_INT_IO_MAX => io,
+            KVM_S390_INT_SERVICE => ext,
+            KVM_S390_INT_VIRTIO => ext,
+            KVM_S390_INT_PFAULT_INIT => ext,
+            KVM_S390_INT_PFAULT_DONE => ext,
+            KVM_S390_PROGRAM_INT => pgm,
+            KVM_S390_INT_EMERGENCY => emerg,
+            KVM_S390_INT_EXTERNAL_CALL => extcall,
+            KVM_S390_SIGP_SET_PREFIX => prefix,
+            KVM_S390_SIGP_STOP => stop,
+            KVM_S390_MCHK => mchk,
+        },
+    }
+    struct kvm_s390_irq_state { buf, flags, len, reserved }
+    struct kvm_guest_debug { control, pad, arch }
+    struct kvm_ioeventfd { datamatch, addr, len, fd, flags, pad }
+    struct kvm_enable_cap { cap, flags, args, pad }
+    struct kvm_ppc_pvinfo { flags, hcall, pad }
+    struct kvm_ppc_one_page_size { page_shift, pte_enc }
+    struct kvm_ppc_one_seg_page_size { page_shift, slb_enc, enc }
+    struct kvm_ppc_smmu_info { flags, slb_size, data_keys, instr_keys, sps }
+    struct kvm_ppc_resize_hpt { flags, shift, pad }
+    struct kvm_irq_routing_irqchip { irqchip, pin }
+    fields kvm_irq_routing_msi { address_lo, address_hi, data, __bindgen_anon_1 }
+    union kvm_irq_routing_msi__bindgen_ty_1 { pad, devid }
+    struct kvm_irq_routing_s390_adapter {
+        ind_addr, summary_addr, ind_offset, summary_offset, adapter_id,
+    }
+    struct kvm_irq_routing_hv_sint { vcpu, sint }
+    fields kvm_irq_routing_entry {
+        gsi, type_, flags, pad,
+        u(type_) {
+            KVM_IRQ_ROUTING_IRQCHIP => irqchip,
+            KVM_IRQ_ROUTING_MSI => msi,
+            KVM_IRQ_ROUTING_S390_ADAPTER => adapter,
+            KVM_IRQ_ROUTING_HV_SINT => hv_sint,
+        },
+    }
+    struct kvm_irq_routing { nr, flags, entries }
+    struct kvm_irqfd { fd, gsi, flags, resamplefd, pad }
+    struct kvm_clock_data { clock, flags, pad }
+    struct kvm_config_tlb { params, array, mmu_type, array_len }
+    fields kvm_dirty_tlb { bitmap, num_dirty }
+    struct kvm_reg_list { n, reg }
+    struct kvm_one_reg { id, addr }
+    struct kvm_msi { address_lo, address_hi, data, flags, devid, pad }
+    struct kvm_arm_device_addr { id, addr }
+    struct kvm_create_device { type_, fd, flags }
+    struct kvm_device_attr { flags, group, attr, addr }
+    struct kvm_vfio_spapr_tce { groupfd, tablefd }
+    struct kvm_s390_ucas_mapping { user_addr, vcpu_addr, length }
+    struct kvm_enc_region { addr, size }
+    struct kvm_s390_pv_sec_parm { origin, length }
+    struct kvm_s390_pv_unp { addr, size, tweak }
+    struct kvm_pv_cmd { cmd, rc, rrc, data, flags, reserved }
+    fields kvm_xen_hvm_attr {
+        type_, pad,
+        u(type_ as u32) {
+            KVM_XEN_ATTR_TYPE_LONG_MODE => long_mode,
+            KVM_XEN_ATTR_TYPE_SHARED_INFO => shared_info,
+            KVM_XEN_ATTR_TYPE_UPCALL_VECTOR => vector,
+        },
+    }
+    struct kvm_xen_hvm_attr__bindgen_ty_1__bindgen_ty_1 { gfn }
+    fields kvm_xen_vcpu_attr {
+        type_, pad,
+        u(type_ as u32) {
+            KVM_XEN_VCPU_ATTR_TYPE_VCPU_INFO => gpa,
+            KVM_XEN_VCPU_ATTR_TYPE_VCPU_TIME_INFO => gpa,
+            KVM_XEN_VCPU_ATTR_TYPE_RUNSTATE_ADDR => gpa,
+            KVM_XEN_VCPU_ATTR_TYPE_RUNSTATE_CURRENT => runstate,
+            KVM_XEN_VCPU_ATTR_TYPE_RUNSTATE_DATA => runstate,
+            KVM_XEN_VCPU_ATTR_TYPE_RUNSTATE_ADJUST => runstate,
+        },
+    }
+    struct kvm_xen_vcpu_attr__bindgen_ty_1__bindgen_ty_1 {
+        state, state_entry_time, time_running, time_runnable, time_blocked, time_offline,
+    }
+    fields kvm_sev_cmd { id, data, error, sev_fd }
+    fields kvm_sev_launch_start { handle, policy, dh_uaddr, dh_len, session_uaddr, session_len }
+    fields kvm_sev_launch_update_data { uaddr, len }
+    fields kvm_sev_launch_secret {
+        hdr_uaddr, hdr_len, guest_uaddr, guest_len, trans_uaddr, trans_len,
+    }
+    fields kvm_sev_launch_measure { uaddr, len }
+    struct kvm_sev_guest_status { handle, policy, state }
+    fields kvm_sev_dbg { src_uaddr, dst_uaddr, len }
+    fields kvm_sev_attestation_report { mnonce, uaddr, len }
+    fields kvm_sev_send_start {
+        policy, pdh_cert_uaddr, pdh_cert_len, plat_certs_uaddr, plat_certs_len, amd_certs_uaddr,
+        amd_certs_len, session_uaddr, session_len,
+    }
+    fields kvm_sev_send_update_data {
+        hdr_uaddr, hdr_len, guest_uaddr, guest_len, trans_uaddr, trans_len,
+    }
+    fields kvm_sev_receive_start { handle, policy, pdh_uaddr, pdh_len, session_uaddr, session_len }
+    fields kvm_sev_receive_update_data {
+        hdr_uaddr, hdr_len, guest_uaddr, guest_len, trans_uaddr, trans_len,
+    }
+    fields kvm_assigned_pci_dev { assigned_dev_id, busnr, devfn, flags, segnr, __bindgen_anon_1 }
+    union kvm_assigned_pci_dev__bindgen_ty_1 { reserved }
+    fields kvm_assigned_irq { assigned_dev_id, host_irq, guest_irq, flags, __bindgen_anon_1 }
+    union kvm_assigned_irq__bindgen_ty_1 { reserved }
+    struct kvm_assigned_msix_nr { assigned_dev_id, entry_nr, padding }
+    struct kvm_assigned_msix_entry { assigned_dev_id, gsi, entry, padding }
+    struct kvm_hyperv_eventfd { conn_id, fd, flags, padding }
+    struct kvm_dirty_gfn { flags, slot, offset }
+    struct kvm_stats_header { flags, name_size, num_desc, id_offset, desc_offset, data_offset }
+    struct kvm_stats_desc { flags, exponent, size, offset, bucket_size, name }
+}
@@ -0,0 +1,161 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Named AArch64 system registers and their KVM register IDs.
+//!
+//! [`SYS_REGS`](constant.SYS_REGS.html) maps the architectural names of the
+//! system registers exposed by KVM to their `KVM_REG_ARM64_SYSREG` IDs, so the
+//! IDs returned by `KVM_GET_REG_LIST` can be named and the other way around.
+
+use super::reg_id::Arm64RegId;
+
+/// A named system register.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SysReg {
+    /// The architectural name of the register, like `MPIDR_EL1`.
+    pub name: &'static str,
+    /// The KVM register ID.
+    pub id: Arm64RegId,
+    /// `false` if `KVM_SET_ONE_REG` rejects any value other than the one read
+    /// with `KVM_GET_ONE_REG`, like for the ID registers.
+    pub writable: bool,
+}
+
+impl SysReg {
+    /// Returns the register named `name`, ignoring the case.
+    pub fn by_name(name: &str) -> Option<&'static SysReg> {
+        SYS_REGS.iter().find(|r| r.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the register with the KVM register ID `id`.
+    pub fn by_id(id: Arm64RegId) -> Option<&'static SysReg> {
+        SYS_REGS.iter().find(|r| r.id == id)
+    }
+}
+
+macro_rules! sys_regs {
+    ($($name:ident = $id:expr, $writable:expr;)*) => {
+        $(
+            #[doc = concat!("The KVM register ID of `", stringify!($name), "`.")]
+            pub const $name: Arm64RegId = $id;
+        )*
+
+        /// The named system registers.
+        pub const SYS_REGS: &[SysReg] = &[
+            $(SysReg { name: stringify!($name), id: $name, writable: $writable },)*
+        ];
+    };
+}
+
+sys_regs! {
+    MDCCINT_EL1 = Arm64RegId::sys_reg(2, 0, 0, 2, 0), true;
+    MDSCR_EL1 = Arm64RegId::sys_reg(2, 0, 0, 2, 2), true;
+
+    MIDR_EL1 = Arm64RegId::sys_reg(3, 0, 0, 0, 0), false;
+    MPIDR_EL1 = Arm64RegId::sys_reg(3, 0, 0, 0, 5), true;
+    REVIDR_EL1 = Arm64RegId::sys_reg(3, 0, 0, 0, 6), false;
+    ID_PFR0_EL1 = Arm64RegId::sys_reg(3, 0, 0, 1, 0), false;
+    ID_PFR1_EL1 = Arm64RegId::sys_reg(3, 0, 0, 1, 1), false;
+    ID_DFR0_EL1 = Arm64RegId::sys_reg(3, 0, 0, 1, 2), false;
+    ID_AFR0_EL1 = Arm64RegId::sys_reg(3, 0, 0, 1, 3), false;
+    ID_MMFR0_EL1 = Arm64RegId::sys_reg(3, 0, 0, 1, 4), false;
+    ID_MMFR1_EL1 = Arm64RegId::sys_reg(3, 0, 0, 1, 5), false;
+    ID_MMFR2_EL1 = Arm64RegId::sys_reg(3, 0, 0, 1, 6), false;
+    ID_MMFR3_EL1 = Arm64RegId::sys_reg(3, 0, 0, 1, 7), false;
+    ID_ISAR0_EL1 = Arm64RegId::sys_reg(3, 0, 0, 2, 0), false;
+    ID_ISAR1_EL1 = Arm64RegId::sys_reg(3, 0, 0, 2, 1), false;
+    ID_ISAR2_EL1 = Arm64RegId::sys_reg(3, 0, 0, 2, 2), false;
+    ID_ISAR3_EL1 = Arm64RegId::sys_reg(3, 0, 0, 2, 3), false;
+    ID_ISAR4_EL1 = Arm64RegId::sys_reg(3, 0, 0, 2, 4), false;
+    ID_ISAR5_EL1 = Arm64RegId::sys_reg(3, 0, 0, 2, 5), false;
+    ID_MMFR4_EL1 = Arm64RegId::sys_reg(3, 0, 0, 2, 6), false;
+    MVFR0_EL1 = Arm64RegId::sys_reg(3, 0, 0, 3, 0), false;
+    MVFR1_EL1 = Arm64RegId::sys_reg(3, 0, 0, 3, 1), false;
+    MVFR2_EL1 = Arm64RegId::sys_reg(3, 0, 0, 3, 2), false;
+    ID_AA64PFR0_EL1 = Arm64RegId::sys_reg(3, 0, 0, 4, 0), false;
+    ID_AA64PFR1_EL1 = Arm64RegId::sys_reg(3, 0, 0, 4, 1), false;
+    ID_AA64ZFR0_EL1 = Arm64RegId::sys_reg(3, 0, 0, 4, 4), false;
+    ID_AA64DFR0_EL1 = Arm64RegId::sys_reg(3, 0, 0, 5, 0), false;
+    ID_AA64DFR1_EL1 = Arm64RegId::sys_reg(3, 0, 0, 5, 1), false;
+    ID_AA64AFR0_EL1 = Arm64RegId::sys_reg(3, 0, 0, 5, 4), false;
+    ID_AA64AFR1_EL1 = Arm64RegId::sys_reg(3, 0, 0, 5, 5), false;
+    ID_AA64ISAR0_EL1 = Arm64RegId::sys_reg(3, 0, 0, 6, 0), false;
+    ID_AA64ISAR1_EL1 = Arm64RegId::sys_reg(3, 0, 0, 6, 1), false;
+    ID_AA64MMFR0_EL1 = Arm64RegId::sys_reg(3, 0, 0, 7, 0), false;
+    ID_AA64MMFR1_EL1 = Arm64RegId::sys_reg(3, 0, 0, 7, 1), false;
+    ID_AA64MMFR2_EL1 = Arm64RegId::sys_reg(3, 0, 0, 7, 2), false;
+    SCTLR_EL1 = Arm64RegId::sys_reg(3, 0, 1, 0, 0), true;
+    ACTLR_EL1 = Arm64RegId::sys_reg(3, 0, 1, 0, 1), true;
+    CPACR_EL1 = Arm64RegId::sys_reg(3, 0, 1, 0, 2), true;
+    TTBR0_EL1 = Arm64RegId::sys_reg(3, 0, 2, 0, 0), true;
+    TTBR1_EL1 = Arm64RegId::sys_reg(3, 0, 2, 0, 1), true;
+    TCR_EL1 = Arm64RegId::sys_reg(3, 0, 2, 0, 2), true;
+    AFSR0_EL1 = Arm64RegId::sys_reg(3, 0, 5, 1, 0), true;
+    AFSR1_EL1 = Arm64RegId::sys_reg(3, 0, 5, 1, 1), true;
+    ESR_EL1 = Arm64RegId::sys_reg(3, 0, 5, 2, 0), true;
+    FAR_EL1 = Arm64RegId::sys_reg(3, 0, 6, 0, 0), true;
+    PAR_EL1 = Arm64RegId::sys_reg(3, 0, 7, 4, 0), true;
+    MAIR_EL1 = Arm64RegId::sys_reg(3, 0, 10, 2, 0), true;
+    AMAIR_EL1 = Arm64RegId::sys_reg(3, 0, 10, 3, 0), true;
+    VBAR_EL1 = Arm64RegId::sys_reg(3, 0, 12, 0, 0), true;
+    CONTEXTIDR_EL1 = Arm64RegId::sys_reg(3, 0, 13, 0, 1), true;
+    TPIDR_EL1 = Arm64RegId::sys_reg(3, 0, 13, 0, 4), true;
+    CNTKCTL_EL1 = Arm64RegId::sys_reg(3, 0, 14, 1, 0), true;
+    CLIDR_EL1 = Arm64RegId::sys_reg(3, 1, 0, 0, 1), false;
+    CSSELR_EL1 = Arm64RegId::sys_reg(3, 2, 0, 0, 0), true;
+    CTR_EL0 = Arm64RegId::sys_reg(3, 3, 0, 0, 1), false;
+    TPIDR_EL0 = Arm64RegId::sys_reg(3, 3, 13, 0, 2), true;
+    TPIDRRO_EL0 = Arm64RegId::sys_reg(3, 3, 13, 0, 3), true;
+    // KVM swaps the encodings of the virtual counter and compare value.
+    CNTVCT_EL0 = Arm64RegId::TIMER_CNT, true;
+    CNTV_CTL_EL0 = Arm64RegId::TIMER_CTL, true;
+    CNTV_CVAL_EL0 = Arm64RegId::TIMER_CVAL, true;
+    DACR32_EL2 = Arm64RegId::sys_reg(3, 4, 3, 0, 0), true;
+    IFSR32_EL2 = Arm64RegId::sys_reg(3, 4, 5, 0, 1), true;
+    FPEXC32_EL2 = Arm64RegId::sys_reg(3, 4, 5, 3, 0), true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::reg_id::Arm64Reg;
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(MPIDR_EL1.raw(), 0x6030_0000_0013_c005);
+        assert_eq!(MIDR_EL1.raw(), 0x6030_0000_0013_c000);
+        assert_eq!(CNTV_CTL_EL0, Arm64RegId::TIMER_CTL);
+
+        let mpidr = SysReg::by_name("mpidr_el1").unwrap();
+        assert_eq!(mpidr.id, MPIDR_EL1);
+        assert!(mpidr.writable);
+        assert!(!SysReg::by_name("ID_AA64PFR0_EL1").unwrap().writable);
+        assert!(SysReg::by_name("MPIDR_EL2").is_none());
+
+        let id = Arm64RegId::from_raw(0x6030_0000_0013_c080).unwrap();
+        assert_eq!(SysReg::by_id(id).unwrap().name, "SCTLR_EL1");
+        assert_eq!(
+            SysReg::by_id(Arm64RegId::TIMER_CNT).unwrap().name,
+            "CNTVCT_EL0"
+        );
+        assert!(SysReg::by_id(Arm64RegId::PC).is_none());
+    }
+
+    #[test]
+    fn test_catalogue() {
+        for (i, reg) in SYS_REGS.iter().enumerate() {
+            assert_eq!(reg.id.size(), 8, "{}", reg.name);
+            match reg.id.decode().unwrap() {
+                Arm64Reg::SysReg { .. } => (),
+                other => panic!("{} is decoded as {:?}", reg.name, other),
+            }
+            assert!(
+                SYS_REGS[i + 1..]
+                    .iter()
+                    .all(|r| r.name != reg.name && r.id != reg.id),
+                "{} is duplicated",
+                reg.name
+            );
+        }
+    }
+}
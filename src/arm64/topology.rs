@@ -0,0 +1,316 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! vcpu topology, `MPIDR_EL1` values and GICv3 redistributor layout.
+//!
+//! The GICv3 addresses each vcpu by the affinity fields of its `MPIDR_EL1`,
+//! and gives it a redistributor in the order of the vcpu indexes.
+//! [`CpuTopology`](struct.CpuTopology.html) computes consistent `MPIDR_EL1`
+//! values and [`redist_regions`](fn.redist_regions.html) places the
+//! redistributors.
+
+use std::fmt;
+
+use super::sysregs::MPIDR_EL1;
+use super::vgic::{self, redist_region};
+use one_reg::OneRegValue;
+
+/// The size of the redistributor of a vcpu: the `RD_base` and `SGI_base`
+/// frames, 64KiB each.
+pub const GICV3_REDIST_SIZE: u64 = 0x2_0000;
+
+// MPIDR_EL1 fields.
+const MPIDR_RES1: u64 = 1 << 31;
+const MPIDR_MT: u64 = 1 << 24;
+const MPIDR_AFF3_SHIFT: u64 = 32;
+
+// An SGI targets up to 16 vcpus of the same Aff3.Aff2.Aff1 with the target
+// list of ICC_SGI1R_EL1, so KVM limits Aff0 to 0-15.
+const MAX_AFF0: u64 = 15;
+const MAX_AFF: u64 = 0xff;
+
+// The redistributor count field of KVM_VGIC_V3_ADDR_TYPE_REDIST_REGION.
+const MAX_REGION_COUNT: u64 = 0xfff;
+
+/// Errors associated with the vcpu topology.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// A level of the topology does not fit in its affinity field.
+    AffinityOverflow,
+    /// The vcpu index is past the vcpus of the topology.
+    InvalidVcpu(u64),
+    /// The redistributor regions cannot hold all the vcpus.
+    NotEnoughRedists { vcpus: u64, capacity: u64 },
+    /// A redistributor region cannot be encoded.
+    InvalidRedistRegion(vgic::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::AffinityOverflow => write!(f, "the topology does not fit in MPIDR_EL1"),
+            Error::InvalidVcpu(index) => write!(f, "no vcpu {} in the topology", index),
+            Error::NotEnoughRedists { vcpus, capacity } => write!(
+                f,
+                "{} vcpus need more than the {} redistributors of the regions",
+                vcpus, capacity
+            ),
+            Error::InvalidRedistRegion(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+impl From<vgic::Error> for Error {
+    fn from(e: vgic::Error) -> Self {
+        Error::InvalidRedistRegion(e)
+    }
+}
+
+/// The position of a vcpu in the topology.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CpuLocation {
+    /// The socket index.
+    pub socket: u64,
+    /// The cluster index in the socket.
+    pub cluster: u64,
+    /// The core index in the cluster.
+    pub core: u64,
+    /// The thread index in the core.
+    pub thread: u64,
+}
+
+/// A symmetric vcpu topology.
+///
+/// Without SMT the cores are in Aff0, the clusters in Aff1 and the sockets in
+/// Aff2. With SMT, `MPIDR_EL1.MT` is set and each level moves up one affinity
+/// field, the threads taking Aff0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CpuTopology {
+    sockets: u64,
+    clusters: u64,
+    cores: u64,
+    threads: u64,
+}
+
+impl CpuTopology {
+    /// Builds a topology of `sockets` with `clusters` of `cores` running
+    /// `threads` each.
+    pub fn new(sockets: u64, clusters: u64, cores: u64, threads: u64) -> Result<Self, Error> {
+        let fits = |count: u64, max: u64| count > 0 && count - 1 <= max;
+        let fit = if threads > 1 {
+            fits(threads, MAX_AFF0)
+                && fits(cores, MAX_AFF)
+                && fits(clusters, MAX_AFF)
+                && fits(sockets, MAX_AFF)
+        } else {
+            threads == 1
+                && fits(cores, MAX_AFF0)
+                && fits(clusters, MAX_AFF)
+                && fits(sockets, MAX_AFF)
+        };
+        if !fit {
+            return Err(Error::AffinityOverflow);
+        }
+        Ok(CpuTopology {
+            sockets,
+            clusters,
+            cores,
+            threads,
+        })
+    }
+
+    /// Returns the number of vcpus of the topology.
+    pub fn vcpus(&self) -> u64 {
+        self.sockets * self.clusters * self.cores * self.threads
+    }
+
+    /// Returns the position of the vcpu `index`, the vcpus being numbered
+    /// thread first, then core, cluster and socket.
+    pub fn location(&self, index: u64) -> Result<CpuLocation, Error> {
+        if index >= self.vcpus() {
+            return Err(Error::InvalidVcpu(index));
+        }
+        Ok(CpuLocation {
+            thread: index % self.threads,
+            core: index / self.threads % self.cores,
+            cluster: index / (self.threads * self.cores) % self.clusters,
+            socket: index / (self.threads * self.cores * self.clusters),
+        })
+    }
+
+    /// Returns the `MPIDR_EL1` value of the vcpu at `location`.
+    pub fn mpidr(&self, location: CpuLocation) -> Result<u64, Error> {
+        let CpuLocation {
+            socket,
+            cluster,
+            core,
+            thread,
+        } = location;
+        if socket >= self.sockets
+            || cluster >= self.clusters
+            || core >= self.cores
+            || thread >= self.threads
+        {
+            return Err(Error::AffinityOverflow);
+        }
+        Ok(if self.threads > 1 {
+            MPIDR_RES1
+                | MPIDR_MT
+                | (socket << MPIDR_AFF3_SHIFT)
+                | (cluster << 16)
+                | (core << 8)
+                | thread
+        } else {
+            MPIDR_RES1 | (socket << 16) | (cluster << 8) | core
+        })
+    }
+
+    /// Returns the `MPIDR_EL1` value of the vcpu `index`.
+    pub fn vcpu_mpidr(&self, index: u64) -> Result<u64, Error> {
+        self.mpidr(self.location(index)?)
+    }
+
+    /// Returns the `MPIDR_EL1` register value to write with `KVM_SET_ONE_REG`
+    /// to the vcpu `index`.
+    pub fn mpidr_reg(&self, index: u64) -> Result<OneRegValue, Error> {
+        let mpidr = self.vcpu_mpidr(index)?;
+        Ok(OneRegValue::from_u64(MPIDR_EL1, mpidr).expect("MPIDR_EL1 is 64-bit wide"))
+    }
+}
+
+/// Returns the size of the redistributors of `vcpus` vcpus.
+pub fn redist_size(vcpus: u64) -> u64 {
+    vcpus * GICV3_REDIST_SIZE
+}
+
+/// Returns the address of the redistributor of the vcpu `index` in a
+/// contiguous region at `base`, as set with `KVM_VGIC_V3_ADDR_TYPE_REDIST`.
+pub fn redist_addr(base: u64, index: u64) -> u64 {
+    base + index * GICV3_REDIST_SIZE
+}
+
+/// Spreads the redistributors of `vcpus` vcpus over the `(base, size)`
+/// address ranges, in order, and returns the
+/// `KVM_VGIC_V3_ADDR_TYPE_REDIST_REGION` value of each region used.
+///
+/// The bases of the ranges must be 64KiB aligned.
+pub fn redist_regions(ranges: &[(u64, u64)], vcpus: u64) -> Result<Vec<u64>, Error> {
+    let mut regions = Vec::new();
+    let mut left = vcpus;
+    let mut capacity = 0;
+    for &(base, size) in ranges {
+        let count = (size / GICV3_REDIST_SIZE).min(MAX_REGION_COUNT);
+        capacity += count;
+        if left == 0 || count == 0 {
+            continue;
+        }
+        let used = left.min(count);
+        // KVM wants the region indexes to follow each other from 0.
+        let index = regions.len() as u64;
+        regions.push(redist_region(base, used, index)?);
+        left -= used;
+    }
+    if left > 0 {
+        return Err(Error::NotEnoughRedists { vcpus, capacity });
+    }
+    Ok(regions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topology() {
+        let topology = CpuTopology::new(2, 2, 4, 1).unwrap();
+        assert_eq!(topology.vcpus(), 16);
+        assert_eq!(topology.vcpu_mpidr(0), Ok(0x8000_0000));
+        assert_eq!(topology.vcpu_mpidr(5), Ok(0x8000_0101));
+        assert_eq!(topology.vcpu_mpidr(15), Ok(0x8001_0103));
+        assert_eq!(
+            topology.location(13),
+            Ok(CpuLocation {
+                socket: 1,
+                cluster: 1,
+                core: 1,
+                thread: 0
+            })
+        );
+        assert_eq!(topology.vcpu_mpidr(16), Err(Error::InvalidVcpu(16)));
+
+        let smt = CpuTopology::new(2, 1, 2, 2).unwrap();
+        assert_eq!(smt.vcpu_mpidr(1), Ok(0x8100_0001));
+        assert_eq!(smt.vcpu_mpidr(7), Ok(0x1_8100_0101));
+        assert_eq!(
+            smt.mpidr(CpuLocation {
+                thread: 2,
+                ..Default::default()
+            }),
+            Err(Error::AffinityOverflow)
+        );
+
+        assert!(CpuTopology::new(1, 1, 16, 1).is_ok());
+        assert_eq!(CpuTopology::new(1, 1, 17, 1), Err(Error::AffinityOverflow));
+        assert_eq!(CpuTopology::new(1, 0, 1, 1), Err(Error::AffinityOverflow));
+        assert_eq!(CpuTopology::new(1, 1, 1, 0), Err(Error::AffinityOverflow));
+    }
+
+    #[test]
+    fn test_mpidr_reg() {
+        let topology = CpuTopology::new(1, 2, 2, 1).unwrap();
+        let reg = topology.mpidr_reg(3).unwrap();
+        assert_eq!(reg.id(), 0x6030_0000_0013_c005);
+        assert_eq!(reg.to_u64(), Ok(0x8000_0101));
+        assert!(topology.mpidr_reg(4).is_err());
+    }
+
+    #[test]
+    fn test_redists() {
+        assert_eq!(redist_size(4), 0x8_0000);
+        assert_eq!(redist_addr(0x080a_0000, 2), 0x080e_0000);
+
+        // 2 redistributors fit in the first range, the third goes to the next.
+        let ranges = [(0x080a_0000, 0x4_0000), (0x1_0000_0000, 0x10_0000)];
+        assert_eq!(
+            redist_regions(&ranges, 3),
+            Ok(vec![
+                redist_region(0x080a_0000, 2, 0).unwrap(),
+                redist_region(0x1_0000_0000, 1, 1).unwrap()
+            ])
+        );
+        assert_eq!(
+            redist_regions(&ranges, 2),
+            Ok(vec![redist_region(0x080a_0000, 2, 0).unwrap()])
+        );
+        let ranges = [(0x080a_0000, 0x1_0000), (0x1_0000_0000, 0x4_0000)];
+        assert_eq!(
+            redist_regions(&ranges, 2),
+            Ok(vec![redist_region(0x1_0000_0000, 2, 0).unwrap()])
+        );
+        assert_eq!(
+            redist_regions(&ranges, 3),
+            Err(Error::NotEnoughRedists {
+                vcpus: 3,
+                capacity: 2
+            })
+        );
+        let ranges = [(0x080a_0000, 0x4_0000), (0x1_0000_0000, 0x10_0000)];
+        assert_eq!(
+            redist_regions(&ranges, 11),
+            Err(Error::NotEnoughRedists {
+                vcpus: 11,
+                capacity: 10
+            })
+        );
+        // An unaligned base is reported, not masked.
+        let ranges = [(0x080a_8000, 0x4_0000)];
+        assert_eq!(
+            redist_regions(&ranges, 1),
+            Err(Error::InvalidRedistRegion(vgic::Error::InvalidRedistBase(
+                0x080a_8000
+            )))
+        );
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Virtual SError injection with `KVM_SET_VCPU_EVENTS`.
+//!
+//! On arm64, `kvm_vcpu_events` only carries the pending virtual SError of the
+//! vcpu. [`VcpuEventsBuilder`](struct.VcpuEventsBuilder.html) builds it for
+//! injection and [`SError`](struct.SError.html) decodes the events read with
+//! `KVM_GET_VCPU_EVENTS`, for instance while migrating.
+
+use std::fmt;
+
+use super::bindings::kvm_vcpu_events;
+
+/// The mask of the ISS field of `ESR_ELx`, the only part of the syndrome the
+/// VMM can choose.
+pub const ESR_ISS_MASK: u64 = (1 << 25) - 1;
+
+/// Errors associated with the arm64 vcpu events.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The syndrome has bits set outside of the ISS field.
+    InvalidEsr(u64),
+    /// The padding or the reserved fields are not zero.
+    ReservedFieldsSet,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::InvalidEsr(esr) => write!(f, "SError syndrome {:#x} is wider than ISS", esr),
+            Error::ReservedFieldsSet => write!(f, "reserved vcpu events fields are set"),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// A pending virtual SError.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SError {
+    /// The ISS of the syndrome, or `None` for an implementation defined one.
+    pub esr: Option<u64>,
+}
+
+impl SError {
+    /// Decodes the pending SError of `events`, `None` if there is none.
+    pub fn from_events(events: &kvm_vcpu_events) -> Result<Option<SError>, Error> {
+        let exception = &events.exception;
+        if exception.pad.iter().any(|&b| b != 0) || events.reserved.iter().any(|&w| w != 0) {
+            return Err(Error::ReservedFieldsSet);
+        }
+        if exception.serror_pending == 0 {
+            return Ok(None);
+        }
+        let esr = if exception.serror_has_esr != 0 {
+            Some(check_esr(exception.serror_esr)?)
+        } else {
+            None
+        };
+        Ok(Some(SError { esr }))
+    }
+}
+
+fn check_esr(esr: u64) -> Result<u64, Error> {
+    if esr & !ESR_ISS_MASK != 0 {
+        return Err(Error::InvalidEsr(esr));
+    }
+    Ok(esr)
+}
+
+/// A builder for the `kvm_vcpu_events` passed to `KVM_SET_VCPU_EVENTS`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct VcpuEventsBuilder {
+    events: kvm_vcpu_events,
+}
+
+impl VcpuEventsBuilder {
+    /// Starts a `kvm_vcpu_events` without pending SError.
+    pub fn new() -> Self {
+        VcpuEventsBuilder::default()
+    }
+
+    /// Makes a virtual SError with an implementation defined syndrome pending.
+    pub fn serror(mut self) -> Self {
+        self.events.exception.serror_pending = 1;
+        self.events.exception.serror_has_esr = 0;
+        self.events.exception.serror_esr = 0;
+        self
+    }
+
+    /// Makes a virtual SError with the syndrome `esr` pending.
+    ///
+    /// Only the ISS can be set. The host needs the RAS extension, reported by
+    /// `KVM_CAP_ARM_INJECT_SERROR_ESR`.
+    pub fn serror_with_esr(mut self, esr: u64) -> Result<Self, Error> {
+        self.events.exception.serror_pending = 1;
+        self.events.exception.serror_has_esr = 1;
+        self.events.exception.serror_esr = check_esr(esr)?;
+        Ok(self)
+    }
+
+    /// Returns the `kvm_vcpu_events`.
+    pub fn build(&self) -> kvm_vcpu_events {
+        self.events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder() {
+        let events = VcpuEventsBuilder::new().build();
+        assert_eq!(events, kvm_vcpu_events::default());
+        assert_eq!(SError::from_events(&events), Ok(None));
+
+        let events = VcpuEventsBuilder::new().serror().build();
+        assert_eq!(events.exception.serror_pending, 1);
+        assert_eq!(events.exception.serror_has_esr, 0);
+        assert_eq!(SError::from_events(&events), Ok(Some(SError { esr: None })));
+
+        // An uncontainable SError: ISS.AET is UC and ISS.DFSC is 0b010001.
+        let events = VcpuEventsBuilder::new()
+            .serror_with_esr(0x11)
+            .unwrap()
+            .build();
+        assert_eq!(events.exception.serror_has_esr, 1);
+        assert_eq!(events.exception.serror_esr, 0x11);
+        assert_eq!(
+            SError::from_events(&events),
+            Ok(Some(SError { esr: Some(0x11) }))
+        );
+
+        assert_eq!(
+            VcpuEventsBuilder::new().serror_with_esr(0x200_0000),
+            Err(Error::InvalidEsr(0x200_0000))
+        );
+        assert!(VcpuEventsBuilder::new()
+            .serror_with_esr(ESR_ISS_MASK)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_decode() {
+        let mut events = VcpuEventsBuilder::new().serror().build();
+        events.reserved[3] = 1;
+        assert_eq!(SError::from_events(&events), Err(Error::ReservedFieldsSet));
+
+        let mut events = VcpuEventsBuilder::new().build();
+        events.exception.pad[0] = 1;
+        assert_eq!(SError::from_events(&events), Err(Error::ReservedFieldsSet));
+
+        let mut events = VcpuEventsBuilder::new().serror().build();
+        events.exception.serror_has_esr = 1;
+        events.exception.serror_esr = 0x9600_0000;
+        assert_eq!(
+            SError::from_events(&events),
+            Err(Error::InvalidEsr(0x9600_0000))
+        );
+    }
+}
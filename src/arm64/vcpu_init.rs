@@ -0,0 +1,499 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Building the `kvm_vcpu_init` passed to `KVM_ARM_VCPU_INIT`.
+//!
+//! `kvm_vcpu_init::features` is a bitmap indexed by the `KVM_ARM_VCPU_*`
+//! feature numbers. [`VcpuInitBuilder`](struct.VcpuInitBuilder.html) sets the
+//! features by name, checks them against the kernel version of the bindings
+//! and [`feature_names`](fn.feature_names.html) renders a bitmap for logging.
+
+use std::fmt;
+
+use super::bindings::*;
+use kernel_abi::BindingsVersion;
+
+const FEATURE_WORDS: usize = 7;
+const FEATURE_BITS: u32 = 32 * FEATURE_WORDS as u32;
+
+// Feature numbers missing from some of the bindings versions.
+const KVM_ARM_VCPU_SVE: u32 = 4;
+const KVM_ARM_VCPU_PTRAUTH_ADDRESS: u32 = 5;
+const KVM_ARM_VCPU_PTRAUTH_GENERIC: u32 = 6;
+const KVM_ARM_VCPU_HAS_EL2: u32 = 7;
+
+// The pairs of features a vcpu cannot have together: KVM rejects an AArch32
+// EL1 with SVE, pointer authentication or nested virtualization.
+const INCOMPATIBLE_FEATURES: [(u32, u32); 4] = [
+    (KVM_ARM_VCPU_EL1_32BIT, KVM_ARM_VCPU_SVE),
+    (KVM_ARM_VCPU_EL1_32BIT, KVM_ARM_VCPU_PTRAUTH_ADDRESS),
+    (KVM_ARM_VCPU_EL1_32BIT, KVM_ARM_VCPU_PTRAUTH_GENERIC),
+    (KVM_ARM_VCPU_EL1_32BIT, KVM_ARM_VCPU_HAS_EL2),
+];
+
+/// Errors associated with building a `kvm_vcpu_init`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// No feature has this name.
+    UnknownFeature(String),
+    /// The feature number is not known to the kernel version of the bindings.
+    UnsupportedFeature {
+        feature: u32,
+        version: BindingsVersion,
+    },
+    /// The target is not one of the `KVM_ARM_TARGET_*` values.
+    InvalidTarget(u32),
+    /// The two feature numbers cannot be set together.
+    IncompatibleFeatures(u32, u32),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::UnknownFeature(ref name) => write!(f, "unknown vcpu feature {}", name),
+            Error::UnsupportedFeature { feature, version } => write!(
+                f,
+                "vcpu feature {} is not supported by the {} bindings",
+                feature, version
+            ),
+            Error::InvalidTarget(target) => write!(f, "invalid vcpu target {}", target),
+            Error::IncompatibleFeatures(first, second) => write!(
+                f,
+                "vcpu features {} and {} cannot be set together",
+                first, second
+            ),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// A vcpu feature known to the bindings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VcpuFeature {
+    /// `KVM_ARM_VCPU_POWER_OFF`: the vcpu starts powered off.
+    PowerOff,
+    /// `KVM_ARM_VCPU_EL1_32BIT`: the vcpu runs EL1 in AArch32 state.
+    El1_32Bit,
+    /// `KVM_ARM_VCPU_PSCI_0_2`: the vcpu uses PSCI v0.2 instead of v0.1.
+    Psci0_2,
+    /// `KVM_ARM_VCPU_PMU_V3`: the vcpu has a PMUv3.
+    PmuV3,
+    /// `KVM_ARM_VCPU_SVE`: the vcpu has SVE, added in 5.2.
+    #[cfg(any(
+        feature = "kvm-v5_4_0",
+        feature = "kvm-v5_10_0",
+        feature = "kvm-v5_15_0",
+        feature = "kvm-v6_1_0",
+        feature = "kvm-v6_6_0",
+        not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+    ))]
+    Sve,
+    /// `KVM_ARM_VCPU_PTRAUTH_ADDRESS`: the vcpu uses address authentication,
+    /// added in 5.1.
+    #[cfg(any(
+        feature = "kvm-v5_4_0",
+        feature = "kvm-v5_10_0",
+        feature = "kvm-v5_15_0",
+        feature = "kvm-v6_1_0",
+        feature = "kvm-v6_6_0",
+        not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+    ))]
+    PtrauthAddress,
+    /// `KVM_ARM_VCPU_PTRAUTH_GENERIC`: the vcpu uses generic authentication,
+    /// added in 5.1.
+    #[cfg(any(
+        feature = "kvm-v5_4_0",
+        feature = "kvm-v5_10_0",
+        feature = "kvm-v5_15_0",
+        feature = "kvm-v6_1_0",
+        feature = "kvm-v6_6_0",
+        not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+    ))]
+    PtrauthGeneric,
+}
+
+impl VcpuFeature {
+    /// All the features known to the bindings.
+    #[cfg(not(any(
+        feature = "kvm-v5_4_0",
+        feature = "kvm-v5_10_0",
+        feature = "kvm-v5_15_0",
+        feature = "kvm-v6_1_0",
+        feature = "kvm-v6_6_0",
+        not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+    )))]
+    pub const ALL: [VcpuFeature; 4] = [
+        VcpuFeature::PowerOff,
+        VcpuFeature::El1_32Bit,
+        VcpuFeature::Psci0_2,
+        VcpuFeature::PmuV3,
+    ];
+
+    /// All the features known to the bindings.
+    #[cfg(any(
+        feature = "kvm-v5_4_0",
+        feature = "kvm-v5_10_0",
+        feature = "kvm-v5_15_0",
+        feature = "kvm-v6_1_0",
+        feature = "kvm-v6_6_0",
+        not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+    ))]
+    pub const ALL: [VcpuFeature; 7] = [
+        VcpuFeature::PowerOff,
+        VcpuFeature::El1_32Bit,
+        VcpuFeature::Psci0_2,
+        VcpuFeature::PmuV3,
+        VcpuFeature::Sve,
+        VcpuFeature::PtrauthAddress,
+        VcpuFeature::PtrauthGeneric,
+    ];
+
+    /// Returns the `KVM_ARM_VCPU_*` feature number.
+    pub fn bit(self) -> u32 {
+        match self {
+            VcpuFeature::PowerOff => KVM_ARM_VCPU_POWER_OFF,
+            VcpuFeature::El1_32Bit => KVM_ARM_VCPU_EL1_32BIT,
+            VcpuFeature::Psci0_2 => KVM_ARM_VCPU_PSCI_0_2,
+            VcpuFeature::PmuV3 => KVM_ARM_VCPU_PMU_V3,
+            #[cfg(any(
+                feature = "kvm-v5_4_0",
+                feature = "kvm-v5_10_0",
+                feature = "kvm-v5_15_0",
+                feature = "kvm-v6_1_0",
+                feature = "kvm-v6_6_0",
+                not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+            ))]
+            VcpuFeature::Sve => KVM_ARM_VCPU_SVE,
+            #[cfg(any(
+                feature = "kvm-v5_4_0",
+                feature = "kvm-v5_10_0",
+                feature = "kvm-v5_15_0",
+                feature = "kvm-v6_1_0",
+                feature = "kvm-v6_6_0",
+                not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+            ))]
+            VcpuFeature::PtrauthAddress => KVM_ARM_VCPU_PTRAUTH_ADDRESS,
+            #[cfg(any(
+                feature = "kvm-v5_4_0",
+                feature = "kvm-v5_10_0",
+                feature = "kvm-v5_15_0",
+                feature = "kvm-v6_1_0",
+                feature = "kvm-v6_6_0",
+                not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+            ))]
+            VcpuFeature::PtrauthGeneric => KVM_ARM_VCPU_PTRAUTH_GENERIC,
+        }
+    }
+
+    /// Returns the name of the feature, without the `KVM_ARM_VCPU_` prefix.
+    pub fn name(self) -> &'static str {
+        match self {
+            VcpuFeature::PowerOff => "POWER_OFF",
+            VcpuFeature::El1_32Bit => "EL1_32BIT",
+            VcpuFeature::Psci0_2 => "PSCI_0_2",
+            VcpuFeature::PmuV3 => "PMU_V3",
+            #[cfg(any(
+                feature = "kvm-v5_4_0",
+                feature = "kvm-v5_10_0",
+                feature = "kvm-v5_15_0",
+                feature = "kvm-v6_1_0",
+                feature = "kvm-v6_6_0",
+                not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+            ))]
+            VcpuFeature::Sve => "SVE",
+            #[cfg(any(
+                feature = "kvm-v5_4_0",
+                feature = "kvm-v5_10_0",
+                feature = "kvm-v5_15_0",
+                feature = "kvm-v6_1_0",
+                feature = "kvm-v6_6_0",
+                not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+            ))]
+            VcpuFeature::PtrauthAddress => "PTRAUTH_ADDRESS",
+            #[cfg(any(
+                feature = "kvm-v5_4_0",
+                feature = "kvm-v5_10_0",
+                feature = "kvm-v5_15_0",
+                feature = "kvm-v6_1_0",
+                feature = "kvm-v6_6_0",
+                not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+            ))]
+            VcpuFeature::PtrauthGeneric => "PTRAUTH_GENERIC",
+        }
+    }
+
+    /// Returns the feature named `name`, with or without the `KVM_ARM_VCPU_`
+    /// prefix and ignoring the case.
+    pub fn from_name(name: &str) -> Option<VcpuFeature> {
+        let prefix = "KVM_ARM_VCPU_";
+        let name = match name.get(..prefix.len()) {
+            Some(start) if start.eq_ignore_ascii_case(prefix) => &name[prefix.len()..],
+            _ => name,
+        };
+        Self::ALL
+            .iter()
+            .cloned()
+            .find(|f| f.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the feature numbered `bit`.
+    pub fn from_bit(bit: u32) -> Option<VcpuFeature> {
+        Self::ALL.iter().cloned().find(|f| f.bit() == bit)
+    }
+}
+
+impl fmt::Display for VcpuFeature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Returns the number of feature bits the kernel of the bindings accepts.
+///
+/// `KVM_ARM_VCPU_INIT` fails with `ENOENT` if a bit past them is set.
+pub fn supported_features(version: BindingsVersion) -> u32 {
+    match version {
+        BindingsVersion::V4_14_0 | BindingsVersion::V4_20_0 => KVM_ARM_VCPU_PMU_V3 + 1,
+        BindingsVersion::V5_4_0
+        | BindingsVersion::V5_10_0
+        | BindingsVersion::V5_15_0
+        | BindingsVersion::V6_1_0 => KVM_ARM_VCPU_PTRAUTH_GENERIC + 1,
+        BindingsVersion::V6_6_0 => KVM_ARM_VCPU_HAS_EL2 + 1,
+    }
+}
+
+/// Returns the names of the features set in `features`, in bit order.
+///
+/// The bits unknown to the bindings are named after their number.
+pub fn feature_names(features: &[u32; FEATURE_WORDS]) -> Vec<String> {
+    (0..FEATURE_BITS)
+        .filter(|bit| features[(bit / 32) as usize] & (1 << (bit % 32)) != 0)
+        .map(|bit| match VcpuFeature::from_bit(bit) {
+            Some(feature) => feature.name().to_string(),
+            None => format!("feature {}", bit),
+        })
+        .collect()
+}
+
+/// A builder for the `kvm_vcpu_init` passed to `KVM_ARM_VCPU_INIT`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VcpuInitBuilder {
+    target: u32,
+    features: [u32; FEATURE_WORDS],
+}
+
+impl VcpuInitBuilder {
+    /// Starts a `kvm_vcpu_init` for `target`, one of the `KVM_ARM_TARGET_*`.
+    pub fn new(target: u32) -> Self {
+        VcpuInitBuilder {
+            target,
+            features: [0; FEATURE_WORDS],
+        }
+    }
+
+    /// Starts from the `kvm_vcpu_init` filled by `KVM_ARM_PREFERRED_TARGET`,
+    /// which selects the target of the host CPU.
+    pub fn from_preferred_target(preferred: &kvm_vcpu_init) -> Self {
+        VcpuInitBuilder {
+            target: preferred.target,
+            features: preferred.features,
+        }
+    }
+
+    /// Sets `feature`.
+    pub fn feature(self, feature: VcpuFeature) -> Self {
+        self.raw_feature(feature.bit())
+    }
+
+    /// Sets the feature named `name`, like `"PSCI_0_2"` or
+    /// `"KVM_ARM_VCPU_POWER_OFF"`.
+    pub fn feature_by_name(self, name: &str) -> Result<Self, Error> {
+        VcpuFeature::from_name(name)
+            .map(|feature| self.feature(feature))
+            .ok_or_else(|| Error::UnknownFeature(name.to_string()))
+    }
+
+    /// Sets the feature number `bit`, which may be unknown to the bindings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit` does not fit in `kvm_vcpu_init::features`.
+    pub fn raw_feature(mut self, bit: u32) -> Self {
+        assert!(bit < FEATURE_BITS, "vcpu feature {} is out of range", bit);
+        self.features[(bit / 32) as usize] |= 1 << (bit % 32);
+        self
+    }
+
+    /// Returns `true` if the feature number `bit` is set.
+    pub fn has_feature(&self, bit: u32) -> bool {
+        bit < FEATURE_BITS && self.features[(bit / 32) as usize] & (1 << (bit % 32)) != 0
+    }
+
+    /// Checks the target and features against the `version` bindings and
+    /// returns the `kvm_vcpu_init`.
+    ///
+    /// `EL1_32BIT` cannot be combined with SVE, pointer authentication or
+    /// `HAS_EL2`, whatever the version.
+    pub fn build(&self, version: BindingsVersion) -> Result<kvm_vcpu_init, Error> {
+        if self.target >= KVM_ARM_NUM_TARGETS {
+            return Err(Error::InvalidTarget(self.target));
+        }
+        if let Some(&(first, second)) = INCOMPATIBLE_FEATURES
+            .iter()
+            .find(|&&(first, second)| self.has_feature(first) && self.has_feature(second))
+        {
+            return Err(Error::IncompatibleFeatures(first, second));
+        }
+        if let Some(feature) =
+            (supported_features(version)..FEATURE_BITS).find(|&bit| self.has_feature(bit))
+        {
+            return Err(Error::UnsupportedFeature { feature, version });
+        }
+        Ok(kvm_vcpu_init {
+            target: self.target,
+            features: self.features,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_features() {
+        assert_eq!(VcpuFeature::PmuV3.bit(), 3);
+        assert_eq!(
+            VcpuFeature::from_name("psci_0_2"),
+            Some(VcpuFeature::Psci0_2)
+        );
+        assert_eq!(
+            VcpuFeature::from_name("KVM_ARM_VCPU_POWER_OFF"),
+            Some(VcpuFeature::PowerOff)
+        );
+        assert_eq!(VcpuFeature::from_name("HAS_EL2"), None);
+        #[cfg(any(
+            feature = "kvm-v5_4_0",
+            feature = "kvm-v5_10_0",
+            feature = "kvm-v5_15_0",
+            feature = "kvm-v6_1_0",
+            feature = "kvm-v6_6_0",
+            not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+        ))]
+        assert_eq!(VcpuFeature::from_name("sve"), Some(VcpuFeature::Sve));
+        for feature in VcpuFeature::ALL.iter() {
+            assert_eq!(VcpuFeature::from_bit(feature.bit()), Some(*feature));
+            assert_eq!(VcpuFeature::from_name(feature.name()), Some(*feature));
+        }
+    }
+
+    #[test]
+    fn test_build() {
+        let init = VcpuInitBuilder::new(KVM_ARM_TARGET_GENERIC_V8)
+            .feature(VcpuFeature::Psci0_2)
+            .feature_by_name("power_off")
+            .unwrap()
+            .build(BindingsVersion::V4_20_0)
+            .unwrap();
+        assert_eq!(init.target, KVM_ARM_TARGET_GENERIC_V8);
+        assert_eq!(init.features, [0b101, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(feature_names(&init.features), ["POWER_OFF", "PSCI_0_2"]);
+
+        assert_eq!(
+            VcpuInitBuilder::new(0).feature_by_name("HAS_EL2"),
+            Err(Error::UnknownFeature("HAS_EL2".to_string()))
+        );
+        assert_eq!(
+            VcpuInitBuilder::new(KVM_ARM_NUM_TARGETS).build(BindingsVersion::V4_14_0),
+            Err(Error::InvalidTarget(KVM_ARM_NUM_TARGETS))
+        );
+        // KVM_ARM_VCPU_SVE was added in 5.2.
+        let sve = VcpuInitBuilder::new(0).raw_feature(4);
+        assert_eq!(
+            sve.build(BindingsVersion::V4_20_0),
+            Err(Error::UnsupportedFeature {
+                feature: 4,
+                version: BindingsVersion::V4_20_0
+            })
+        );
+        assert!(sve.build(BindingsVersion::V5_4_0).is_ok());
+        #[cfg(not(any(
+            feature = "kvm-v5_4_0",
+            feature = "kvm-v5_10_0",
+            feature = "kvm-v5_15_0",
+            feature = "kvm-v6_1_0",
+            feature = "kvm-v6_6_0",
+            not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+        )))]
+        assert_eq!(feature_names(&sve.features), ["feature 4"]);
+        #[cfg(any(
+            feature = "kvm-v5_4_0",
+            feature = "kvm-v5_10_0",
+            feature = "kvm-v5_15_0",
+            feature = "kvm-v6_1_0",
+            feature = "kvm-v6_6_0",
+            not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+        ))]
+        assert_eq!(feature_names(&sve.features), ["SVE"]);
+    }
+
+    #[test]
+    fn test_incompatible_features() {
+        let aarch32 = VcpuInitBuilder::new(KVM_ARM_TARGET_GENERIC_V8)
+            .feature(VcpuFeature::El1_32Bit)
+            .feature(VcpuFeature::Psci0_2)
+            .feature(VcpuFeature::PowerOff);
+        assert!(aarch32.build(BindingsVersion::V4_20_0).is_ok());
+        // KVM emulates an AArch32 PMUv3 too.
+        assert!(aarch32
+            .clone()
+            .feature(VcpuFeature::PmuV3)
+            .build(BindingsVersion::V4_14_0)
+            .is_ok());
+        // The combination is rejected before the feature support.
+        for &bit in &[
+            KVM_ARM_VCPU_SVE,
+            KVM_ARM_VCPU_PTRAUTH_ADDRESS,
+            KVM_ARM_VCPU_PTRAUTH_GENERIC,
+            KVM_ARM_VCPU_HAS_EL2,
+        ] {
+            assert_eq!(
+                aarch32
+                    .clone()
+                    .raw_feature(bit)
+                    .build(BindingsVersion::V4_20_0),
+                Err(Error::IncompatibleFeatures(KVM_ARM_VCPU_EL1_32BIT, bit))
+            );
+            assert_eq!(
+                VcpuInitBuilder::new(KVM_ARM_TARGET_GENERIC_V8)
+                    .raw_feature(bit)
+                    .build(BindingsVersion::V4_20_0),
+                Err(Error::UnsupportedFeature {
+                    feature: bit,
+                    version: BindingsVersion::V4_20_0
+                })
+            );
+        }
+        assert!(VcpuInitBuilder::new(KVM_ARM_TARGET_GENERIC_V8)
+            .feature(VcpuFeature::PmuV3)
+            .build(BindingsVersion::V4_20_0)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_preferred_target() {
+        let preferred = kvm_vcpu_init {
+            target: KVM_ARM_TARGET_CORTEX_A57,
+            features: [0; 7],
+        };
+        let builder =
+            VcpuInitBuilder::from_preferred_target(&preferred).feature(VcpuFeature::PmuV3);
+        assert!(builder.has_feature(KVM_ARM_VCPU_PMU_V3));
+        assert!(!builder.has_feature(KVM_ARM_VCPU_EL1_32BIT));
+        assert!(!builder.has_feature(FEATURE_BITS));
+        let init = builder.build(BindingsVersion::V4_14_0).unwrap();
+        assert_eq!(init.target, KVM_ARM_TARGET_CORTEX_A57);
+        assert_eq!(feature_names(&init.features), ["PMU_V3"]);
+    }
+}
@@ -0,0 +1,382 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Device attributes of the GICv2, GICv3 and ITS devices.
+//!
+//! The in-kernel interrupt controllers are configured with
+//! `KVM_SET_DEVICE_ATTR` and saved with `KVM_GET_DEVICE_ATTR`.
+//! [`VgicAttr`](enum.VgicAttr.html) encodes the `group` and `attr` of every
+//! attribute they accept; `addr` points to the data, whose size is given by
+//! [`VgicAttr::data_size`](enum.VgicAttr.html#method.data_size).
+
+use std::fmt;
+use std::mem::size_of;
+
+use super::bindings::*;
+
+// Not in the 4.14 bindings.
+const KVM_VGIC_V3_ADDR_TYPE_REDIST_REGION: u32 = 5;
+const KVM_DEV_ARM_ITS_CTRL_RESET: u32 = 4;
+
+// The fields of KVM_VGIC_V3_ADDR_TYPE_REDIST_REGION.
+const REDIST_REGION_BASE_MASK: u64 = 0x000f_ffff_ffff_0000;
+const REDIST_REGION_COUNT_SHIFT: u64 = 52;
+const REDIST_REGION_MAX_COUNT: u64 = 0xfff;
+const REDIST_REGION_MAX_INDEX: u64 = 0xfff;
+
+/// Errors associated with the interrupt controller attributes.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The redistributor region base is not 64KiB aligned or is past 52 bits.
+    InvalidRedistBase(u64),
+    /// The redistributor region holds no redistributors.
+    EmptyRedistRegion,
+    /// The redistributor count does not fit in 12 bits.
+    RedistCountOverflow(u64),
+    /// The redistributor region index does not fit in 12 bits.
+    RedistIndexOverflow(u64),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::InvalidRedistBase(base) => {
+                write!(f, "invalid redistributor region base {:#x}", base)
+            }
+            Error::EmptyRedistRegion => write!(f, "empty redistributor region"),
+            Error::RedistCountOverflow(count) => {
+                write!(f, "too many redistributors in a region: {}", count)
+            }
+            Error::RedistIndexOverflow(index) => {
+                write!(f, "invalid redistributor region index {}", index)
+            }
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// The interrupt controller devices created with `KVM_CREATE_DEVICE`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VgicDevice {
+    /// A GICv2.
+    V2,
+    /// A GICv3.
+    V3,
+    /// A GICv3 ITS.
+    Its,
+}
+
+impl VgicDevice {
+    /// Returns the `kvm_create_device::type_` of the device.
+    pub fn device_type(self) -> u32 {
+        match self {
+            VgicDevice::V2 => kvm_device_type_KVM_DEV_TYPE_ARM_VGIC_V2,
+            VgicDevice::V3 => kvm_device_type_KVM_DEV_TYPE_ARM_VGIC_V3,
+            VgicDevice::Its => kvm_device_type_KVM_DEV_TYPE_ARM_VGIC_ITS,
+        }
+    }
+}
+
+/// The guest physical address of a region of an interrupt controller.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VgicAddr {
+    /// The GICv2 distributor, `KVM_VGIC_V2_DIST_SIZE` bytes.
+    V2Dist,
+    /// The GICv2 CPU interface, `KVM_VGIC_V2_CPU_SIZE` bytes.
+    V2Cpu,
+    /// The GICv3 distributor.
+    V3Dist,
+    /// The GICv3 redistributors of all the vcpus, contiguous.
+    V3Redist,
+    /// A region of GICv3 redistributors, encoded with
+    /// [`redist_region`](fn.redist_region.html). Added in 4.18.
+    V3RedistRegion,
+    /// The ITS control frame.
+    Its,
+}
+
+impl VgicAddr {
+    /// Returns the `KVM_VGIC_*_ADDR_TYPE_*` value.
+    pub fn addr_type(self) -> u32 {
+        match self {
+            VgicAddr::V2Dist => KVM_VGIC_V2_ADDR_TYPE_DIST,
+            VgicAddr::V2Cpu => KVM_VGIC_V2_ADDR_TYPE_CPU,
+            VgicAddr::V3Dist => KVM_VGIC_V3_ADDR_TYPE_DIST,
+            VgicAddr::V3Redist => KVM_VGIC_V3_ADDR_TYPE_REDIST,
+            VgicAddr::V3RedistRegion => KVM_VGIC_V3_ADDR_TYPE_REDIST_REGION,
+            VgicAddr::Its => KVM_VGIC_ITS_ADDR_TYPE,
+        }
+    }
+}
+
+/// Encodes the `KVM_VGIC_V3_ADDR_TYPE_REDIST_REGION` data: a region of
+/// `count` redistributors at `base`, numbered `index`.
+///
+/// `base` must be 64KiB aligned and below 4PiB, `count` must be non-zero and
+/// fit in 12 bits, and `index` must fit in 12 bits too.
+pub fn redist_region(base: u64, count: u64, index: u64) -> Result<u64, Error> {
+    if base & !REDIST_REGION_BASE_MASK != 0 {
+        return Err(Error::InvalidRedistBase(base));
+    }
+    if count == 0 {
+        return Err(Error::EmptyRedistRegion);
+    }
+    if count > REDIST_REGION_MAX_COUNT {
+        return Err(Error::RedistCountOverflow(count));
+    }
+    if index > REDIST_REGION_MAX_INDEX {
+        return Err(Error::RedistIndexOverflow(index));
+    }
+    Ok((count << REDIST_REGION_COUNT_SHIFT) | base | index)
+}
+
+/// Packs the affinity fields of an `MPIDR_EL1` value into the 32 bits of the
+/// `KVM_DEV_ARM_VGIC_V3_MPIDR` field.
+pub fn vgic_mpidr(mpidr: u64) -> u64 {
+    ((mpidr >> 8) & 0xff00_0000) | (mpidr & 0x00ff_ffff)
+}
+
+/// An attribute of the GICv2, GICv3 or ITS devices.
+///
+/// The GICv2 registers are addressed by CPU interface number (`cpuid`), the
+/// GICv3 ones by the `MPIDR_EL1` value of the vcpu.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VgicAttr {
+    /// `KVM_DEV_ARM_VGIC_GRP_ADDR`: the base address of a region.
+    Addr(VgicAddr),
+    /// `KVM_DEV_ARM_VGIC_GRP_NR_IRQS`: the number of interrupts, SGIs and PPIs
+    /// included.
+    NrIrqs,
+    /// `KVM_DEV_ARM_VGIC_CTRL_INIT`: initializes the device once configured.
+    CtrlInit,
+    /// `KVM_DEV_ARM_VGIC_SAVE_PENDING_TABLES`: flushes the pending state of
+    /// the LPIs to guest memory.
+    SavePendingTables,
+    /// A GICv2 distributor register, banked for `cpuid`.
+    V2DistReg { cpuid: u8, offset: u32 },
+    /// A GICv2 CPU interface register of `cpuid`.
+    V2CpuReg { cpuid: u8, offset: u32 },
+    /// A GICv3 distributor register.
+    V3DistReg { offset: u32 },
+    /// A GICv3 redistributor register of the vcpu `mpidr`.
+    V3RedistReg { mpidr: u64, offset: u32 },
+    /// A GICv3 CPU interface system register of the vcpu `mpidr`, `instr`
+    /// being its `op0:op1:CRn:CRm:op2` encoding as in `KVM_REG_ARM64_SYSREG`.
+    V3CpuSysreg { mpidr: u64, instr: u16 },
+    /// The line level of the 32 interrupts starting at `intid`, a multiple of
+    /// 32, as seen by the vcpu `mpidr`.
+    LineLevelInfo { mpidr: u64, intid: u32 },
+    /// An ITS register.
+    ItsReg { offset: u32 },
+    /// `KVM_DEV_ARM_ITS_SAVE_TABLES`: saves the ITS tables to guest memory.
+    ItsSaveTables,
+    /// `KVM_DEV_ARM_ITS_RESTORE_TABLES`: restores the ITS tables from guest
+    /// memory.
+    ItsRestoreTables,
+    /// `KVM_DEV_ARM_ITS_CTRL_RESET`: resets the ITS. Added in 4.19.
+    ItsReset,
+}
+
+impl VgicAttr {
+    /// Returns the `kvm_device_attr::group`.
+    pub fn group(self) -> u32 {
+        match self {
+            VgicAttr::Addr(_) => KVM_DEV_ARM_VGIC_GRP_ADDR,
+            VgicAttr::NrIrqs => KVM_DEV_ARM_VGIC_GRP_NR_IRQS,
+            VgicAttr::CtrlInit
+            | VgicAttr::SavePendingTables
+            | VgicAttr::ItsSaveTables
+            | VgicAttr::ItsRestoreTables
+            | VgicAttr::ItsReset => KVM_DEV_ARM_VGIC_GRP_CTRL,
+            VgicAttr::V2DistReg { .. } | VgicAttr::V3DistReg { .. } => {
+                KVM_DEV_ARM_VGIC_GRP_DIST_REGS
+            }
+            VgicAttr::V2CpuReg { .. } => KVM_DEV_ARM_VGIC_GRP_CPU_REGS,
+            VgicAttr::V3RedistReg { .. } => KVM_DEV_ARM_VGIC_GRP_REDIST_REGS,
+            VgicAttr::V3CpuSysreg { .. } => KVM_DEV_ARM_VGIC_GRP_CPU_SYSREGS,
+            VgicAttr::LineLevelInfo { .. } => KVM_DEV_ARM_VGIC_GRP_LEVEL_INFO,
+            VgicAttr::ItsReg { .. } => KVM_DEV_ARM_VGIC_GRP_ITS_REGS,
+        }
+    }
+
+    /// Returns the `kvm_device_attr::attr`.
+    pub fn attr(self) -> u64 {
+        let cpuid = |cpuid: u8| {
+            (u64::from(cpuid) << KVM_DEV_ARM_VGIC_CPUID_SHIFT) & KVM_DEV_ARM_VGIC_CPUID_MASK
+        };
+        let mpidr = |mpidr: u64| {
+            (vgic_mpidr(mpidr) << KVM_DEV_ARM_VGIC_V3_MPIDR_SHIFT)
+                & KVM_DEV_ARM_VGIC_V3_MPIDR_MASK as u64
+        };
+        let offset = |offset: u32| u64::from(offset & KVM_DEV_ARM_VGIC_OFFSET_MASK);
+        match self {
+            VgicAttr::Addr(addr) => u64::from(addr.addr_type()),
+            VgicAttr::NrIrqs => 0,
+            VgicAttr::CtrlInit => u64::from(KVM_DEV_ARM_VGIC_CTRL_INIT),
+            VgicAttr::SavePendingTables => u64::from(KVM_DEV_ARM_VGIC_SAVE_PENDING_TABLES),
+            VgicAttr::V2DistReg {
+                cpuid: id,
+                offset: off,
+            }
+            | VgicAttr::V2CpuReg {
+                cpuid: id,
+                offset: off,
+            } => cpuid(id) | offset(off),
+            VgicAttr::V3DistReg { offset: off } | VgicAttr::ItsReg { offset: off } => offset(off),
+            VgicAttr::V3RedistReg {
+                mpidr: mp,
+                offset: off,
+            } => mpidr(mp) | offset(off),
+            VgicAttr::V3CpuSysreg { mpidr: mp, instr } => {
+                mpidr(mp) | u64::from(u32::from(instr) & KVM_DEV_ARM_VGIC_SYSREG_INSTR_MASK)
+            }
+            VgicAttr::LineLevelInfo { mpidr: mp, intid } => {
+                mpidr(mp)
+                    | u64::from(
+                        ((VGIC_LEVEL_INFO_LINE_LEVEL << KVM_DEV_ARM_VGIC_LINE_LEVEL_INFO_SHIFT)
+                            & KVM_DEV_ARM_VGIC_LINE_LEVEL_INFO_MASK)
+                            | (intid & KVM_DEV_ARM_VGIC_LINE_LEVEL_INTID_MASK),
+                    )
+            }
+            VgicAttr::ItsSaveTables => u64::from(KVM_DEV_ARM_ITS_SAVE_TABLES),
+            VgicAttr::ItsRestoreTables => u64::from(KVM_DEV_ARM_ITS_RESTORE_TABLES),
+            VgicAttr::ItsReset => u64::from(KVM_DEV_ARM_ITS_CTRL_RESET),
+        }
+    }
+
+    /// Returns the size in bytes of the data `kvm_device_attr::addr` points
+    /// to, 0 if the attribute has no data.
+    pub fn data_size(self) -> usize {
+        match self {
+            VgicAttr::CtrlInit
+            | VgicAttr::SavePendingTables
+            | VgicAttr::ItsSaveTables
+            | VgicAttr::ItsRestoreTables
+            | VgicAttr::ItsReset => 0,
+            VgicAttr::NrIrqs
+            | VgicAttr::V2DistReg { .. }
+            | VgicAttr::V2CpuReg { .. }
+            | VgicAttr::V3DistReg { .. }
+            | VgicAttr::V3RedistReg { .. }
+            | VgicAttr::LineLevelInfo { .. } => size_of::<u32>(),
+            VgicAttr::Addr(_) | VgicAttr::V3CpuSysreg { .. } | VgicAttr::ItsReg { .. } => {
+                size_of::<u64>()
+            }
+        }
+    }
+
+    /// Returns the `kvm_device_attr` of the attribute, with `addr` pointing to
+    /// the data, or 0 if it has none.
+    pub fn kvm_device_attr(self, addr: u64) -> kvm_device_attr {
+        kvm_device_attr {
+            flags: 0,
+            group: self.group(),
+            attr: self.attr(),
+            addr,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_devices() {
+        assert_eq!(VgicDevice::V2.device_type(), 5);
+        assert_eq!(VgicDevice::V3.device_type(), 7);
+        assert_eq!(VgicDevice::Its.device_type(), 8);
+    }
+
+    #[test]
+    fn test_setup_attrs() {
+        let base = 0x0800_0000u64;
+        let attr = VgicAttr::Addr(VgicAddr::V3Dist).kvm_device_attr(&base as *const u64 as u64);
+        assert_eq!(attr.group, KVM_DEV_ARM_VGIC_GRP_ADDR);
+        assert_eq!(attr.attr, 2);
+        assert_eq!(attr.flags, 0);
+        assert_eq!(unsafe { *(attr.addr as *const u64) }, base);
+        assert_eq!(VgicAttr::Addr(VgicAddr::V3RedistRegion).attr(), 5);
+        assert_eq!(redist_region(0x080a_0000, 4, 1), Ok(0x0040_0000_080a_0001));
+        assert_eq!(
+            redist_region(0x080a_8000, 4, 1),
+            Err(Error::InvalidRedistBase(0x080a_8000))
+        );
+        assert_eq!(
+            redist_region(1 << 52, 4, 1),
+            Err(Error::InvalidRedistBase(1 << 52))
+        );
+        assert_eq!(
+            redist_region(0x080a_0000, 0, 1),
+            Err(Error::EmptyRedistRegion)
+        );
+        assert_eq!(
+            redist_region(0x080a_0000, 0x1000, 1),
+            Err(Error::RedistCountOverflow(0x1000))
+        );
+        assert_eq!(
+            redist_region(0x080a_0000, 4, 0x1000),
+            Err(Error::RedistIndexOverflow(0x1000))
+        );
+
+        assert_eq!(VgicAttr::NrIrqs.group(), 3);
+        assert_eq!(VgicAttr::NrIrqs.data_size(), 4);
+        assert_eq!(VgicAttr::CtrlInit.group(), KVM_DEV_ARM_VGIC_GRP_CTRL);
+        assert_eq!(VgicAttr::CtrlInit.attr(), 0);
+        assert_eq!(VgicAttr::CtrlInit.data_size(), 0);
+        assert_eq!(VgicAttr::SavePendingTables.attr(), 3);
+        assert_eq!(VgicAttr::ItsSaveTables.attr(), 1);
+        assert_eq!(VgicAttr::ItsRestoreTables.attr(), 2);
+        assert_eq!(VgicAttr::ItsReset.attr(), 4);
+    }
+
+    #[test]
+    fn test_register_attrs() {
+        assert_eq!(vgic_mpidr(0x8000_0000), 0);
+        assert_eq!(vgic_mpidr(0x12_0003_0201), 0x1203_0201);
+
+        let attr = VgicAttr::V2DistReg {
+            cpuid: 3,
+            offset: 0x100,
+        };
+        assert_eq!(attr.group(), KVM_DEV_ARM_VGIC_GRP_DIST_REGS);
+        assert_eq!(attr.attr(), 0x3_0000_0100);
+        assert_eq!(
+            VgicAttr::V2CpuReg {
+                cpuid: 1,
+                offset: 4
+            }
+            .attr(),
+            0x1_0000_0004
+        );
+        assert_eq!(VgicAttr::V3DistReg { offset: 0x6100 }.attr(), 0x6100);
+
+        let attr = VgicAttr::V3RedistReg {
+            mpidr: 0x8000_0101,
+            offset: 0x10080,
+        };
+        assert_eq!(attr.group(), KVM_DEV_ARM_VGIC_GRP_REDIST_REGS);
+        assert_eq!(attr.attr(), 0x0000_0101_0001_0080);
+
+        // ICC_PMR_EL1: op0 3, op1 0, CRn 4, CRm 6, op2 0.
+        let attr = VgicAttr::V3CpuSysreg {
+            mpidr: 1,
+            instr: 0xc230,
+        };
+        assert_eq!(attr.group(), KVM_DEV_ARM_VGIC_GRP_CPU_SYSREGS);
+        assert_eq!(attr.attr(), 0x1_0000_c230);
+        assert_eq!(attr.data_size(), 8);
+
+        let attr = VgicAttr::LineLevelInfo {
+            mpidr: 0,
+            intid: 64,
+        };
+        assert_eq!(attr.group(), KVM_DEV_ARM_VGIC_GRP_LEVEL_INFO);
+        assert_eq!(attr.attr(), 64);
+
+        let attr = VgicAttr::ItsReg { offset: 0x80 };
+        assert_eq!(attr.group(), KVM_DEV_ARM_VGIC_GRP_ITS_REGS);
+        assert_eq!(attr.data_size(), 8);
+    }
+}
@@ -0,0 +1,444 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Owned wrappers for structs ending in a flexible array member.
+//!
+//! Structs like `kvm_msrs` or `kvm_cpuid2` are made of a header holding the
+//! number of entries followed by an `__IncompleteArrayField` with the entries
+//! themselves. [`FamStructWrapper`](struct.FamStructWrapper.html) allocates the
+//! header and the entries in one buffer with the alignment of the header and
+//! keeps the count field in sync with the entries.
+
+use std::fmt;
+use std::mem::{self, size_of};
+
+/// Errors associated with the [`FamStructWrapper`](struct.FamStructWrapper.html).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The requested number of entries is larger than the one accepted by the kernel.
+    SizeLimitExceeded,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::SizeLimitExceeded => write!(f, "the maximum number of entries was exceeded"),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// Trait for structs ending in a flexible array member.
+///
+/// # Safety
+///
+/// Implementors must be plain old data for which the all-zero bit pattern is
+/// valid, and `as_slice`/`as_mut_slice` must describe the entries stored right
+/// after the header.
+#[allow(clippy::len_without_is_empty)]
+pub unsafe trait FamStruct {
+    /// The type of the flexible array entries.
+    type Entry: Copy;
+
+    /// Returns the number of entries as stored in the header.
+    fn len(&self) -> usize;
+
+    /// Sets the number of entries stored in the header.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure `len` entries are allocated after the header.
+    unsafe fn set_len(&mut self, len: usize);
+
+    /// Returns the maximum number of entries accepted by the kernel.
+    fn max_len() -> usize;
+
+    /// Returns the first `len` entries.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure `len` entries are allocated after the header.
+    unsafe fn as_slice(&self, len: usize) -> &[Self::Entry];
+
+    /// Returns the first `len` entries as a mutable slice.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure `len` entries are allocated after the header.
+    unsafe fn as_mut_slice(&mut self, len: usize) -> &mut [Self::Entry];
+}
+
+/// Implements [`FamStruct`](trait.FamStruct.html) for a bindgen struct.
+///
+/// The arguments are the struct, the type of the entries, the flexible array
+/// field, the count field and its type, and the maximum number of entries.
+macro_rules! generate_fam_struct_impl {
+    ($t:ty, $entry:ty, $field:ident, $len_field:ident, $len_ty:ty, $max:expr) => {
+        unsafe impl $crate::fam::FamStruct for $t {
+            type Entry = $entry;
+
+            fn len(&self) -> usize {
+                self.$len_field as usize
+            }
+
+            unsafe fn set_len(&mut self, len: usize) {
+                self.$len_field = len as $len_ty;
+            }
+
+            fn max_len() -> usize {
+                $max
+            }
+
+            unsafe fn as_slice(&self, len: usize) -> &[$entry] {
+                self.$field.as_slice(len)
+            }
+
+            unsafe fn as_mut_slice(&mut self, len: usize) -> &mut [$entry] {
+                self.$field.as_mut_slice(len)
+            }
+        }
+    };
+}
+
+/// Owned buffer holding a `T` header followed by its entries.
+///
+/// The buffer is a `Vec<T>` so the header is always correctly aligned, and
+/// it is grown in multiples of `size_of::<T>()` to fit the entries.
+pub struct FamStructWrapper<T: FamStruct> {
+    mem_allocator: Vec<T>,
+}
+
+impl<T: FamStruct> FamStructWrapper<T> {
+    // Number of `T` elements needed to hold the header and `num_elements` entries.
+    fn mem_allocator_len(num_elements: usize) -> usize {
+        let entries_size = num_elements * size_of::<T::Entry>();
+        entries_size.div_ceil(size_of::<T>()) + 1
+    }
+
+    // Grows the buffer with zeroed memory so that it holds at least `len` elements.
+    fn grow(&mut self, len: usize) {
+        let additional = len.saturating_sub(self.mem_allocator.len());
+        self.mem_allocator.reserve_exact(additional);
+        for _ in 0..additional {
+            // Safe because `FamStruct` implementors are valid when zeroed.
+            self.mem_allocator.push(unsafe { mem::zeroed() });
+        }
+    }
+
+    /// Creates a wrapper holding `num_elements` zeroed entries.
+    ///
+    /// Returns `Error::SizeLimitExceeded` if `num_elements` is larger than
+    /// `T::max_len()`.
+    pub fn new(num_elements: usize) -> Result<Self, Error> {
+        if num_elements > T::max_len() {
+            return Err(Error::SizeLimitExceeded);
+        }
+        let mut wrapper: Self = FamStructWrapper {
+            mem_allocator: Vec::new(),
+        };
+        wrapper.grow(Self::mem_allocator_len(num_elements));
+        // Safe because the buffer was sized for `num_elements` entries.
+        unsafe { wrapper.mem_allocator[0].set_len(num_elements) };
+        Ok(wrapper)
+    }
+
+    /// Creates a wrapper holding a copy of `entries`.
+    pub fn from_entries(entries: &[T::Entry]) -> Result<Self, Error> {
+        let mut wrapper = Self::new(entries.len())?;
+        wrapper.as_mut_slice().copy_from_slice(entries);
+        Ok(wrapper)
+    }
+
+    /// Returns a reference to the header.
+    pub fn as_fam_struct_ref(&self) -> &T {
+        &self.mem_allocator[0]
+    }
+
+    /// Returns a mutable reference to the header.
+    ///
+    /// # Safety
+    ///
+    /// The caller must not grow the count field past [`capacity`](#method.capacity).
+    pub unsafe fn as_mut_fam_struct(&mut self) -> &mut T {
+        &mut self.mem_allocator[0]
+    }
+
+    /// Returns a pointer to the header, suitable for ioctls reading the struct.
+    pub fn as_fam_struct_ptr(&self) -> *const T {
+        self.as_fam_struct_ref()
+    }
+
+    /// Returns a mutable pointer to the header, suitable for ioctls writing the struct.
+    pub fn as_mut_fam_struct_ptr(&mut self) -> *mut T {
+        &mut self.mem_allocator[0]
+    }
+
+    /// Returns the number of entries.
+    ///
+    /// This is the value of the count field, capped to the allocated capacity.
+    pub fn len(&self) -> usize {
+        ::std::cmp::min(self.as_fam_struct_ref().len(), self.capacity())
+    }
+
+    /// Returns `true` if the wrapper holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of entries that fit in the current allocation.
+    pub fn capacity(&self) -> usize {
+        (self.mem_allocator.len() - 1) * size_of::<T>() / size_of::<T::Entry>()
+    }
+
+    /// Returns the entries.
+    pub fn as_slice(&self) -> &[T::Entry] {
+        let len = self.len();
+        // Safe because `len` is capped to the allocated capacity.
+        unsafe { self.as_fam_struct_ref().as_slice(len) }
+    }
+
+    /// Returns the entries as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T::Entry] {
+        let len = self.len();
+        // Safe because `len` is capped to the allocated capacity.
+        unsafe { self.mem_allocator[0].as_mut_slice(len) }
+    }
+
+    /// Appends an entry, growing the allocation if needed.
+    ///
+    /// The capacity is doubled on each growth, up to `T::max_len()` entries.
+    ///
+    /// Returns `Error::SizeLimitExceeded` if the wrapper is already full.
+    pub fn push(&mut self, entry: T::Entry) -> Result<(), Error> {
+        let len = self.len();
+        if len >= T::max_len() {
+            return Err(Error::SizeLimitExceeded);
+        }
+        if len == self.capacity() {
+            let new_capacity = ::std::cmp::min(::std::cmp::max(len * 2, 1), T::max_len());
+            let new_len = Self::mem_allocator_len(new_capacity);
+            self.grow(new_len);
+        }
+        // Safe because the buffer holds at least `len + 1` entries.
+        unsafe {
+            self.mem_allocator[0].set_len(len + 1);
+        }
+        self.as_mut_slice()[len] = entry;
+        Ok(())
+    }
+
+    /// Shortens the entries to `len`, keeping the allocation.
+    ///
+    /// Does nothing if `len` is not smaller than the current length.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            // Safe because `len` entries are already allocated.
+            unsafe { self.mem_allocator[0].set_len(len) };
+        }
+    }
+
+    /// Keeps only the entries for which `f` returns `true`.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T::Entry) -> bool,
+    {
+        let mut kept = 0;
+        let len = self.len();
+        {
+            let entries = self.as_mut_slice();
+            for i in 0..len {
+                if f(&entries[i]) {
+                    entries[kept] = entries[i];
+                    kept += 1;
+                }
+            }
+        }
+        self.truncate(kept);
+    }
+}
+
+impl<T: FamStruct> Clone for FamStructWrapper<T> {
+    fn clone(&self) -> Self {
+        let mut clone: Self = FamStructWrapper {
+            mem_allocator: Vec::new(),
+        };
+        clone.grow(self.mem_allocator.len());
+        // Safe because both buffers have the same size and `T` is plain old data.
+        unsafe {
+            ::std::ptr::copy_nonoverlapping(
+                self.mem_allocator.as_ptr(),
+                clone.mem_allocator.as_mut_ptr(),
+                self.mem_allocator.len(),
+            );
+        }
+        clone
+    }
+}
+
+impl<T: FamStruct> PartialEq for FamStructWrapper<T>
+where
+    T::Entry: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: FamStruct> fmt::Debug for FamStructWrapper<T>
+where
+    T::Entry: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FamStructWrapper")
+            .field("len", &self.len())
+            .field("entries", &self.as_slice())
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: FamStruct + ::serde::Serialize> ::serde::Serialize for FamStructWrapper<T>
+where
+    T::Entry: ::serde::Serialize,
+{
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.as_fam_struct_ref(), self.as_slice()).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: FamStruct + ::serde::Deserialize<'de>> ::serde::Deserialize<'de>
+    for FamStructWrapper<T>
+where
+    T::Entry: ::serde::Deserialize<'de>,
+{
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (header, entries): (T, Vec<T::Entry>) =
+            ::serde::Deserialize::deserialize(deserializer)?;
+        let mut wrapper = Self::from_entries(&entries).map_err(::serde::de::Error::custom)?;
+        wrapper.mem_allocator[0] = header;
+        // Safe because the buffer was sized for the deserialized entries.
+        unsafe { wrapper.mem_allocator[0].set_len(entries.len()) };
+        Ok(wrapper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX_LEN: usize = 10;
+
+    #[repr(C)]
+    struct MockFamStruct {
+        pub len: u32,
+        pub padding: u32,
+        pub entries: [u64; 0],
+    }
+
+    unsafe impl FamStruct for MockFamStruct {
+        type Entry = u64;
+
+        fn len(&self) -> usize {
+            self.len as usize
+        }
+
+        unsafe fn set_len(&mut self, len: usize) {
+            self.len = len as u32;
+        }
+
+        fn max_len() -> usize {
+            MAX_LEN
+        }
+
+        unsafe fn as_slice(&self, len: usize) -> &[u64] {
+            ::std::slice::from_raw_parts(self.entries.as_ptr(), len)
+        }
+
+        unsafe fn as_mut_slice(&mut self, len: usize) -> &mut [u64] {
+            ::std::slice::from_raw_parts_mut(self.entries.as_mut_ptr(), len)
+        }
+    }
+
+    type MockWrapper = FamStructWrapper<MockFamStruct>;
+
+    #[test]
+    fn test_new() {
+        for n in 0..MAX_LEN + 1 {
+            let wrapper = MockWrapper::new(n).unwrap();
+            assert_eq!(wrapper.len(), n);
+            assert_eq!(wrapper.as_fam_struct_ref().len, n as u32);
+            assert!(wrapper.capacity() >= n);
+            assert!(wrapper.as_slice().iter().all(|e| *e == 0));
+        }
+        assert_eq!(
+            MockWrapper::new(MAX_LEN + 1).unwrap_err(),
+            Error::SizeLimitExceeded
+        );
+    }
+
+    #[test]
+    fn test_from_entries() {
+        let entries = [1u64, 2, 3];
+        let wrapper = MockWrapper::from_entries(&entries).unwrap();
+        assert_eq!(wrapper.as_slice(), &entries);
+        assert!(MockWrapper::from_entries(&[0; MAX_LEN + 1]).is_err());
+    }
+
+    #[test]
+    fn test_push_truncate_retain() {
+        let mut wrapper = MockWrapper::new(0).unwrap();
+        assert!(wrapper.is_empty());
+        let mut capacities = Vec::new();
+        for i in 0..MAX_LEN {
+            wrapper.push(i as u64).unwrap();
+            assert_eq!(wrapper.len(), i + 1);
+            assert_eq!(wrapper.as_fam_struct_ref().len(), i + 1);
+            if capacities.last() != Some(&wrapper.capacity()) {
+                capacities.push(wrapper.capacity());
+            }
+        }
+        // The capacity doubles on each growth and stops at the limit.
+        assert_eq!(capacities, vec![1, 2, 4, 8, MAX_LEN]);
+        assert_eq!(wrapper.push(0).unwrap_err(), Error::SizeLimitExceeded);
+        assert_eq!(wrapper.as_slice(), &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        wrapper.retain(|e| e % 2 == 0);
+        assert_eq!(wrapper.as_slice(), &[0, 2, 4, 6, 8]);
+        assert_eq!(wrapper.as_fam_struct_ref().len, 5);
+
+        let capacity = wrapper.capacity();
+        wrapper.truncate(2);
+        assert_eq!(wrapper.as_slice(), &[0, 2]);
+        assert_eq!(wrapper.capacity(), capacity);
+        wrapper.truncate(3);
+        assert_eq!(wrapper.len(), 2);
+    }
+
+    #[test]
+    fn test_len_capped_to_capacity() {
+        let mut wrapper = MockWrapper::new(2).unwrap();
+        unsafe { wrapper.as_mut_fam_struct().len = 1000 };
+        assert_eq!(wrapper.len(), wrapper.capacity());
+    }
+
+    #[test]
+    fn test_clone_eq() {
+        let mut wrapper = MockWrapper::from_entries(&[4, 5, 6]).unwrap();
+        let clone = wrapper.clone();
+        assert_eq!(wrapper, clone);
+        wrapper.as_mut_slice()[0] = 7;
+        assert_ne!(wrapper, clone);
+        assert_eq!(clone.as_slice(), &[4, 5, 6]);
+    }
+
+    #[test]
+    fn test_ptr() {
+        let mut wrapper = MockWrapper::from_entries(&[1]).unwrap();
+        let ptr = wrapper.as_mut_fam_struct_ptr();
+        assert_eq!(ptr as *const MockFamStruct, wrapper.as_fam_struct_ptr());
+        assert_eq!(ptr as usize % ::std::mem::align_of::<MockFamStruct>(), 0);
+        assert_eq!(unsafe { (*ptr).len }, 1);
+    }
+}
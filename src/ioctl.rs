@@ -0,0 +1,66 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers for building ioctl request numbers.
+//!
+//! bindgen does not translate the `_IO`, `_IOR`, `_IOW` and `_IOWR` function-like
+//! macros, so the request numbers are rebuilt here from the `_IOC_*` constants and
+//! the struct sizes of the bindings. The macros expect the bindings of the calling
+//! module to be in scope.
+
+/// Defines a `pub const` ioctl request number from its direction, type, number and size.
+macro_rules! ioctl_ioc_nr {
+    ($name:ident, $dir:expr, $ty:expr, $nr:expr, $size:expr) => {
+        pub const $name: ::std::os::raw::c_ulong = (($dir << _IOC_DIRSHIFT)
+            | ($ty << _IOC_TYPESHIFT)
+            | ($nr << _IOC_NRSHIFT)
+            | ($size << _IOC_SIZESHIFT))
+            as ::std::os::raw::c_ulong;
+    };
+}
+
+/// Equivalent of the C `_IO` macro.
+macro_rules! ioctl_io_nr {
+    ($name:ident, $ty:expr, $nr:expr) => {
+        ioctl_ioc_nr!($name, _IOC_NONE, $ty, $nr, 0);
+    };
+}
+
+/// Equivalent of the C `_IOR` macro.
+macro_rules! ioctl_ior_nr {
+    ($name:ident, $ty:expr, $nr:expr, $size:ty) => {
+        ioctl_ioc_nr!(
+            $name,
+            _IOC_READ,
+            $ty,
+            $nr,
+            ::std::mem::size_of::<$size>() as u32
+        );
+    };
+}
+
+/// Equivalent of the C `_IOW` macro.
+macro_rules! ioctl_iow_nr {
+    ($name:ident, $ty:expr, $nr:expr, $size:ty) => {
+        ioctl_ioc_nr!(
+            $name,
+            _IOC_WRITE,
+            $ty,
+            $nr,
+            ::std::mem::size_of::<$size>() as u32
+        );
+    };
+}
+
+/// Equivalent of the C `_IOWR` macro.
+macro_rules! ioctl_iowr_nr {
+    ($name:ident, $ty:expr, $nr:expr, $size:ty) => {
+        ioctl_ioc_nr!(
+            $name,
+            _IOC_READ | _IOC_WRITE,
+            $ty,
+            $nr,
+            ::std::mem::size_of::<$size>() as u32
+        );
+    };
+}
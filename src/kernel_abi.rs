@@ -0,0 +1,507 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runtime detection of the host kernel ABI.
+//!
+//! A binary can be built with several versions of the bindings (see the
+//! `kvm-v*` features). [`KernelAbi`](struct.KernelAbi.html) reads the host
+//! kernel release and KVM API version, picks the compiled bindings version
+//! matching the host best, and reports the structs whose layout differs from
+//! the default bindings, so callers can refuse or adapt on older hosts.
+
+use std::ffi::CStr;
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io;
+use std::mem;
+use std::os::unix::io::AsRawFd;
+
+use super::ioctls::KVM_GET_API_VERSION;
+use super::KVM_API_VERSION;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use x86::layouts;
+
+#[cfg(target_arch = "arm")]
+use arm::layouts;
+
+#[cfg(target_arch = "aarch64")]
+use arm64::layouts;
+
+/// Errors associated with detecting the host kernel ABI.
+#[derive(Debug)]
+pub enum Error {
+    /// The `uname` system call failed.
+    Uname(io::Error),
+    /// The kernel release could not be parsed.
+    InvalidRelease(String),
+    /// `/dev/kvm` could not be opened.
+    OpenKvm(io::Error),
+    /// The `KVM_GET_API_VERSION` ioctl failed.
+    GetApiVersion(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Uname(ref e) => write!(f, "uname failed: {}", e),
+            Error::InvalidRelease(ref r) => write!(f, "invalid kernel release: {}", r),
+            Error::OpenKvm(ref e) => write!(f, "cannot open /dev/kvm: {}", e),
+            Error::GetApiVersion(ref e) => write!(f, "KVM_GET_API_VERSION failed: {}", e),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// A Linux kernel version, as found at the start of the kernel release.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KernelVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl KernelVersion {
+    /// Creates a kernel version.
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        KernelVersion {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parses a kernel release such as `4.19.0-16-amd64`.
+    ///
+    /// Only the leading numeric components are used; a missing patch level is 0.
+    pub fn parse(release: &str) -> Result<Self, Error> {
+        let numbers: Vec<u32> = release
+            .split(|c: char| !c.is_ascii_digit())
+            .take(3)
+            .map_while(|n| n.parse().ok())
+            .collect();
+        if numbers.len() < 2 || !release.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(Error::InvalidRelease(release.to_string()));
+        }
+        Ok(KernelVersion::new(
+            numbers[0],
+            numbers[1],
+            numbers.get(2).cloned().unwrap_or(0),
+        ))
+    }
+}
+
+impl fmt::Display for KernelVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The kernel versions the bindings are generated for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BindingsVersion {
+    /// Bindings for Linux 4.14 (`kvm-v4_14_0`).
+    V4_14_0,
+    /// Bindings for Linux 4.20 (`kvm-v4_20_0`).
+    V4_20_0,
+    /// Bindings for Linux 5.4 (`kvm-v5_4_0`).
+    V5_4_0,
+    /// Bindings for Linux 5.10 (`kvm-v5_10_0`), not generated for 32-bit arm.
+    V5_10_0,
+    /// Bindings for Linux 5.15 (`kvm-v5_15_0`), not generated for 32-bit arm.
+    V5_15_0,
+    /// Bindings for Linux 6.1 (`kvm-v6_1_0`), not generated for 32-bit arm.
+    V6_1_0,
+    /// Bindings for Linux 6.6 (`kvm-v6_6_0`), not generated for 32-bit arm.
+    V6_6_0,
+}
+
+impl BindingsVersion {
+    /// All the bindings versions, oldest first.
+    pub const ALL: [BindingsVersion; 7] = [
+        BindingsVersion::V4_14_0,
+        BindingsVersion::V4_20_0,
+        BindingsVersion::V5_4_0,
+        BindingsVersion::V5_10_0,
+        BindingsVersion::V5_15_0,
+        BindingsVersion::V6_1_0,
+        BindingsVersion::V6_6_0,
+    ];
+
+    /// Returns the kernel version the bindings were generated from.
+    pub fn kernel_version(self) -> KernelVersion {
+        match self {
+            BindingsVersion::V4_14_0 => KernelVersion::new(4, 14, 0),
+            BindingsVersion::V4_20_0 => KernelVersion::new(4, 20, 0),
+            BindingsVersion::V5_4_0 => KernelVersion::new(5, 4, 0),
+            BindingsVersion::V5_10_0 => KernelVersion::new(5, 10, 0),
+            BindingsVersion::V5_15_0 => KernelVersion::new(5, 15, 0),
+            BindingsVersion::V6_1_0 => KernelVersion::new(6, 1, 0),
+            BindingsVersion::V6_6_0 => KernelVersion::new(6, 6, 0),
+        }
+    }
+
+    /// Returns `true` if these bindings are compiled in.
+    pub fn is_compiled(self) -> bool {
+        match self {
+            BindingsVersion::V4_14_0 => cfg!(feature = "kvm-v4_14_0"),
+            BindingsVersion::V4_20_0 => cfg!(feature = "kvm-v4_20_0"),
+            // 32-bit arm stops at 5.4, which is also its default.
+            BindingsVersion::V5_4_0 => cfg!(any(
+                feature = "kvm-v5_4_0",
+                all(
+                    target_arch = "arm",
+                    not(any(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))
+                )
+            )),
+            BindingsVersion::V5_10_0 => {
+                cfg!(all(not(target_arch = "arm"), feature = "kvm-v5_10_0"))
+            }
+            BindingsVersion::V5_15_0 => {
+                cfg!(all(not(target_arch = "arm"), feature = "kvm-v5_15_0"))
+            }
+            BindingsVersion::V6_1_0 => cfg!(all(not(target_arch = "arm"), feature = "kvm-v6_1_0")),
+            BindingsVersion::V6_6_0 => cfg!(all(
+                not(target_arch = "arm"),
+                any(
+                    feature = "kvm-v6_6_0",
+                    not(any(
+                        feature = "kvm-v4_14_0",
+                        feature = "kvm-v4_20_0",
+                        feature = "kvm-v5_4_0",
+                        feature = "kvm-v5_10_0",
+                        feature = "kvm-v5_15_0",
+                        feature = "kvm-v6_1_0"
+                    ))
+                )
+            )),
+        }
+    }
+
+    /// Returns the compiled bindings versions, oldest first.
+    pub fn compiled() -> Vec<BindingsVersion> {
+        Self::ALL
+            .iter()
+            .cloned()
+            .filter(|v| v.is_compiled())
+            .collect()
+    }
+
+    /// Returns the version re-exported at the crate root: the newest compiled one.
+    pub fn default_version() -> BindingsVersion {
+        *Self::compiled()
+            .last()
+            .expect("at least one bindings version is always compiled")
+    }
+
+    /// Returns the layouts of the `kvm_*` structs of these bindings.
+    ///
+    /// The list is empty if these bindings are not generated for the host
+    /// architecture.
+    pub fn struct_layouts(self) -> &'static [StructLayout] {
+        match self {
+            BindingsVersion::V4_14_0 => layouts::V4_14_0,
+            BindingsVersion::V4_20_0 => layouts::V4_20_0,
+            BindingsVersion::V5_4_0 => layouts::V5_4_0,
+            #[cfg(not(target_arch = "arm"))]
+            BindingsVersion::V5_10_0 => layouts::V5_10_0,
+            #[cfg(not(target_arch = "arm"))]
+            BindingsVersion::V5_15_0 => layouts::V5_15_0,
+            #[cfg(not(target_arch = "arm"))]
+            BindingsVersion::V6_1_0 => layouts::V6_1_0,
+            #[cfg(not(target_arch = "arm"))]
+            BindingsVersion::V6_6_0 => layouts::V6_6_0,
+            #[cfg(target_arch = "arm")]
+            BindingsVersion::V5_10_0
+            | BindingsVersion::V5_15_0
+            | BindingsVersion::V6_1_0
+            | BindingsVersion::V6_6_0 => &[],
+        }
+    }
+}
+
+impl fmt::Display for BindingsVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let version = self.kernel_version();
+        write!(f, "v{}_{}_{}", version.major, version.minor, version.patch)
+    }
+}
+
+/// Size and alignment of a struct in one bindings version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StructLayout {
+    pub name: &'static str,
+    pub size: usize,
+    pub align: usize,
+}
+
+/// A struct whose layout differs between two bindings versions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayoutChange {
+    /// The struct only exists in the newer bindings.
+    Added(StructLayout),
+    /// The struct only exists in the older bindings.
+    Removed(StructLayout),
+    /// The struct exists in both bindings with a different size or alignment.
+    Changed {
+        from: StructLayout,
+        to: StructLayout,
+    },
+}
+
+impl LayoutChange {
+    /// Returns the name of the struct.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            LayoutChange::Added(l) | LayoutChange::Removed(l) => l.name,
+            LayoutChange::Changed { from, .. } => from.name,
+        }
+    }
+}
+
+/// Returns the structs whose layout differs between the `from` and `to` bindings.
+///
+/// `Added` structs only exist in `to` and `Removed` ones only exist in `from`.
+pub fn layout_changes(from: BindingsVersion, to: BindingsVersion) -> Vec<LayoutChange> {
+    let from_layouts = from.struct_layouts();
+    let to_layouts = to.struct_layouts();
+    let find = |layouts: &'static [StructLayout], name| layouts.iter().find(|l| l.name == name);
+
+    let mut changes = Vec::new();
+    for from_layout in from_layouts {
+        match find(to_layouts, from_layout.name) {
+            None => changes.push(LayoutChange::Removed(*from_layout)),
+            Some(to_layout) if to_layout != from_layout => changes.push(LayoutChange::Changed {
+                from: *from_layout,
+                to: *to_layout,
+            }),
+            Some(_) => (),
+        }
+    }
+    for to_layout in to_layouts {
+        if find(from_layouts, to_layout.name).is_none() {
+            changes.push(LayoutChange::Added(*to_layout));
+        }
+    }
+    changes
+}
+
+/// The ABI of the host kernel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KernelAbi {
+    release: KernelVersion,
+    api_version: i32,
+}
+
+impl KernelAbi {
+    /// Creates a `KernelAbi` from a kernel version and the value returned by
+    /// `KVM_GET_API_VERSION`.
+    pub fn new(release: KernelVersion, api_version: i32) -> Self {
+        KernelAbi {
+            release,
+            api_version,
+        }
+    }
+
+    /// Reads the ABI of the running kernel with `uname` and `KVM_GET_API_VERSION`.
+    pub fn from_host() -> Result<Self, Error> {
+        // Safe because `utsname` is plain old data and `uname` only writes to it.
+        let mut uts: ::libc::utsname = unsafe { mem::zeroed() };
+        if unsafe { ::libc::uname(&mut uts) } < 0 {
+            return Err(Error::Uname(io::Error::last_os_error()));
+        }
+        // Safe because `uname` writes a NUL-terminated string.
+        let release = unsafe { CStr::from_ptr(uts.release.as_ptr()) };
+        let release = KernelVersion::parse(&release.to_string_lossy())?;
+
+        let kvm = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open("/dev/kvm")
+            .map_err(Error::OpenKvm)?;
+        // Safe because `KVM_GET_API_VERSION` takes no argument.
+        let api_version = unsafe { ::libc::ioctl(kvm.as_raw_fd(), KVM_GET_API_VERSION as _) };
+        if api_version < 0 {
+            return Err(Error::GetApiVersion(io::Error::last_os_error()));
+        }
+        Ok(KernelAbi::new(release, api_version))
+    }
+
+    /// Returns the kernel version.
+    pub fn release(&self) -> KernelVersion {
+        self.release
+    }
+
+    /// Returns the KVM API version.
+    pub fn api_version(&self) -> i32 {
+        self.api_version
+    }
+
+    /// Returns `true` if the KVM API version is the one of the bindings, `KVM_API_VERSION`.
+    pub fn is_api_supported(&self) -> bool {
+        self.api_version == KVM_API_VERSION as i32
+    }
+
+    /// Returns the newest compiled bindings version not newer than the host kernel.
+    ///
+    /// Returns `None` if the KVM API version is not supported or the host kernel
+    /// is older than all the compiled bindings.
+    pub fn best_match(&self) -> Option<BindingsVersion> {
+        if !self.is_api_supported() {
+            return None;
+        }
+        BindingsVersion::compiled()
+            .into_iter()
+            .rev()
+            .find(|v| v.kernel_version() <= self.release)
+    }
+
+    /// Returns the structs whose layout in the default bindings differs from the
+    /// best match for the host.
+    ///
+    /// Returns `None` if there is no best match. An empty list means the default
+    /// bindings can be used as they are.
+    pub fn layout_changes(&self) -> Option<Vec<LayoutChange>> {
+        self.best_match()
+            .map(|best| layout_changes(best, BindingsVersion::default_version()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn test_parse_release() {
+        assert_eq!(
+            KernelVersion::parse("4.19.0-16-amd64").unwrap(),
+            KernelVersion::new(4, 19, 0)
+        );
+        assert_eq!(
+            KernelVersion::parse("5.10.209-198.858.amzn2.x86_64").unwrap(),
+            KernelVersion::new(5, 10, 209)
+        );
+        assert_eq!(
+            KernelVersion::parse("6.1").unwrap(),
+            KernelVersion::new(6, 1, 0)
+        );
+        assert_eq!(
+            KernelVersion::parse("4.20-rc1").unwrap(),
+            KernelVersion::new(4, 20, 0)
+        );
+        assert!(KernelVersion::parse("linux").is_err());
+        assert!(KernelVersion::parse("5").is_err());
+        assert!(KernelVersion::parse("").is_err());
+        assert_eq!(KernelVersion::new(4, 14, 0).to_string(), "4.14.0");
+    }
+
+    #[test]
+    fn test_bindings_versions() {
+        let compiled = BindingsVersion::compiled();
+        assert!(!compiled.is_empty());
+        assert_eq!(
+            compiled.contains(&BindingsVersion::V4_14_0),
+            cfg!(feature = "kvm-v4_14_0")
+        );
+        assert_eq!(
+            BindingsVersion::default_version(),
+            *compiled.last().unwrap()
+        );
+        assert_eq!(BindingsVersion::V4_20_0.to_string(), "v4_20_0");
+    }
+
+    #[test]
+    fn test_best_match() {
+        let newest = BindingsVersion::default_version();
+        let oldest = BindingsVersion::compiled()[0];
+
+        let abi = KernelAbi::new(KernelVersion::new(6, 6, 0), KVM_API_VERSION as i32);
+        assert_eq!(abi.best_match(), Some(newest));
+        assert_eq!(abi.layout_changes(), Some(vec![]));
+
+        let abi = KernelAbi::new(oldest.kernel_version(), KVM_API_VERSION as i32);
+        assert_eq!(abi.best_match(), Some(oldest));
+
+        let abi = KernelAbi::new(KernelVersion::new(3, 10, 0), KVM_API_VERSION as i32);
+        assert_eq!(abi.best_match(), None);
+        assert_eq!(abi.layout_changes(), None);
+
+        let abi = KernelAbi::new(KernelVersion::new(6, 6, 0), 11);
+        assert!(!abi.is_api_supported());
+        assert_eq!(abi.best_match(), None);
+    }
+
+    #[cfg(all(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))]
+    #[test]
+    fn test_best_match_older_host() {
+        let abi = KernelAbi::new(KernelVersion::new(4, 19, 0), KVM_API_VERSION as i32);
+        assert_eq!(abi.best_match(), Some(BindingsVersion::V4_14_0));
+        let changes = abi.layout_changes().unwrap();
+        assert!(changes.contains(&LayoutChange::Added(StructLayout {
+            name: "kvm_enc_region",
+            size: 16,
+            align: 8
+        })));
+    }
+
+    #[test]
+    fn test_layout_changes() {
+        let changes = layout_changes(BindingsVersion::V4_14_0, BindingsVersion::V4_20_0);
+        let names: Vec<&str> = changes.iter().map(|c| c.name()).collect();
+        assert!(names.contains(&"kvm_enc_region"));
+        assert!(names.contains(&"kvm_hyperv_eventfd"));
+        assert!(changes.iter().all(|c| match *c {
+            LayoutChange::Added(_) | LayoutChange::Changed { .. } => true,
+            LayoutChange::Removed(_) => false,
+        }));
+
+        let reverse = layout_changes(BindingsVersion::V4_20_0, BindingsVersion::V4_14_0);
+        assert_eq!(reverse.len(), changes.len());
+        assert!(layout_changes(BindingsVersion::V4_20_0, BindingsVersion::V4_20_0).is_empty());
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn test_layout_changes_x86() {
+        let changes = layout_changes(BindingsVersion::V4_14_0, BindingsVersion::V4_20_0);
+        assert!(changes.iter().any(|c| c.name() == "kvm_nested_state"));
+        assert!(changes.iter().any(|c| c.name() == "kvm_sev_cmd"));
+
+        let changes = layout_changes(BindingsVersion::V5_4_0, BindingsVersion::V6_1_0);
+        assert!(changes.contains(&LayoutChange::Changed {
+            from: StructLayout {
+                name: "kvm_hyperv_exit",
+                size: 40,
+                align: 8
+            },
+            to: StructLayout {
+                name: "kvm_hyperv_exit",
+                size: 56,
+                align: 8
+            },
+        }));
+        assert!(changes.iter().any(|c| match *c {
+            LayoutChange::Added(l) => l.name == "kvm_sregs2",
+            _ => false,
+        }));
+
+        let changes = layout_changes(BindingsVersion::V6_1_0, BindingsVersion::V6_6_0);
+        assert!(changes.iter().any(|c| match *c {
+            LayoutChange::Removed(l) => l.name == "kvm_memory_alias",
+            _ => false,
+        }));
+    }
+
+    #[test]
+    fn test_from_host() {
+        if !Path::new("/dev/kvm").exists() {
+            return;
+        }
+        if let Ok(abi) = KernelAbi::from_host() {
+            assert!(abi.is_api_supported());
+            assert!(abi.release() >= KernelVersion::new(2, 6, 20));
+        }
+    }
+}
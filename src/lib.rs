@@ -5,17 +5,154 @@
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+extern crate libc;
+#[cfg(feature = "serde")]
+extern crate serde;
+
+#[cfg(all(test, feature = "serde"))]
+extern crate bincode;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+
+#[macro_use]
+mod ioctl;
+#[macro_use]
+pub mod fam;
+#[cfg(feature = "serde")]
+#[macro_use]
+mod serialize;
+
+// The bindings of the other architectures are compiled with the `cross-arch`
+// feature, and for the tests on 64-bit hosts so their layout, ioctl and wrapper
+// tests run whatever the host is.
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    feature = "cross-arch",
+    all(test, target_pointer_width = "64")
+))]
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", feature = "cross-arch")),
+    allow(dead_code, unused_imports)
+)]
 mod x86;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::x86::bindings::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::x86::*;
 
-#[cfg(target_arch = "aarch")]
+#[cfg(any(
+    target_arch = "arm",
+    feature = "cross-arch",
+    all(test, target_pointer_width = "64")
+))]
+#[cfg_attr(
+    not(any(target_arch = "arm", feature = "cross-arch")),
+    allow(dead_code, unused_imports)
+)]
 mod arm;
-#[cfg(target_arch = "aarch")]
+#[cfg(target_arch = "arm")]
 pub use self::arm::bindings::*;
+#[cfg(target_arch = "arm")]
+pub use self::arm::*;
 
-#[cfg(target_arch = "aarch64")]
+#[cfg(any(
+    target_arch = "aarch64",
+    feature = "cross-arch",
+    all(test, target_pointer_width = "64")
+))]
+#[cfg_attr(
+    not(any(target_arch = "aarch64", feature = "cross-arch")),
+    allow(dead_code, unused_imports)
+)]
 mod arm64;
 #[cfg(target_arch = "aarch64")]
 pub use self::arm64::bindings::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::arm64::*;
+
+/// The bindings of every architecture, whatever the host architecture.
+///
+/// Enabled by the `cross-arch` feature, for tools inspecting the state of
+/// guests of another architecture. The bindings use the C types of their own
+/// architecture, so on 64-bit hosts their layout is the one of their target.
+#[cfg(feature = "cross-arch")]
+pub mod arch {
+    /// Bindings for x86_64.
+    pub mod x86_64 {
+        pub use x86::bindings::*;
+        pub use x86::*;
+    }
+
+    /// Bindings for arm64.
+    pub mod arm64 {
+        pub use arm64::bindings::*;
+        pub use arm64::*;
+    }
+
+    /// Bindings for 32-bit arm.
+    pub mod arm {
+        pub use arm::bindings::*;
+        pub use arm::*;
+    }
+}
+
+pub mod kernel_abi;
+pub mod one_reg;
+pub mod vcpu_exit;
+
+#[cfg(all(test, feature = "cross-arch"))]
+mod tests {
+    use super::arch;
+    use std::mem::size_of;
+
+    #[test]
+    fn test_cross_arch() {
+        assert_eq!(size_of::<arch::x86_64::kvm_regs>(), 144);
+        assert_eq!(size_of::<arch::arm64::user_pt_regs>(), 272);
+        assert_eq!(size_of::<arch::arm64::kvm_regs>(), 864);
+        assert_eq!(size_of::<arch::arm::kvm_regs>(), 152);
+        assert_eq!(arch::arm64::KVM_REG_ARM64_SYSREG, 0x13_0000);
+        assert_eq!(arch::arm64::ioctls::KVM_ARM_VCPU_INIT, 0x4020_aeae);
+    }
+
+    #[cfg(feature = "kvm-v4_20_0")]
+    #[test]
+    fn test_cross_arch_v4_20_0() {
+        assert_eq!(size_of::<arch::arm64::v4_20_0::kvm_vcpu_events>(), 64);
+    }
+
+    #[cfg(feature = "kvm-v5_4_0")]
+    #[test]
+    fn test_cross_arch_v5_4_0() {
+        assert_eq!(size_of::<arch::arm64::kvm_vcpu_events>(), 64);
+        assert_eq!(size_of::<arch::arm64::v5_4_0::kvm_vcpu_events>(), 64);
+        assert_eq!(size_of::<arch::arm::v5_4_0::kvm_regs>(), 152);
+        assert_eq!(
+            arch::arm64::v5_4_0::ioctls::KVM_ARM_VCPU_FINALIZE,
+            0x4004_aec2
+        );
+    }
+
+    #[cfg(any(
+        feature = "kvm-v6_6_0",
+        not(any(
+            feature = "kvm-v4_14_0",
+            feature = "kvm-v4_20_0",
+            feature = "kvm-v5_4_0",
+            feature = "kvm-v5_10_0",
+            feature = "kvm-v5_15_0",
+            feature = "kvm-v6_1_0"
+        ))
+    ))]
+    #[test]
+    fn test_cross_arch_v6_6_0() {
+        assert_eq!(size_of::<arch::arm64::v6_6_0::kvm_vcpu_events>(), 64);
+        assert_eq!(size_of::<arch::arm64::v6_6_0::kvm_smccc_filter>(), 24);
+        assert_eq!(
+            arch::arm64::v6_6_0::ioctls::KVM_ARM_SET_COUNTER_OFFSET,
+            0x4010_aeb5
+        );
+        assert_eq!(arch::arm64::v6_6_0::KVM_ARM_VCPU_HAS_EL2, 7);
+    }
+}
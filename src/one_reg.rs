@@ -0,0 +1,219 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Values of the registers accessed with `KVM_GET_ONE_REG` and `KVM_SET_ONE_REG`.
+//!
+//! `kvm_one_reg::addr` points to a buffer whose size is encoded in the
+//! `KVM_REG_SIZE` bits of `kvm_one_reg::id`.
+//! [`OneRegValue`](struct.OneRegValue.html) owns a buffer of the right size
+//! for a register ID and converts its content to and from integers and bytes.
+
+use std::fmt;
+
+use super::*;
+
+// The largest size defined by `KVM_REG_SIZE_U2048`.
+const MAX_REG_SIZE: usize = 256;
+
+/// Errors associated with register values.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The `KVM_REG_SIZE` bits of the register ID are not a valid size.
+    InvalidSize(u64),
+    /// The value does not have the size of the register.
+    SizeMismatch { expected: usize, actual: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::InvalidSize(id) => write!(f, "{:#x} does not encode a valid register size", id),
+            Error::SizeMismatch { expected, actual } => write!(
+                f,
+                "the register is {} bytes wide but the value is {} bytes",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// Returns the size in bytes of the register `id`, as encoded by `KVM_REG_SIZE`.
+pub fn reg_size(id: u64) -> Result<usize, Error> {
+    let size = 1usize << ((id & KVM_REG_SIZE_MASK) >> KVM_REG_SIZE_SHIFT);
+    if size > MAX_REG_SIZE {
+        return Err(Error::InvalidSize(id));
+    }
+    Ok(size)
+}
+
+/// The value of a register, in a buffer of the size encoded in its ID.
+///
+/// The value is stored in the byte order of the host, which is the one
+/// `KVM_GET_ONE_REG` and `KVM_SET_ONE_REG` use.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OneRegValue {
+    id: u64,
+    data: Vec<u8>,
+}
+
+macro_rules! int_conversions {
+    ($($ty:ident, $from:ident, $to:ident;)*) => {
+        $(
+            #[doc = concat!("Builds the value of the register `id` from a `", stringify!($ty), "`.")]
+            pub fn $from<I: Into<u64>>(id: I, value: $ty) -> Result<Self, Error> {
+                OneRegValue::from_bytes(id, &value.to_ne_bytes())
+            }
+
+            #[doc = concat!("Returns the value as a `", stringify!($ty), "`.")]
+            pub fn $to(&self) -> Result<$ty, Error> {
+                let mut bytes = [0u8; ::std::mem::size_of::<$ty>()];
+                self.check_size(bytes.len())?;
+                bytes.copy_from_slice(&self.data);
+                Ok($ty::from_ne_bytes(bytes))
+            }
+        )*
+    };
+}
+
+impl OneRegValue {
+    /// Builds a zeroed value for the register `id`, to be filled by
+    /// `KVM_GET_ONE_REG`.
+    pub fn new<I: Into<u64>>(id: I) -> Result<Self, Error> {
+        let id = id.into();
+        Ok(OneRegValue {
+            id,
+            data: vec![0; reg_size(id)?],
+        })
+    }
+
+    /// Builds the value of the register `id` from its bytes.
+    pub fn from_bytes<I: Into<u64>>(id: I, bytes: &[u8]) -> Result<Self, Error> {
+        let mut value = OneRegValue::new(id)?;
+        value.check_size(bytes.len())?;
+        value.data.copy_from_slice(bytes);
+        Ok(value)
+    }
+
+    int_conversions! {
+        u32, from_u32, to_u32;
+        u64, from_u64, to_u64;
+        u128, from_u128, to_u128;
+    }
+
+    /// Returns the register ID.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the size of the register in bytes.
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the bytes of the value.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the bytes of the value, for modification.
+    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    /// Returns the `kvm_one_reg` to pass to `KVM_GET_ONE_REG` or
+    /// `KVM_SET_ONE_REG`.
+    ///
+    /// `addr` points to the buffer of `self`, which must stay alive and must not
+    /// be moved until the ioctl returns.
+    pub fn as_kvm_one_reg(&mut self) -> kvm_one_reg {
+        kvm_one_reg {
+            id: self.id,
+            addr: self.data.as_mut_ptr() as u64,
+        }
+    }
+
+    fn check_size(&self, actual: usize) -> Result<(), Error> {
+        if actual != self.data.len() {
+            return Err(Error::SizeMismatch {
+                expected: self.data.len(),
+                actual,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const U32_REG: u64 = 0x6020_0000_0010_0000;
+    const U64_REG: u64 = 0x6030_0000_0010_0000;
+    const U128_REG: u64 = 0x6040_0000_0010_0000;
+    const U2048_REG: u64 = 0x6080_0000_0015_0000;
+
+    #[test]
+    fn test_reg_size() {
+        assert_eq!(reg_size(U32_REG).unwrap(), 4);
+        assert_eq!(reg_size(U64_REG).unwrap(), 8);
+        assert_eq!(reg_size(U128_REG).unwrap(), 16);
+        assert_eq!(reg_size(U2048_REG).unwrap(), 256);
+        assert_eq!(
+            reg_size(0x6090_0000_0015_0000),
+            Err(Error::InvalidSize(0x6090_0000_0015_0000))
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        let value = OneRegValue::from_u64(U64_REG, 0x1234_5678_9abc_def0).unwrap();
+        assert_eq!(value.id(), U64_REG);
+        assert_eq!(value.size(), 8);
+        assert_eq!(value.to_u64().unwrap(), 0x1234_5678_9abc_def0);
+        assert_eq!(
+            value.to_u32(),
+            Err(Error::SizeMismatch {
+                expected: 8,
+                actual: 4
+            })
+        );
+
+        let vreg = OneRegValue::from_u128(U128_REG, u128::MAX - 1).unwrap();
+        assert_eq!(vreg.to_u128().unwrap(), u128::MAX - 1);
+        assert_eq!(vreg.as_bytes(), &(u128::MAX - 1).to_ne_bytes()[..]);
+
+        assert_eq!(
+            OneRegValue::from_u32(U64_REG, 1),
+            Err(Error::SizeMismatch {
+                expected: 8,
+                actual: 4
+            })
+        );
+        assert_eq!(
+            OneRegValue::from_u32(U32_REG, 1).unwrap().to_u32().unwrap(),
+            1
+        );
+
+        let mut sve = OneRegValue::new(U2048_REG).unwrap();
+        assert!(sve.as_bytes().iter().all(|&b| b == 0));
+        sve.as_mut_bytes()[255] = 0xff;
+        assert_eq!(
+            OneRegValue::from_bytes(U2048_REG, sve.as_bytes()).unwrap(),
+            sve
+        );
+        assert!(OneRegValue::from_bytes(U2048_REG, &[0; 128]).is_err());
+    }
+
+    #[test]
+    fn test_kvm_one_reg() {
+        let mut value = OneRegValue::new(U64_REG).unwrap();
+        let reg = value.as_kvm_one_reg();
+        assert_eq!(reg.id, U64_REG);
+
+        // Emulate `KVM_GET_ONE_REG` writing through `addr`.
+        unsafe { *(reg.addr as *mut [u8; 8]) = 42u64.to_ne_bytes() };
+        assert_eq!(value.to_u64().unwrap(), 42);
+    }
+}
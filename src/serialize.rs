@@ -0,0 +1,435 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serde support for the bindgen structs, enabled by the `serde` feature.
+//!
+//! Most bindings are plain old data made of fixed size arrays and bitfield
+//! units, so they are serialized as their raw bytes. This keeps the in-memory
+//! layout the kernel expects and round-trips every member, including the
+//! reserved fields.
+//!
+//! Reading the raw bytes of a value is only sound if all of them are
+//! initialized. [`serde_impls!`](macro.serde_impls.html) takes the fields of
+//! each struct and fails to build if they don't cover the whole struct, and a
+//! struct with padding is serialized field by field instead. Writing a union
+//! member may leave the other bytes of the union uninitialized, so unions and
+//! the structs containing them are never read as raw bytes either: a union is
+//! serialized through one of its members if they all span the whole union, or
+//! else through the member selected by a field of the enclosing struct, such
+//! as the exit reason of `kvm_run`, which must then name the member that was
+//! written. Unions without such a field, and the structs containing them, don't
+//! implement `Serialize` and `Deserialize`.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::ptr;
+
+use serde::de::{self, SeqAccess, Visitor};
+use serde::{Deserializer, Serializer};
+
+/// Plain old data without padding.
+///
+/// # Safety
+///
+/// Every byte of a `Self` must belong to a `Pod` field, and any bit pattern
+/// must be a valid `Self`.
+pub(crate) unsafe trait Pod {}
+
+macro_rules! pod_primitives {
+    ($($t:ty),*) => {
+        $(unsafe impl Pod for $t {})*
+    };
+}
+
+pod_primitives!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+/// Returns the size of the field `field` returns, checking it is `Pod`.
+///
+/// `field` is never called, it only names the field in constant expressions.
+pub(crate) const fn pod_size<T, F: Pod>(_field: fn(&T) -> &F) -> usize {
+    size_of::<F>()
+}
+
+/// Serializes `value` as its `size_of::<T>()` raw bytes.
+pub(crate) fn serialize_pod<T: Pod, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    // Safe because `T` has no padding, so all its bytes are initialized.
+    let bytes =
+        unsafe { ::std::slice::from_raw_parts(value as *const T as *const u8, size_of::<T>()) };
+    serializer.serialize_bytes(bytes)
+}
+
+/// Deserializes a `T` from the raw bytes written by [`serialize_pod`](fn.serialize_pod.html).
+pub(crate) fn deserialize_pod<'de, T: Pod, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    deserializer.deserialize_bytes(PodVisitor(PhantomData))
+}
+
+struct PodVisitor<T>(PhantomData<T>);
+
+impl<T: Pod> PodVisitor<T> {
+    fn from_bytes<E: de::Error>(bytes: &[u8]) -> Result<T, E> {
+        if bytes.len() != size_of::<T>() {
+            return Err(E::invalid_length(
+                bytes.len(),
+                &PodVisitor::<T>(PhantomData),
+            ));
+        }
+        // Safe because the length was checked and any bit pattern is a valid
+        // `Pod`.
+        Ok(unsafe { ptr::read_unaligned(bytes.as_ptr() as *const T) })
+    }
+}
+
+impl<'de, T: Pod> Visitor<'de> for PodVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{} bytes", size_of::<T>())
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        Self::from_bytes(v)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let mut bytes = Vec::with_capacity(size_of::<T>());
+        while let Some(byte) = seq.next_element::<u8>()? {
+            if bytes.len() == size_of::<T>() {
+                return Err(de::Error::invalid_length(bytes.len() + 1, &self));
+            }
+            bytes.push(byte);
+        }
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// Implements `Serialize` and `Deserialize` for bindgen structs and unions.
+///
+/// A struct listed as `struct` has no padding and doesn't contain unions, so it
+/// also implements `Pod` and is serialized as its raw bytes. A struct listed as
+/// `fields` is serialized field by field instead.
+///
+/// A union listed as `union` names all its members, which must be `Pod` and
+/// span the whole union, and is serialized as the raw bytes of the first one. A union with
+/// smaller members has no impls of its own: the field holding it is followed
+/// by the struct field that selects the member, and the patterns mapping its
+/// values to members. The selected member is serialized as an `Option`, which
+/// is `None` if no pattern matches:
+///
+/// ```ignore
+/// serde_impls! {
+///     fields kvm_irqchip {
+///         chip_id,
+///         pad,
+///         chip(chip_id) {
+///             KVM_IRQCHIP_PIC_MASTER => pic,
+///             KVM_IRQCHIP_PIC_SLAVE => pic,
+///             KVM_IRQCHIP_IOAPIC => ioapic
+///         }
+///     }
+///     struct kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1 { vector, _bitfield_1, reserved, dest_id }
+///     union kvm_ioapic_state__bindgen_ty_1 { bits, fields }
+/// }
+/// ```
+macro_rules! serde_impls {
+    ($($kind:ident $t:ident { $($body:tt)* })*) => {
+        $(serde_impls!(@$kind $t { $($body)* });)*
+    };
+    (@struct $t:ident { $($f:ident),* $(,)* }) => {
+        const _: () = assert!(
+            ::std::mem::size_of::<$t>() == 0 $(+ $crate::serialize::pod_size(|v: &$t| &v.$f))*,
+            concat!(stringify!($t), " has padding or unlisted fields")
+        );
+        // Safe because the fields are `Pod` and cover the whole struct.
+        unsafe impl $crate::serialize::Pod for $t {}
+
+        impl ::serde::Serialize for $t {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $crate::serialize::serialize_pod(self, serializer)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $t {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $crate::serialize::deserialize_pod(deserializer)
+            }
+        }
+    };
+    (@union $t:ident { $m:ident $(, $n:ident)* $(,)* }) => {
+        const _: () = assert!(
+            // The closures only name the members, they are never called.
+            ::std::mem::size_of::<$t>() == $crate::serialize::pod_size(|v: &$t| unsafe { &v.$m })
+                $(&& ::std::mem::size_of::<$t>() == $crate::serialize::pod_size(|v: &$t| unsafe { &v.$n }))*,
+            concat!("a member of ", stringify!($t), " does not span the union")
+        );
+
+        impl ::serde::Serialize for $t {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                // Safe because all the members are `Pod` and span the whole
+                // union, so writing any of them initializes all the bytes.
+                $crate::serialize::serialize_pod(unsafe { &self.$m }, serializer)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $t {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok($t { $m: $crate::serialize::deserialize_pod(deserializer)? })
+            }
+        }
+    };
+    (@fields $t:ident {
+        $($f:ident $(($($tag:ident).+ $(as $cast:ty)*) { $($p:pat => $m:ident),* $(,)* })*),* $(,)*
+    }) => {
+        impl ::serde::Serialize for $t {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use ::serde::ser::SerializeTuple;
+
+                let mut tuple = serializer.serialize_tuple([$(stringify!($f)),*].len())?;
+                $(serde_impls!(
+                    @serialize_field tuple, self.$f $(, self.$($tag).+ $(as $cast)* => { $($p => $m),* })*
+                );)*
+                tuple.end()
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $t {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct FieldVisitor;
+
+                impl<'de> ::serde::de::Visitor<'de> for FieldVisitor {
+                    type Value = $t;
+
+                    fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                        write!(formatter, "the fields of {}", stringify!($t))
+                    }
+
+                    fn visit_seq<A: ::serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<$t, A::Error> {
+                        // The bindgen `Default` impls zero the unions, so the
+                        // members that aren't deserialized are left zeroed.
+                        let mut value = $t::default();
+                        let mut len = 0;
+                        $(
+                            serde_impls!(
+                                @deserialize_field seq, self, len, value.$f
+                                $(, value.$($tag).+ $(as $cast)* => { $($p => $m),* })*
+                            );
+                            len += 1;
+                        )*
+                        let _ = len;
+                        Ok(value)
+                    }
+                }
+
+                let len = [$(stringify!($f)),*].len();
+                deserializer.deserialize_tuple(len, FieldVisitor)
+            }
+        }
+    };
+    (@serialize_field $tuple:ident, $field:expr) => {
+        $tuple.serialize_element(&$field)?;
+    };
+    (@serialize_field $tuple:ident, $field:expr, $tag:expr => { $($p:pat => $m:ident),* }) => {
+        #[allow(unreachable_patterns)]
+        match $tag {
+            // Safe as long as the tag selects the member that was written, as
+            // it does in the values the kernel fills and the deserializer builds.
+            $($p => $tuple.serialize_element(&Some(unsafe { &$field.$m }))?,)*
+            _ => $tuple.serialize_element(&None::<()>)?,
+        }
+    };
+    (@deserialize_field $seq:ident, $visitor:ident, $len:ident, $field:expr) => {
+        $field = $seq
+            .next_element()?
+            .ok_or_else(|| ::serde::de::Error::invalid_length($len, &$visitor))?;
+    };
+    (@deserialize_field
+        $seq:ident, $visitor:ident, $len:ident, $field:expr, $tag:expr => { $($p:pat => $m:ident),* }
+    ) => {
+        #[allow(unreachable_patterns)]
+        match $tag {
+            $($p => {
+                let member = $seq
+                    .next_element::<Option<_>>()?
+                    .ok_or_else(|| ::serde::de::Error::invalid_length($len, &$visitor))?;
+                if let Some(member) = member {
+                    $field.$m = member;
+                }
+            })*
+            _ => {
+                $seq.next_element::<Option<()>>()?
+                    .ok_or_else(|| ::serde::de::Error::invalid_length($len, &$visitor))?;
+            }
+        }
+    };
+}
+
+/// Checks that a `T` filled with a byte pattern survives a JSON and a bincode round trip.
+#[cfg(test)]
+pub(crate) fn assert_round_trip<T>()
+where
+    T: Pod + ::serde::Serialize + ::serde::de::DeserializeOwned,
+{
+    let mut bytes = vec![0u8; size_of::<T>()];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = (i % 251) as u8;
+    }
+    let value: T = unsafe { ptr::read_unaligned(bytes.as_ptr() as *const T) };
+    let as_bytes = |value: &T| unsafe {
+        ::std::slice::from_raw_parts(value as *const T as *const u8, size_of::<T>()).to_vec()
+    };
+
+    let json = ::serde_json::to_string(&value).unwrap();
+    let from_json: T = ::serde_json::from_str(&json).unwrap();
+    assert_eq!(as_bytes(&from_json), bytes);
+
+    let bin = ::bincode::serialize(&value).unwrap();
+    let from_bin: T = ::bincode::deserialize(&bin).unwrap();
+    assert_eq!(as_bytes(&from_bin), bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Blob {
+        a: u32,
+        b: [u8; 1024],
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct Padded {
+        a: u8,
+        b: u64,
+        c: Blob,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    union Spanning {
+        a: u32,
+        b: [u8; 4],
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    union Partial {
+        small: u8,
+        big: u64,
+        spanning: Spanning,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct Tagged {
+        tag: u32,
+        pad: u32,
+        u: Partial,
+    }
+
+    impl Default for Blob {
+        fn default() -> Self {
+            Blob { a: 0, b: [0; 1024] }
+        }
+    }
+
+    impl Default for Tagged {
+        fn default() -> Self {
+            unsafe { ::std::mem::zeroed() }
+        }
+    }
+
+    serde_impls! {
+        struct Blob { a, b }
+        fields Padded { a, b, c }
+        union Spanning { a, b }
+        fields Tagged { tag, pad, u(tag) { 1 => small, 2 => big, 3..=4 => spanning } }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip::<Blob>();
+
+        let mut padded = Padded {
+            a: 0x12,
+            b: 0x3456,
+            c: Blob::default(),
+        };
+        padded.c.b[1023] = 0x78;
+        let json = ::serde_json::to_string(&padded).unwrap();
+        assert_eq!(::serde_json::from_str::<Padded>(&json).unwrap(), padded);
+        let bin = ::bincode::serialize(&padded).unwrap();
+        assert_eq!(bin.len(), 1 + 8 + 8 + size_of::<Blob>());
+        assert_eq!(::bincode::deserialize::<Padded>(&bin).unwrap(), padded);
+        assert!(::bincode::deserialize::<Padded>(&bin[..9]).is_err());
+    }
+
+    #[test]
+    fn test_tagged_round_trip() {
+        let tagged = Tagged {
+            tag: 1,
+            pad: 0,
+            u: Partial { small: 0x12 },
+        };
+        let json = ::serde_json::to_string(&tagged).unwrap();
+        assert_eq!(json, "[1,0,18]");
+        let from_json = ::serde_json::from_str::<Tagged>(&json).unwrap();
+        assert_eq!(unsafe { from_json.u.big }, 0x12);
+
+        let tagged = Tagged {
+            tag: 2,
+            pad: 0,
+            u: Partial {
+                big: 0x1234_5678_9abc,
+            },
+        };
+        let bin = ::bincode::serialize(&tagged).unwrap();
+        assert_eq!(bin.len(), 4 + 4 + 1 + 8);
+        let from_bin = ::bincode::deserialize::<Tagged>(&bin).unwrap();
+        assert_eq!(unsafe { from_bin.u.big }, 0x1234_5678_9abc);
+
+        let tagged = Tagged {
+            tag: 4,
+            pad: 0,
+            u: Partial {
+                spanning: Spanning { b: [1, 2, 3, 4] },
+            },
+        };
+        let json = ::serde_json::to_string(&tagged).unwrap();
+        let from_json = ::serde_json::from_str::<Tagged>(&json).unwrap();
+        assert_eq!(unsafe { from_json.u.big }, 0x0403_0201);
+
+        // The member of an unknown tag isn't serialized and stays zeroed.
+        let tagged = Tagged {
+            tag: 5,
+            pad: 0,
+            u: Partial { small: 0x12 },
+        };
+        let json = ::serde_json::to_string(&tagged).unwrap();
+        assert_eq!(json, "[5,0,null]");
+        let from_json = ::serde_json::from_str::<Tagged>(&json).unwrap();
+        assert_eq!(unsafe { from_json.u.big }, 0);
+        assert!(::serde_json::from_str::<Tagged>("[5,0,18]").is_err());
+        assert!(::serde_json::from_str::<Tagged>("[1,0]").is_err());
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        assert!(::serde_json::from_str::<Blob>("[1, 2, 3]").is_err());
+        let too_long = vec![0u8; size_of::<Blob>() + 1];
+        assert!(
+            ::serde_json::from_str::<Blob>(&::serde_json::to_string(&too_long).unwrap()).is_err()
+        );
+        let bin = ::bincode::serialize(&vec![0u8; 4]).unwrap();
+        assert!(::bincode::deserialize::<Blob>(&bin).is_err());
+    }
+}
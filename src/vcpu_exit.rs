@@ -0,0 +1,599 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Typed decoding of the exit information stored in `kvm_run`.
+//!
+//! After `KVM_RUN` returns, `kvm_run::exit_reason` tells which member of the
+//! anonymous union in `kvm_run` is valid. [`VcpuExit`](enum.VcpuExit.html)
+//! reads the right member and borrows the exit data from the run page.
+
+use std::cmp::min;
+use std::fmt;
+use std::mem::{align_of, size_of};
+
+use super::*;
+
+/// Names the flags of a `KVM_EXIT_SYSTEM_EVENT` exit in the default bindings.
+/// Since Linux 5.18 they share a union with the event data.
+#[cfg(all(
+    not(target_arch = "arm"),
+    any(
+        feature = "kvm-v6_1_0",
+        feature = "kvm-v6_6_0",
+        not(any(
+            feature = "kvm-v4_14_0",
+            feature = "kvm-v4_20_0",
+            feature = "kvm-v5_4_0",
+            feature = "kvm-v5_10_0",
+            feature = "kvm-v5_15_0"
+        ))
+    )
+))]
+macro_rules! system_event_flags {
+    ($event:expr) => {
+        $event.__bindgen_anon_1.flags
+    };
+}
+
+/// Names the flags of a `KVM_EXIT_SYSTEM_EVENT` exit in the default bindings.
+#[cfg(not(all(
+    not(target_arch = "arm"),
+    any(
+        feature = "kvm-v6_1_0",
+        feature = "kvm-v6_6_0",
+        not(any(
+            feature = "kvm-v4_14_0",
+            feature = "kvm-v4_20_0",
+            feature = "kvm-v5_4_0",
+            feature = "kvm-v5_10_0",
+            feature = "kvm-v5_15_0"
+        ))
+    )
+)))]
+macro_rules! system_event_flags {
+    ($event:expr) => {
+        $event.flags
+    };
+}
+
+/// Errors associated with decoding a `kvm_run` exit.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The mapping passed along the `kvm_run` is smaller than `kvm_run` itself.
+    RunMmapTooSmall,
+    /// The mapping passed along the `kvm_run` is not aligned for `kvm_run`.
+    RunMmapMisaligned,
+    /// The IO data described by `io.data_offset` is outside of the run mapping.
+    IoDataOutOfBounds,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::RunMmapTooSmall => write!(f, "the run mapping is smaller than kvm_run"),
+            Error::RunMmapMisaligned => write!(f, "the run mapping is not aligned for kvm_run"),
+            Error::IoDataOutOfBounds => write!(f, "the IO data is outside of the run mapping"),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// Hyper-V specific exits, reported through `KVM_EXIT_HYPERV`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HypervExit<'a> {
+    /// `KVM_EXIT_HYPERV_SYNIC`: the guest changed a SynIC MSR.
+    Synic {
+        msr: u32,
+        control: u64,
+        evt_page: u64,
+        msg_page: u64,
+    },
+    /// `KVM_EXIT_HYPERV_HCALL`: the guest issued a Hyper-V hypercall.
+    Hcall {
+        input: u64,
+        result: u64,
+        params: &'a [u64; 2],
+    },
+    /// A Hyper-V exit type unknown to these bindings.
+    Unsupported(u32),
+}
+
+/// A decoded `kvm_run` exit.
+#[derive(Debug, PartialEq)]
+pub enum VcpuExit<'a> {
+    /// `KVM_EXIT_UNKNOWN`.
+    Unknown { hardware_exit_reason: u64 },
+    /// `KVM_EXIT_EXCEPTION`.
+    Exception { exception: u32, error_code: u32 },
+    /// `KVM_EXIT_IO` with `KVM_EXIT_IO_IN`. `data` is the buffer the VMM has to fill.
+    IoIn {
+        port: u16,
+        size: u8,
+        count: u32,
+        data: &'a mut [u8],
+    },
+    /// `KVM_EXIT_IO` with `KVM_EXIT_IO_OUT`.
+    IoOut {
+        port: u16,
+        size: u8,
+        count: u32,
+        data: &'a [u8],
+    },
+    /// `KVM_EXIT_HYPERCALL`.
+    Hypercall {
+        nr: u64,
+        args: &'a [u64; 6],
+        ret: u64,
+        longmode: u32,
+    },
+    /// `KVM_EXIT_DEBUG`.
+    Debug(&'a kvm_debug_exit_arch),
+    /// `KVM_EXIT_HLT`.
+    Hlt,
+    /// `KVM_EXIT_MMIO` for a read. `data` is the buffer the VMM has to fill.
+    MmioRead { phys_addr: u64, data: &'a mut [u8] },
+    /// `KVM_EXIT_MMIO` for a write.
+    MmioWrite { phys_addr: u64, data: &'a [u8] },
+    /// `KVM_EXIT_IRQ_WINDOW_OPEN`.
+    IrqWindowOpen,
+    /// `KVM_EXIT_SHUTDOWN`.
+    Shutdown,
+    /// `KVM_EXIT_FAIL_ENTRY`.
+    FailEntry { hardware_entry_failure_reason: u64 },
+    /// `KVM_EXIT_INTR`.
+    Intr,
+    /// `KVM_EXIT_SET_TPR`.
+    SetTpr,
+    /// `KVM_EXIT_TPR_ACCESS`.
+    TprAccess { rip: u64, is_write: bool },
+    /// `KVM_EXIT_S390_SIEIC`.
+    S390Sieic { icptcode: u8, ipa: u16, ipb: u32 },
+    /// `KVM_EXIT_S390_RESET`.
+    S390Reset { flags: u64 },
+    /// `KVM_EXIT_DCR`.
+    Dcr {
+        dcrn: u32,
+        data: u32,
+        is_write: bool,
+    },
+    /// `KVM_EXIT_NMI`.
+    Nmi,
+    /// `KVM_EXIT_INTERNAL_ERROR`. `data` holds the first `ndata` words.
+    InternalError { suberror: u32, data: &'a [u64] },
+    /// `KVM_EXIT_OSI`.
+    Osi { gprs: &'a [u64; 32] },
+    /// `KVM_EXIT_PAPR_HCALL`.
+    PaprHcall {
+        nr: u64,
+        ret: u64,
+        args: &'a [u64; 9],
+    },
+    /// `KVM_EXIT_S390_UCONTROL`.
+    S390Ucontrol { trans_exc_code: u64, pgm_code: u32 },
+    /// `KVM_EXIT_WATCHDOG`.
+    Watchdog,
+    /// `KVM_EXIT_S390_TSCH`.
+    S390Tsch {
+        subchannel_id: u16,
+        subchannel_nr: u16,
+        io_int_parm: u32,
+        io_int_word: u32,
+        ipb: u32,
+        dequeued: bool,
+    },
+    /// `KVM_EXIT_EPR`.
+    Epr { epr: u32 },
+    /// `KVM_EXIT_SYSTEM_EVENT`, with one of the `KVM_SYSTEM_EVENT_*` types.
+    SystemEvent { type_: u32, flags: u64 },
+    /// `KVM_EXIT_S390_STSI`.
+    S390Stsi {
+        addr: u64,
+        ar: u8,
+        fc: u8,
+        sel1: u8,
+        sel2: u16,
+    },
+    /// `KVM_EXIT_IOAPIC_EOI`.
+    IoapicEoi { vector: u8 },
+    /// `KVM_EXIT_HYPERV`.
+    Hyperv(HypervExit<'a>),
+    /// An exit reason unknown to these bindings.
+    Unsupported(u32),
+}
+
+impl<'a> VcpuExit<'a> {
+    /// Decodes the exit stored in the run page.
+    ///
+    /// `run_mmap` is the whole mapping of the vCPU file descriptor, which
+    /// starts with `kvm_run` and holds the data of IO exits. The data of
+    /// `IoIn` and `MmioRead` exits is borrowed mutably so that the VMM can
+    /// write its response before the next `KVM_RUN`.
+    pub fn new(run_mmap: &'a mut [u8]) -> Result<VcpuExit<'a>, Error> {
+        if run_mmap.len() < size_of::<kvm_run>() {
+            return Err(Error::RunMmapTooSmall);
+        }
+        if !(run_mmap.as_ptr() as usize).is_multiple_of(align_of::<kvm_run>()) {
+            return Err(Error::RunMmapMisaligned);
+        }
+        let run = run_mmap.as_mut_ptr() as *mut kvm_run;
+
+        // Safe because the mapping is large and aligned enough for `kvm_run`,
+        // which is plain old data.
+        let exit_reason = unsafe { (*run).exit_reason };
+        // The exits with mutable data are decoded through the raw pointer, so
+        // that no other reference into the run page outlives the data.
+        match exit_reason {
+            KVM_EXIT_IO => {
+                // Safe because `io` is the union member selected by `KVM_EXIT_IO`.
+                let io = unsafe { (*run).__bindgen_anon_1.io };
+                let len = io.size as usize * io.count as usize;
+                let start = io.data_offset as usize;
+                let data = start
+                    .checked_add(len)
+                    .and_then(move |end| run_mmap.get_mut(start..end))
+                    .ok_or(Error::IoDataOutOfBounds)?;
+                let exit = match u32::from(io.direction) {
+                    KVM_EXIT_IO_OUT => VcpuExit::IoOut {
+                        port: io.port,
+                        size: io.size,
+                        count: io.count,
+                        data,
+                    },
+                    _ => VcpuExit::IoIn {
+                        port: io.port,
+                        size: io.size,
+                        count: io.count,
+                        data,
+                    },
+                };
+                return Ok(exit);
+            }
+            KVM_EXIT_MMIO => {
+                // Safe because `mmio` is the union member selected by
+                // `KVM_EXIT_MMIO`, and `run_mmap` is borrowed mutably for `'a`.
+                let mmio = unsafe { &mut (*run).__bindgen_anon_1.mmio };
+                let len = min(mmio.len as usize, mmio.data.len());
+                let exit = if mmio.is_write != 0 {
+                    VcpuExit::MmioWrite {
+                        phys_addr: mmio.phys_addr,
+                        data: &mmio.data[..len],
+                    }
+                } else {
+                    VcpuExit::MmioRead {
+                        phys_addr: mmio.phys_addr,
+                        data: &mut mmio.data[..len],
+                    }
+                };
+                return Ok(exit);
+            }
+            _ => (),
+        }
+
+        // Safe because the union member read in each arm is the one selected by
+        // `exit_reason`, and all members are plain old data.
+        let exit = unsafe {
+            let u = &(*run).__bindgen_anon_1;
+            match exit_reason {
+                KVM_EXIT_UNKNOWN => VcpuExit::Unknown {
+                    hardware_exit_reason: u.hw.hardware_exit_reason,
+                },
+                KVM_EXIT_EXCEPTION => VcpuExit::Exception {
+                    exception: u.ex.exception,
+                    error_code: u.ex.error_code,
+                },
+                KVM_EXIT_HYPERCALL => VcpuExit::Hypercall {
+                    nr: u.hypercall.nr,
+                    args: &u.hypercall.args,
+                    ret: u.hypercall.ret,
+                    longmode: u.hypercall.longmode,
+                },
+                KVM_EXIT_DEBUG => VcpuExit::Debug(&u.debug.arch),
+                KVM_EXIT_HLT => VcpuExit::Hlt,
+                KVM_EXIT_IRQ_WINDOW_OPEN => VcpuExit::IrqWindowOpen,
+                KVM_EXIT_SHUTDOWN => VcpuExit::Shutdown,
+                KVM_EXIT_FAIL_ENTRY => VcpuExit::FailEntry {
+                    hardware_entry_failure_reason: u.fail_entry.hardware_entry_failure_reason,
+                },
+                KVM_EXIT_INTR => VcpuExit::Intr,
+                KVM_EXIT_SET_TPR => VcpuExit::SetTpr,
+                KVM_EXIT_TPR_ACCESS => VcpuExit::TprAccess {
+                    rip: u.tpr_access.rip,
+                    is_write: u.tpr_access.is_write != 0,
+                },
+                KVM_EXIT_S390_SIEIC => VcpuExit::S390Sieic {
+                    icptcode: u.s390_sieic.icptcode,
+                    ipa: u.s390_sieic.ipa,
+                    ipb: u.s390_sieic.ipb,
+                },
+                KVM_EXIT_S390_RESET => VcpuExit::S390Reset {
+                    flags: u.s390_reset_flags,
+                },
+                KVM_EXIT_DCR => VcpuExit::Dcr {
+                    dcrn: u.dcr.dcrn,
+                    data: u.dcr.data,
+                    is_write: u.dcr.is_write != 0,
+                },
+                KVM_EXIT_NMI => VcpuExit::Nmi,
+                KVM_EXIT_INTERNAL_ERROR => {
+                    let internal = &u.internal;
+                    let ndata = min(internal.ndata as usize, internal.data.len());
+                    VcpuExit::InternalError {
+                        suberror: internal.suberror,
+                        data: &internal.data[..ndata],
+                    }
+                }
+                KVM_EXIT_OSI => VcpuExit::Osi { gprs: &u.osi.gprs },
+                KVM_EXIT_PAPR_HCALL => VcpuExit::PaprHcall {
+                    nr: u.papr_hcall.nr,
+                    ret: u.papr_hcall.ret,
+                    args: &u.papr_hcall.args,
+                },
+                KVM_EXIT_S390_UCONTROL => VcpuExit::S390Ucontrol {
+                    trans_exc_code: u.s390_ucontrol.trans_exc_code,
+                    pgm_code: u.s390_ucontrol.pgm_code,
+                },
+                KVM_EXIT_WATCHDOG => VcpuExit::Watchdog,
+                KVM_EXIT_S390_TSCH => VcpuExit::S390Tsch {
+                    subchannel_id: u.s390_tsch.subchannel_id,
+                    subchannel_nr: u.s390_tsch.subchannel_nr,
+                    io_int_parm: u.s390_tsch.io_int_parm,
+                    io_int_word: u.s390_tsch.io_int_word,
+                    ipb: u.s390_tsch.ipb,
+                    dequeued: u.s390_tsch.dequeued != 0,
+                },
+                KVM_EXIT_EPR => VcpuExit::Epr { epr: u.epr.epr },
+                KVM_EXIT_SYSTEM_EVENT => VcpuExit::SystemEvent {
+                    type_: u.system_event.type_,
+                    flags: system_event_flags!(u.system_event),
+                },
+                KVM_EXIT_S390_STSI => VcpuExit::S390Stsi {
+                    addr: u.s390_stsi.addr,
+                    ar: u.s390_stsi.ar,
+                    fc: u.s390_stsi.fc,
+                    sel1: u.s390_stsi.sel1,
+                    sel2: u.s390_stsi.sel2,
+                },
+                KVM_EXIT_IOAPIC_EOI => VcpuExit::IoapicEoi {
+                    vector: u.eoi.vector,
+                },
+                KVM_EXIT_HYPERV => VcpuExit::Hyperv(HypervExit::from(&u.hyperv)),
+                reason => VcpuExit::Unsupported(reason),
+            }
+        };
+        Ok(exit)
+    }
+}
+
+impl<'a> From<&'a kvm_hyperv_exit> for HypervExit<'a> {
+    fn from(exit: &'a kvm_hyperv_exit) -> Self {
+        // Safe because the union member read in each arm is the one selected by `type_`.
+        unsafe {
+            match exit.type_ {
+                KVM_EXIT_HYPERV_SYNIC => HypervExit::Synic {
+                    msr: exit.u.synic.msr,
+                    control: exit.u.synic.control,
+                    evt_page: exit.u.synic.evt_page,
+                    msg_page: exit.u.synic.msg_page,
+                },
+                KVM_EXIT_HYPERV_HCALL => HypervExit::Hcall {
+                    input: exit.u.hcall.input,
+                    result: exit.u.hcall.result,
+                    params: &exit.u.hcall.params,
+                },
+                type_ => HypervExit::Unsupported(type_),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::slice;
+
+    // A zeroed buffer standing in for the mmap'ed run page, aligned for `kvm_run`.
+    struct RunPage(Vec<u64>);
+
+    impl RunPage {
+        fn new() -> Self {
+            RunPage(vec![0; 4096 / size_of::<u64>()])
+        }
+
+        fn run(&mut self) -> &mut kvm_run {
+            unsafe { &mut *(self.0.as_mut_ptr() as *mut kvm_run) }
+        }
+
+        fn bytes_mut(&mut self) -> &mut [u8] {
+            unsafe { slice::from_raw_parts_mut(self.0.as_mut_ptr() as *mut u8, 4096) }
+        }
+
+        fn decode(&mut self) -> Result<VcpuExit<'_>, Error> {
+            VcpuExit::new(self.bytes_mut())
+        }
+    }
+
+    #[test]
+    fn test_io_exit() {
+        let mut page = RunPage::new();
+        {
+            let run = page.run();
+            run.exit_reason = KVM_EXIT_IO;
+            run.__bindgen_anon_1.io.direction = KVM_EXIT_IO_OUT as u8;
+            run.__bindgen_anon_1.io.size = 2;
+            run.__bindgen_anon_1.io.port = 0x3f8;
+            run.__bindgen_anon_1.io.count = 2;
+            run.__bindgen_anon_1.io.data_offset = 0x800;
+        }
+        page.bytes_mut()[0x800..0x804].copy_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(
+            page.decode().unwrap(),
+            VcpuExit::IoOut {
+                port: 0x3f8,
+                size: 2,
+                count: 2,
+                data: &[1, 2, 3, 4],
+            }
+        );
+
+        // The response to an IN lands in the run page.
+        page.run().__bindgen_anon_1.io.direction = KVM_EXIT_IO_IN as u8;
+        match page.decode().unwrap() {
+            VcpuExit::IoIn { port, data, .. } => {
+                assert_eq!(port, 0x3f8);
+                data.copy_from_slice(&[5, 6, 7, 8]);
+            }
+            exit => panic!("unexpected exit {:?}", exit),
+        }
+        assert_eq!(page.bytes_mut()[0x800..0x804], [5, 6, 7, 8]);
+
+        page.run().__bindgen_anon_1.io.data_offset = 4095;
+        assert_eq!(page.decode().unwrap_err(), Error::IoDataOutOfBounds);
+        page.run().__bindgen_anon_1.io.data_offset = u64::MAX;
+        assert_eq!(page.decode().unwrap_err(), Error::IoDataOutOfBounds);
+    }
+
+    #[test]
+    fn test_mmio_exit() {
+        let mut page = RunPage::new();
+        {
+            let run = page.run();
+            run.exit_reason = KVM_EXIT_MMIO;
+            run.__bindgen_anon_1.mmio.phys_addr = 0xd000_0000;
+            run.__bindgen_anon_1.mmio.len = 4;
+            run.__bindgen_anon_1.mmio.data = [1, 2, 3, 4, 5, 6, 7, 8];
+        }
+        match page.decode().unwrap() {
+            VcpuExit::MmioRead { phys_addr, data } => {
+                assert_eq!(phys_addr, 0xd000_0000);
+                assert_eq!(data.len(), 4);
+                data.copy_from_slice(&[0xa, 0xb, 0xc, 0xd]);
+            }
+            exit => panic!("unexpected exit {:?}", exit),
+        }
+        assert_eq!(
+            unsafe { page.run().__bindgen_anon_1.mmio.data },
+            [0xa, 0xb, 0xc, 0xd, 5, 6, 7, 8]
+        );
+        page.run().__bindgen_anon_1.mmio.is_write = 1;
+        assert_eq!(
+            page.decode().unwrap(),
+            VcpuExit::MmioWrite {
+                phys_addr: 0xd000_0000,
+                data: &[0xa, 0xb, 0xc, 0xd]
+            }
+        );
+    }
+
+    #[test]
+    fn test_internal_error_exit() {
+        let mut page = RunPage::new();
+        {
+            let run = page.run();
+            run.exit_reason = KVM_EXIT_INTERNAL_ERROR;
+            run.__bindgen_anon_1.internal.suberror = KVM_INTERNAL_ERROR_EMULATION;
+            run.__bindgen_anon_1.internal.ndata = 2;
+            let mut data = [0; 16];
+            data[..2].copy_from_slice(&[0xaa, 0xbb]);
+            run.__bindgen_anon_1.internal.data = data;
+        }
+        assert_eq!(
+            page.decode().unwrap(),
+            VcpuExit::InternalError {
+                suberror: KVM_INTERNAL_ERROR_EMULATION,
+                data: &[0xaa, 0xbb],
+            }
+        );
+        page.run().__bindgen_anon_1.internal.ndata = 1000;
+        match page.decode().unwrap() {
+            VcpuExit::InternalError { data, .. } => assert_eq!(data.len(), 16),
+            exit => panic!("unexpected exit {:?}", exit),
+        }
+    }
+
+    #[test]
+    fn test_simple_exits() {
+        let mut page = RunPage::new();
+        page.run().exit_reason = KVM_EXIT_UNKNOWN;
+        page.run().__bindgen_anon_1.hw.hardware_exit_reason = 0x31;
+        assert_eq!(
+            page.decode().unwrap(),
+            VcpuExit::Unknown {
+                hardware_exit_reason: 0x31
+            }
+        );
+
+        page.run().exit_reason = KVM_EXIT_FAIL_ENTRY;
+        assert_eq!(
+            page.decode().unwrap(),
+            VcpuExit::FailEntry {
+                hardware_entry_failure_reason: 0x31
+            }
+        );
+
+        page.run().exit_reason = KVM_EXIT_SYSTEM_EVENT;
+        page.run().__bindgen_anon_1.system_event.type_ = KVM_SYSTEM_EVENT_RESET;
+        system_event_flags!(page.run().__bindgen_anon_1.system_event) = 1;
+        assert_eq!(
+            page.decode().unwrap(),
+            VcpuExit::SystemEvent {
+                type_: KVM_SYSTEM_EVENT_RESET,
+                flags: 1
+            }
+        );
+
+        page.run().exit_reason = KVM_EXIT_IOAPIC_EOI;
+        page.run().__bindgen_anon_1.eoi.vector = 0x20;
+        assert_eq!(page.decode().unwrap(), VcpuExit::IoapicEoi { vector: 0x20 });
+
+        page.run().exit_reason = KVM_EXIT_HLT;
+        assert_eq!(page.decode().unwrap(), VcpuExit::Hlt);
+
+        page.run().exit_reason = 0xffff;
+        assert_eq!(page.decode().unwrap(), VcpuExit::Unsupported(0xffff));
+    }
+
+    #[test]
+    fn test_hypercall_and_hyperv_exits() {
+        let mut page = RunPage::new();
+        page.run().exit_reason = KVM_EXIT_HYPERCALL;
+        page.run().__bindgen_anon_1.hypercall.nr = 9;
+        page.run().__bindgen_anon_1.hypercall.args = [1, 2, 3, 4, 5, 6];
+        assert_eq!(
+            page.decode().unwrap(),
+            VcpuExit::Hypercall {
+                nr: 9,
+                args: &[1, 2, 3, 4, 5, 6],
+                ret: 0,
+                longmode: 0,
+            }
+        );
+
+        page.run().exit_reason = KVM_EXIT_HYPERV;
+        page.run().__bindgen_anon_1.hyperv.type_ = KVM_EXIT_HYPERV_HCALL;
+        page.run().__bindgen_anon_1.hyperv.u.hcall.input = 0x12;
+        page.run().__bindgen_anon_1.hyperv.u.hcall.result = 0;
+        page.run().__bindgen_anon_1.hyperv.u.hcall.params = [7, 8];
+        assert_eq!(
+            page.decode().unwrap(),
+            VcpuExit::Hyperv(HypervExit::Hcall {
+                input: 0x12,
+                result: 0,
+                params: &[7, 8],
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_run_mmap() {
+        let mut page = RunPage::new();
+        assert_eq!(
+            VcpuExit::new(&mut page.bytes_mut()[..16]).unwrap_err(),
+            Error::RunMmapTooSmall
+        );
+        assert_eq!(
+            VcpuExit::new(&mut page.bytes_mut()[1..]).unwrap_err(),
+            Error::RunMmapMisaligned
+        );
+    }
+}
@@ -515,18 +515,18 @@ pub const KVM_X2APIC_API_DISABLE_BROADCAST_QUIRK: u32 = 2;
 pub const KVM_ARM_DEV_EL1_VTIMER: u32 = 1;
 pub const KVM_ARM_DEV_EL1_PTIMER: u32 = 2;
 pub const KVM_ARM_DEV_PMU: u32 = 4;
-pub type __s8 = ::std::os::raw::c_schar;
-pub type __u8 = ::std::os::raw::c_uchar;
-pub type __s16 = ::std::os::raw::c_short;
-pub type __u16 = ::std::os::raw::c_ushort;
-pub type __s32 = ::std::os::raw::c_int;
-pub type __u32 = ::std::os::raw::c_uint;
-pub type __s64 = ::std::os::raw::c_longlong;
-pub type __u64 = ::std::os::raw::c_ulonglong;
+pub type __s8 = super::raw::c_schar;
+pub type __u8 = super::raw::c_uchar;
+pub type __s16 = super::raw::c_short;
+pub type __u16 = super::raw::c_ushort;
+pub type __s32 = super::raw::c_int;
+pub type __u32 = super::raw::c_uint;
+pub type __s64 = super::raw::c_longlong;
+pub type __u64 = super::raw::c_ulonglong;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct __kernel_fd_set {
-    pub fds_bits: [::std::os::raw::c_ulong; 16usize],
+    pub fds_bits: [super::raw::c_ulong; 16usize],
 }
 #[test]
 fn bindgen_test_layout___kernel_fd_set() {
@@ -541,7 +541,7 @@ fn bindgen_test_layout___kernel_fd_set() {
         concat!("Alignment of ", stringify!(__kernel_fd_set))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__kernel_fd_set>())).fds_bits as *const _ as usize },
+        ::std::mem::offset_of!(__kernel_fd_set, fds_bits),
         0usize,
         concat!(
             "Offset of field: ",
@@ -552,31 +552,31 @@ fn bindgen_test_layout___kernel_fd_set() {
     );
 }
 pub type __kernel_sighandler_t =
-    ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>;
-pub type __kernel_key_t = ::std::os::raw::c_int;
-pub type __kernel_mqd_t = ::std::os::raw::c_int;
-pub type __kernel_old_uid_t = ::std::os::raw::c_ushort;
-pub type __kernel_old_gid_t = ::std::os::raw::c_ushort;
-pub type __kernel_old_dev_t = ::std::os::raw::c_ulong;
-pub type __kernel_long_t = ::std::os::raw::c_long;
-pub type __kernel_ulong_t = ::std::os::raw::c_ulong;
+    ::std::option::Option<unsafe extern "C" fn(arg1: super::raw::c_int)>;
+pub type __kernel_key_t = super::raw::c_int;
+pub type __kernel_mqd_t = super::raw::c_int;
+pub type __kernel_old_uid_t = super::raw::c_ushort;
+pub type __kernel_old_gid_t = super::raw::c_ushort;
+pub type __kernel_old_dev_t = super::raw::c_ulong;
+pub type __kernel_long_t = super::raw::c_long;
+pub type __kernel_ulong_t = super::raw::c_ulong;
 pub type __kernel_ino_t = __kernel_ulong_t;
-pub type __kernel_mode_t = ::std::os::raw::c_uint;
-pub type __kernel_pid_t = ::std::os::raw::c_int;
-pub type __kernel_ipc_pid_t = ::std::os::raw::c_int;
-pub type __kernel_uid_t = ::std::os::raw::c_uint;
-pub type __kernel_gid_t = ::std::os::raw::c_uint;
+pub type __kernel_mode_t = super::raw::c_uint;
+pub type __kernel_pid_t = super::raw::c_int;
+pub type __kernel_ipc_pid_t = super::raw::c_int;
+pub type __kernel_uid_t = super::raw::c_uint;
+pub type __kernel_gid_t = super::raw::c_uint;
 pub type __kernel_suseconds_t = __kernel_long_t;
-pub type __kernel_daddr_t = ::std::os::raw::c_int;
-pub type __kernel_uid32_t = ::std::os::raw::c_uint;
-pub type __kernel_gid32_t = ::std::os::raw::c_uint;
+pub type __kernel_daddr_t = super::raw::c_int;
+pub type __kernel_uid32_t = super::raw::c_uint;
+pub type __kernel_gid32_t = super::raw::c_uint;
 pub type __kernel_size_t = __kernel_ulong_t;
 pub type __kernel_ssize_t = __kernel_long_t;
 pub type __kernel_ptrdiff_t = __kernel_long_t;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct __kernel_fsid_t {
-    pub val: [::std::os::raw::c_int; 2usize],
+    pub val: [super::raw::c_int; 2usize],
 }
 #[test]
 fn bindgen_test_layout___kernel_fsid_t() {
@@ -591,7 +591,7 @@ fn bindgen_test_layout___kernel_fsid_t() {
         concat!("Alignment of ", stringify!(__kernel_fsid_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__kernel_fsid_t>())).val as *const _ as usize },
+        ::std::mem::offset_of!(__kernel_fsid_t, val),
         0usize,
         concat!(
             "Offset of field: ",
@@ -602,14 +602,14 @@ fn bindgen_test_layout___kernel_fsid_t() {
     );
 }
 pub type __kernel_off_t = __kernel_long_t;
-pub type __kernel_loff_t = ::std::os::raw::c_longlong;
+pub type __kernel_loff_t = super::raw::c_longlong;
 pub type __kernel_time_t = __kernel_long_t;
 pub type __kernel_clock_t = __kernel_long_t;
-pub type __kernel_timer_t = ::std::os::raw::c_int;
-pub type __kernel_clockid_t = ::std::os::raw::c_int;
-pub type __kernel_caddr_t = *mut ::std::os::raw::c_char;
-pub type __kernel_uid16_t = ::std::os::raw::c_ushort;
-pub type __kernel_gid16_t = ::std::os::raw::c_ushort;
+pub type __kernel_timer_t = super::raw::c_int;
+pub type __kernel_clockid_t = super::raw::c_int;
+pub type __kernel_caddr_t = *mut super::raw::c_char;
+pub type __kernel_uid16_t = super::raw::c_ushort;
+pub type __kernel_gid16_t = super::raw::c_ushort;
 pub type __le16 = __u16;
 pub type __be16 = __u16;
 pub type __le32 = __u32;
@@ -640,7 +640,7 @@ fn bindgen_test_layout_kvm_memory_alias() {
         concat!("Alignment of ", stringify!(kvm_memory_alias))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_alias>())).slot as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_alias, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -650,7 +650,7 @@ fn bindgen_test_layout_kvm_memory_alias() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_alias>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_alias, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -660,9 +660,7 @@ fn bindgen_test_layout_kvm_memory_alias() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_memory_alias>())).guest_phys_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_memory_alias, guest_phys_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -672,7 +670,7 @@ fn bindgen_test_layout_kvm_memory_alias() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_alias>())).memory_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_alias, memory_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -682,9 +680,7 @@ fn bindgen_test_layout_kvm_memory_alias() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_memory_alias>())).target_phys_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_memory_alias, target_phys_addr),
         24usize,
         concat!(
             "Offset of field: ",
@@ -727,7 +723,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         concat!("Alignment of ", stringify!(kvm_pic_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).last_irr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, last_irr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -737,7 +733,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).irr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, irr),
         1usize,
         concat!(
             "Offset of field: ",
@@ -747,7 +743,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).imr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, imr),
         2usize,
         concat!(
             "Offset of field: ",
@@ -757,7 +753,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).isr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, isr),
         3usize,
         concat!(
             "Offset of field: ",
@@ -767,7 +763,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).priority_add as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, priority_add),
         4usize,
         concat!(
             "Offset of field: ",
@@ -777,7 +773,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).irq_base as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, irq_base),
         5usize,
         concat!(
             "Offset of field: ",
@@ -787,7 +783,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).read_reg_select as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, read_reg_select),
         6usize,
         concat!(
             "Offset of field: ",
@@ -797,7 +793,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).poll as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, poll),
         7usize,
         concat!(
             "Offset of field: ",
@@ -807,7 +803,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).special_mask as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, special_mask),
         8usize,
         concat!(
             "Offset of field: ",
@@ -817,7 +813,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).init_state as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, init_state),
         9usize,
         concat!(
             "Offset of field: ",
@@ -827,7 +823,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).auto_eoi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, auto_eoi),
         10usize,
         concat!(
             "Offset of field: ",
@@ -837,9 +833,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pic_state>())).rotate_on_auto_eoi as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pic_state, rotate_on_auto_eoi),
         11usize,
         concat!(
             "Offset of field: ",
@@ -849,9 +843,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pic_state>())).special_fully_nested_mode as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pic_state, special_fully_nested_mode),
         12usize,
         concat!(
             "Offset of field: ",
@@ -861,7 +853,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).init4 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, init4),
         13usize,
         concat!(
             "Offset of field: ",
@@ -871,7 +863,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).elcr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, elcr),
         14usize,
         concat!(
             "Offset of field: ",
@@ -881,7 +873,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).elcr_mask as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, elcr_mask),
         15usize,
         concat!(
             "Offset of field: ",
@@ -935,10 +927,7 @@ fn bindgen_test_layout_kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1>())).vector
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1, vector),
         0usize,
         concat!(
             "Offset of field: ",
@@ -948,10 +937,7 @@ fn bindgen_test_layout_kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1>())).reserved
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1, reserved),
         3usize,
         concat!(
             "Offset of field: ",
@@ -961,10 +947,7 @@ fn bindgen_test_layout_kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1>())).dest_id
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1, dest_id),
         7usize,
         concat!(
             "Offset of field: ",
@@ -1124,9 +1107,7 @@ fn bindgen_test_layout_kvm_ioapic_state__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_ioapic_state__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ioapic_state__bindgen_ty_1>())).bits as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ioapic_state__bindgen_ty_1, bits),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1136,9 +1117,7 @@ fn bindgen_test_layout_kvm_ioapic_state__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ioapic_state__bindgen_ty_1>())).fields as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ioapic_state__bindgen_ty_1, fields),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1166,7 +1145,7 @@ fn bindgen_test_layout_kvm_ioapic_state() {
         concat!("Alignment of ", stringify!(kvm_ioapic_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioapic_state>())).base_address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioapic_state, base_address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1176,7 +1155,7 @@ fn bindgen_test_layout_kvm_ioapic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioapic_state>())).ioregsel as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioapic_state, ioregsel),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1186,7 +1165,7 @@ fn bindgen_test_layout_kvm_ioapic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioapic_state>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioapic_state, id),
         12usize,
         concat!(
             "Offset of field: ",
@@ -1196,7 +1175,7 @@ fn bindgen_test_layout_kvm_ioapic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioapic_state>())).irr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioapic_state, irr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1206,7 +1185,7 @@ fn bindgen_test_layout_kvm_ioapic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioapic_state>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioapic_state, pad),
         20usize,
         concat!(
             "Offset of field: ",
@@ -1216,7 +1195,7 @@ fn bindgen_test_layout_kvm_ioapic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioapic_state>())).redirtbl as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioapic_state, redirtbl),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1266,7 +1245,7 @@ fn bindgen_test_layout_kvm_regs() {
         concat!("Alignment of ", stringify!(kvm_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rax as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rax),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1276,7 +1255,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rbx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rbx),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1286,7 +1265,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rcx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rcx),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1296,7 +1275,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rdx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rdx),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1306,7 +1285,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rsi),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1316,7 +1295,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rdi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rdi),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1326,7 +1305,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rsp as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rsp),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1336,7 +1315,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rbp as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rbp),
         56usize,
         concat!(
             "Offset of field: ",
@@ -1346,7 +1325,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r8 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r8),
         64usize,
         concat!(
             "Offset of field: ",
@@ -1356,7 +1335,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r9 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r9),
         72usize,
         concat!(
             "Offset of field: ",
@@ -1366,7 +1345,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r10 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r10),
         80usize,
         concat!(
             "Offset of field: ",
@@ -1376,7 +1355,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r11 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r11),
         88usize,
         concat!(
             "Offset of field: ",
@@ -1386,7 +1365,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r12 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r12),
         96usize,
         concat!(
             "Offset of field: ",
@@ -1396,7 +1375,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r13 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r13),
         104usize,
         concat!(
             "Offset of field: ",
@@ -1406,7 +1385,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r14 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r14),
         112usize,
         concat!(
             "Offset of field: ",
@@ -1416,7 +1395,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r15 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r15),
         120usize,
         concat!(
             "Offset of field: ",
@@ -1426,7 +1405,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rip),
         128usize,
         concat!(
             "Offset of field: ",
@@ -1436,7 +1415,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rflags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rflags),
         136usize,
         concat!(
             "Offset of field: ",
@@ -1449,7 +1428,7 @@ fn bindgen_test_layout_kvm_regs() {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct kvm_lapic_state {
-    pub regs: [::std::os::raw::c_char; 1024usize],
+    pub regs: [super::raw::c_char; 1024usize],
 }
 #[test]
 fn bindgen_test_layout_kvm_lapic_state() {
@@ -1464,7 +1443,7 @@ fn bindgen_test_layout_kvm_lapic_state() {
         concat!("Alignment of ", stringify!(kvm_lapic_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_lapic_state>())).regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_lapic_state, regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1509,7 +1488,7 @@ fn bindgen_test_layout_kvm_segment() {
         concat!("Alignment of ", stringify!(kvm_segment))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).base as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1519,7 +1498,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).limit as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, limit),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1529,7 +1508,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).selector as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, selector),
         12usize,
         concat!(
             "Offset of field: ",
@@ -1539,7 +1518,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, type_),
         14usize,
         concat!(
             "Offset of field: ",
@@ -1549,7 +1528,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).present as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, present),
         15usize,
         concat!(
             "Offset of field: ",
@@ -1559,7 +1538,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).dpl as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, dpl),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1569,7 +1548,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).db as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, db),
         17usize,
         concat!(
             "Offset of field: ",
@@ -1579,7 +1558,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).s as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, s),
         18usize,
         concat!(
             "Offset of field: ",
@@ -1589,7 +1568,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).l as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, l),
         19usize,
         concat!(
             "Offset of field: ",
@@ -1599,7 +1578,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).g as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, g),
         20usize,
         concat!(
             "Offset of field: ",
@@ -1609,7 +1588,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).avl as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, avl),
         21usize,
         concat!(
             "Offset of field: ",
@@ -1619,7 +1598,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).unusable as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, unusable),
         22usize,
         concat!(
             "Offset of field: ",
@@ -1629,7 +1608,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, padding),
         23usize,
         concat!(
             "Offset of field: ",
@@ -1659,7 +1638,7 @@ fn bindgen_test_layout_kvm_dtable() {
         concat!("Alignment of ", stringify!(kvm_dtable))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dtable>())).base as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dtable, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1669,7 +1648,7 @@ fn bindgen_test_layout_kvm_dtable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dtable>())).limit as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dtable, limit),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1679,7 +1658,7 @@ fn bindgen_test_layout_kvm_dtable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dtable>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dtable, padding),
         10usize,
         concat!(
             "Offset of field: ",
@@ -1724,7 +1703,7 @@ fn bindgen_test_layout_kvm_sregs() {
         concat!("Alignment of ", stringify!(kvm_sregs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).cs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, cs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1734,7 +1713,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).ds as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, ds),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1744,7 +1723,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).es as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, es),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1754,7 +1733,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).fs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, fs),
         72usize,
         concat!(
             "Offset of field: ",
@@ -1764,7 +1743,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).gs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, gs),
         96usize,
         concat!(
             "Offset of field: ",
@@ -1774,7 +1753,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).ss as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, ss),
         120usize,
         concat!(
             "Offset of field: ",
@@ -1784,7 +1763,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).tr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, tr),
         144usize,
         concat!(
             "Offset of field: ",
@@ -1794,7 +1773,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).ldt as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, ldt),
         168usize,
         concat!(
             "Offset of field: ",
@@ -1804,7 +1783,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).gdt as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, gdt),
         192usize,
         concat!(
             "Offset of field: ",
@@ -1814,7 +1793,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).idt as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, idt),
         208usize,
         concat!(
             "Offset of field: ",
@@ -1824,7 +1803,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).cr0 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, cr0),
         224usize,
         concat!(
             "Offset of field: ",
@@ -1834,7 +1813,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).cr2 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, cr2),
         232usize,
         concat!(
             "Offset of field: ",
@@ -1844,7 +1823,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).cr3 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, cr3),
         240usize,
         concat!(
             "Offset of field: ",
@@ -1854,7 +1833,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).cr4 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, cr4),
         248usize,
         concat!(
             "Offset of field: ",
@@ -1864,7 +1843,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).cr8 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, cr8),
         256usize,
         concat!(
             "Offset of field: ",
@@ -1874,7 +1853,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).efer as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, efer),
         264usize,
         concat!(
             "Offset of field: ",
@@ -1884,7 +1863,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).apic_base as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, apic_base),
         272usize,
         concat!(
             "Offset of field: ",
@@ -1894,7 +1873,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).interrupt_bitmap as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, interrupt_bitmap),
         280usize,
         concat!(
             "Offset of field: ",
@@ -1932,7 +1911,7 @@ fn bindgen_test_layout_kvm_fpu() {
         concat!("Alignment of ", stringify!(kvm_fpu))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).fpr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, fpr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1942,7 +1921,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).fcw as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, fcw),
         128usize,
         concat!(
             "Offset of field: ",
@@ -1952,7 +1931,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).fsw as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, fsw),
         130usize,
         concat!(
             "Offset of field: ",
@@ -1962,7 +1941,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).ftwx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, ftwx),
         132usize,
         concat!(
             "Offset of field: ",
@@ -1972,7 +1951,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).pad1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, pad1),
         133usize,
         concat!(
             "Offset of field: ",
@@ -1982,7 +1961,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).last_opcode as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, last_opcode),
         134usize,
         concat!(
             "Offset of field: ",
@@ -1992,7 +1971,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).last_ip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, last_ip),
         136usize,
         concat!(
             "Offset of field: ",
@@ -2002,7 +1981,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).last_dp as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, last_dp),
         144usize,
         concat!(
             "Offset of field: ",
@@ -2012,7 +1991,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).xmm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, xmm),
         152usize,
         concat!(
             "Offset of field: ",
@@ -2022,7 +2001,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).mxcsr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, mxcsr),
         408usize,
         concat!(
             "Offset of field: ",
@@ -2032,7 +2011,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).pad2 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, pad2),
         412usize,
         concat!(
             "Offset of field: ",
@@ -2062,7 +2041,7 @@ fn bindgen_test_layout_kvm_msr_entry() {
         concat!("Alignment of ", stringify!(kvm_msr_entry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msr_entry>())).index as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msr_entry, index),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2072,7 +2051,7 @@ fn bindgen_test_layout_kvm_msr_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msr_entry>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msr_entry, reserved),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2082,7 +2061,7 @@ fn bindgen_test_layout_kvm_msr_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msr_entry>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msr_entry, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2112,7 +2091,7 @@ fn bindgen_test_layout_kvm_msrs() {
         concat!("Alignment of ", stringify!(kvm_msrs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msrs>())).nmsrs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msrs, nmsrs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2122,7 +2101,7 @@ fn bindgen_test_layout_kvm_msrs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msrs>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msrs, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2132,7 +2111,7 @@ fn bindgen_test_layout_kvm_msrs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msrs>())).entries as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msrs, entries),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2161,7 +2140,7 @@ fn bindgen_test_layout_kvm_msr_list() {
         concat!("Alignment of ", stringify!(kvm_msr_list))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msr_list>())).nmsrs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msr_list, nmsrs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2171,7 +2150,7 @@ fn bindgen_test_layout_kvm_msr_list() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msr_list>())).indices as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msr_list, indices),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2204,7 +2183,7 @@ fn bindgen_test_layout_kvm_cpuid_entry() {
         concat!("Alignment of ", stringify!(kvm_cpuid_entry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry>())).function as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry, function),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2214,7 +2193,7 @@ fn bindgen_test_layout_kvm_cpuid_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry>())).eax as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry, eax),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2224,7 +2203,7 @@ fn bindgen_test_layout_kvm_cpuid_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry>())).ebx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry, ebx),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2234,7 +2213,7 @@ fn bindgen_test_layout_kvm_cpuid_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry>())).ecx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry, ecx),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2244,7 +2223,7 @@ fn bindgen_test_layout_kvm_cpuid_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry>())).edx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry, edx),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2254,7 +2233,7 @@ fn bindgen_test_layout_kvm_cpuid_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry, padding),
         20usize,
         concat!(
             "Offset of field: ",
@@ -2284,7 +2263,7 @@ fn bindgen_test_layout_kvm_cpuid() {
         concat!("Alignment of ", stringify!(kvm_cpuid))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid>())).nent as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid, nent),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2294,7 +2273,7 @@ fn bindgen_test_layout_kvm_cpuid() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid, padding),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2304,7 +2283,7 @@ fn bindgen_test_layout_kvm_cpuid() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid>())).entries as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid, entries),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2339,7 +2318,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         concat!("Alignment of ", stringify!(kvm_cpuid_entry2))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).function as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, function),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2349,7 +2328,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).index as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, index),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2359,7 +2338,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2369,7 +2348,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).eax as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, eax),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2379,7 +2358,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).ebx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, ebx),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2389,7 +2368,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).ecx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, ecx),
         20usize,
         concat!(
             "Offset of field: ",
@@ -2399,7 +2378,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).edx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, edx),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2409,7 +2388,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, padding),
         28usize,
         concat!(
             "Offset of field: ",
@@ -2439,7 +2418,7 @@ fn bindgen_test_layout_kvm_cpuid2() {
         concat!("Alignment of ", stringify!(kvm_cpuid2))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid2>())).nent as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid2, nent),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2449,7 +2428,7 @@ fn bindgen_test_layout_kvm_cpuid2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid2>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid2, padding),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2459,7 +2438,7 @@ fn bindgen_test_layout_kvm_cpuid2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid2>())).entries as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid2, entries),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2499,7 +2478,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         concat!("Alignment of ", stringify!(kvm_pit_channel_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_channel_state>())).count as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_channel_state, count),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2509,9 +2488,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pit_channel_state>())).latched_count as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pit_channel_state, latched_count),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2521,9 +2498,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pit_channel_state>())).count_latched as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pit_channel_state, count_latched),
         6usize,
         concat!(
             "Offset of field: ",
@@ -2533,9 +2508,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pit_channel_state>())).status_latched as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pit_channel_state, status_latched),
         7usize,
         concat!(
             "Offset of field: ",
@@ -2545,7 +2518,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_channel_state>())).status as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_channel_state, status),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2555,9 +2528,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pit_channel_state>())).read_state as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pit_channel_state, read_state),
         9usize,
         concat!(
             "Offset of field: ",
@@ -2567,9 +2538,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pit_channel_state>())).write_state as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pit_channel_state, write_state),
         10usize,
         concat!(
             "Offset of field: ",
@@ -2579,9 +2548,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pit_channel_state>())).write_latch as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pit_channel_state, write_latch),
         11usize,
         concat!(
             "Offset of field: ",
@@ -2591,7 +2558,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_channel_state>())).rw_mode as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_channel_state, rw_mode),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2601,7 +2568,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_channel_state>())).mode as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_channel_state, mode),
         13usize,
         concat!(
             "Offset of field: ",
@@ -2611,7 +2578,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_channel_state>())).bcd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_channel_state, bcd),
         14usize,
         concat!(
             "Offset of field: ",
@@ -2621,7 +2588,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_channel_state>())).gate as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_channel_state, gate),
         15usize,
         concat!(
             "Offset of field: ",
@@ -2631,9 +2598,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pit_channel_state>())).count_load_time as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pit_channel_state, count_load_time),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2665,7 +2630,7 @@ fn bindgen_test_layout_kvm_debug_exit_arch() {
         concat!("Alignment of ", stringify!(kvm_debug_exit_arch))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_exit_arch>())).exception as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_exit_arch, exception),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2675,7 +2640,7 @@ fn bindgen_test_layout_kvm_debug_exit_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_exit_arch>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_exit_arch, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2685,7 +2650,7 @@ fn bindgen_test_layout_kvm_debug_exit_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_exit_arch>())).pc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_exit_arch, pc),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2695,7 +2660,7 @@ fn bindgen_test_layout_kvm_debug_exit_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_exit_arch>())).dr6 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_exit_arch, dr6),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2705,7 +2670,7 @@ fn bindgen_test_layout_kvm_debug_exit_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_exit_arch>())).dr7 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_exit_arch, dr7),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2733,7 +2698,7 @@ fn bindgen_test_layout_kvm_guest_debug_arch() {
         concat!("Alignment of ", stringify!(kvm_guest_debug_arch))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug_arch>())).debugreg as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug_arch, debugreg),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2761,7 +2726,7 @@ fn bindgen_test_layout_kvm_pit_state() {
         concat!("Alignment of ", stringify!(kvm_pit_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_state>())).channels as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_state, channels),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2791,7 +2756,7 @@ fn bindgen_test_layout_kvm_pit_state2() {
         concat!("Alignment of ", stringify!(kvm_pit_state2))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_state2>())).channels as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_state2, channels),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2801,7 +2766,7 @@ fn bindgen_test_layout_kvm_pit_state2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_state2>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_state2, flags),
         72usize,
         concat!(
             "Offset of field: ",
@@ -2811,7 +2776,7 @@ fn bindgen_test_layout_kvm_pit_state2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_state2>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_state2, reserved),
         76usize,
         concat!(
             "Offset of field: ",
@@ -2840,9 +2805,7 @@ fn bindgen_test_layout_kvm_reinject_control() {
         concat!("Alignment of ", stringify!(kvm_reinject_control))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_reinject_control>())).pit_reinject as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_reinject_control, pit_reinject),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2852,7 +2815,7 @@ fn bindgen_test_layout_kvm_reinject_control() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_reinject_control>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_reinject_control, reserved),
         1usize,
         concat!(
             "Offset of field: ",
@@ -2895,9 +2858,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_vcpu_events__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).injected as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, injected),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2907,9 +2868,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, nr),
         1usize,
         concat!(
             "Offset of field: ",
@@ -2919,10 +2878,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).has_error_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, has_error_code),
         2usize,
         concat!(
             "Offset of field: ",
@@ -2932,9 +2888,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, pad),
         3usize,
         concat!(
             "Offset of field: ",
@@ -2944,10 +2898,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).error_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, error_code),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2978,9 +2929,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_2() {
         concat!("Alignment of ", stringify!(kvm_vcpu_events__bindgen_ty_2))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_2>())).injected as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_2, injected),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2990,9 +2939,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_2>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_2, nr),
         1usize,
         concat!(
             "Offset of field: ",
@@ -3002,9 +2949,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_2>())).soft as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_2, soft),
         2usize,
         concat!(
             "Offset of field: ",
@@ -3014,9 +2959,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_2>())).shadow as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_2, shadow),
         3usize,
         concat!(
             "Offset of field: ",
@@ -3047,9 +2990,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_3() {
         concat!("Alignment of ", stringify!(kvm_vcpu_events__bindgen_ty_3))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_3>())).injected as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_3, injected),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3059,9 +3000,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_3>())).pending as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_3, pending),
         1usize,
         concat!(
             "Offset of field: ",
@@ -3071,9 +3010,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_3>())).masked as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_3, masked),
         2usize,
         concat!(
             "Offset of field: ",
@@ -3083,9 +3020,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_3>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_3, pad),
         3usize,
         concat!(
             "Offset of field: ",
@@ -3116,9 +3051,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_4() {
         concat!("Alignment of ", stringify!(kvm_vcpu_events__bindgen_ty_4))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_4>())).smm as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_4, smm),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3128,9 +3061,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_4>())).pending as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_4, pending),
         1usize,
         concat!(
             "Offset of field: ",
@@ -3140,10 +3071,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_4>())).smm_inside_nmi as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_4, smm_inside_nmi),
         2usize,
         concat!(
             "Offset of field: ",
@@ -3153,10 +3081,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_4>())).latched_init as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_4, latched_init),
         3usize,
         concat!(
             "Offset of field: ",
@@ -3179,7 +3104,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         concat!("Alignment of ", stringify!(kvm_vcpu_events))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).exception as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, exception),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3189,7 +3114,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).interrupt as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, interrupt),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3199,7 +3124,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).nmi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, nmi),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3209,7 +3134,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).sipi_vector as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, sipi_vector),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3219,7 +3144,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, flags),
         20usize,
         concat!(
             "Offset of field: ",
@@ -3229,7 +3154,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).smi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, smi),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3239,7 +3164,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, reserved),
         28usize,
         concat!(
             "Offset of field: ",
@@ -3271,7 +3196,7 @@ fn bindgen_test_layout_kvm_debugregs() {
         concat!("Alignment of ", stringify!(kvm_debugregs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debugregs>())).db as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debugregs, db),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3281,7 +3206,7 @@ fn bindgen_test_layout_kvm_debugregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debugregs>())).dr6 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debugregs, dr6),
         32usize,
         concat!(
             "Offset of field: ",
@@ -3291,7 +3216,7 @@ fn bindgen_test_layout_kvm_debugregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debugregs>())).dr7 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debugregs, dr7),
         40usize,
         concat!(
             "Offset of field: ",
@@ -3301,7 +3226,7 @@ fn bindgen_test_layout_kvm_debugregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debugregs>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debugregs, flags),
         48usize,
         concat!(
             "Offset of field: ",
@@ -3311,7 +3236,7 @@ fn bindgen_test_layout_kvm_debugregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debugregs>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debugregs, reserved),
         56usize,
         concat!(
             "Offset of field: ",
@@ -3339,7 +3264,7 @@ fn bindgen_test_layout_kvm_xsave() {
         concat!("Alignment of ", stringify!(kvm_xsave))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xsave>())).region as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xsave, region),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3374,7 +3299,7 @@ fn bindgen_test_layout_kvm_xcr() {
         concat!("Alignment of ", stringify!(kvm_xcr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xcr>())).xcr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xcr, xcr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3384,7 +3309,7 @@ fn bindgen_test_layout_kvm_xcr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xcr>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xcr, reserved),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3394,7 +3319,7 @@ fn bindgen_test_layout_kvm_xcr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xcr>())).value as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xcr, value),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3425,7 +3350,7 @@ fn bindgen_test_layout_kvm_xcrs() {
         concat!("Alignment of ", stringify!(kvm_xcrs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xcrs>())).nr_xcrs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xcrs, nr_xcrs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3435,7 +3360,7 @@ fn bindgen_test_layout_kvm_xcrs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xcrs>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xcrs, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3445,7 +3370,7 @@ fn bindgen_test_layout_kvm_xcrs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xcrs>())).xcrs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xcrs, xcrs),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3455,7 +3380,7 @@ fn bindgen_test_layout_kvm_xcrs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xcrs>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xcrs, padding),
         264usize,
         concat!(
             "Offset of field: ",
@@ -3500,7 +3425,7 @@ fn bindgen_test_layout_kvm_user_trace_setup() {
         concat!("Alignment of ", stringify!(kvm_user_trace_setup))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_user_trace_setup>())).buf_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_user_trace_setup, buf_size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3510,7 +3435,7 @@ fn bindgen_test_layout_kvm_user_trace_setup() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_user_trace_setup>())).buf_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_user_trace_setup, buf_nr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3540,7 +3465,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         concat!("Alignment of ", stringify!(kvm_breakpoint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3550,7 +3475,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, padding),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3560,7 +3485,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, address),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3591,7 +3516,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         concat!("Alignment of ", stringify!(kvm_debug_guest))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3601,7 +3526,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3611,7 +3536,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).breakpoints as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, breakpoints),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3621,7 +3546,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).singlestep as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, singlestep),
         72usize,
         concat!(
             "Offset of field: ",
@@ -3652,7 +3577,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         concat!("Alignment of ", stringify!(kvm_memory_region))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).slot as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3662,7 +3587,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3672,9 +3597,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_memory_region>())).guest_phys_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_memory_region, guest_phys_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3684,7 +3607,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).memory_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, memory_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3716,9 +3639,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         concat!("Alignment of ", stringify!(kvm_userspace_memory_region))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).slot as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3728,9 +3649,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3740,10 +3659,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).guest_phys_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, guest_phys_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3753,9 +3669,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).memory_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, memory_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3765,10 +3679,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).userspace_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, userspace_addr),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3804,7 +3715,7 @@ fn bindgen_test_layout_kvm_irq_level__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_irq_level__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_level__bindgen_ty_1>())).irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_level__bindgen_ty_1, irq),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3814,9 +3725,7 @@ fn bindgen_test_layout_kvm_irq_level__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_level__bindgen_ty_1>())).status as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_level__bindgen_ty_1, status),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3844,7 +3753,7 @@ fn bindgen_test_layout_kvm_irq_level() {
         concat!("Alignment of ", stringify!(kvm_irq_level))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_level>())).level as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_level, level),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3869,7 +3778,7 @@ pub struct kvm_irqchip {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union kvm_irqchip__bindgen_ty_1 {
-    pub dummy: [::std::os::raw::c_char; 512usize],
+    pub dummy: [super::raw::c_char; 512usize],
     pub pic: kvm_pic_state,
     pub ioapic: kvm_ioapic_state,
     _bindgen_union_align: [u64; 64usize],
@@ -3887,7 +3796,7 @@ fn bindgen_test_layout_kvm_irqchip__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_irqchip__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip__bindgen_ty_1>())).dummy as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip__bindgen_ty_1, dummy),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3897,7 +3806,7 @@ fn bindgen_test_layout_kvm_irqchip__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip__bindgen_ty_1>())).pic as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip__bindgen_ty_1, pic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3907,9 +3816,7 @@ fn bindgen_test_layout_kvm_irqchip__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irqchip__bindgen_ty_1>())).ioapic as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irqchip__bindgen_ty_1, ioapic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3937,7 +3844,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         concat!("Alignment of ", stringify!(kvm_irqchip))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).chip_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, chip_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3947,7 +3854,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3957,7 +3864,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).chip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, chip),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3991,7 +3898,7 @@ fn bindgen_test_layout_kvm_pit_config() {
         concat!("Alignment of ", stringify!(kvm_pit_config))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_config>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_config, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4001,7 +3908,7 @@ fn bindgen_test_layout_kvm_pit_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_config>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_config, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4033,7 +3940,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         concat!("Alignment of ", stringify!(kvm_s390_skeys))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).start_gfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, start_gfn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4043,7 +3950,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).count as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, count),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4053,7 +3960,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).skeydata_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, skeydata_addr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4063,7 +3970,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4073,7 +3980,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, reserved),
         28usize,
         concat!(
             "Offset of field: ",
@@ -4126,10 +4033,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_s390_cmma_log__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_cmma_log__bindgen_ty_1>())).remaining as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_cmma_log__bindgen_ty_1, remaining),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4139,9 +4043,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_cmma_log__bindgen_ty_1>())).mask as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_cmma_log__bindgen_ty_1, mask),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4169,7 +4071,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         concat!("Alignment of ", stringify!(kvm_s390_cmma_log))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).start_gfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, start_gfn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4179,7 +4081,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).count as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, count),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4189,7 +4091,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -4199,7 +4101,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).values as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, values),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4254,10 +4156,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).msr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, msr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4267,10 +4166,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).control
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, control),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4280,10 +4176,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).evt_page
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, evt_page),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4293,10 +4186,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).msg_page
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, msg_page),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4332,10 +4222,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).input
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, input),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4345,10 +4232,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).result
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, result),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4358,10 +4242,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).params
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, params),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4384,9 +4265,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_hyperv_exit__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1>())).synic as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1, synic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4396,9 +4275,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1>())).hcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1, hcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4426,7 +4303,7 @@ fn bindgen_test_layout_kvm_hyperv_exit() {
         concat!("Alignment of ", stringify!(kvm_hyperv_exit))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_exit>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_exit, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4436,7 +4313,7 @@ fn bindgen_test_layout_kvm_hyperv_exit() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_exit>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_exit, u),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4492,7 +4369,7 @@ pub union kvm_run__bindgen_ty_1 {
     pub s390_stsi: kvm_run__bindgen_ty_1__bindgen_ty_18,
     pub eoi: kvm_run__bindgen_ty_1__bindgen_ty_19,
     pub hyperv: kvm_hyperv_exit,
-    pub padding: [::std::os::raw::c_char; 256usize],
+    pub padding: [super::raw::c_char; 256usize],
     _bindgen_union_align: [u64; 32usize],
 }
 #[repr(C)]
@@ -4516,10 +4393,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_1>())).hardware_exit_reason
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_1, hardware_exit_reason),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4550,10 +4424,10 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_2>()))
-                .hardware_entry_failure_reason as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            kvm_run__bindgen_ty_1__bindgen_ty_2,
+            hardware_entry_failure_reason
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4585,10 +4459,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_3>())).exception as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_3, exception),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4598,10 +4469,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_3>())).error_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_3, error_code),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4636,10 +4504,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).direction as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, direction),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4649,10 +4514,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, size),
         1usize,
         concat!(
             "Offset of field: ",
@@ -4662,10 +4524,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).port as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, port),
         2usize,
         concat!(
             "Offset of field: ",
@@ -4675,10 +4534,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).count as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, count),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4688,10 +4544,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).data_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, data_offset),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4722,10 +4575,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_5() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_5>())).arch as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_5, arch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4759,10 +4609,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).phys_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, phys_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4772,10 +4619,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4785,9 +4629,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).len as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4797,10 +4639,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, is_write),
         20usize,
         concat!(
             "Offset of field: ",
@@ -4835,9 +4674,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4847,10 +4684,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).args as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, args),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4860,9 +4694,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).ret as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, ret),
         56usize,
         concat!(
             "Offset of field: ",
@@ -4872,10 +4704,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).longmode as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, longmode),
         64usize,
         concat!(
             "Offset of field: ",
@@ -4885,9 +4714,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, pad),
         68usize,
         concat!(
             "Offset of field: ",
@@ -4920,9 +4747,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).rip as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, rip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4932,10 +4757,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, is_write),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4945,9 +4767,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -4980,10 +4800,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).icptcode as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, icptcode),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4993,9 +4810,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).ipa as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, ipa),
         2usize,
         concat!(
             "Offset of field: ",
@@ -5005,9 +4820,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).ipb as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, ipb),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5042,10 +4855,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_10() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_10>())).trans_exc_code
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_10, trans_exc_code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5055,10 +4865,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_10() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_10>())).pgm_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_10, pgm_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5094,10 +4901,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).dcrn as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, dcrn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5107,10 +4911,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, data),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5120,10 +4921,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, is_write),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5159,10 +4957,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).suberror as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, suberror),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5172,10 +4967,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).ndata as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, ndata),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5185,10 +4977,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5222,10 +5011,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_13() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_13>())).gprs as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_13, gprs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5261,9 +5047,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5273,10 +5057,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).ret as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, ret),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5286,10 +5067,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).args as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, args),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5328,10 +5106,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).subchannel_id
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, subchannel_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5341,10 +5116,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).subchannel_nr
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, subchannel_nr),
         2usize,
         concat!(
             "Offset of field: ",
@@ -5354,10 +5126,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).io_int_parm as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, io_int_parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5367,10 +5136,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).io_int_word as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, io_int_word),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5380,10 +5146,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).ipb as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, ipb),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5393,10 +5156,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).dequeued as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, dequeued),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5430,10 +5190,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_16() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_16>())).epr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_16, epr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5468,10 +5225,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_17() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_17>())).type_ as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_17, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5481,10 +5235,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_17() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_17>())).flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_17, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5523,10 +5274,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5536,9 +5284,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).ar as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, ar),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5548,10 +5294,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, reserved),
         9usize,
         concat!(
             "Offset of field: ",
@@ -5561,9 +5304,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).fc as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, fc),
         10usize,
         concat!(
             "Offset of field: ",
@@ -5573,10 +5314,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).sel1 as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, sel1),
         11usize,
         concat!(
             "Offset of field: ",
@@ -5586,10 +5324,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).sel2 as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, sel2),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5623,10 +5358,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_19() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_19>())).vector as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_19, vector),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5649,7 +5381,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_run__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hw as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hw),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5659,9 +5391,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).fail_entry as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, fail_entry),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5671,7 +5401,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).ex as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, ex),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5681,7 +5411,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).io as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, io),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5691,7 +5421,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).debug as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, debug),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5701,7 +5431,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).mmio as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, mmio),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5711,7 +5441,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hypercall as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hypercall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5721,9 +5451,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).tpr_access as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, tpr_access),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5733,9 +5461,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_sieic as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_sieic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5745,9 +5471,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_reset_flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_reset_flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5757,9 +5481,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_ucontrol as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_ucontrol),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5769,7 +5491,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).dcr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, dcr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5779,7 +5501,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).internal as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, internal),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5789,7 +5511,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).osi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, osi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5799,9 +5521,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).papr_hcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, papr_hcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5811,7 +5531,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_tsch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_tsch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5821,7 +5541,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).epr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, epr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5831,9 +5551,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).system_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, system_event),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5843,7 +5561,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_stsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_stsi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5853,7 +5571,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).eoi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, eoi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5863,7 +5581,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hyperv as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hyperv),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5873,7 +5591,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, padding),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5892,7 +5610,7 @@ impl Default for kvm_run__bindgen_ty_1 {
 #[derive(Copy, Clone)]
 pub union kvm_run__bindgen_ty_2 {
     pub regs: kvm_sync_regs,
-    pub padding: [::std::os::raw::c_char; 2048usize],
+    pub padding: [super::raw::c_char; 2048usize],
     _bindgen_union_align: [u8; 2048usize],
 }
 #[test]
@@ -5908,7 +5626,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_2() {
         concat!("Alignment of ", stringify!(kvm_run__bindgen_ty_2))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_2>())).regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_2, regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5918,7 +5636,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_2>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_2, padding),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5946,9 +5664,7 @@ fn bindgen_test_layout_kvm_run() {
         concat!("Alignment of ", stringify!(kvm_run))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run>())).request_interrupt_window as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run, request_interrupt_window),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5958,7 +5674,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).immediate_exit as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, immediate_exit),
         1usize,
         concat!(
             "Offset of field: ",
@@ -5968,7 +5684,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).padding1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, padding1),
         2usize,
         concat!(
             "Offset of field: ",
@@ -5978,7 +5694,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).exit_reason as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, exit_reason),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5988,9 +5704,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run>())).ready_for_interrupt_injection as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run, ready_for_interrupt_injection),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6000,7 +5714,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).if_flag as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, if_flag),
         13usize,
         concat!(
             "Offset of field: ",
@@ -6010,7 +5724,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, flags),
         14usize,
         concat!(
             "Offset of field: ",
@@ -6020,7 +5734,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).cr8 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, cr8),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6030,7 +5744,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).apic_base as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, apic_base),
         24usize,
         concat!(
             "Offset of field: ",
@@ -6040,7 +5754,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).kvm_valid_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, kvm_valid_regs),
         288usize,
         concat!(
             "Offset of field: ",
@@ -6050,7 +5764,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).kvm_dirty_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, kvm_dirty_regs),
         296usize,
         concat!(
             "Offset of field: ",
@@ -6060,7 +5774,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).s as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, s),
         304usize,
         concat!(
             "Offset of field: ",
@@ -6095,7 +5809,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio_zone))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6105,7 +5819,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6115,7 +5829,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6146,7 +5860,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).phys_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, phys_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6156,7 +5870,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6166,7 +5880,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6176,7 +5890,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, data),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6206,7 +5920,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio_ring))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).first as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, first),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6216,7 +5930,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).last as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, last),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6226,9 +5940,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).coalesced_mmio as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, coalesced_mmio),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6261,7 +5973,7 @@ fn bindgen_test_layout_kvm_translation() {
         concat!("Alignment of ", stringify!(kvm_translation))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).linear_address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, linear_address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6271,9 +5983,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_translation>())).physical_address as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_translation, physical_address),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6283,7 +5993,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).valid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, valid),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6293,7 +6003,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).writeable as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, writeable),
         17usize,
         concat!(
             "Offset of field: ",
@@ -6303,7 +6013,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).usermode as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, usermode),
         18usize,
         concat!(
             "Offset of field: ",
@@ -6313,7 +6023,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, pad),
         19usize,
         concat!(
             "Offset of field: ",
@@ -6347,7 +6057,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         concat!("Alignment of ", stringify!(kvm_s390_mem_op))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).gaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, gaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6357,7 +6067,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6367,7 +6077,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6377,7 +6087,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).op as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, op),
         20usize,
         concat!(
             "Offset of field: ",
@@ -6387,7 +6097,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).buf as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, buf),
         24usize,
         concat!(
             "Offset of field: ",
@@ -6397,7 +6107,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).ar as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, ar),
         32usize,
         concat!(
             "Offset of field: ",
@@ -6407,7 +6117,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, reserved),
         33usize,
         concat!(
             "Offset of field: ",
@@ -6435,7 +6145,7 @@ fn bindgen_test_layout_kvm_interrupt() {
         concat!("Alignment of ", stringify!(kvm_interrupt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_interrupt>())).irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_interrupt, irq),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6455,7 +6165,7 @@ pub struct kvm_dirty_log {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union kvm_dirty_log__bindgen_ty_1 {
-    pub dirty_bitmap: *mut ::std::os::raw::c_void,
+    pub dirty_bitmap: *mut super::raw::c_void,
     pub padding2: __u64,
     _bindgen_union_align: u64,
 }
@@ -6472,10 +6182,7 @@ fn bindgen_test_layout_kvm_dirty_log__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_dirty_log__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_dirty_log__bindgen_ty_1>())).dirty_bitmap as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_dirty_log__bindgen_ty_1, dirty_bitmap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6485,9 +6192,7 @@ fn bindgen_test_layout_kvm_dirty_log__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_dirty_log__bindgen_ty_1>())).padding2 as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_dirty_log__bindgen_ty_1, padding2),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6515,7 +6220,7 @@ fn bindgen_test_layout_kvm_dirty_log() {
         concat!("Alignment of ", stringify!(kvm_dirty_log))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_log>())).slot as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_log, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6525,7 +6230,7 @@ fn bindgen_test_layout_kvm_dirty_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_log>())).padding1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_log, padding1),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6559,7 +6264,7 @@ fn bindgen_test_layout_kvm_signal_mask() {
         concat!("Alignment of ", stringify!(kvm_signal_mask))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_signal_mask>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_signal_mask, len),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6569,7 +6274,7 @@ fn bindgen_test_layout_kvm_signal_mask() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_signal_mask>())).sigset as *const _ as usize },
+        ::std::mem::offset_of!(kvm_signal_mask, sigset),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6599,7 +6304,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         concat!("Alignment of ", stringify!(kvm_tpr_access_ctl))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6609,7 +6314,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6619,7 +6324,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, reserved),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6647,7 +6352,7 @@ fn bindgen_test_layout_kvm_vapic_addr() {
         concat!("Alignment of ", stringify!(kvm_vapic_addr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vapic_addr>())).vapic_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vapic_addr, vapic_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6675,7 +6380,7 @@ fn bindgen_test_layout_kvm_mp_state() {
         concat!("Alignment of ", stringify!(kvm_mp_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_mp_state>())).mp_state as *const _ as usize },
+        ::std::mem::offset_of!(kvm_mp_state, mp_state),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6704,7 +6409,7 @@ fn bindgen_test_layout_kvm_s390_psw() {
         concat!("Alignment of ", stringify!(kvm_s390_psw))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_psw>())).mask as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_psw, mask),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6714,7 +6419,7 @@ fn bindgen_test_layout_kvm_s390_psw() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_psw>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_psw, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6744,7 +6449,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         concat!("Alignment of ", stringify!(kvm_s390_interrupt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6754,7 +6459,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).parm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6764,7 +6469,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).parm64 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, parm64),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6795,7 +6500,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         concat!("Alignment of ", stringify!(kvm_s390_io_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).subchannel_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, subchannel_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6805,7 +6510,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).subchannel_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, subchannel_nr),
         2usize,
         concat!(
             "Offset of field: ",
@@ -6815,7 +6520,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).io_int_parm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, io_int_parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6825,7 +6530,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).io_int_word as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, io_int_word),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6855,7 +6560,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         concat!("Alignment of ", stringify!(kvm_s390_ext_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).ext_params as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, ext_params),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6865,7 +6570,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6875,7 +6580,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).ext_params2 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, ext_params2),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6915,9 +6620,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         concat!("Alignment of ", stringify!(kvm_s390_pgm_info))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_pgm_info>())).trans_exc_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, trans_exc_code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6927,7 +6630,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).mon_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, mon_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6937,7 +6640,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_address),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6947,7 +6650,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).data_exc_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, data_exc_code),
         24usize,
         concat!(
             "Offset of field: ",
@@ -6957,7 +6660,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, code),
         28usize,
         concat!(
             "Offset of field: ",
@@ -6967,7 +6670,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).mon_class_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, mon_class_nr),
         30usize,
         concat!(
             "Offset of field: ",
@@ -6977,7 +6680,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_code),
         32usize,
         concat!(
             "Offset of field: ",
@@ -6987,7 +6690,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_atmid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_atmid),
         33usize,
         concat!(
             "Offset of field: ",
@@ -6997,7 +6700,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).exc_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, exc_access_id),
         34usize,
         concat!(
             "Offset of field: ",
@@ -7007,7 +6710,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_access_id),
         35usize,
         concat!(
             "Offset of field: ",
@@ -7017,7 +6720,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).op_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, op_access_id),
         36usize,
         concat!(
             "Offset of field: ",
@@ -7027,7 +6730,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, flags),
         37usize,
         concat!(
             "Offset of field: ",
@@ -7037,7 +6740,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, pad),
         38usize,
         concat!(
             "Offset of field: ",
@@ -7065,7 +6768,7 @@ fn bindgen_test_layout_kvm_s390_prefix_info() {
         concat!("Alignment of ", stringify!(kvm_s390_prefix_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_prefix_info>())).address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_prefix_info, address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7093,7 +6796,7 @@ fn bindgen_test_layout_kvm_s390_extcall_info() {
         concat!("Alignment of ", stringify!(kvm_s390_extcall_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_extcall_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_extcall_info, code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7121,7 +6824,7 @@ fn bindgen_test_layout_kvm_s390_emerg_info() {
         concat!("Alignment of ", stringify!(kvm_s390_emerg_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_emerg_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_emerg_info, code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7149,7 +6852,7 @@ fn bindgen_test_layout_kvm_s390_stop_info() {
         concat!("Alignment of ", stringify!(kvm_s390_stop_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_stop_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_stop_info, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7182,7 +6885,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         concat!("Alignment of ", stringify!(kvm_s390_mchk_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).cr14 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, cr14),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7192,7 +6895,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).mcic as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, mcic),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7202,10 +6905,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_mchk_info>())).failing_storage_address as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, failing_storage_address),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7215,9 +6915,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_mchk_info>())).ext_damage_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, ext_damage_code),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7227,7 +6925,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, pad),
         28usize,
         concat!(
             "Offset of field: ",
@@ -7237,7 +6935,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).fixed_logout as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, fixed_logout),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7264,7 +6962,7 @@ pub union kvm_s390_irq__bindgen_ty_1 {
     pub prefix: kvm_s390_prefix_info,
     pub stop: kvm_s390_stop_info,
     pub mchk: kvm_s390_mchk_info,
-    pub reserved: [::std::os::raw::c_char; 64usize],
+    pub reserved: [super::raw::c_char; 64usize],
     _bindgen_union_align: [u64; 8usize],
 }
 #[test]
@@ -7280,7 +6978,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_s390_irq__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).io as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, io),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7290,7 +6988,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).ext as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, ext),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7300,7 +6998,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).pgm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, pgm),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7310,9 +7008,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).emerg as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, emerg),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7322,9 +7018,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).extcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, extcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7334,9 +7028,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).prefix as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, prefix),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7346,7 +7038,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).stop as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, stop),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7356,7 +7048,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).mchk as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, mchk),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7366,9 +7058,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7396,7 +7086,7 @@ fn bindgen_test_layout_kvm_s390_irq() {
         concat!("Alignment of ", stringify!(kvm_s390_irq))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7406,7 +7096,7 @@ fn bindgen_test_layout_kvm_s390_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq, u),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7442,7 +7132,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         concat!("Alignment of ", stringify!(kvm_s390_irq_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).buf as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, buf),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7452,7 +7142,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7462,7 +7152,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, len),
         12usize,
         concat!(
             "Offset of field: ",
@@ -7472,7 +7162,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, reserved),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7502,7 +7192,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         concat!("Alignment of ", stringify!(kvm_guest_debug))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).control as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, control),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7512,7 +7202,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7522,7 +7212,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).arch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, arch),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7562,7 +7252,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         concat!("Alignment of ", stringify!(kvm_ioeventfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).datamatch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, datamatch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7572,7 +7262,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7582,7 +7272,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7592,7 +7282,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, fd),
         20usize,
         concat!(
             "Offset of field: ",
@@ -7602,7 +7292,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7612,7 +7302,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, pad),
         28usize,
         concat!(
             "Offset of field: ",
@@ -7648,7 +7338,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         concat!("Alignment of ", stringify!(kvm_enable_cap))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).cap as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, cap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7658,7 +7348,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7668,7 +7358,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).args as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, args),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7678,7 +7368,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, pad),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7713,7 +7403,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         concat!("Alignment of ", stringify!(kvm_ppc_pvinfo))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7723,7 +7413,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).hcall as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, hcall),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7733,7 +7423,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, pad),
         20usize,
         concat!(
             "Offset of field: ",
@@ -7767,9 +7457,7 @@ fn bindgen_test_layout_kvm_ppc_one_page_size() {
         concat!("Alignment of ", stringify!(kvm_ppc_one_page_size))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_page_size>())).page_shift as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_page_size, page_shift),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7779,7 +7467,7 @@ fn bindgen_test_layout_kvm_ppc_one_page_size() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_one_page_size>())).pte_enc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_one_page_size, pte_enc),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7809,9 +7497,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         concat!("Alignment of ", stringify!(kvm_ppc_one_seg_page_size))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).page_shift as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, page_shift),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7821,9 +7507,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).slb_enc as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, slb_enc),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7833,7 +7517,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).enc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, enc),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7865,7 +7549,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         concat!("Alignment of ", stringify!(kvm_ppc_smmu_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7875,7 +7559,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).slb_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, slb_size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7885,7 +7569,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).data_keys as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, data_keys),
         12usize,
         concat!(
             "Offset of field: ",
@@ -7895,7 +7579,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).instr_keys as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, instr_keys),
         14usize,
         concat!(
             "Offset of field: ",
@@ -7905,7 +7589,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).sps as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, sps),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7935,7 +7619,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         concat!("Alignment of ", stringify!(kvm_ppc_resize_hpt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7945,7 +7629,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).shift as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, shift),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7955,7 +7639,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -7984,7 +7668,7 @@ fn bindgen_test_layout_kvm_irq_routing_irqchip() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_irqchip))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_irqchip>())).irqchip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_irqchip, irqchip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7994,7 +7678,7 @@ fn bindgen_test_layout_kvm_irq_routing_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_irqchip>())).pin as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_irqchip, pin),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8035,9 +7719,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_msi__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_msi__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8047,9 +7729,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_msi__bindgen_ty_1>())).devid as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_msi__bindgen_ty_1, devid),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8077,7 +7757,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_msi))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).address_lo as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, address_lo),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8087,7 +7767,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).address_hi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, address_hi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8097,7 +7777,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8134,9 +7814,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_s390_adapter))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).ind_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, ind_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8146,10 +7824,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).summary_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, summary_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8159,9 +7834,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).ind_offset as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, ind_offset),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8171,10 +7844,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).summary_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, summary_offset),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8184,9 +7854,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).adapter_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, adapter_id),
         28usize,
         concat!(
             "Offset of field: ",
@@ -8215,7 +7883,7 @@ fn bindgen_test_layout_kvm_irq_routing_hv_sint() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_hv_sint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_hv_sint>())).vcpu as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_hv_sint, vcpu),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8225,7 +7893,7 @@ fn bindgen_test_layout_kvm_irq_routing_hv_sint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_hv_sint>())).sint as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_hv_sint, sint),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8270,10 +7938,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).irqchip as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, irqchip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8283,9 +7948,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).msi as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, msi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8295,10 +7958,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).adapter as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, adapter),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8308,10 +7968,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).hv_sint as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, hv_sint),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8321,9 +7978,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8351,7 +8006,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_entry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, gsi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8361,7 +8016,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, type_),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8371,7 +8026,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8381,7 +8036,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -8391,7 +8046,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, u),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8425,7 +8080,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         concat!("Alignment of ", stringify!(kvm_irq_routing))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8435,7 +8090,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8445,7 +8100,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).entries as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, entries),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8484,7 +8139,7 @@ fn bindgen_test_layout_kvm_x86_mce() {
         concat!("Alignment of ", stringify!(kvm_x86_mce))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_x86_mce>())).status as *const _ as usize },
+        ::std::mem::offset_of!(kvm_x86_mce, status),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8494,7 +8149,7 @@ fn bindgen_test_layout_kvm_x86_mce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_x86_mce>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_x86_mce, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8504,7 +8159,7 @@ fn bindgen_test_layout_kvm_x86_mce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_x86_mce>())).misc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_x86_mce, misc),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8514,7 +8169,7 @@ fn bindgen_test_layout_kvm_x86_mce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_x86_mce>())).mcg_status as *const _ as usize },
+        ::std::mem::offset_of!(kvm_x86_mce, mcg_status),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8524,7 +8179,7 @@ fn bindgen_test_layout_kvm_x86_mce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_x86_mce>())).bank as *const _ as usize },
+        ::std::mem::offset_of!(kvm_x86_mce, bank),
         32usize,
         concat!(
             "Offset of field: ",
@@ -8534,7 +8189,7 @@ fn bindgen_test_layout_kvm_x86_mce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_x86_mce>())).pad1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_x86_mce, pad1),
         33usize,
         concat!(
             "Offset of field: ",
@@ -8544,7 +8199,7 @@ fn bindgen_test_layout_kvm_x86_mce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_x86_mce>())).pad2 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_x86_mce, pad2),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8578,7 +8233,7 @@ fn bindgen_test_layout_kvm_xen_hvm_config() {
         concat!("Alignment of ", stringify!(kvm_xen_hvm_config))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xen_hvm_config>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xen_hvm_config, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8588,7 +8243,7 @@ fn bindgen_test_layout_kvm_xen_hvm_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xen_hvm_config>())).msr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xen_hvm_config, msr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8598,7 +8253,7 @@ fn bindgen_test_layout_kvm_xen_hvm_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xen_hvm_config>())).blob_addr_32 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xen_hvm_config, blob_addr_32),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8608,7 +8263,7 @@ fn bindgen_test_layout_kvm_xen_hvm_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xen_hvm_config>())).blob_addr_64 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xen_hvm_config, blob_addr_64),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8618,7 +8273,7 @@ fn bindgen_test_layout_kvm_xen_hvm_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xen_hvm_config>())).blob_size_32 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xen_hvm_config, blob_size_32),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8628,7 +8283,7 @@ fn bindgen_test_layout_kvm_xen_hvm_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xen_hvm_config>())).blob_size_64 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xen_hvm_config, blob_size_64),
         25usize,
         concat!(
             "Offset of field: ",
@@ -8638,7 +8293,7 @@ fn bindgen_test_layout_kvm_xen_hvm_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xen_hvm_config>())).pad2 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xen_hvm_config, pad2),
         26usize,
         concat!(
             "Offset of field: ",
@@ -8670,7 +8325,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         concat!("Alignment of ", stringify!(kvm_irqfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, fd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8680,7 +8335,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, gsi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8690,7 +8345,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8700,7 +8355,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).resamplefd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, resamplefd),
         12usize,
         concat!(
             "Offset of field: ",
@@ -8710,7 +8365,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, pad),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8740,7 +8395,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         concat!("Alignment of ", stringify!(kvm_clock_data))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).clock as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, clock),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8750,7 +8405,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8760,7 +8415,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -8791,7 +8446,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         concat!("Alignment of ", stringify!(kvm_config_tlb))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).params as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, params),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8801,7 +8456,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).array as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, array),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8811,7 +8466,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).mmu_type as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, mmu_type),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8821,7 +8476,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).array_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, array_len),
         20usize,
         concat!(
             "Offset of field: ",
@@ -8850,7 +8505,7 @@ fn bindgen_test_layout_kvm_dirty_tlb() {
         concat!("Alignment of ", stringify!(kvm_dirty_tlb))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_tlb>())).bitmap as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_tlb, bitmap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8860,7 +8515,7 @@ fn bindgen_test_layout_kvm_dirty_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_tlb>())).num_dirty as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_tlb, num_dirty),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8889,7 +8544,7 @@ fn bindgen_test_layout_kvm_reg_list() {
         concat!("Alignment of ", stringify!(kvm_reg_list))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_reg_list>())).n as *const _ as usize },
+        ::std::mem::offset_of!(kvm_reg_list, n),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8899,7 +8554,7 @@ fn bindgen_test_layout_kvm_reg_list() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_reg_list>())).reg as *const _ as usize },
+        ::std::mem::offset_of!(kvm_reg_list, reg),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8928,7 +8583,7 @@ fn bindgen_test_layout_kvm_one_reg() {
         concat!("Alignment of ", stringify!(kvm_one_reg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_one_reg>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_one_reg, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8938,7 +8593,7 @@ fn bindgen_test_layout_kvm_one_reg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_one_reg>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_one_reg, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8971,7 +8626,7 @@ fn bindgen_test_layout_kvm_msi() {
         concat!("Alignment of ", stringify!(kvm_msi))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).address_lo as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, address_lo),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8981,7 +8636,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).address_hi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, address_hi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8991,7 +8646,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9001,7 +8656,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -9011,7 +8666,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).devid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, devid),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9021,7 +8676,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, pad),
         20usize,
         concat!(
             "Offset of field: ",
@@ -9050,7 +8705,7 @@ fn bindgen_test_layout_kvm_arm_device_addr() {
         concat!("Alignment of ", stringify!(kvm_arm_device_addr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_arm_device_addr>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_arm_device_addr, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9060,7 +8715,7 @@ fn bindgen_test_layout_kvm_arm_device_addr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_arm_device_addr>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_arm_device_addr, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9090,7 +8745,7 @@ fn bindgen_test_layout_kvm_create_device() {
         concat!("Alignment of ", stringify!(kvm_create_device))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9100,7 +8755,7 @@ fn bindgen_test_layout_kvm_create_device() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, fd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9110,7 +8765,7 @@ fn bindgen_test_layout_kvm_create_device() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9141,7 +8796,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         concat!("Alignment of ", stringify!(kvm_device_attr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9151,7 +8806,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).group as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, group),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9161,7 +8816,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).attr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, attr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9171,7 +8826,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, addr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9210,7 +8865,7 @@ fn bindgen_test_layout_kvm_vfio_spapr_tce() {
         concat!("Alignment of ", stringify!(kvm_vfio_spapr_tce))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vfio_spapr_tce>())).groupfd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vfio_spapr_tce, groupfd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9220,7 +8875,7 @@ fn bindgen_test_layout_kvm_vfio_spapr_tce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vfio_spapr_tce>())).tablefd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vfio_spapr_tce, tablefd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9250,7 +8905,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         concat!("Alignment of ", stringify!(kvm_s390_ucas_mapping))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).user_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, user_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9260,7 +8915,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).vcpu_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, vcpu_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9270,7 +8925,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).length as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, length),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9312,10 +8967,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_pci_dev__bindgen_ty_1>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9343,9 +8995,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         concat!("Alignment of ", stringify!(kvm_assigned_pci_dev))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9355,7 +9005,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).busnr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, busnr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9365,7 +9015,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).devfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, devfn),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9375,7 +9025,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -9385,7 +9035,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).segnr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, segnr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9428,9 +9078,7 @@ fn bindgen_test_layout_kvm_assigned_irq__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_assigned_irq__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_irq__bindgen_ty_1>())).reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_irq__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9458,9 +9106,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         concat!("Alignment of ", stringify!(kvm_assigned_irq))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_irq>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_irq, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9470,7 +9116,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).host_irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, host_irq),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9480,7 +9126,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).guest_irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, guest_irq),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9490,7 +9136,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -9525,9 +9171,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         concat!("Alignment of ", stringify!(kvm_assigned_msix_nr))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9537,7 +9181,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).entry_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, entry_nr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9547,7 +9191,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, padding),
         6usize,
         concat!(
             "Offset of field: ",
@@ -9578,9 +9222,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         concat!("Alignment of ", stringify!(kvm_assigned_msix_entry))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9590,7 +9232,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, gsi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9600,7 +9242,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).entry as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, entry),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9610,7 +9252,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, padding),
         10usize,
         concat!(
             "Offset of field: ",
@@ -551,18 +551,18 @@ pub const KVM_ARM_DEV_EL1_PTIMER: u32 = 2;
 pub const KVM_ARM_DEV_PMU: u32 = 4;
 pub const KVM_HYPERV_CONN_ID_MASK: u32 = 16777215;
 pub const KVM_HYPERV_EVENTFD_DEASSIGN: u32 = 1;
-pub type __s8 = ::std::os::raw::c_schar;
-pub type __u8 = ::std::os::raw::c_uchar;
-pub type __s16 = ::std::os::raw::c_short;
-pub type __u16 = ::std::os::raw::c_ushort;
-pub type __s32 = ::std::os::raw::c_int;
-pub type __u32 = ::std::os::raw::c_uint;
-pub type __s64 = ::std::os::raw::c_longlong;
-pub type __u64 = ::std::os::raw::c_ulonglong;
+pub type __s8 = super::raw::c_schar;
+pub type __u8 = super::raw::c_uchar;
+pub type __s16 = super::raw::c_short;
+pub type __u16 = super::raw::c_ushort;
+pub type __s32 = super::raw::c_int;
+pub type __u32 = super::raw::c_uint;
+pub type __s64 = super::raw::c_longlong;
+pub type __u64 = super::raw::c_ulonglong;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct __kernel_fd_set {
-    pub fds_bits: [::std::os::raw::c_ulong; 16usize],
+    pub fds_bits: [super::raw::c_ulong; 16usize],
 }
 #[test]
 fn bindgen_test_layout___kernel_fd_set() {
@@ -577,7 +577,7 @@ fn bindgen_test_layout___kernel_fd_set() {
         concat!("Alignment of ", stringify!(__kernel_fd_set))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__kernel_fd_set>())).fds_bits as *const _ as usize },
+        ::std::mem::offset_of!(__kernel_fd_set, fds_bits),
         0usize,
         concat!(
             "Offset of field: ",
@@ -588,31 +588,31 @@ fn bindgen_test_layout___kernel_fd_set() {
     );
 }
 pub type __kernel_sighandler_t =
-    ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>;
-pub type __kernel_key_t = ::std::os::raw::c_int;
-pub type __kernel_mqd_t = ::std::os::raw::c_int;
-pub type __kernel_old_uid_t = ::std::os::raw::c_ushort;
-pub type __kernel_old_gid_t = ::std::os::raw::c_ushort;
-pub type __kernel_old_dev_t = ::std::os::raw::c_ulong;
-pub type __kernel_long_t = ::std::os::raw::c_long;
-pub type __kernel_ulong_t = ::std::os::raw::c_ulong;
+    ::std::option::Option<unsafe extern "C" fn(arg1: super::raw::c_int)>;
+pub type __kernel_key_t = super::raw::c_int;
+pub type __kernel_mqd_t = super::raw::c_int;
+pub type __kernel_old_uid_t = super::raw::c_ushort;
+pub type __kernel_old_gid_t = super::raw::c_ushort;
+pub type __kernel_old_dev_t = super::raw::c_ulong;
+pub type __kernel_long_t = super::raw::c_long;
+pub type __kernel_ulong_t = super::raw::c_ulong;
 pub type __kernel_ino_t = __kernel_ulong_t;
-pub type __kernel_mode_t = ::std::os::raw::c_uint;
-pub type __kernel_pid_t = ::std::os::raw::c_int;
-pub type __kernel_ipc_pid_t = ::std::os::raw::c_int;
-pub type __kernel_uid_t = ::std::os::raw::c_uint;
-pub type __kernel_gid_t = ::std::os::raw::c_uint;
+pub type __kernel_mode_t = super::raw::c_uint;
+pub type __kernel_pid_t = super::raw::c_int;
+pub type __kernel_ipc_pid_t = super::raw::c_int;
+pub type __kernel_uid_t = super::raw::c_uint;
+pub type __kernel_gid_t = super::raw::c_uint;
 pub type __kernel_suseconds_t = __kernel_long_t;
-pub type __kernel_daddr_t = ::std::os::raw::c_int;
-pub type __kernel_uid32_t = ::std::os::raw::c_uint;
-pub type __kernel_gid32_t = ::std::os::raw::c_uint;
+pub type __kernel_daddr_t = super::raw::c_int;
+pub type __kernel_uid32_t = super::raw::c_uint;
+pub type __kernel_gid32_t = super::raw::c_uint;
 pub type __kernel_size_t = __kernel_ulong_t;
 pub type __kernel_ssize_t = __kernel_long_t;
 pub type __kernel_ptrdiff_t = __kernel_long_t;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct __kernel_fsid_t {
-    pub val: [::std::os::raw::c_int; 2usize],
+    pub val: [super::raw::c_int; 2usize],
 }
 #[test]
 fn bindgen_test_layout___kernel_fsid_t() {
@@ -627,7 +627,7 @@ fn bindgen_test_layout___kernel_fsid_t() {
         concat!("Alignment of ", stringify!(__kernel_fsid_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__kernel_fsid_t>())).val as *const _ as usize },
+        ::std::mem::offset_of!(__kernel_fsid_t, val),
         0usize,
         concat!(
             "Offset of field: ",
@@ -638,15 +638,15 @@ fn bindgen_test_layout___kernel_fsid_t() {
     );
 }
 pub type __kernel_off_t = __kernel_long_t;
-pub type __kernel_loff_t = ::std::os::raw::c_longlong;
+pub type __kernel_loff_t = super::raw::c_longlong;
 pub type __kernel_time_t = __kernel_long_t;
-pub type __kernel_time64_t = ::std::os::raw::c_longlong;
+pub type __kernel_time64_t = super::raw::c_longlong;
 pub type __kernel_clock_t = __kernel_long_t;
-pub type __kernel_timer_t = ::std::os::raw::c_int;
-pub type __kernel_clockid_t = ::std::os::raw::c_int;
-pub type __kernel_caddr_t = *mut ::std::os::raw::c_char;
-pub type __kernel_uid16_t = ::std::os::raw::c_ushort;
-pub type __kernel_gid16_t = ::std::os::raw::c_ushort;
+pub type __kernel_timer_t = super::raw::c_int;
+pub type __kernel_clockid_t = super::raw::c_int;
+pub type __kernel_caddr_t = *mut super::raw::c_char;
+pub type __kernel_uid16_t = super::raw::c_ushort;
+pub type __kernel_gid16_t = super::raw::c_ushort;
 pub type __le16 = __u16;
 pub type __be16 = __u16;
 pub type __le32 = __u32;
@@ -655,7 +655,7 @@ pub type __le64 = __u64;
 pub type __be64 = __u64;
 pub type __sum16 = __u16;
 pub type __wsum = __u32;
-pub type __poll_t = ::std::os::raw::c_uint;
+pub type __poll_t = super::raw::c_uint;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct kvm_memory_alias {
@@ -678,7 +678,7 @@ fn bindgen_test_layout_kvm_memory_alias() {
         concat!("Alignment of ", stringify!(kvm_memory_alias))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_alias>())).slot as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_alias, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -688,7 +688,7 @@ fn bindgen_test_layout_kvm_memory_alias() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_alias>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_alias, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -698,9 +698,7 @@ fn bindgen_test_layout_kvm_memory_alias() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_memory_alias>())).guest_phys_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_memory_alias, guest_phys_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -710,7 +708,7 @@ fn bindgen_test_layout_kvm_memory_alias() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_alias>())).memory_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_alias, memory_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -720,9 +718,7 @@ fn bindgen_test_layout_kvm_memory_alias() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_memory_alias>())).target_phys_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_memory_alias, target_phys_addr),
         24usize,
         concat!(
             "Offset of field: ",
@@ -765,7 +761,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         concat!("Alignment of ", stringify!(kvm_pic_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).last_irr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, last_irr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -775,7 +771,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).irr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, irr),
         1usize,
         concat!(
             "Offset of field: ",
@@ -785,7 +781,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).imr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, imr),
         2usize,
         concat!(
             "Offset of field: ",
@@ -795,7 +791,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).isr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, isr),
         3usize,
         concat!(
             "Offset of field: ",
@@ -805,7 +801,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).priority_add as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, priority_add),
         4usize,
         concat!(
             "Offset of field: ",
@@ -815,7 +811,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).irq_base as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, irq_base),
         5usize,
         concat!(
             "Offset of field: ",
@@ -825,7 +821,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).read_reg_select as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, read_reg_select),
         6usize,
         concat!(
             "Offset of field: ",
@@ -835,7 +831,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).poll as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, poll),
         7usize,
         concat!(
             "Offset of field: ",
@@ -845,7 +841,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).special_mask as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, special_mask),
         8usize,
         concat!(
             "Offset of field: ",
@@ -855,7 +851,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).init_state as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, init_state),
         9usize,
         concat!(
             "Offset of field: ",
@@ -865,7 +861,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).auto_eoi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, auto_eoi),
         10usize,
         concat!(
             "Offset of field: ",
@@ -875,9 +871,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pic_state>())).rotate_on_auto_eoi as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pic_state, rotate_on_auto_eoi),
         11usize,
         concat!(
             "Offset of field: ",
@@ -887,9 +881,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pic_state>())).special_fully_nested_mode as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pic_state, special_fully_nested_mode),
         12usize,
         concat!(
             "Offset of field: ",
@@ -899,7 +891,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).init4 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, init4),
         13usize,
         concat!(
             "Offset of field: ",
@@ -909,7 +901,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).elcr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, elcr),
         14usize,
         concat!(
             "Offset of field: ",
@@ -919,7 +911,7 @@ fn bindgen_test_layout_kvm_pic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pic_state>())).elcr_mask as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pic_state, elcr_mask),
         15usize,
         concat!(
             "Offset of field: ",
@@ -973,10 +965,7 @@ fn bindgen_test_layout_kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1>())).vector
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1, vector),
         0usize,
         concat!(
             "Offset of field: ",
@@ -986,10 +975,7 @@ fn bindgen_test_layout_kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1>())).reserved
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1, reserved),
         3usize,
         concat!(
             "Offset of field: ",
@@ -999,10 +985,7 @@ fn bindgen_test_layout_kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1>())).dest_id
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ioapic_state__bindgen_ty_1__bindgen_ty_1, dest_id),
         7usize,
         concat!(
             "Offset of field: ",
@@ -1162,9 +1145,7 @@ fn bindgen_test_layout_kvm_ioapic_state__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_ioapic_state__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ioapic_state__bindgen_ty_1>())).bits as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ioapic_state__bindgen_ty_1, bits),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1174,9 +1155,7 @@ fn bindgen_test_layout_kvm_ioapic_state__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ioapic_state__bindgen_ty_1>())).fields as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ioapic_state__bindgen_ty_1, fields),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1204,7 +1183,7 @@ fn bindgen_test_layout_kvm_ioapic_state() {
         concat!("Alignment of ", stringify!(kvm_ioapic_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioapic_state>())).base_address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioapic_state, base_address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1214,7 +1193,7 @@ fn bindgen_test_layout_kvm_ioapic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioapic_state>())).ioregsel as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioapic_state, ioregsel),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1224,7 +1203,7 @@ fn bindgen_test_layout_kvm_ioapic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioapic_state>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioapic_state, id),
         12usize,
         concat!(
             "Offset of field: ",
@@ -1234,7 +1213,7 @@ fn bindgen_test_layout_kvm_ioapic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioapic_state>())).irr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioapic_state, irr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1244,7 +1223,7 @@ fn bindgen_test_layout_kvm_ioapic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioapic_state>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioapic_state, pad),
         20usize,
         concat!(
             "Offset of field: ",
@@ -1254,7 +1233,7 @@ fn bindgen_test_layout_kvm_ioapic_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioapic_state>())).redirtbl as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioapic_state, redirtbl),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1304,7 +1283,7 @@ fn bindgen_test_layout_kvm_regs() {
         concat!("Alignment of ", stringify!(kvm_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rax as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rax),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1314,7 +1293,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rbx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rbx),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1324,7 +1303,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rcx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rcx),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1334,7 +1313,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rdx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rdx),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1344,7 +1323,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rsi),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1354,7 +1333,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rdi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rdi),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1364,7 +1343,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rsp as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rsp),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1374,7 +1353,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rbp as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rbp),
         56usize,
         concat!(
             "Offset of field: ",
@@ -1384,7 +1363,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r8 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r8),
         64usize,
         concat!(
             "Offset of field: ",
@@ -1394,7 +1373,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r9 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r9),
         72usize,
         concat!(
             "Offset of field: ",
@@ -1404,7 +1383,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r10 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r10),
         80usize,
         concat!(
             "Offset of field: ",
@@ -1414,7 +1393,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r11 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r11),
         88usize,
         concat!(
             "Offset of field: ",
@@ -1424,7 +1403,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r12 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r12),
         96usize,
         concat!(
             "Offset of field: ",
@@ -1434,7 +1413,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r13 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r13),
         104usize,
         concat!(
             "Offset of field: ",
@@ -1444,7 +1423,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r14 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r14),
         112usize,
         concat!(
             "Offset of field: ",
@@ -1454,7 +1433,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).r15 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, r15),
         120usize,
         concat!(
             "Offset of field: ",
@@ -1464,7 +1443,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rip),
         128usize,
         concat!(
             "Offset of field: ",
@@ -1474,7 +1453,7 @@ fn bindgen_test_layout_kvm_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_regs>())).rflags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_regs, rflags),
         136usize,
         concat!(
             "Offset of field: ",
@@ -1487,7 +1466,7 @@ fn bindgen_test_layout_kvm_regs() {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct kvm_lapic_state {
-    pub regs: [::std::os::raw::c_char; 1024usize],
+    pub regs: [super::raw::c_char; 1024usize],
 }
 #[test]
 fn bindgen_test_layout_kvm_lapic_state() {
@@ -1502,7 +1481,7 @@ fn bindgen_test_layout_kvm_lapic_state() {
         concat!("Alignment of ", stringify!(kvm_lapic_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_lapic_state>())).regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_lapic_state, regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1547,7 +1526,7 @@ fn bindgen_test_layout_kvm_segment() {
         concat!("Alignment of ", stringify!(kvm_segment))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).base as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1557,7 +1536,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).limit as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, limit),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1567,7 +1546,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).selector as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, selector),
         12usize,
         concat!(
             "Offset of field: ",
@@ -1577,7 +1556,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, type_),
         14usize,
         concat!(
             "Offset of field: ",
@@ -1587,7 +1566,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).present as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, present),
         15usize,
         concat!(
             "Offset of field: ",
@@ -1597,7 +1576,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).dpl as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, dpl),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1607,7 +1586,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).db as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, db),
         17usize,
         concat!(
             "Offset of field: ",
@@ -1617,7 +1596,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).s as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, s),
         18usize,
         concat!(
             "Offset of field: ",
@@ -1627,7 +1606,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).l as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, l),
         19usize,
         concat!(
             "Offset of field: ",
@@ -1637,7 +1616,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).g as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, g),
         20usize,
         concat!(
             "Offset of field: ",
@@ -1647,7 +1626,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).avl as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, avl),
         21usize,
         concat!(
             "Offset of field: ",
@@ -1657,7 +1636,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).unusable as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, unusable),
         22usize,
         concat!(
             "Offset of field: ",
@@ -1667,7 +1646,7 @@ fn bindgen_test_layout_kvm_segment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_segment>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_segment, padding),
         23usize,
         concat!(
             "Offset of field: ",
@@ -1697,7 +1676,7 @@ fn bindgen_test_layout_kvm_dtable() {
         concat!("Alignment of ", stringify!(kvm_dtable))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dtable>())).base as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dtable, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1707,7 +1686,7 @@ fn bindgen_test_layout_kvm_dtable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dtable>())).limit as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dtable, limit),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1717,7 +1696,7 @@ fn bindgen_test_layout_kvm_dtable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dtable>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dtable, padding),
         10usize,
         concat!(
             "Offset of field: ",
@@ -1762,7 +1741,7 @@ fn bindgen_test_layout_kvm_sregs() {
         concat!("Alignment of ", stringify!(kvm_sregs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).cs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, cs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1772,7 +1751,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).ds as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, ds),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1782,7 +1761,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).es as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, es),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1792,7 +1771,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).fs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, fs),
         72usize,
         concat!(
             "Offset of field: ",
@@ -1802,7 +1781,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).gs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, gs),
         96usize,
         concat!(
             "Offset of field: ",
@@ -1812,7 +1791,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).ss as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, ss),
         120usize,
         concat!(
             "Offset of field: ",
@@ -1822,7 +1801,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).tr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, tr),
         144usize,
         concat!(
             "Offset of field: ",
@@ -1832,7 +1811,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).ldt as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, ldt),
         168usize,
         concat!(
             "Offset of field: ",
@@ -1842,7 +1821,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).gdt as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, gdt),
         192usize,
         concat!(
             "Offset of field: ",
@@ -1852,7 +1831,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).idt as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, idt),
         208usize,
         concat!(
             "Offset of field: ",
@@ -1862,7 +1841,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).cr0 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, cr0),
         224usize,
         concat!(
             "Offset of field: ",
@@ -1872,7 +1851,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).cr2 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, cr2),
         232usize,
         concat!(
             "Offset of field: ",
@@ -1882,7 +1861,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).cr3 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, cr3),
         240usize,
         concat!(
             "Offset of field: ",
@@ -1892,7 +1871,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).cr4 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, cr4),
         248usize,
         concat!(
             "Offset of field: ",
@@ -1902,7 +1881,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).cr8 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, cr8),
         256usize,
         concat!(
             "Offset of field: ",
@@ -1912,7 +1891,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).efer as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, efer),
         264usize,
         concat!(
             "Offset of field: ",
@@ -1922,7 +1901,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).apic_base as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, apic_base),
         272usize,
         concat!(
             "Offset of field: ",
@@ -1932,7 +1911,7 @@ fn bindgen_test_layout_kvm_sregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sregs>())).interrupt_bitmap as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sregs, interrupt_bitmap),
         280usize,
         concat!(
             "Offset of field: ",
@@ -1970,7 +1949,7 @@ fn bindgen_test_layout_kvm_fpu() {
         concat!("Alignment of ", stringify!(kvm_fpu))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).fpr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, fpr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1980,7 +1959,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).fcw as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, fcw),
         128usize,
         concat!(
             "Offset of field: ",
@@ -1990,7 +1969,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).fsw as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, fsw),
         130usize,
         concat!(
             "Offset of field: ",
@@ -2000,7 +1979,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).ftwx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, ftwx),
         132usize,
         concat!(
             "Offset of field: ",
@@ -2010,7 +1989,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).pad1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, pad1),
         133usize,
         concat!(
             "Offset of field: ",
@@ -2020,7 +1999,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).last_opcode as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, last_opcode),
         134usize,
         concat!(
             "Offset of field: ",
@@ -2030,7 +2009,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).last_ip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, last_ip),
         136usize,
         concat!(
             "Offset of field: ",
@@ -2040,7 +2019,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).last_dp as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, last_dp),
         144usize,
         concat!(
             "Offset of field: ",
@@ -2050,7 +2029,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).xmm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, xmm),
         152usize,
         concat!(
             "Offset of field: ",
@@ -2060,7 +2039,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).mxcsr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, mxcsr),
         408usize,
         concat!(
             "Offset of field: ",
@@ -2070,7 +2049,7 @@ fn bindgen_test_layout_kvm_fpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_fpu>())).pad2 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_fpu, pad2),
         412usize,
         concat!(
             "Offset of field: ",
@@ -2100,7 +2079,7 @@ fn bindgen_test_layout_kvm_msr_entry() {
         concat!("Alignment of ", stringify!(kvm_msr_entry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msr_entry>())).index as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msr_entry, index),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2110,7 +2089,7 @@ fn bindgen_test_layout_kvm_msr_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msr_entry>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msr_entry, reserved),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2120,7 +2099,7 @@ fn bindgen_test_layout_kvm_msr_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msr_entry>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msr_entry, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2150,7 +2129,7 @@ fn bindgen_test_layout_kvm_msrs() {
         concat!("Alignment of ", stringify!(kvm_msrs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msrs>())).nmsrs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msrs, nmsrs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2160,7 +2139,7 @@ fn bindgen_test_layout_kvm_msrs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msrs>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msrs, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2170,7 +2149,7 @@ fn bindgen_test_layout_kvm_msrs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msrs>())).entries as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msrs, entries),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2199,7 +2178,7 @@ fn bindgen_test_layout_kvm_msr_list() {
         concat!("Alignment of ", stringify!(kvm_msr_list))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msr_list>())).nmsrs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msr_list, nmsrs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2209,7 +2188,7 @@ fn bindgen_test_layout_kvm_msr_list() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msr_list>())).indices as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msr_list, indices),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2242,7 +2221,7 @@ fn bindgen_test_layout_kvm_cpuid_entry() {
         concat!("Alignment of ", stringify!(kvm_cpuid_entry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry>())).function as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry, function),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2252,7 +2231,7 @@ fn bindgen_test_layout_kvm_cpuid_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry>())).eax as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry, eax),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2262,7 +2241,7 @@ fn bindgen_test_layout_kvm_cpuid_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry>())).ebx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry, ebx),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2272,7 +2251,7 @@ fn bindgen_test_layout_kvm_cpuid_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry>())).ecx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry, ecx),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2282,7 +2261,7 @@ fn bindgen_test_layout_kvm_cpuid_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry>())).edx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry, edx),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2292,7 +2271,7 @@ fn bindgen_test_layout_kvm_cpuid_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry, padding),
         20usize,
         concat!(
             "Offset of field: ",
@@ -2322,7 +2301,7 @@ fn bindgen_test_layout_kvm_cpuid() {
         concat!("Alignment of ", stringify!(kvm_cpuid))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid>())).nent as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid, nent),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2332,7 +2311,7 @@ fn bindgen_test_layout_kvm_cpuid() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid, padding),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2342,7 +2321,7 @@ fn bindgen_test_layout_kvm_cpuid() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid>())).entries as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid, entries),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2377,7 +2356,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         concat!("Alignment of ", stringify!(kvm_cpuid_entry2))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).function as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, function),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2387,7 +2366,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).index as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, index),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2397,7 +2376,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2407,7 +2386,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).eax as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, eax),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2417,7 +2396,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).ebx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, ebx),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2427,7 +2406,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).ecx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, ecx),
         20usize,
         concat!(
             "Offset of field: ",
@@ -2437,7 +2416,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).edx as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, edx),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2447,7 +2426,7 @@ fn bindgen_test_layout_kvm_cpuid_entry2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid_entry2>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid_entry2, padding),
         28usize,
         concat!(
             "Offset of field: ",
@@ -2477,7 +2456,7 @@ fn bindgen_test_layout_kvm_cpuid2() {
         concat!("Alignment of ", stringify!(kvm_cpuid2))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid2>())).nent as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid2, nent),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2487,7 +2466,7 @@ fn bindgen_test_layout_kvm_cpuid2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid2>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid2, padding),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2497,7 +2476,7 @@ fn bindgen_test_layout_kvm_cpuid2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_cpuid2>())).entries as *const _ as usize },
+        ::std::mem::offset_of!(kvm_cpuid2, entries),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2537,7 +2516,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         concat!("Alignment of ", stringify!(kvm_pit_channel_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_channel_state>())).count as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_channel_state, count),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2547,9 +2526,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pit_channel_state>())).latched_count as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pit_channel_state, latched_count),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2559,9 +2536,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pit_channel_state>())).count_latched as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pit_channel_state, count_latched),
         6usize,
         concat!(
             "Offset of field: ",
@@ -2571,9 +2546,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pit_channel_state>())).status_latched as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pit_channel_state, status_latched),
         7usize,
         concat!(
             "Offset of field: ",
@@ -2583,7 +2556,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_channel_state>())).status as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_channel_state, status),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2593,9 +2566,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pit_channel_state>())).read_state as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pit_channel_state, read_state),
         9usize,
         concat!(
             "Offset of field: ",
@@ -2605,9 +2576,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pit_channel_state>())).write_state as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pit_channel_state, write_state),
         10usize,
         concat!(
             "Offset of field: ",
@@ -2617,9 +2586,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pit_channel_state>())).write_latch as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pit_channel_state, write_latch),
         11usize,
         concat!(
             "Offset of field: ",
@@ -2629,7 +2596,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_channel_state>())).rw_mode as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_channel_state, rw_mode),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2639,7 +2606,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_channel_state>())).mode as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_channel_state, mode),
         13usize,
         concat!(
             "Offset of field: ",
@@ -2649,7 +2616,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_channel_state>())).bcd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_channel_state, bcd),
         14usize,
         concat!(
             "Offset of field: ",
@@ -2659,7 +2626,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_channel_state>())).gate as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_channel_state, gate),
         15usize,
         concat!(
             "Offset of field: ",
@@ -2669,9 +2636,7 @@ fn bindgen_test_layout_kvm_pit_channel_state() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_pit_channel_state>())).count_load_time as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_pit_channel_state, count_load_time),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2703,7 +2668,7 @@ fn bindgen_test_layout_kvm_debug_exit_arch() {
         concat!("Alignment of ", stringify!(kvm_debug_exit_arch))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_exit_arch>())).exception as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_exit_arch, exception),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2713,7 +2678,7 @@ fn bindgen_test_layout_kvm_debug_exit_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_exit_arch>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_exit_arch, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2723,7 +2688,7 @@ fn bindgen_test_layout_kvm_debug_exit_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_exit_arch>())).pc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_exit_arch, pc),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2733,7 +2698,7 @@ fn bindgen_test_layout_kvm_debug_exit_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_exit_arch>())).dr6 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_exit_arch, dr6),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2743,7 +2708,7 @@ fn bindgen_test_layout_kvm_debug_exit_arch() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_exit_arch>())).dr7 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_exit_arch, dr7),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2771,7 +2736,7 @@ fn bindgen_test_layout_kvm_guest_debug_arch() {
         concat!("Alignment of ", stringify!(kvm_guest_debug_arch))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug_arch>())).debugreg as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug_arch, debugreg),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2799,7 +2764,7 @@ fn bindgen_test_layout_kvm_pit_state() {
         concat!("Alignment of ", stringify!(kvm_pit_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_state>())).channels as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_state, channels),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2829,7 +2794,7 @@ fn bindgen_test_layout_kvm_pit_state2() {
         concat!("Alignment of ", stringify!(kvm_pit_state2))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_state2>())).channels as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_state2, channels),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2839,7 +2804,7 @@ fn bindgen_test_layout_kvm_pit_state2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_state2>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_state2, flags),
         72usize,
         concat!(
             "Offset of field: ",
@@ -2849,7 +2814,7 @@ fn bindgen_test_layout_kvm_pit_state2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_state2>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_state2, reserved),
         76usize,
         concat!(
             "Offset of field: ",
@@ -2878,9 +2843,7 @@ fn bindgen_test_layout_kvm_reinject_control() {
         concat!("Alignment of ", stringify!(kvm_reinject_control))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_reinject_control>())).pit_reinject as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_reinject_control, pit_reinject),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2890,7 +2853,7 @@ fn bindgen_test_layout_kvm_reinject_control() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_reinject_control>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_reinject_control, reserved),
         1usize,
         concat!(
             "Offset of field: ",
@@ -2935,9 +2898,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_vcpu_events__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).injected as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, injected),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2947,9 +2908,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, nr),
         1usize,
         concat!(
             "Offset of field: ",
@@ -2959,10 +2918,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).has_error_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, has_error_code),
         2usize,
         concat!(
             "Offset of field: ",
@@ -2972,9 +2928,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).pending as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, pending),
         3usize,
         concat!(
             "Offset of field: ",
@@ -2984,10 +2938,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_1>())).error_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_1, error_code),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3018,9 +2969,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_2() {
         concat!("Alignment of ", stringify!(kvm_vcpu_events__bindgen_ty_2))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_2>())).injected as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_2, injected),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3030,9 +2979,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_2>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_2, nr),
         1usize,
         concat!(
             "Offset of field: ",
@@ -3042,9 +2989,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_2>())).soft as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_2, soft),
         2usize,
         concat!(
             "Offset of field: ",
@@ -3054,9 +2999,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_2>())).shadow as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_2, shadow),
         3usize,
         concat!(
             "Offset of field: ",
@@ -3087,9 +3030,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_3() {
         concat!("Alignment of ", stringify!(kvm_vcpu_events__bindgen_ty_3))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_3>())).injected as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_3, injected),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3099,9 +3040,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_3>())).pending as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_3, pending),
         1usize,
         concat!(
             "Offset of field: ",
@@ -3111,9 +3050,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_3>())).masked as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_3, masked),
         2usize,
         concat!(
             "Offset of field: ",
@@ -3123,9 +3060,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_3>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_3, pad),
         3usize,
         concat!(
             "Offset of field: ",
@@ -3156,9 +3091,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_4() {
         concat!("Alignment of ", stringify!(kvm_vcpu_events__bindgen_ty_4))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_4>())).smm as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_4, smm),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3168,9 +3101,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_4>())).pending as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_4, pending),
         1usize,
         concat!(
             "Offset of field: ",
@@ -3180,10 +3111,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_4>())).smm_inside_nmi as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_4, smm_inside_nmi),
         2usize,
         concat!(
             "Offset of field: ",
@@ -3193,10 +3121,7 @@ fn bindgen_test_layout_kvm_vcpu_events__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events__bindgen_ty_4>())).latched_init as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events__bindgen_ty_4, latched_init),
         3usize,
         concat!(
             "Offset of field: ",
@@ -3219,7 +3144,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         concat!("Alignment of ", stringify!(kvm_vcpu_events))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).exception as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, exception),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3229,7 +3154,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).interrupt as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, interrupt),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3239,7 +3164,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).nmi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, nmi),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3249,7 +3174,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).sipi_vector as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, sipi_vector),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3259,7 +3184,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, flags),
         20usize,
         concat!(
             "Offset of field: ",
@@ -3269,7 +3194,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).smi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, smi),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3279,7 +3204,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vcpu_events>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vcpu_events, reserved),
         28usize,
         concat!(
             "Offset of field: ",
@@ -3289,9 +3214,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events>())).exception_has_payload as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events, exception_has_payload),
         55usize,
         concat!(
             "Offset of field: ",
@@ -3301,9 +3224,7 @@ fn bindgen_test_layout_kvm_vcpu_events() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vcpu_events>())).exception_payload as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_vcpu_events, exception_payload),
         56usize,
         concat!(
             "Offset of field: ",
@@ -3335,7 +3256,7 @@ fn bindgen_test_layout_kvm_debugregs() {
         concat!("Alignment of ", stringify!(kvm_debugregs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debugregs>())).db as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debugregs, db),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3345,7 +3266,7 @@ fn bindgen_test_layout_kvm_debugregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debugregs>())).dr6 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debugregs, dr6),
         32usize,
         concat!(
             "Offset of field: ",
@@ -3355,7 +3276,7 @@ fn bindgen_test_layout_kvm_debugregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debugregs>())).dr7 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debugregs, dr7),
         40usize,
         concat!(
             "Offset of field: ",
@@ -3365,7 +3286,7 @@ fn bindgen_test_layout_kvm_debugregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debugregs>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debugregs, flags),
         48usize,
         concat!(
             "Offset of field: ",
@@ -3375,7 +3296,7 @@ fn bindgen_test_layout_kvm_debugregs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debugregs>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debugregs, reserved),
         56usize,
         concat!(
             "Offset of field: ",
@@ -3403,7 +3324,7 @@ fn bindgen_test_layout_kvm_xsave() {
         concat!("Alignment of ", stringify!(kvm_xsave))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xsave>())).region as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xsave, region),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3438,7 +3359,7 @@ fn bindgen_test_layout_kvm_xcr() {
         concat!("Alignment of ", stringify!(kvm_xcr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xcr>())).xcr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xcr, xcr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3448,7 +3369,7 @@ fn bindgen_test_layout_kvm_xcr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xcr>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xcr, reserved),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3458,7 +3379,7 @@ fn bindgen_test_layout_kvm_xcr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xcr>())).value as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xcr, value),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3489,7 +3410,7 @@ fn bindgen_test_layout_kvm_xcrs() {
         concat!("Alignment of ", stringify!(kvm_xcrs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xcrs>())).nr_xcrs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xcrs, nr_xcrs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3499,7 +3420,7 @@ fn bindgen_test_layout_kvm_xcrs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xcrs>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xcrs, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3509,7 +3430,7 @@ fn bindgen_test_layout_kvm_xcrs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xcrs>())).xcrs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xcrs, xcrs),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3519,7 +3440,7 @@ fn bindgen_test_layout_kvm_xcrs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xcrs>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xcrs, padding),
         264usize,
         concat!(
             "Offset of field: ",
@@ -3549,7 +3470,7 @@ fn bindgen_test_layout_kvm_sync_regs() {
         concat!("Alignment of ", stringify!(kvm_sync_regs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sync_regs>())).regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sync_regs, regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3559,7 +3480,7 @@ fn bindgen_test_layout_kvm_sync_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sync_regs>())).sregs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sync_regs, sregs),
         144usize,
         concat!(
             "Offset of field: ",
@@ -3569,7 +3490,7 @@ fn bindgen_test_layout_kvm_sync_regs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sync_regs>())).events as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sync_regs, events),
         456usize,
         concat!(
             "Offset of field: ",
@@ -3607,10 +3528,7 @@ fn bindgen_test_layout_kvm_vmx_nested_state__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_vmx_nested_state__bindgen_ty_1>())).flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_vmx_nested_state__bindgen_ty_1, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3633,7 +3551,7 @@ fn bindgen_test_layout_kvm_vmx_nested_state() {
         concat!("Alignment of ", stringify!(kvm_vmx_nested_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vmx_nested_state>())).vmxon_pa as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vmx_nested_state, vmxon_pa),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3643,7 +3561,7 @@ fn bindgen_test_layout_kvm_vmx_nested_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vmx_nested_state>())).vmcs_pa as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vmx_nested_state, vmcs_pa),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3653,7 +3571,7 @@ fn bindgen_test_layout_kvm_vmx_nested_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vmx_nested_state>())).smm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vmx_nested_state, smm),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3691,9 +3609,7 @@ fn bindgen_test_layout_kvm_nested_state__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_nested_state__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_nested_state__bindgen_ty_1>())).vmx as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_nested_state__bindgen_ty_1, vmx),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3703,9 +3619,7 @@ fn bindgen_test_layout_kvm_nested_state__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_nested_state__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_nested_state__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3733,7 +3647,7 @@ fn bindgen_test_layout_kvm_nested_state() {
         concat!("Alignment of ", stringify!(kvm_nested_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_nested_state>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_nested_state, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3743,7 +3657,7 @@ fn bindgen_test_layout_kvm_nested_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_nested_state>())).format as *const _ as usize },
+        ::std::mem::offset_of!(kvm_nested_state, format),
         2usize,
         concat!(
             "Offset of field: ",
@@ -3753,7 +3667,7 @@ fn bindgen_test_layout_kvm_nested_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_nested_state>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_nested_state, size),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3763,7 +3677,7 @@ fn bindgen_test_layout_kvm_nested_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_nested_state>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_nested_state, data),
         128usize,
         concat!(
             "Offset of field: ",
@@ -3797,7 +3711,7 @@ fn bindgen_test_layout_kvm_user_trace_setup() {
         concat!("Alignment of ", stringify!(kvm_user_trace_setup))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_user_trace_setup>())).buf_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_user_trace_setup, buf_size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3807,7 +3721,7 @@ fn bindgen_test_layout_kvm_user_trace_setup() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_user_trace_setup>())).buf_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_user_trace_setup, buf_nr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3837,7 +3751,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         concat!("Alignment of ", stringify!(kvm_breakpoint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3847,7 +3761,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, padding),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3857,7 +3771,7 @@ fn bindgen_test_layout_kvm_breakpoint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_breakpoint>())).address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_breakpoint, address),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3888,7 +3802,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         concat!("Alignment of ", stringify!(kvm_debug_guest))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3898,7 +3812,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3908,7 +3822,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).breakpoints as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, breakpoints),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3918,7 +3832,7 @@ fn bindgen_test_layout_kvm_debug_guest() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_debug_guest>())).singlestep as *const _ as usize },
+        ::std::mem::offset_of!(kvm_debug_guest, singlestep),
         72usize,
         concat!(
             "Offset of field: ",
@@ -3949,7 +3863,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         concat!("Alignment of ", stringify!(kvm_memory_region))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).slot as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3959,7 +3873,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3969,9 +3883,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_memory_region>())).guest_phys_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_memory_region, guest_phys_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3981,7 +3893,7 @@ fn bindgen_test_layout_kvm_memory_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_memory_region>())).memory_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_memory_region, memory_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4013,9 +3925,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         concat!("Alignment of ", stringify!(kvm_userspace_memory_region))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).slot as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4025,9 +3935,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4037,10 +3945,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).guest_phys_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, guest_phys_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4050,9 +3955,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).memory_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, memory_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4062,10 +3965,7 @@ fn bindgen_test_layout_kvm_userspace_memory_region() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_userspace_memory_region>())).userspace_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_userspace_memory_region, userspace_addr),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4101,7 +4001,7 @@ fn bindgen_test_layout_kvm_irq_level__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_irq_level__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_level__bindgen_ty_1>())).irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_level__bindgen_ty_1, irq),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4111,9 +4011,7 @@ fn bindgen_test_layout_kvm_irq_level__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_level__bindgen_ty_1>())).status as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_level__bindgen_ty_1, status),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4141,7 +4039,7 @@ fn bindgen_test_layout_kvm_irq_level() {
         concat!("Alignment of ", stringify!(kvm_irq_level))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_level>())).level as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_level, level),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4166,7 +4064,7 @@ pub struct kvm_irqchip {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union kvm_irqchip__bindgen_ty_1 {
-    pub dummy: [::std::os::raw::c_char; 512usize],
+    pub dummy: [super::raw::c_char; 512usize],
     pub pic: kvm_pic_state,
     pub ioapic: kvm_ioapic_state,
     _bindgen_union_align: [u64; 64usize],
@@ -4184,7 +4082,7 @@ fn bindgen_test_layout_kvm_irqchip__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_irqchip__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip__bindgen_ty_1>())).dummy as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip__bindgen_ty_1, dummy),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4194,7 +4092,7 @@ fn bindgen_test_layout_kvm_irqchip__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip__bindgen_ty_1>())).pic as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip__bindgen_ty_1, pic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4204,9 +4102,7 @@ fn bindgen_test_layout_kvm_irqchip__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irqchip__bindgen_ty_1>())).ioapic as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irqchip__bindgen_ty_1, ioapic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4234,7 +4130,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         concat!("Alignment of ", stringify!(kvm_irqchip))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).chip_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, chip_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4244,7 +4140,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4254,7 +4150,7 @@ fn bindgen_test_layout_kvm_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqchip>())).chip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqchip, chip),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4288,7 +4184,7 @@ fn bindgen_test_layout_kvm_pit_config() {
         concat!("Alignment of ", stringify!(kvm_pit_config))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_config>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_config, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4298,7 +4194,7 @@ fn bindgen_test_layout_kvm_pit_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_pit_config>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_pit_config, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4330,7 +4226,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         concat!("Alignment of ", stringify!(kvm_s390_skeys))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).start_gfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, start_gfn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4340,7 +4236,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).count as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, count),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4350,7 +4246,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).skeydata_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, skeydata_addr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4360,7 +4256,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4370,7 +4266,7 @@ fn bindgen_test_layout_kvm_s390_skeys() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_skeys>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_skeys, reserved),
         28usize,
         concat!(
             "Offset of field: ",
@@ -4423,10 +4319,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_s390_cmma_log__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_cmma_log__bindgen_ty_1>())).remaining as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_cmma_log__bindgen_ty_1, remaining),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4436,9 +4329,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_cmma_log__bindgen_ty_1>())).mask as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_cmma_log__bindgen_ty_1, mask),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4466,7 +4357,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         concat!("Alignment of ", stringify!(kvm_s390_cmma_log))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).start_gfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, start_gfn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4476,7 +4367,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).count as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, count),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4486,7 +4377,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -4496,7 +4387,7 @@ fn bindgen_test_layout_kvm_s390_cmma_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_cmma_log>())).values as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_cmma_log, values),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4551,10 +4442,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).msr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, msr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4564,10 +4452,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).control
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, control),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4577,10 +4462,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).evt_page
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, evt_page),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4590,10 +4472,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1>())).msg_page
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_1, msg_page),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4629,10 +4508,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).input
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, input),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4642,10 +4518,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).result
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, result),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4655,10 +4528,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2>())).params
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1__bindgen_ty_2, params),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4681,9 +4551,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_hyperv_exit__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1>())).synic as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1, synic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4693,9 +4561,7 @@ fn bindgen_test_layout_kvm_hyperv_exit__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_hyperv_exit__bindgen_ty_1>())).hcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_hyperv_exit__bindgen_ty_1, hcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4723,7 +4589,7 @@ fn bindgen_test_layout_kvm_hyperv_exit() {
         concat!("Alignment of ", stringify!(kvm_hyperv_exit))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_exit>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_exit, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4733,7 +4599,7 @@ fn bindgen_test_layout_kvm_hyperv_exit() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_exit>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_exit, u),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4789,7 +4655,7 @@ pub union kvm_run__bindgen_ty_1 {
     pub s390_stsi: kvm_run__bindgen_ty_1__bindgen_ty_18,
     pub eoi: kvm_run__bindgen_ty_1__bindgen_ty_19,
     pub hyperv: kvm_hyperv_exit,
-    pub padding: [::std::os::raw::c_char; 256usize],
+    pub padding: [super::raw::c_char; 256usize],
     _bindgen_union_align: [u64; 32usize],
 }
 #[repr(C)]
@@ -4813,10 +4679,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_1>())).hardware_exit_reason
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_1, hardware_exit_reason),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4847,10 +4710,10 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_2>()))
-                .hardware_entry_failure_reason as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            kvm_run__bindgen_ty_1__bindgen_ty_2,
+            hardware_entry_failure_reason
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4882,10 +4745,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_3>())).exception as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_3, exception),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4895,10 +4755,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_3>())).error_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_3, error_code),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4933,10 +4790,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).direction as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, direction),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4946,10 +4800,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, size),
         1usize,
         concat!(
             "Offset of field: ",
@@ -4959,10 +4810,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).port as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, port),
         2usize,
         concat!(
             "Offset of field: ",
@@ -4972,10 +4820,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).count as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, count),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4985,10 +4830,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_4>())).data_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_4, data_offset),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5019,10 +4861,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_5() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_5>())).arch as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_5, arch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5056,10 +4895,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).phys_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, phys_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5069,10 +4905,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5082,9 +4915,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).len as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5094,10 +4925,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_6>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_6, is_write),
         20usize,
         concat!(
             "Offset of field: ",
@@ -5132,9 +4960,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5144,10 +4970,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).args as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, args),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5157,9 +4980,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).ret as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, ret),
         56usize,
         concat!(
             "Offset of field: ",
@@ -5169,10 +4990,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).longmode as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, longmode),
         64usize,
         concat!(
             "Offset of field: ",
@@ -5182,9 +5000,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_7>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_7, pad),
         68usize,
         concat!(
             "Offset of field: ",
@@ -5217,9 +5033,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).rip as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, rip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5229,10 +5043,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, is_write),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5242,9 +5053,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_8() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_8>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_8, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5277,10 +5086,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).icptcode as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, icptcode),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5290,9 +5096,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).ipa as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, ipa),
         2usize,
         concat!(
             "Offset of field: ",
@@ -5302,9 +5106,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_9() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_9>())).ipb as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_9, ipb),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5339,10 +5141,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_10() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_10>())).trans_exc_code
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_10, trans_exc_code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5352,10 +5151,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_10() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_10>())).pgm_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_10, pgm_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5391,10 +5187,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).dcrn as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, dcrn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5404,10 +5197,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, data),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5417,10 +5207,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_11() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_11>())).is_write as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_11, is_write),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5456,10 +5243,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).suberror as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, suberror),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5469,10 +5253,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).ndata as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, ndata),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5482,10 +5263,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_12() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_12>())).data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_12, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5519,10 +5297,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_13() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_13>())).gprs as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_13, gprs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5558,9 +5333,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).nr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5570,10 +5343,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).ret as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, ret),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5583,10 +5353,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_14() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_14>())).args as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_14, args),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5625,10 +5392,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).subchannel_id
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, subchannel_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5638,10 +5402,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).subchannel_nr
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, subchannel_nr),
         2usize,
         concat!(
             "Offset of field: ",
@@ -5651,10 +5412,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).io_int_parm as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, io_int_parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5664,10 +5422,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).io_int_word as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, io_int_word),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5677,10 +5432,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).ipb as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, ipb),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5690,10 +5442,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_15() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_15>())).dequeued as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_15, dequeued),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5727,10 +5476,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_16() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_16>())).epr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_16, epr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5765,10 +5511,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_17() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_17>())).type_ as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_17, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5778,10 +5521,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_17() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_17>())).flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_17, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5820,10 +5560,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5833,9 +5570,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).ar as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, ar),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5845,10 +5580,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, reserved),
         9usize,
         concat!(
             "Offset of field: ",
@@ -5858,9 +5590,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).fc as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, fc),
         10usize,
         concat!(
             "Offset of field: ",
@@ -5870,10 +5600,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).sel1 as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, sel1),
         11usize,
         concat!(
             "Offset of field: ",
@@ -5883,10 +5610,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_18() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_18>())).sel2 as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_18, sel2),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5920,10 +5644,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1__bindgen_ty_19() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1__bindgen_ty_19>())).vector as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1__bindgen_ty_19, vector),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5946,7 +5667,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_run__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hw as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hw),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5956,9 +5677,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).fail_entry as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, fail_entry),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5968,7 +5687,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).ex as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, ex),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5978,7 +5697,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).io as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, io),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5988,7 +5707,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).debug as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, debug),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5998,7 +5717,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).mmio as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, mmio),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6008,7 +5727,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hypercall as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hypercall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6018,9 +5737,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).tpr_access as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, tpr_access),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6030,9 +5747,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_sieic as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_sieic),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6042,9 +5757,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_reset_flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_reset_flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6054,9 +5767,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_ucontrol as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_ucontrol),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6066,7 +5777,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).dcr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, dcr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6076,7 +5787,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).internal as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, internal),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6086,7 +5797,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).osi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, osi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6096,9 +5807,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).papr_hcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, papr_hcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6108,7 +5817,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_tsch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_tsch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6118,7 +5827,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).epr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, epr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6128,9 +5837,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).system_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, system_event),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6140,7 +5847,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).s390_stsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, s390_stsi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6150,7 +5857,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).eoi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, eoi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6160,7 +5867,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).hyperv as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, hyperv),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6170,7 +5877,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_1>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_1, padding),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6189,7 +5896,7 @@ impl Default for kvm_run__bindgen_ty_1 {
 #[derive(Copy, Clone)]
 pub union kvm_run__bindgen_ty_2 {
     pub regs: kvm_sync_regs,
-    pub padding: [::std::os::raw::c_char; 2048usize],
+    pub padding: [super::raw::c_char; 2048usize],
     _bindgen_union_align: [u64; 256usize],
 }
 #[test]
@@ -6205,7 +5912,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_2() {
         concat!("Alignment of ", stringify!(kvm_run__bindgen_ty_2))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_2>())).regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_2, regs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6215,7 +5922,7 @@ fn bindgen_test_layout_kvm_run__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run__bindgen_ty_2>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run__bindgen_ty_2, padding),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6243,9 +5950,7 @@ fn bindgen_test_layout_kvm_run() {
         concat!("Alignment of ", stringify!(kvm_run))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run>())).request_interrupt_window as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run, request_interrupt_window),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6255,7 +5960,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).immediate_exit as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, immediate_exit),
         1usize,
         concat!(
             "Offset of field: ",
@@ -6265,7 +5970,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).padding1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, padding1),
         2usize,
         concat!(
             "Offset of field: ",
@@ -6275,7 +5980,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).exit_reason as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, exit_reason),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6285,9 +5990,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_run>())).ready_for_interrupt_injection as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_run, ready_for_interrupt_injection),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6297,7 +6000,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).if_flag as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, if_flag),
         13usize,
         concat!(
             "Offset of field: ",
@@ -6307,7 +6010,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, flags),
         14usize,
         concat!(
             "Offset of field: ",
@@ -6317,7 +6020,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).cr8 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, cr8),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6327,7 +6030,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).apic_base as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, apic_base),
         24usize,
         concat!(
             "Offset of field: ",
@@ -6337,7 +6040,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).kvm_valid_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, kvm_valid_regs),
         288usize,
         concat!(
             "Offset of field: ",
@@ -6347,7 +6050,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).kvm_dirty_regs as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, kvm_dirty_regs),
         296usize,
         concat!(
             "Offset of field: ",
@@ -6357,7 +6060,7 @@ fn bindgen_test_layout_kvm_run() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_run>())).s as *const _ as usize },
+        ::std::mem::offset_of!(kvm_run, s),
         304usize,
         concat!(
             "Offset of field: ",
@@ -6405,10 +6108,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio_zone__bindgen_ty_1>())).pad as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6418,10 +6118,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio_zone__bindgen_ty_1>())).pio as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone__bindgen_ty_1, pio),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6449,7 +6146,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio_zone))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6459,7 +6156,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_zone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_zone>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_zone, size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6505,9 +6202,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6517,9 +6212,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio__bindgen_ty_1>())).pio as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio__bindgen_ty_1, pio),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6547,7 +6240,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).phys_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, phys_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6557,7 +6250,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6567,7 +6260,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio, data),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6601,7 +6294,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         concat!("Alignment of ", stringify!(kvm_coalesced_mmio_ring))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).first as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, first),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6611,7 +6304,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).last as *const _ as usize },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, last),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6621,9 +6314,7 @@ fn bindgen_test_layout_kvm_coalesced_mmio_ring() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_coalesced_mmio_ring>())).coalesced_mmio as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_coalesced_mmio_ring, coalesced_mmio),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6661,7 +6352,7 @@ fn bindgen_test_layout_kvm_translation() {
         concat!("Alignment of ", stringify!(kvm_translation))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).linear_address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, linear_address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6671,9 +6362,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_translation>())).physical_address as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_translation, physical_address),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6683,7 +6372,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).valid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, valid),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6693,7 +6382,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).writeable as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, writeable),
         17usize,
         concat!(
             "Offset of field: ",
@@ -6703,7 +6392,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).usermode as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, usermode),
         18usize,
         concat!(
             "Offset of field: ",
@@ -6713,7 +6402,7 @@ fn bindgen_test_layout_kvm_translation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_translation>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_translation, pad),
         19usize,
         concat!(
             "Offset of field: ",
@@ -6747,7 +6436,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         concat!("Alignment of ", stringify!(kvm_s390_mem_op))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).gaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, gaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6757,7 +6446,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6767,7 +6456,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6777,7 +6466,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).op as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, op),
         20usize,
         concat!(
             "Offset of field: ",
@@ -6787,7 +6476,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).buf as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, buf),
         24usize,
         concat!(
             "Offset of field: ",
@@ -6797,7 +6486,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).ar as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, ar),
         32usize,
         concat!(
             "Offset of field: ",
@@ -6807,7 +6496,7 @@ fn bindgen_test_layout_kvm_s390_mem_op() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mem_op>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mem_op, reserved),
         33usize,
         concat!(
             "Offset of field: ",
@@ -6835,7 +6524,7 @@ fn bindgen_test_layout_kvm_interrupt() {
         concat!("Alignment of ", stringify!(kvm_interrupt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_interrupt>())).irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_interrupt, irq),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6855,7 +6544,7 @@ pub struct kvm_dirty_log {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union kvm_dirty_log__bindgen_ty_1 {
-    pub dirty_bitmap: *mut ::std::os::raw::c_void,
+    pub dirty_bitmap: *mut super::raw::c_void,
     pub padding2: __u64,
     _bindgen_union_align: u64,
 }
@@ -6872,10 +6561,7 @@ fn bindgen_test_layout_kvm_dirty_log__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_dirty_log__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_dirty_log__bindgen_ty_1>())).dirty_bitmap as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_dirty_log__bindgen_ty_1, dirty_bitmap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6885,9 +6571,7 @@ fn bindgen_test_layout_kvm_dirty_log__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_dirty_log__bindgen_ty_1>())).padding2 as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_dirty_log__bindgen_ty_1, padding2),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6915,7 +6599,7 @@ fn bindgen_test_layout_kvm_dirty_log() {
         concat!("Alignment of ", stringify!(kvm_dirty_log))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_log>())).slot as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_log, slot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6925,7 +6609,7 @@ fn bindgen_test_layout_kvm_dirty_log() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_log>())).padding1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_log, padding1),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6959,7 +6643,7 @@ fn bindgen_test_layout_kvm_signal_mask() {
         concat!("Alignment of ", stringify!(kvm_signal_mask))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_signal_mask>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_signal_mask, len),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6969,7 +6653,7 @@ fn bindgen_test_layout_kvm_signal_mask() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_signal_mask>())).sigset as *const _ as usize },
+        ::std::mem::offset_of!(kvm_signal_mask, sigset),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6999,7 +6683,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         concat!("Alignment of ", stringify!(kvm_tpr_access_ctl))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).enabled as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, enabled),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7009,7 +6693,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7019,7 +6703,7 @@ fn bindgen_test_layout_kvm_tpr_access_ctl() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_tpr_access_ctl>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_tpr_access_ctl, reserved),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7047,7 +6731,7 @@ fn bindgen_test_layout_kvm_vapic_addr() {
         concat!("Alignment of ", stringify!(kvm_vapic_addr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vapic_addr>())).vapic_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vapic_addr, vapic_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7075,7 +6759,7 @@ fn bindgen_test_layout_kvm_mp_state() {
         concat!("Alignment of ", stringify!(kvm_mp_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_mp_state>())).mp_state as *const _ as usize },
+        ::std::mem::offset_of!(kvm_mp_state, mp_state),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7104,7 +6788,7 @@ fn bindgen_test_layout_kvm_s390_psw() {
         concat!("Alignment of ", stringify!(kvm_s390_psw))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_psw>())).mask as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_psw, mask),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7114,7 +6798,7 @@ fn bindgen_test_layout_kvm_s390_psw() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_psw>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_psw, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7144,7 +6828,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         concat!("Alignment of ", stringify!(kvm_s390_interrupt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7154,7 +6838,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).parm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7164,7 +6848,7 @@ fn bindgen_test_layout_kvm_s390_interrupt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_interrupt>())).parm64 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_interrupt, parm64),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7195,7 +6879,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         concat!("Alignment of ", stringify!(kvm_s390_io_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).subchannel_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, subchannel_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7205,7 +6889,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).subchannel_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, subchannel_nr),
         2usize,
         concat!(
             "Offset of field: ",
@@ -7215,7 +6899,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).io_int_parm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, io_int_parm),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7225,7 +6909,7 @@ fn bindgen_test_layout_kvm_s390_io_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_io_info>())).io_int_word as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_io_info, io_int_word),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7255,7 +6939,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         concat!("Alignment of ", stringify!(kvm_s390_ext_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).ext_params as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, ext_params),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7265,7 +6949,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7275,7 +6959,7 @@ fn bindgen_test_layout_kvm_s390_ext_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ext_info>())).ext_params2 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ext_info, ext_params2),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7315,9 +6999,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         concat!("Alignment of ", stringify!(kvm_s390_pgm_info))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_pgm_info>())).trans_exc_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, trans_exc_code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7327,7 +7009,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).mon_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, mon_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7337,7 +7019,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_address),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7347,7 +7029,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).data_exc_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, data_exc_code),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7357,7 +7039,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, code),
         28usize,
         concat!(
             "Offset of field: ",
@@ -7367,7 +7049,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).mon_class_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, mon_class_nr),
         30usize,
         concat!(
             "Offset of field: ",
@@ -7377,7 +7059,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_code),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7387,7 +7069,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_atmid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_atmid),
         33usize,
         concat!(
             "Offset of field: ",
@@ -7397,7 +7079,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).exc_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, exc_access_id),
         34usize,
         concat!(
             "Offset of field: ",
@@ -7407,7 +7089,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).per_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, per_access_id),
         35usize,
         concat!(
             "Offset of field: ",
@@ -7417,7 +7099,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).op_access_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, op_access_id),
         36usize,
         concat!(
             "Offset of field: ",
@@ -7427,7 +7109,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, flags),
         37usize,
         concat!(
             "Offset of field: ",
@@ -7437,7 +7119,7 @@ fn bindgen_test_layout_kvm_s390_pgm_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_pgm_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_pgm_info, pad),
         38usize,
         concat!(
             "Offset of field: ",
@@ -7465,7 +7147,7 @@ fn bindgen_test_layout_kvm_s390_prefix_info() {
         concat!("Alignment of ", stringify!(kvm_s390_prefix_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_prefix_info>())).address as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_prefix_info, address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7493,7 +7175,7 @@ fn bindgen_test_layout_kvm_s390_extcall_info() {
         concat!("Alignment of ", stringify!(kvm_s390_extcall_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_extcall_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_extcall_info, code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7521,7 +7203,7 @@ fn bindgen_test_layout_kvm_s390_emerg_info() {
         concat!("Alignment of ", stringify!(kvm_s390_emerg_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_emerg_info>())).code as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_emerg_info, code),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7549,7 +7231,7 @@ fn bindgen_test_layout_kvm_s390_stop_info() {
         concat!("Alignment of ", stringify!(kvm_s390_stop_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_stop_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_stop_info, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7582,7 +7264,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         concat!("Alignment of ", stringify!(kvm_s390_mchk_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).cr14 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, cr14),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7592,7 +7274,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).mcic as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, mcic),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7602,10 +7284,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_mchk_info>())).failing_storage_address as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, failing_storage_address),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7615,9 +7294,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_mchk_info>())).ext_damage_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, ext_damage_code),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7627,7 +7304,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, pad),
         28usize,
         concat!(
             "Offset of field: ",
@@ -7637,7 +7314,7 @@ fn bindgen_test_layout_kvm_s390_mchk_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_mchk_info>())).fixed_logout as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_mchk_info, fixed_logout),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7664,7 +7341,7 @@ pub union kvm_s390_irq__bindgen_ty_1 {
     pub prefix: kvm_s390_prefix_info,
     pub stop: kvm_s390_stop_info,
     pub mchk: kvm_s390_mchk_info,
-    pub reserved: [::std::os::raw::c_char; 64usize],
+    pub reserved: [super::raw::c_char; 64usize],
     _bindgen_union_align: [u64; 8usize],
 }
 #[test]
@@ -7680,7 +7357,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_s390_irq__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).io as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, io),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7690,7 +7367,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).ext as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, ext),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7700,7 +7377,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).pgm as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, pgm),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7710,9 +7387,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).emerg as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, emerg),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7722,9 +7397,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).extcall as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, extcall),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7734,9 +7407,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).prefix as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, prefix),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7746,7 +7417,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).stop as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, stop),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7756,7 +7427,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).mchk as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, mchk),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7766,9 +7437,7 @@ fn bindgen_test_layout_kvm_s390_irq__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_s390_irq__bindgen_ty_1>())).reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_s390_irq__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7796,7 +7465,7 @@ fn bindgen_test_layout_kvm_s390_irq() {
         concat!("Alignment of ", stringify!(kvm_s390_irq))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7806,7 +7475,7 @@ fn bindgen_test_layout_kvm_s390_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq, u),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7842,7 +7511,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         concat!("Alignment of ", stringify!(kvm_s390_irq_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).buf as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, buf),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7852,7 +7521,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7862,7 +7531,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, len),
         12usize,
         concat!(
             "Offset of field: ",
@@ -7872,7 +7541,7 @@ fn bindgen_test_layout_kvm_s390_irq_state() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_irq_state>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_irq_state, reserved),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7902,7 +7571,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         concat!("Alignment of ", stringify!(kvm_guest_debug))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).control as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, control),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7912,7 +7581,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, pad),
         4usize,
         concat!(
             "Offset of field: ",
@@ -7922,7 +7591,7 @@ fn bindgen_test_layout_kvm_guest_debug() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_guest_debug>())).arch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_guest_debug, arch),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7962,7 +7631,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         concat!("Alignment of ", stringify!(kvm_ioeventfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).datamatch as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, datamatch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7972,7 +7641,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7982,7 +7651,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7992,7 +7661,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, fd),
         20usize,
         concat!(
             "Offset of field: ",
@@ -8002,7 +7671,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8012,7 +7681,7 @@ fn bindgen_test_layout_kvm_ioeventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ioeventfd>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ioeventfd, pad),
         28usize,
         concat!(
             "Offset of field: ",
@@ -8048,7 +7717,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         concat!("Alignment of ", stringify!(kvm_enable_cap))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).cap as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, cap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8058,7 +7727,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8068,7 +7737,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).args as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, args),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8078,7 +7747,7 @@ fn bindgen_test_layout_kvm_enable_cap() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enable_cap>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enable_cap, pad),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8113,7 +7782,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         concat!("Alignment of ", stringify!(kvm_ppc_pvinfo))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8123,7 +7792,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).hcall as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, hcall),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8133,7 +7802,7 @@ fn bindgen_test_layout_kvm_ppc_pvinfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_pvinfo>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_pvinfo, pad),
         20usize,
         concat!(
             "Offset of field: ",
@@ -8167,9 +7836,7 @@ fn bindgen_test_layout_kvm_ppc_one_page_size() {
         concat!("Alignment of ", stringify!(kvm_ppc_one_page_size))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_page_size>())).page_shift as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_page_size, page_shift),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8179,7 +7846,7 @@ fn bindgen_test_layout_kvm_ppc_one_page_size() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_one_page_size>())).pte_enc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_one_page_size, pte_enc),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8209,9 +7876,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         concat!("Alignment of ", stringify!(kvm_ppc_one_seg_page_size))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).page_shift as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, page_shift),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8221,9 +7886,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).slb_enc as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, slb_enc),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8233,7 +7896,7 @@ fn bindgen_test_layout_kvm_ppc_one_seg_page_size() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_one_seg_page_size>())).enc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_one_seg_page_size, enc),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8265,7 +7928,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         concat!("Alignment of ", stringify!(kvm_ppc_smmu_info))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8275,7 +7938,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).slb_size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, slb_size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8285,7 +7948,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).data_keys as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, data_keys),
         12usize,
         concat!(
             "Offset of field: ",
@@ -8295,7 +7958,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).instr_keys as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, instr_keys),
         14usize,
         concat!(
             "Offset of field: ",
@@ -8305,7 +7968,7 @@ fn bindgen_test_layout_kvm_ppc_smmu_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_smmu_info>())).sps as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_smmu_info, sps),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8335,7 +7998,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         concat!("Alignment of ", stringify!(kvm_ppc_resize_hpt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8345,7 +8008,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).shift as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, shift),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8355,7 +8018,7 @@ fn bindgen_test_layout_kvm_ppc_resize_hpt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_ppc_resize_hpt>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_ppc_resize_hpt, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -8384,7 +8047,7 @@ fn bindgen_test_layout_kvm_irq_routing_irqchip() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_irqchip))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_irqchip>())).irqchip as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_irqchip, irqchip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8394,7 +8057,7 @@ fn bindgen_test_layout_kvm_irq_routing_irqchip() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_irqchip>())).pin as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_irqchip, pin),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8435,9 +8098,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_msi__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_msi__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8447,9 +8108,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_msi__bindgen_ty_1>())).devid as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_msi__bindgen_ty_1, devid),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8477,7 +8136,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_msi))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).address_lo as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, address_lo),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8487,7 +8146,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).address_hi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, address_hi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8497,7 +8156,7 @@ fn bindgen_test_layout_kvm_irq_routing_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_msi>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_msi, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8534,9 +8193,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_s390_adapter))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).ind_addr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, ind_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8546,10 +8203,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).summary_addr as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, summary_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8559,9 +8213,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).ind_offset as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, ind_offset),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8571,10 +8223,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).summary_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, summary_offset),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8584,9 +8233,7 @@ fn bindgen_test_layout_kvm_irq_routing_s390_adapter() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_s390_adapter>())).adapter_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_s390_adapter, adapter_id),
         28usize,
         concat!(
             "Offset of field: ",
@@ -8615,7 +8262,7 @@ fn bindgen_test_layout_kvm_irq_routing_hv_sint() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_hv_sint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_hv_sint>())).vcpu as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_hv_sint, vcpu),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8625,7 +8272,7 @@ fn bindgen_test_layout_kvm_irq_routing_hv_sint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_hv_sint>())).sint as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_hv_sint, sint),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8670,10 +8317,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).irqchip as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, irqchip),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8683,9 +8327,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).msi as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, msi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8695,10 +8337,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).adapter as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, adapter),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8708,10 +8347,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).hv_sint as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, hv_sint),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8721,9 +8357,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_irq_routing_entry__bindgen_ty_1>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_irq_routing_entry__bindgen_ty_1, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8751,7 +8385,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         concat!("Alignment of ", stringify!(kvm_irq_routing_entry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, gsi),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8761,7 +8395,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, type_),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8771,7 +8405,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8781,7 +8415,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -8791,7 +8425,7 @@ fn bindgen_test_layout_kvm_irq_routing_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing_entry>())).u as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing_entry, u),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8825,7 +8459,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         concat!("Alignment of ", stringify!(kvm_irq_routing))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, nr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8835,7 +8469,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, flags),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8845,7 +8479,7 @@ fn bindgen_test_layout_kvm_irq_routing() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irq_routing>())).entries as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irq_routing, entries),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8884,7 +8518,7 @@ fn bindgen_test_layout_kvm_x86_mce() {
         concat!("Alignment of ", stringify!(kvm_x86_mce))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_x86_mce>())).status as *const _ as usize },
+        ::std::mem::offset_of!(kvm_x86_mce, status),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8894,7 +8528,7 @@ fn bindgen_test_layout_kvm_x86_mce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_x86_mce>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_x86_mce, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8904,7 +8538,7 @@ fn bindgen_test_layout_kvm_x86_mce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_x86_mce>())).misc as *const _ as usize },
+        ::std::mem::offset_of!(kvm_x86_mce, misc),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8914,7 +8548,7 @@ fn bindgen_test_layout_kvm_x86_mce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_x86_mce>())).mcg_status as *const _ as usize },
+        ::std::mem::offset_of!(kvm_x86_mce, mcg_status),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8924,7 +8558,7 @@ fn bindgen_test_layout_kvm_x86_mce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_x86_mce>())).bank as *const _ as usize },
+        ::std::mem::offset_of!(kvm_x86_mce, bank),
         32usize,
         concat!(
             "Offset of field: ",
@@ -8934,7 +8568,7 @@ fn bindgen_test_layout_kvm_x86_mce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_x86_mce>())).pad1 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_x86_mce, pad1),
         33usize,
         concat!(
             "Offset of field: ",
@@ -8944,7 +8578,7 @@ fn bindgen_test_layout_kvm_x86_mce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_x86_mce>())).pad2 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_x86_mce, pad2),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8978,7 +8612,7 @@ fn bindgen_test_layout_kvm_xen_hvm_config() {
         concat!("Alignment of ", stringify!(kvm_xen_hvm_config))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xen_hvm_config>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xen_hvm_config, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8988,7 +8622,7 @@ fn bindgen_test_layout_kvm_xen_hvm_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xen_hvm_config>())).msr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xen_hvm_config, msr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8998,7 +8632,7 @@ fn bindgen_test_layout_kvm_xen_hvm_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xen_hvm_config>())).blob_addr_32 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xen_hvm_config, blob_addr_32),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9008,7 +8642,7 @@ fn bindgen_test_layout_kvm_xen_hvm_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xen_hvm_config>())).blob_addr_64 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xen_hvm_config, blob_addr_64),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9018,7 +8652,7 @@ fn bindgen_test_layout_kvm_xen_hvm_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xen_hvm_config>())).blob_size_32 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xen_hvm_config, blob_size_32),
         24usize,
         concat!(
             "Offset of field: ",
@@ -9028,7 +8662,7 @@ fn bindgen_test_layout_kvm_xen_hvm_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xen_hvm_config>())).blob_size_64 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xen_hvm_config, blob_size_64),
         25usize,
         concat!(
             "Offset of field: ",
@@ -9038,7 +8672,7 @@ fn bindgen_test_layout_kvm_xen_hvm_config() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_xen_hvm_config>())).pad2 as *const _ as usize },
+        ::std::mem::offset_of!(kvm_xen_hvm_config, pad2),
         26usize,
         concat!(
             "Offset of field: ",
@@ -9070,7 +8704,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         concat!("Alignment of ", stringify!(kvm_irqfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, fd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9080,7 +8714,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, gsi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9090,7 +8724,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9100,7 +8734,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).resamplefd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, resamplefd),
         12usize,
         concat!(
             "Offset of field: ",
@@ -9110,7 +8744,7 @@ fn bindgen_test_layout_kvm_irqfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_irqfd>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_irqfd, pad),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9140,7 +8774,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         concat!("Alignment of ", stringify!(kvm_clock_data))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).clock as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, clock),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9150,7 +8784,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9160,7 +8794,7 @@ fn bindgen_test_layout_kvm_clock_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_clock_data>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_clock_data, pad),
         12usize,
         concat!(
             "Offset of field: ",
@@ -9191,7 +8825,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         concat!("Alignment of ", stringify!(kvm_config_tlb))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).params as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, params),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9201,7 +8835,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).array as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, array),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9211,7 +8845,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).mmu_type as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, mmu_type),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9221,7 +8855,7 @@ fn bindgen_test_layout_kvm_config_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_config_tlb>())).array_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_config_tlb, array_len),
         20usize,
         concat!(
             "Offset of field: ",
@@ -9250,7 +8884,7 @@ fn bindgen_test_layout_kvm_dirty_tlb() {
         concat!("Alignment of ", stringify!(kvm_dirty_tlb))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_tlb>())).bitmap as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_tlb, bitmap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9260,7 +8894,7 @@ fn bindgen_test_layout_kvm_dirty_tlb() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_dirty_tlb>())).num_dirty as *const _ as usize },
+        ::std::mem::offset_of!(kvm_dirty_tlb, num_dirty),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9289,7 +8923,7 @@ fn bindgen_test_layout_kvm_reg_list() {
         concat!("Alignment of ", stringify!(kvm_reg_list))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_reg_list>())).n as *const _ as usize },
+        ::std::mem::offset_of!(kvm_reg_list, n),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9299,7 +8933,7 @@ fn bindgen_test_layout_kvm_reg_list() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_reg_list>())).reg as *const _ as usize },
+        ::std::mem::offset_of!(kvm_reg_list, reg),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9328,7 +8962,7 @@ fn bindgen_test_layout_kvm_one_reg() {
         concat!("Alignment of ", stringify!(kvm_one_reg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_one_reg>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_one_reg, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9338,7 +8972,7 @@ fn bindgen_test_layout_kvm_one_reg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_one_reg>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_one_reg, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9371,7 +9005,7 @@ fn bindgen_test_layout_kvm_msi() {
         concat!("Alignment of ", stringify!(kvm_msi))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).address_lo as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, address_lo),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9381,7 +9015,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).address_hi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, address_hi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9391,7 +9025,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9401,7 +9035,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -9411,7 +9045,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).devid as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, devid),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9421,7 +9055,7 @@ fn bindgen_test_layout_kvm_msi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_msi>())).pad as *const _ as usize },
+        ::std::mem::offset_of!(kvm_msi, pad),
         20usize,
         concat!(
             "Offset of field: ",
@@ -9450,7 +9084,7 @@ fn bindgen_test_layout_kvm_arm_device_addr() {
         concat!("Alignment of ", stringify!(kvm_arm_device_addr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_arm_device_addr>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_arm_device_addr, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9460,7 +9094,7 @@ fn bindgen_test_layout_kvm_arm_device_addr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_arm_device_addr>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_arm_device_addr, addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9490,7 +9124,7 @@ fn bindgen_test_layout_kvm_create_device() {
         concat!("Alignment of ", stringify!(kvm_create_device))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9500,7 +9134,7 @@ fn bindgen_test_layout_kvm_create_device() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, fd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9510,7 +9144,7 @@ fn bindgen_test_layout_kvm_create_device() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_create_device>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_create_device, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9541,7 +9175,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         concat!("Alignment of ", stringify!(kvm_device_attr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9551,7 +9185,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).group as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, group),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9561,7 +9195,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).attr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, attr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9571,7 +9205,7 @@ fn bindgen_test_layout_kvm_device_attr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_device_attr>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_device_attr, addr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9610,7 +9244,7 @@ fn bindgen_test_layout_kvm_vfio_spapr_tce() {
         concat!("Alignment of ", stringify!(kvm_vfio_spapr_tce))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vfio_spapr_tce>())).groupfd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vfio_spapr_tce, groupfd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9620,7 +9254,7 @@ fn bindgen_test_layout_kvm_vfio_spapr_tce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_vfio_spapr_tce>())).tablefd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_vfio_spapr_tce, tablefd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9650,7 +9284,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         concat!("Alignment of ", stringify!(kvm_s390_ucas_mapping))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).user_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, user_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9660,7 +9294,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).vcpu_addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, vcpu_addr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9670,7 +9304,7 @@ fn bindgen_test_layout_kvm_s390_ucas_mapping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_s390_ucas_mapping>())).length as *const _ as usize },
+        ::std::mem::offset_of!(kvm_s390_ucas_mapping, length),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9699,7 +9333,7 @@ fn bindgen_test_layout_kvm_enc_region() {
         concat!("Alignment of ", stringify!(kvm_enc_region))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enc_region>())).addr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enc_region, addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9709,7 +9343,7 @@ fn bindgen_test_layout_kvm_enc_region() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_enc_region>())).size as *const _ as usize },
+        ::std::mem::offset_of!(kvm_enc_region, size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9762,7 +9396,7 @@ fn bindgen_test_layout_kvm_sev_cmd() {
         concat!("Alignment of ", stringify!(kvm_sev_cmd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_cmd>())).id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_cmd, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9772,7 +9406,7 @@ fn bindgen_test_layout_kvm_sev_cmd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_cmd>())).data as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_cmd, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9782,7 +9416,7 @@ fn bindgen_test_layout_kvm_sev_cmd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_cmd>())).error as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_cmd, error),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9792,7 +9426,7 @@ fn bindgen_test_layout_kvm_sev_cmd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_cmd>())).sev_fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_cmd, sev_fd),
         20usize,
         concat!(
             "Offset of field: ",
@@ -9825,7 +9459,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         concat!("Alignment of ", stringify!(kvm_sev_launch_start))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_start>())).handle as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_start, handle),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9835,7 +9469,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_start>())).policy as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_start, policy),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9845,7 +9479,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_start>())).dh_uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_start, dh_uaddr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9855,7 +9489,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_start>())).dh_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_start, dh_len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9865,9 +9499,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_start>())).session_uaddr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_start, session_uaddr),
         24usize,
         concat!(
             "Offset of field: ",
@@ -9877,9 +9509,7 @@ fn bindgen_test_layout_kvm_sev_launch_start() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_start>())).session_len as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_start, session_len),
         32usize,
         concat!(
             "Offset of field: ",
@@ -9908,9 +9538,7 @@ fn bindgen_test_layout_kvm_sev_launch_update_data() {
         concat!("Alignment of ", stringify!(kvm_sev_launch_update_data))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_update_data>())).uaddr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_update_data, uaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9920,7 +9548,7 @@ fn bindgen_test_layout_kvm_sev_launch_update_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_update_data>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_update_data, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9953,7 +9581,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         concat!("Alignment of ", stringify!(kvm_sev_launch_secret))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_secret>())).hdr_uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, hdr_uaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9963,7 +9591,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_secret>())).hdr_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, hdr_len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9973,9 +9601,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_secret>())).guest_uaddr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, guest_uaddr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9985,7 +9611,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_secret>())).guest_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, guest_len),
         24usize,
         concat!(
             "Offset of field: ",
@@ -9995,9 +9621,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_sev_launch_secret>())).trans_uaddr as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, trans_uaddr),
         32usize,
         concat!(
             "Offset of field: ",
@@ -10007,7 +9631,7 @@ fn bindgen_test_layout_kvm_sev_launch_secret() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_secret>())).trans_len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_secret, trans_len),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10036,7 +9660,7 @@ fn bindgen_test_layout_kvm_sev_launch_measure() {
         concat!("Alignment of ", stringify!(kvm_sev_launch_measure))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_measure>())).uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_measure, uaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10046,7 +9670,7 @@ fn bindgen_test_layout_kvm_sev_launch_measure() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_launch_measure>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_launch_measure, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -10076,7 +9700,7 @@ fn bindgen_test_layout_kvm_sev_guest_status() {
         concat!("Alignment of ", stringify!(kvm_sev_guest_status))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_guest_status>())).handle as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_guest_status, handle),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10086,7 +9710,7 @@ fn bindgen_test_layout_kvm_sev_guest_status() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_guest_status>())).policy as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_guest_status, policy),
         4usize,
         concat!(
             "Offset of field: ",
@@ -10096,7 +9720,7 @@ fn bindgen_test_layout_kvm_sev_guest_status() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_guest_status>())).state as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_guest_status, state),
         8usize,
         concat!(
             "Offset of field: ",
@@ -10126,7 +9750,7 @@ fn bindgen_test_layout_kvm_sev_dbg() {
         concat!("Alignment of ", stringify!(kvm_sev_dbg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_dbg>())).src_uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_dbg, src_uaddr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10136,7 +9760,7 @@ fn bindgen_test_layout_kvm_sev_dbg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_dbg>())).dst_uaddr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_dbg, dst_uaddr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -10146,7 +9770,7 @@ fn bindgen_test_layout_kvm_sev_dbg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_sev_dbg>())).len as *const _ as usize },
+        ::std::mem::offset_of!(kvm_sev_dbg, len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -10188,10 +9812,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_pci_dev__bindgen_ty_1>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10219,9 +9840,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         concat!("Alignment of ", stringify!(kvm_assigned_pci_dev))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10231,7 +9850,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).busnr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, busnr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -10241,7 +9860,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).devfn as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, devfn),
         8usize,
         concat!(
             "Offset of field: ",
@@ -10251,7 +9870,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -10261,7 +9880,7 @@ fn bindgen_test_layout_kvm_assigned_pci_dev() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_pci_dev>())).segnr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_pci_dev, segnr),
         16usize,
         concat!(
             "Offset of field: ",
@@ -10304,9 +9923,7 @@ fn bindgen_test_layout_kvm_assigned_irq__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(kvm_assigned_irq__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_irq__bindgen_ty_1>())).reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_irq__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10334,9 +9951,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         concat!("Alignment of ", stringify!(kvm_assigned_irq))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_irq>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_irq, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10346,7 +9961,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).host_irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, host_irq),
         4usize,
         concat!(
             "Offset of field: ",
@@ -10356,7 +9971,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).guest_irq as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, guest_irq),
         8usize,
         concat!(
             "Offset of field: ",
@@ -10366,7 +9981,7 @@ fn bindgen_test_layout_kvm_assigned_irq() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_irq>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_irq, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -10401,9 +10016,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         concat!("Alignment of ", stringify!(kvm_assigned_msix_nr))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10413,7 +10026,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).entry_nr as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, entry_nr),
         4usize,
         concat!(
             "Offset of field: ",
@@ -10423,7 +10036,7 @@ fn bindgen_test_layout_kvm_assigned_msix_nr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_nr>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_nr, padding),
         6usize,
         concat!(
             "Offset of field: ",
@@ -10454,9 +10067,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         concat!("Alignment of ", stringify!(kvm_assigned_msix_entry))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).assigned_dev_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, assigned_dev_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10466,7 +10077,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).gsi as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, gsi),
         4usize,
         concat!(
             "Offset of field: ",
@@ -10476,7 +10087,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).entry as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, entry),
         8usize,
         concat!(
             "Offset of field: ",
@@ -10486,7 +10097,7 @@ fn bindgen_test_layout_kvm_assigned_msix_entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_assigned_msix_entry>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_assigned_msix_entry, padding),
         10usize,
         concat!(
             "Offset of field: ",
@@ -10517,7 +10128,7 @@ fn bindgen_test_layout_kvm_hyperv_eventfd() {
         concat!("Alignment of ", stringify!(kvm_hyperv_eventfd))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_eventfd>())).conn_id as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_eventfd, conn_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10527,7 +10138,7 @@ fn bindgen_test_layout_kvm_hyperv_eventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_eventfd>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_eventfd, fd),
         4usize,
         concat!(
             "Offset of field: ",
@@ -10537,7 +10148,7 @@ fn bindgen_test_layout_kvm_hyperv_eventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_eventfd>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_eventfd, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -10547,7 +10158,7 @@ fn bindgen_test_layout_kvm_hyperv_eventfd() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<kvm_hyperv_eventfd>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(kvm_hyperv_eventfd, padding),
         12usize,
         concat!(
             "Offset of field: ",
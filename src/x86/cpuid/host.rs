@@ -0,0 +1,292 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! The CPUID of the host, in the format of `kvm_cpuid2`.
+//!
+//! [`host_entries`](fn.host_entries.html) runs the `cpuid` instruction over
+//! the basic and extended leaves of the host and returns entries of the same
+//! type as `KVM_GET_SUPPORTED_CPUID`, so the host and guest CPUID can be
+//! compared with [`CpuidEntries`](../trait.CpuidEntries.html).
+
+use super::super::bindings::*;
+
+// The output of `cpuid`: EAX, EBX, ECX and EDX.
+type Regs = [u32; 4];
+
+// The first extended leaf.
+const EXTENDED_LEAVES: u32 = 0x8000_0000;
+
+// Leaf 0xB and 0x1F ECX: the level type, 0 for the invalid levels.
+const LEVEL_TYPE_MASK: u32 = 0xff00;
+// Leaf 0x4 and 0x8000001D EAX: the cache type, 0 past the last cache.
+const CACHE_TYPE_MASK: u32 = 0x1f;
+// Leaf 0x12 EAX, from subleaf 2: the EPC section type, 0 past the last section.
+const EPC_SECTION_TYPE_MASK: u32 = 0xf;
+
+/// Returns `true` if the subleaf passed in `ECX` selects the output of the
+/// leaf `function`, and the entries need `KVM_CPUID_FLAG_SIGNIFCANT_INDEX`.
+pub fn has_subleaves(function: u32) -> bool {
+    matches!(
+        function,
+        0x4 | 0x7 | 0xb | 0xd | 0x12 | 0x14 | 0x17 | 0x18 | 0x1f | 0x8000_001d
+    )
+}
+
+fn entry(function: u32, index: u32, regs: Regs) -> kvm_cpuid_entry2 {
+    kvm_cpuid_entry2 {
+        function,
+        index,
+        flags: if has_subleaves(function) {
+            KVM_CPUID_FLAG_SIGNIFCANT_INDEX
+        } else {
+            0
+        },
+        eax: regs[0],
+        ebx: regs[1],
+        ecx: regs[2],
+        edx: regs[3],
+        ..Default::default()
+    }
+}
+
+// Appends the subleaves of `function` from `first` up to the first one `last`
+// matches, included, like KVM does.
+fn push_until<C, L>(
+    entries: &mut Vec<kvm_cpuid_entry2>,
+    cpuid: &mut C,
+    function: u32,
+    first: u32,
+    last: L,
+) where
+    C: FnMut(u32, u32) -> Regs,
+    L: Fn(&Regs) -> bool,
+{
+    for index in first.. {
+        let regs = cpuid(function, index);
+        entries.push(entry(function, index, regs));
+        // No CPU has 64 caches or topology levels.
+        if last(&regs) || index == 63 {
+            break;
+        }
+    }
+}
+
+fn push_leaf<C>(entries: &mut Vec<kvm_cpuid_entry2>, cpuid: &mut C, function: u32)
+where
+    C: FnMut(u32, u32) -> Regs,
+{
+    match function {
+        0x4 | 0x8000_001d => push_until(entries, cpuid, function, 0, |regs| {
+            regs[0] & CACHE_TYPE_MASK == 0
+        }),
+        0xb | 0x1f => push_until(entries, cpuid, function, 0, |regs| {
+            regs[2] & LEVEL_TYPE_MASK == 0
+        }),
+        // The SGX capabilities and attributes, then the EPC sections.
+        0x12 => {
+            entries.push(entry(function, 0, cpuid(function, 0)));
+            entries.push(entry(function, 1, cpuid(function, 1)));
+            push_until(entries, cpuid, function, 2, |regs| {
+                regs[0] & EPC_SECTION_TYPE_MASK == 0
+            });
+        }
+        // Subleaf 0 EAX holds the last subleaf.
+        0x7 | 0x14 | 0x17 | 0x18 => {
+            let regs = cpuid(function, 0);
+            entries.push(entry(function, 0, regs));
+            for index in 1..=regs[0] {
+                entries.push(entry(function, index, cpuid(function, index)));
+            }
+        }
+        0xd => {
+            let user = cpuid(function, 0);
+            let supervisor = cpuid(function, 1);
+            entries.push(entry(function, 0, user));
+            entries.push(entry(function, 1, supervisor));
+            // The state components of XCR0 and IA32_XSS.
+            let components =
+                u64::from(user[0] | supervisor[2]) | (u64::from(user[3] | supervisor[3]) << 32);
+            for index in (2..64).filter(|i| components & (1 << i) != 0) {
+                entries.push(entry(function, index, cpuid(function, index)));
+            }
+        }
+        _ => entries.push(entry(function, 0, cpuid(function, 0))),
+    }
+}
+
+/// Returns the CPUID entries reported by `cpuid`, called with the leaf and
+/// subleaf.
+///
+/// The subleaves of the leaves 0x4, 0x7, 0xB, 0xD, 0x12, 0x14, 0x17, 0x18, 0x1F
+/// and 0x8000001D are enumerated and flagged with
+/// `KVM_CPUID_FLAG_SIGNIFCANT_INDEX`. The other leaves only have their
+/// subleaf 0.
+pub fn entries_from<C>(mut cpuid: C) -> Vec<kvm_cpuid_entry2>
+where
+    C: FnMut(u32, u32) -> Regs,
+{
+    let mut entries = Vec::new();
+    for &base in &[0, EXTENDED_LEAVES] {
+        let max = cpuid(base, 0)[0];
+        if max < base {
+            continue;
+        }
+        for function in base..=max {
+            push_leaf(&mut entries, &mut cpuid, function);
+        }
+    }
+    entries
+}
+
+/// Returns the CPUID entries of the host.
+///
+/// There may be more entries than `KVM_MAX_CPUID_ENTRIES`.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub fn host_entries() -> Vec<kvm_cpuid_entry2> {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::__cpuid_count;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::__cpuid_count;
+
+    entries_from(|function, index| {
+        // `cpuid` is available on all the CPUs running KVM. It is a safe
+        // function in the newer toolchains.
+        #[allow(unused_unsafe)]
+        let regs = unsafe { __cpuid_count(function, index) };
+        [regs.eax, regs.ebx, regs.ecx, regs.edx]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{CpuidEntries, Feature};
+    use super::*;
+
+    // A host with 2 caches, 2 topology levels, 2 subleaves of leaf 0x7, AVX and
+    // long mode.
+    fn fake_cpuid(function: u32, index: u32) -> Regs {
+        match (function, index) {
+            (0x0, _) => [0xd, 0x756e_6547, 0x6c65_746e, 0x4965_6e69],
+            (0x4, 0) => [0x121, 0, 0, 0],
+            (0x4, 1) => [0x163, 0, 0, 0],
+            (0x7, 0) => [0x1, 0x20, 0, 0],
+            (0x7, 1) => [0x20, 0, 0, 0],
+            (0xb, 0) => [0x1, 0x2, 0x100, 0x3],
+            (0xb, 1) => [0x4, 0x8, 0x201, 0x3],
+            (0xb, n) => [0, 0, n, 0x3],
+            (0xd, 0) => [0x7, 0x340, 0x340, 0],
+            (0xd, 1) => [0x1, 0, 0x100, 0],
+            (0xd, 2) => [0x100, 0x240, 0, 0],
+            (0xd, 8) => [0x80, 0, 0x1, 0],
+            (0x8000_0000, _) => [0x8000_0001, 0, 0, 0],
+            (0x8000_0001, _) => [0, 0, 0x1, 1 << 29],
+            (0x1, _) => [0x5_0654, 0, 1 << 28, 0],
+            _ => [0; 4],
+        }
+    }
+
+    #[test]
+    fn test_entries_from() {
+        let entries = entries_from(fake_cpuid);
+        let leaves: Vec<(u32, u32)> = entries.iter().map(|e| (e.function, e.index)).collect();
+        assert_eq!(
+            leaves,
+            vec![
+                (0x0, 0),
+                (0x1, 0),
+                (0x2, 0),
+                (0x3, 0),
+                (0x4, 0),
+                (0x4, 1),
+                (0x4, 2),
+                (0x5, 0),
+                (0x6, 0),
+                (0x7, 0),
+                (0x7, 1),
+                (0x8, 0),
+                (0x9, 0),
+                (0xa, 0),
+                (0xb, 0),
+                (0xb, 1),
+                (0xb, 2),
+                (0xc, 0),
+                (0xd, 0),
+                (0xd, 1),
+                (0xd, 2),
+                (0xd, 8),
+                (0x8000_0000, 0),
+                (0x8000_0001, 0),
+            ]
+        );
+        assert_eq!(entries[0].flags, 0);
+        assert_eq!(entries[4].flags, KVM_CPUID_FLAG_SIGNIFCANT_INDEX);
+        assert_eq!(entries.entry(0xb, 1).unwrap().ecx, 0x201);
+        assert_eq!(entries.entry(0x7, 1).unwrap().eax, 0x20);
+        assert!(entries.has(Feature::Avx));
+        assert!(entries.has(Feature::Lm));
+    }
+
+    #[test]
+    fn test_sgx_and_max_subleaf_leaves() {
+        let entries = entries_from(|function, index| match (function, index) {
+            (0x0, _) => [0x18, 0, 0, 0],
+            (0x12, 0) => [0x3, 0, 0, 0],
+            (0x12, 2) | (0x12, 3) => [0x1, 0, 0, 0],
+            (0x14, 0) | (0x17, 0) => [0x1, 0, 0, 0],
+            (0x18, 0) => [0x2, 0, 0, 0],
+            _ => [0; 4],
+        });
+        let leaves: Vec<(u32, u32)> = entries
+            .iter()
+            .filter(|e| e.function >= 0x12)
+            .map(|e| (e.function, e.index))
+            .collect();
+        assert_eq!(
+            leaves,
+            vec![
+                (0x12, 0),
+                (0x12, 1),
+                (0x12, 2),
+                (0x12, 3),
+                (0x12, 4),
+                (0x13, 0),
+                (0x14, 0),
+                (0x14, 1),
+                (0x15, 0),
+                (0x16, 0),
+                (0x17, 0),
+                (0x17, 1),
+                (0x18, 0),
+                (0x18, 1),
+                (0x18, 2),
+            ]
+        );
+        for e in entries.iter().filter(|e| e.function >= 0x12) {
+            let flags = match e.function {
+                0x13 | 0x15 | 0x16 => 0,
+                _ => KVM_CPUID_FLAG_SIGNIFCANT_INDEX,
+            };
+            assert_eq!(e.flags, flags);
+        }
+    }
+
+    #[test]
+    fn test_no_extended_leaves() {
+        let entries = entries_from(|function, _| match function {
+            0x0 => [0x1, 0, 0, 0],
+            _ => [0; 4],
+        });
+        assert_eq!(entries.len(), 2);
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn test_host_entries() {
+        let entries = host_entries();
+        // Every x86-64 CPU has the extended leaves and long mode.
+        assert!(entries.entry(0x0, 0).is_some());
+        assert!(entries.entry(0x8000_0000, 0).is_some());
+        #[cfg(target_arch = "x86_64")]
+        assert!(entries.has(Feature::Lm));
+    }
+}
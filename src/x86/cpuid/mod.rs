@@ -0,0 +1,491 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Named CPUID feature bits over the entries of `kvm_cpuid2`.
+//!
+//! The entries returned by `KVM_GET_SUPPORTED_CPUID` and passed to
+//! `KVM_SET_CPUID2` are looked up by function and index with
+//! [`CpuidEntries`](trait.CpuidEntries.html), and their feature bits are read
+//! and changed by name with [`Feature`](enum.Feature.html).
+
+use std::fmt;
+
+use super::bindings::*;
+
+pub mod host;
+pub mod normalize;
+pub mod templates;
+
+/// Errors associated with the CPUID entries.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// There is no entry for the leaf.
+    MissingLeaf { function: u32, index: u32 },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::MissingLeaf { function, index } => {
+                write!(f, "no CPUID entry for leaf {:#x}.{}", function, index)
+            }
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// An output register of the `cpuid` instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Register {
+    /// `EAX`.
+    Eax,
+    /// `EBX`.
+    Ebx,
+    /// `ECX`.
+    Ecx,
+    /// `EDX`.
+    Edx,
+}
+
+impl Register {
+    /// Returns the value of the register in `entry`.
+    pub fn get(self, entry: &kvm_cpuid_entry2) -> u32 {
+        match self {
+            Register::Eax => entry.eax,
+            Register::Ebx => entry.ebx,
+            Register::Ecx => entry.ecx,
+            Register::Edx => entry.edx,
+        }
+    }
+
+    /// Returns a mutable reference to the register in `entry`.
+    pub fn get_mut(self, entry: &mut kvm_cpuid_entry2) -> &mut u32 {
+        match self {
+            Register::Eax => &mut entry.eax,
+            Register::Ebx => &mut entry.ebx,
+            Register::Ecx => &mut entry.ecx,
+            Register::Edx => &mut entry.edx,
+        }
+    }
+}
+
+/// The position of a feature bit in the CPUID leaves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FeatureBit {
+    /// The leaf, passed in `EAX`.
+    pub function: u32,
+    /// The subleaf, passed in `ECX`.
+    pub index: u32,
+    /// The output register.
+    pub register: Register,
+    /// The bit number in the register.
+    pub bit: u32,
+}
+
+macro_rules! features {
+    ($($variant:ident = $name:expr, $function:expr, $index:expr, $reg:ident, $bit:expr;)*) => {
+        /// A CPUID feature bit, named as in the flags of `/proc/cpuinfo`.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum Feature {
+            $(
+                #[doc = concat!("`", $name, "`.")]
+                $variant,
+            )*
+        }
+
+        impl Feature {
+            /// The known features, in leaf and bit order.
+            pub const ALL: &'static [Feature] = &[$(Feature::$variant,)*];
+
+            /// Returns the name of the feature in `/proc/cpuinfo`.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Feature::$variant => $name,)*
+                }
+            }
+
+            /// Returns the position of the feature bit.
+            pub fn location(self) -> FeatureBit {
+                match self {
+                    $(Feature::$variant => FeatureBit {
+                        function: $function,
+                        index: $index,
+                        register: Register::$reg,
+                        bit: $bit,
+                    },)*
+                }
+            }
+        }
+    };
+}
+
+features! {
+    Sse3 = "sse3", 1, 0, Ecx, 0;
+    Pclmulqdq = "pclmulqdq", 1, 0, Ecx, 1;
+    Dtes64 = "dtes64", 1, 0, Ecx, 2;
+    Monitor = "monitor", 1, 0, Ecx, 3;
+    DsCpl = "ds_cpl", 1, 0, Ecx, 4;
+    Vmx = "vmx", 1, 0, Ecx, 5;
+    Smx = "smx", 1, 0, Ecx, 6;
+    Est = "est", 1, 0, Ecx, 7;
+    Tm2 = "tm2", 1, 0, Ecx, 8;
+    Ssse3 = "ssse3", 1, 0, Ecx, 9;
+    CnxtId = "cnxt_id", 1, 0, Ecx, 10;
+    Sdbg = "sdbg", 1, 0, Ecx, 11;
+    Fma = "fma", 1, 0, Ecx, 12;
+    Cx16 = "cx16", 1, 0, Ecx, 13;
+    Xtpr = "xtpr", 1, 0, Ecx, 14;
+    Pdcm = "pdcm", 1, 0, Ecx, 15;
+    Pcid = "pcid", 1, 0, Ecx, 17;
+    Dca = "dca", 1, 0, Ecx, 18;
+    Sse4_1 = "sse4_1", 1, 0, Ecx, 19;
+    Sse4_2 = "sse4_2", 1, 0, Ecx, 20;
+    X2apic = "x2apic", 1, 0, Ecx, 21;
+    Movbe = "movbe", 1, 0, Ecx, 22;
+    Popcnt = "popcnt", 1, 0, Ecx, 23;
+    TscDeadlineTimer = "tsc_deadline_timer", 1, 0, Ecx, 24;
+    Aes = "aes", 1, 0, Ecx, 25;
+    Xsave = "xsave", 1, 0, Ecx, 26;
+    Osxsave = "osxsave", 1, 0, Ecx, 27;
+    Avx = "avx", 1, 0, Ecx, 28;
+    F16c = "f16c", 1, 0, Ecx, 29;
+    Rdrand = "rdrand", 1, 0, Ecx, 30;
+    Hypervisor = "hypervisor", 1, 0, Ecx, 31;
+
+    Fpu = "fpu", 1, 0, Edx, 0;
+    Vme = "vme", 1, 0, Edx, 1;
+    De = "de", 1, 0, Edx, 2;
+    Pse = "pse", 1, 0, Edx, 3;
+    Tsc = "tsc", 1, 0, Edx, 4;
+    Msr = "msr", 1, 0, Edx, 5;
+    Pae = "pae", 1, 0, Edx, 6;
+    Mce = "mce", 1, 0, Edx, 7;
+    Cx8 = "cx8", 1, 0, Edx, 8;
+    Apic = "apic", 1, 0, Edx, 9;
+    Sep = "sep", 1, 0, Edx, 11;
+    Mtrr = "mtrr", 1, 0, Edx, 12;
+    Pge = "pge", 1, 0, Edx, 13;
+    Mca = "mca", 1, 0, Edx, 14;
+    Cmov = "cmov", 1, 0, Edx, 15;
+    Pat = "pat", 1, 0, Edx, 16;
+    Pse36 = "pse36", 1, 0, Edx, 17;
+    Pn = "pn", 1, 0, Edx, 18;
+    Clflush = "clflush", 1, 0, Edx, 19;
+    Ds = "ds", 1, 0, Edx, 21;
+    Acpi = "acpi", 1, 0, Edx, 22;
+    Mmx = "mmx", 1, 0, Edx, 23;
+    Fxsr = "fxsr", 1, 0, Edx, 24;
+    Sse = "sse", 1, 0, Edx, 25;
+    Sse2 = "sse2", 1, 0, Edx, 26;
+    Ss = "ss", 1, 0, Edx, 27;
+    Ht = "ht", 1, 0, Edx, 28;
+    Tm = "tm", 1, 0, Edx, 29;
+    Pbe = "pbe", 1, 0, Edx, 31;
+
+    Fsgsbase = "fsgsbase", 7, 0, Ebx, 0;
+    TscAdjust = "tsc_adjust", 7, 0, Ebx, 1;
+    Sgx = "sgx", 7, 0, Ebx, 2;
+    Bmi1 = "bmi1", 7, 0, Ebx, 3;
+    Hle = "hle", 7, 0, Ebx, 4;
+    Avx2 = "avx2", 7, 0, Ebx, 5;
+    FdpExcptnOnly = "fdp_excptn_only", 7, 0, Ebx, 6;
+    Smep = "smep", 7, 0, Ebx, 7;
+    Bmi2 = "bmi2", 7, 0, Ebx, 8;
+    Erms = "erms", 7, 0, Ebx, 9;
+    Invpcid = "invpcid", 7, 0, Ebx, 10;
+    Rtm = "rtm", 7, 0, Ebx, 11;
+    Cqm = "cqm", 7, 0, Ebx, 12;
+    ZeroFcsFds = "zero_fcs_fds", 7, 0, Ebx, 13;
+    Mpx = "mpx", 7, 0, Ebx, 14;
+    RdtA = "rdt_a", 7, 0, Ebx, 15;
+    Avx512f = "avx512f", 7, 0, Ebx, 16;
+    Avx512dq = "avx512dq", 7, 0, Ebx, 17;
+    Rdseed = "rdseed", 7, 0, Ebx, 18;
+    Adx = "adx", 7, 0, Ebx, 19;
+    Smap = "smap", 7, 0, Ebx, 20;
+    Avx512ifma = "avx512ifma", 7, 0, Ebx, 21;
+    Clflushopt = "clflushopt", 7, 0, Ebx, 23;
+    Clwb = "clwb", 7, 0, Ebx, 24;
+    IntelPt = "intel_pt", 7, 0, Ebx, 25;
+    Avx512pf = "avx512pf", 7, 0, Ebx, 26;
+    Avx512er = "avx512er", 7, 0, Ebx, 27;
+    Avx512cd = "avx512cd", 7, 0, Ebx, 28;
+    ShaNi = "sha_ni", 7, 0, Ebx, 29;
+    Avx512bw = "avx512bw", 7, 0, Ebx, 30;
+    Avx512vl = "avx512vl", 7, 0, Ebx, 31;
+
+    Avx512vbmi = "avx512vbmi", 7, 0, Ecx, 1;
+    Umip = "umip", 7, 0, Ecx, 2;
+    Pku = "pku", 7, 0, Ecx, 3;
+    Ospke = "ospke", 7, 0, Ecx, 4;
+    Waitpkg = "waitpkg", 7, 0, Ecx, 5;
+    Avx512Vbmi2 = "avx512_vbmi2", 7, 0, Ecx, 6;
+    Gfni = "gfni", 7, 0, Ecx, 8;
+    Vaes = "vaes", 7, 0, Ecx, 9;
+    Vpclmulqdq = "vpclmulqdq", 7, 0, Ecx, 10;
+    Avx512Vnni = "avx512_vnni", 7, 0, Ecx, 11;
+    Avx512Bitalg = "avx512_bitalg", 7, 0, Ecx, 12;
+    Avx512Vpopcntdq = "avx512_vpopcntdq", 7, 0, Ecx, 14;
+    La57 = "la57", 7, 0, Ecx, 16;
+    Rdpid = "rdpid", 7, 0, Ecx, 22;
+    Cldemote = "cldemote", 7, 0, Ecx, 25;
+    Movdiri = "movdiri", 7, 0, Ecx, 27;
+    Movdir64b = "movdir64b", 7, 0, Ecx, 28;
+
+    Avx512_4Vnniw = "avx512_4vnniw", 7, 0, Edx, 2;
+    Avx512_4Fmaps = "avx512_4fmaps", 7, 0, Edx, 3;
+    MdClear = "md_clear", 7, 0, Edx, 10;
+    Pconfig = "pconfig", 7, 0, Edx, 18;
+    SpecCtrl = "spec_ctrl", 7, 0, Edx, 26;
+    IntelStibp = "intel_stibp", 7, 0, Edx, 27;
+    FlushL1d = "flush_l1d", 7, 0, Edx, 28;
+    ArchCapabilities = "arch_capabilities", 7, 0, Edx, 29;
+    SpecCtrlSsbd = "spec_ctrl_ssbd", 7, 0, Edx, 31;
+
+    Xsaveopt = "xsaveopt", 0xd, 1, Eax, 0;
+    Xsavec = "xsavec", 0xd, 1, Eax, 1;
+    Xgetbv1 = "xgetbv1", 0xd, 1, Eax, 2;
+    Xsaves = "xsaves", 0xd, 1, Eax, 3;
+
+    LahfLm = "lahf_lm", 0x8000_0001, 0, Ecx, 0;
+    CmpLegacy = "cmp_legacy", 0x8000_0001, 0, Ecx, 1;
+    Svm = "svm", 0x8000_0001, 0, Ecx, 2;
+    Extapic = "extapic", 0x8000_0001, 0, Ecx, 3;
+    Cr8Legacy = "cr8_legacy", 0x8000_0001, 0, Ecx, 4;
+    Abm = "abm", 0x8000_0001, 0, Ecx, 5;
+    Sse4a = "sse4a", 0x8000_0001, 0, Ecx, 6;
+    Misalignsse = "misalignsse", 0x8000_0001, 0, Ecx, 7;
+    ThreeDNowPrefetch = "3dnowprefetch", 0x8000_0001, 0, Ecx, 8;
+    Osvw = "osvw", 0x8000_0001, 0, Ecx, 9;
+    Ibs = "ibs", 0x8000_0001, 0, Ecx, 10;
+    Xop = "xop", 0x8000_0001, 0, Ecx, 11;
+    Skinit = "skinit", 0x8000_0001, 0, Ecx, 12;
+    Wdt = "wdt", 0x8000_0001, 0, Ecx, 13;
+    Lwp = "lwp", 0x8000_0001, 0, Ecx, 15;
+    Fma4 = "fma4", 0x8000_0001, 0, Ecx, 16;
+    Tce = "tce", 0x8000_0001, 0, Ecx, 17;
+    Tbm = "tbm", 0x8000_0001, 0, Ecx, 21;
+    Topoext = "topoext", 0x8000_0001, 0, Ecx, 22;
+    PerfctrCore = "perfctr_core", 0x8000_0001, 0, Ecx, 23;
+    PerfctrNb = "perfctr_nb", 0x8000_0001, 0, Ecx, 24;
+    Bpext = "bpext", 0x8000_0001, 0, Ecx, 26;
+    Ptsc = "ptsc", 0x8000_0001, 0, Ecx, 27;
+    PerfctrLlc = "perfctr_llc", 0x8000_0001, 0, Ecx, 28;
+    Mwaitx = "mwaitx", 0x8000_0001, 0, Ecx, 29;
+
+    Syscall = "syscall", 0x8000_0001, 0, Edx, 11;
+    Nx = "nx", 0x8000_0001, 0, Edx, 20;
+    Mmxext = "mmxext", 0x8000_0001, 0, Edx, 22;
+    FxsrOpt = "fxsr_opt", 0x8000_0001, 0, Edx, 25;
+    Pdpe1gb = "pdpe1gb", 0x8000_0001, 0, Edx, 26;
+    Rdtscp = "rdtscp", 0x8000_0001, 0, Edx, 27;
+    Lm = "lm", 0x8000_0001, 0, Edx, 29;
+    ThreeDNowExt = "3dnowext", 0x8000_0001, 0, Edx, 30;
+    ThreeDNow = "3dnow", 0x8000_0001, 0, Edx, 31;
+
+    Clzero = "clzero", 0x8000_0008, 0, Ebx, 0;
+    Irperf = "irperf", 0x8000_0008, 0, Ebx, 1;
+    Xsaveerptr = "xsaveerptr", 0x8000_0008, 0, Ebx, 2;
+    Wbnoinvd = "wbnoinvd", 0x8000_0008, 0, Ebx, 9;
+    AmdIbpb = "amd_ibpb", 0x8000_0008, 0, Ebx, 12;
+    AmdIbrs = "amd_ibrs", 0x8000_0008, 0, Ebx, 14;
+    AmdStibp = "amd_stibp", 0x8000_0008, 0, Ebx, 15;
+    AmdSsbd = "amd_ssbd", 0x8000_0008, 0, Ebx, 24;
+    VirtSsbd = "virt_ssbd", 0x8000_0008, 0, Ebx, 25;
+    AmdSsbNo = "amd_ssb_no", 0x8000_0008, 0, Ebx, 26;
+}
+
+impl Feature {
+    /// Returns the feature named `name` in `/proc/cpuinfo`.
+    pub fn from_name(name: &str) -> Option<Feature> {
+        Feature::ALL.iter().cloned().find(|f| f.name() == name)
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Lookups and feature bits over CPUID entries, like the slices of a
+/// [`CpuId`](../bindings/type.CpuId.html).
+pub trait CpuidEntries {
+    /// Returns the entry of the leaf `function` and subleaf `index`.
+    ///
+    /// The index only has to match for the entries flagged with
+    /// `KVM_CPUID_FLAG_SIGNIFCANT_INDEX`, like KVM does.
+    fn entry(&self, function: u32, index: u32) -> Option<&kvm_cpuid_entry2>;
+
+    /// Returns a mutable reference to the entry of the leaf `function` and
+    /// subleaf `index`.
+    fn entry_mut(&mut self, function: u32, index: u32) -> Option<&mut kvm_cpuid_entry2>;
+
+    /// Returns `true` if the feature bit is set, `false` if it is clear or if
+    /// its leaf is missing.
+    fn has(&self, feature: Feature) -> bool {
+        let FeatureBit {
+            function,
+            index,
+            register,
+            bit,
+        } = feature.location();
+        self.entry(function, index)
+            .is_some_and(|entry| register.get(entry) & (1 << bit) != 0)
+    }
+
+    /// Sets or clears the feature bit.
+    ///
+    /// Clearing a feature of a missing leaf succeeds, as the guest does not
+    /// see it either.
+    fn set(&mut self, feature: Feature, enabled: bool) -> Result<(), Error> {
+        let FeatureBit {
+            function,
+            index,
+            register,
+            bit,
+        } = feature.location();
+        let entry = match self.entry_mut(function, index) {
+            Some(entry) => entry,
+            None if !enabled => return Ok(()),
+            None => return Err(Error::MissingLeaf { function, index }),
+        };
+        let value = register.get_mut(entry);
+        if enabled {
+            *value |= 1 << bit;
+        } else {
+            *value &= !(1 << bit);
+        }
+        Ok(())
+    }
+
+    /// Returns the known features that are set, in leaf and bit order.
+    fn features(&self) -> Vec<Feature> {
+        Feature::ALL
+            .iter()
+            .cloned()
+            .filter(|&f| self.has(f))
+            .collect()
+    }
+}
+
+fn matches_leaf(entry: &kvm_cpuid_entry2, function: u32, index: u32) -> bool {
+    entry.function == function
+        && (entry.flags & KVM_CPUID_FLAG_SIGNIFCANT_INDEX == 0 || entry.index == index)
+}
+
+impl CpuidEntries for [kvm_cpuid_entry2] {
+    fn entry(&self, function: u32, index: u32) -> Option<&kvm_cpuid_entry2> {
+        self.iter().find(|e| matches_leaf(e, function, index))
+    }
+
+    fn entry_mut(&mut self, function: u32, index: u32) -> Option<&mut kvm_cpuid_entry2> {
+        self.iter_mut().find(|e| matches_leaf(e, function, index))
+    }
+}
+
+/// Returns the entry of the leaf `function` and subleaf `index` with the
+/// `[eax, ebx, ecx, edx]` registers, flagged like the host entries.
+#[cfg(test)]
+pub(crate) fn leaf(function: u32, index: u32, regs: [u32; 4]) -> kvm_cpuid_entry2 {
+    kvm_cpuid_entry2 {
+        function,
+        index,
+        flags: if host::has_subleaves(function) {
+            KVM_CPUID_FLAG_SIGNIFCANT_INDEX
+        } else {
+            0
+        },
+        eax: regs[0],
+        ebx: regs[1],
+        ecx: regs[2],
+        edx: regs[3],
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let entries = [
+            leaf(0x1, 0, [0; 4]),
+            leaf(0x7, 0, [0; 4]),
+            leaf(0xd, 0, [0; 4]),
+            leaf(0xd, 1, [0; 4]),
+        ];
+        // The index of leaf 1 is not significant.
+        assert_eq!(entries.entry(0x1, 3), Some(&entries[0]));
+        assert_eq!(entries.entry(0xd, 1), Some(&entries[3]));
+        assert_eq!(entries.entry(0x7, 1), None);
+        assert_eq!(entries.entry(0x8000_0001, 0), None);
+    }
+
+    #[test]
+    fn test_features() {
+        let mut cpuid = CpuId::from_entries(&[
+            leaf(0x1, 0, [0; 4]),
+            leaf(0x7, 0, [0; 4]),
+            leaf(0xd, 1, [0; 4]),
+        ])
+        .unwrap();
+        let entries = cpuid.as_mut_slice();
+        assert!(!entries.has(Feature::Avx512f));
+        entries.set(Feature::Avx512f, true).unwrap();
+        entries.set(Feature::X2apic, true).unwrap();
+        entries.set(Feature::Xsaves, true).unwrap();
+        assert!(entries.has(Feature::Avx512f));
+        assert_eq!(entries.entry(0x7, 0).unwrap().ebx, 1 << 16);
+        assert_eq!(entries.entry(0x1, 0).unwrap().ecx, 1 << 21);
+        assert_eq!(entries.entry(0xd, 1).unwrap().eax, 1 << 3);
+        assert_eq!(
+            entries.features(),
+            vec![Feature::X2apic, Feature::Avx512f, Feature::Xsaves]
+        );
+
+        entries.set(Feature::X2apic, false).unwrap();
+        assert_eq!(entries.entry(0x1, 0).unwrap().ecx, 0);
+
+        // Leaf 0x80000001 is missing.
+        assert!(!entries.has(Feature::Lm));
+        assert!(entries.set(Feature::Lm, false).is_ok());
+        assert_eq!(
+            entries.set(Feature::Lm, true),
+            Err(Error::MissingLeaf {
+                function: 0x8000_0001,
+                index: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(Feature::from_name("avx512f"), Some(Feature::Avx512f));
+        assert_eq!(
+            Feature::from_name("3dnowprefetch"),
+            Some(Feature::ThreeDNowPrefetch)
+        );
+        assert_eq!(Feature::from_name("avx1024"), None);
+        assert_eq!(format!("{}", Feature::SpecCtrl), "spec_ctrl");
+        assert_eq!(
+            Feature::Hypervisor.location(),
+            FeatureBit {
+                function: 0x1,
+                index: 0,
+                register: Register::Ecx,
+                bit: 31
+            }
+        );
+
+        // Each bit is named once.
+        for (i, a) in Feature::ALL.iter().enumerate() {
+            for b in &Feature::ALL[i + 1..] {
+                assert_ne!(a.location(), b.location());
+                assert_ne!(a.name(), b.name());
+            }
+        }
+    }
+}
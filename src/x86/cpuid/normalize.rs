@@ -0,0 +1,571 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Normalization of the CPUID entries of `KVM_GET_SUPPORTED_CPUID` for a vcpu.
+//!
+//! `KVM_GET_SUPPORTED_CPUID` describes what KVM can virtualize, not a vcpu:
+//! the APIC IDs, the topology and the cache sharing are the ones of the host,
+//! or zero. Each step of the normalization is a function over the entries, and
+//! [`CpuidNormalizer`](struct.CpuidNormalizer.html) runs all of them for an
+//! Intel or AMD guest of a given [`CpuTopology`](struct.CpuTopology.html).
+
+use std::fmt;
+
+use super::super::bindings::*;
+use super::{CpuidEntries, Feature};
+
+// Leaf 0x1 fields.
+const LEAF1_EBX_KEEP_MASK: u32 = 0xffff;
+const LEAF1_EBX_COUNT_SHIFT: u32 = 16;
+const LEAF1_EBX_APIC_ID_SHIFT: u32 = 24;
+const LEAF1_EDX_HTT: u32 = 1 << 28;
+
+// Leaf 0xB and 0x1F level types.
+const LEVEL_TYPE_SMT: u32 = 1;
+const LEVEL_TYPE_CORE: u32 = 2;
+
+// Leaf 0x4 and 0x8000001D fields.
+const CACHE_TYPE_MASK: u32 = 0x1f;
+const CACHE_LEVEL_SHIFT: u32 = 5;
+const CACHE_SHARING_SHIFT: u32 = 14;
+const CACHE_SHARING_MASK: u32 = 0xfff;
+const CACHE_CORES_SHIFT: u32 = 26;
+const CACHE_CORES_MASK: u32 = 0x3f;
+
+// Leaf 0x80000007 EDX: the TSC runs at a constant rate in all power states.
+const INVARIANT_TSC: u32 = 1 << 8;
+
+// Leaf 0x80000008 ECX fields.
+const AMD_NC_MASK: u32 = 0xff;
+const AMD_APIC_ID_SIZE_SHIFT: u32 = 12;
+const AMD_APIC_ID_SIZE_MASK: u32 = 0xf;
+
+// The legacy region and the header of the XSAVE area.
+const XSAVE_LEGACY_SIZE: u32 = 576;
+
+/// Errors associated with the normalization of the CPUID entries.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// A level of the topology is empty, or the topology does not fit in the
+    /// APIC IDs and the CPUID fields.
+    InvalidTopology,
+    /// The vcpu index is past the vcpus of the topology.
+    InvalidVcpu(u32),
+    /// There is no entry for a leaf every guest needs.
+    MissingLeaf { function: u32, index: u32 },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::InvalidTopology => write!(f, "the topology does not fit in CPUID"),
+            Error::InvalidVcpu(index) => write!(f, "no vcpu {} in the topology", index),
+            Error::MissingLeaf { function, index } => {
+                write!(f, "no CPUID entry for leaf {:#x}.{}", function, index)
+            }
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+impl From<super::Error> for Error {
+    fn from(error: super::Error) -> Self {
+        match error {
+            super::Error::MissingLeaf { function, index } => Error::MissingLeaf { function, index },
+        }
+    }
+}
+
+/// The CPU vendor, which selects the leaves describing the topology.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CpuVendor {
+    /// `GenuineIntel`.
+    Intel,
+    /// `AuthenticAMD`.
+    Amd,
+}
+
+impl CpuVendor {
+    /// Reads the vendor from the identification string of leaf 0x0.
+    pub fn from_entries(entries: &[kvm_cpuid_entry2]) -> Option<CpuVendor> {
+        let leaf = entries.entry(0x0, 0)?;
+        let mut vendor = [0u8; 12];
+        vendor[..4].copy_from_slice(&leaf.ebx.to_le_bytes());
+        vendor[4..8].copy_from_slice(&leaf.edx.to_le_bytes());
+        vendor[8..].copy_from_slice(&leaf.ecx.to_le_bytes());
+        match &vendor {
+            b"GenuineIntel" => Some(CpuVendor::Intel),
+            b"AuthenticAMD" => Some(CpuVendor::Amd),
+            _ => None,
+        }
+    }
+}
+
+/// A symmetric vcpu topology of `sockets` with `cores` running `threads`
+/// each.
+///
+/// The APIC ID of a vcpu packs its thread, core and socket indexes, each
+/// level taking as many bits as needed to count its members.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CpuTopology {
+    sockets: u32,
+    cores: u32,
+    threads: u32,
+}
+
+// The number of APIC ID bits needed to number `count` members.
+fn id_bits(count: u32) -> u32 {
+    32 - (count - 1).leading_zeros()
+}
+
+impl CpuTopology {
+    /// Builds a topology of `sockets` with `cores` running `threads` each.
+    ///
+    /// The logical processors of a socket must fit in the 8-bit count of
+    /// leaf 0x1.
+    pub fn new(sockets: u32, cores: u32, threads: u32) -> Result<Self, Error> {
+        if sockets == 0 || cores == 0 || threads == 0 {
+            return Err(Error::InvalidTopology);
+        }
+        let per_socket = cores
+            .checked_mul(threads)
+            .filter(|&count| count <= 0xff)
+            .ok_or(Error::InvalidTopology)?;
+        if per_socket.checked_mul(sockets).is_none()
+            || id_bits(threads) + id_bits(cores) + id_bits(sockets) > 32
+        {
+            return Err(Error::InvalidTopology);
+        }
+        Ok(CpuTopology {
+            sockets,
+            cores,
+            threads,
+        })
+    }
+
+    /// Returns the number of vcpus of the topology.
+    pub fn vcpus(&self) -> u32 {
+        self.sockets * self.cores * self.threads
+    }
+
+    /// Returns the number of vcpus of a socket.
+    pub fn vcpus_per_socket(&self) -> u32 {
+        self.cores * self.threads
+    }
+
+    /// Returns the number of APIC ID bits numbering the threads of a core.
+    pub fn thread_bits(&self) -> u32 {
+        id_bits(self.threads)
+    }
+
+    /// Returns the number of APIC ID bits numbering the threads and the cores
+    /// of a socket.
+    pub fn socket_bits(&self) -> u32 {
+        id_bits(self.threads) + id_bits(self.cores)
+    }
+
+    /// Returns the APIC ID of the vcpu `index`, the vcpus being numbered
+    /// thread first, then core and socket.
+    pub fn apic_id(&self, index: u32) -> Result<u32, Error> {
+        if index >= self.vcpus() {
+            return Err(Error::InvalidVcpu(index));
+        }
+        let thread = index % self.threads;
+        let core = index / self.threads % self.cores;
+        let socket = index / self.vcpus_per_socket();
+        Ok((socket << self.socket_bits()) | (core << self.thread_bits()) | thread)
+    }
+}
+
+/// Sets the bit of leaf 0x1 telling the guest it runs in a virtual machine.
+pub fn set_hypervisor_bit(entries: &mut [kvm_cpuid_entry2]) -> Result<(), Error> {
+    entries.set(Feature::Hypervisor, true)?;
+    Ok(())
+}
+
+/// Writes the APIC ID of the vcpu `index` and the topology to leaf 0x1 and to
+/// the extended topology leaves 0xB and 0x1F.
+///
+/// The subleaves of 0xB and 0x1F past the core level are marked invalid.
+pub fn set_apic_ids(
+    entries: &mut [kvm_cpuid_entry2],
+    topology: &CpuTopology,
+    index: u32,
+) -> Result<(), Error> {
+    let apic_id = topology.apic_id(index)?;
+    let per_socket = topology.vcpus_per_socket();
+    {
+        let leaf = entries.entry_mut(0x1, 0).ok_or(Error::MissingLeaf {
+            function: 0x1,
+            index: 0,
+        })?;
+        leaf.ebx = (leaf.ebx & LEAF1_EBX_KEEP_MASK)
+            | (per_socket << LEAF1_EBX_COUNT_SHIFT)
+            | ((apic_id & 0xff) << LEAF1_EBX_APIC_ID_SHIFT);
+        if per_socket > 1 {
+            leaf.edx |= LEAF1_EDX_HTT;
+        } else {
+            leaf.edx &= !LEAF1_EDX_HTT;
+        }
+    }
+
+    for entry in entries
+        .iter_mut()
+        .filter(|e| e.function == 0xb || e.function == 0x1f)
+    {
+        let (shift, count, level_type) = match entry.index {
+            0 => (topology.thread_bits(), topology.threads, LEVEL_TYPE_SMT),
+            1 => (topology.socket_bits(), per_socket, LEVEL_TYPE_CORE),
+            _ => (0, 0, 0),
+        };
+        entry.eax = shift;
+        entry.ebx = count;
+        entry.ecx = (level_type << 8) | (entry.index & 0xff);
+        entry.edx = apic_id;
+    }
+    Ok(())
+}
+
+/// Writes the topology of the vcpu `index` to the AMD leaves: the core count
+/// of leaf 0x80000008 and the extended APIC ID, core and node of leaf
+/// 0x8000001E.
+pub fn set_amd_topology(
+    entries: &mut [kvm_cpuid_entry2],
+    topology: &CpuTopology,
+    index: u32,
+) -> Result<(), Error> {
+    let apic_id = topology.apic_id(index)?;
+    if let Some(leaf) = entries.entry_mut(0x8000_0008, 0) {
+        leaf.ecx &= !(AMD_NC_MASK | (AMD_APIC_ID_SIZE_MASK << AMD_APIC_ID_SIZE_SHIFT));
+        leaf.ecx |=
+            (topology.vcpus_per_socket() - 1) | (topology.socket_bits() << AMD_APIC_ID_SIZE_SHIFT);
+    }
+    if let Some(leaf) = entries.entry_mut(0x8000_001e, 0) {
+        let core = index / topology.threads % topology.cores;
+        let socket = index / topology.vcpus_per_socket();
+        leaf.eax = apic_id;
+        leaf.ebx = ((topology.threads - 1) << 8) | (core & 0xff);
+        leaf.ecx = socket & 0xff;
+        leaf.edx = 0;
+    }
+    Ok(())
+}
+
+/// Writes the sharing of the caches to the deterministic cache parameters
+/// leaf of the vendor: 0x4 for Intel, 0x8000001D for AMD.
+///
+/// The L1 and L2 caches are shared by the threads of a core, the L3 by the
+/// socket.
+pub fn set_cache_topology(
+    entries: &mut [kvm_cpuid_entry2],
+    topology: &CpuTopology,
+    vendor: CpuVendor,
+) {
+    let function = match vendor {
+        CpuVendor::Intel => 0x4,
+        CpuVendor::Amd => 0x8000_001d,
+    };
+    for entry in entries.iter_mut().filter(|e| e.function == function) {
+        if entry.eax & CACHE_TYPE_MASK == 0 {
+            // No more caches.
+            continue;
+        }
+        let shared_by_socket = (entry.eax >> CACHE_LEVEL_SHIFT) & 0x7 >= 3;
+        let sharing = match vendor {
+            // Intel counts the APIC IDs reserved for the sharing processors.
+            CpuVendor::Intel if shared_by_socket => (1 << topology.socket_bits()) - 1,
+            CpuVendor::Intel => (1 << topology.thread_bits()) - 1,
+            CpuVendor::Amd if shared_by_socket => topology.vcpus_per_socket() - 1,
+            CpuVendor::Amd => topology.threads - 1,
+        };
+        entry.eax &= !(CACHE_SHARING_MASK << CACHE_SHARING_SHIFT);
+        entry.eax |= (sharing & CACHE_SHARING_MASK) << CACHE_SHARING_SHIFT;
+        if vendor == CpuVendor::Intel {
+            let cores = (1 << (topology.socket_bits() - topology.thread_bits())) - 1;
+            entry.eax &= !(CACHE_CORES_MASK << CACHE_CORES_SHIFT);
+            entry.eax |= (cores & CACHE_CORES_MASK) << CACHE_CORES_SHIFT;
+        }
+    }
+}
+
+/// Clears the power management leaves KVM does not emulate: `MONITOR` and
+/// `MWAIT` in leaf 0x5, thermal and power management in leaf 0x6, and
+/// advanced power management in leaf 0x80000007 but the invariant TSC.
+pub fn clear_power_management(entries: &mut [kvm_cpuid_entry2]) {
+    for entry in entries.iter_mut() {
+        match entry.function {
+            0x5 | 0x6 => {
+                entry.eax = 0;
+                entry.ebx = 0;
+                entry.ecx = 0;
+                entry.edx = 0;
+            }
+            0x8000_0007 => {
+                entry.eax = 0;
+                entry.ebx = 0;
+                entry.ecx = 0;
+                entry.edx &= INVARIANT_TSC;
+            }
+            _ => {}
+        }
+    }
+    // Clearing the features of a missing leaf cannot fail.
+    let _ = entries.set(Feature::Monitor, false);
+    let _ = entries.set(Feature::Mwaitx, false);
+}
+
+/// Limits the XSAVE state components of leaf 0xD to the ones of `xcr0`.
+///
+/// The user state components outside of `xcr0` are removed from subleaf 0
+/// and their subleaves are cleared, and the maximum XSAVE area size of
+/// subleaf 0 is recomputed. KVM computes the size for the current `XCR0` of
+/// the guest itself.
+pub fn cap_xsave(entries: &mut [kvm_cpuid_entry2], xcr0: u64) {
+    let mut size = XSAVE_LEGACY_SIZE;
+    for entry in entries
+        .iter_mut()
+        .filter(|e| e.function == 0xd && (2..64).contains(&e.index))
+    {
+        // ECX bit 0 is set for the supervisor components, enabled in IA32_XSS.
+        let supervisor = entry.ecx & 1 != 0;
+        if !supervisor && xcr0 & (1 << entry.index) == 0 {
+            entry.eax = 0;
+            entry.ebx = 0;
+            entry.ecx = 0;
+            entry.edx = 0;
+        } else if !supervisor {
+            size = size.max(entry.ebx + entry.eax);
+        }
+    }
+    if let Some(leaf) = entries.entry_mut(0xd, 0) {
+        leaf.eax &= xcr0 as u32;
+        leaf.edx &= (xcr0 >> 32) as u32;
+        leaf.ecx = size;
+    }
+}
+
+/// Runs the normalization steps for one vcpu.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CpuidNormalizer {
+    vendor: CpuVendor,
+    topology: CpuTopology,
+    xcr0: Option<u64>,
+}
+
+impl CpuidNormalizer {
+    /// Starts a normalization for a `vendor` guest with `topology`.
+    pub fn new(vendor: CpuVendor, topology: CpuTopology) -> Self {
+        CpuidNormalizer {
+            vendor,
+            topology,
+            xcr0: None,
+        }
+    }
+
+    /// Caps leaf 0xD to the state components of `xcr0`.
+    pub fn xcr0(mut self, xcr0: u64) -> Self {
+        self.xcr0 = Some(xcr0);
+        self
+    }
+
+    /// Normalizes `entries` for the vcpu `index`.
+    pub fn normalize(&self, entries: &mut [kvm_cpuid_entry2], index: u32) -> Result<(), Error> {
+        set_hypervisor_bit(entries)?;
+        set_apic_ids(entries, &self.topology, index)?;
+        if self.vendor == CpuVendor::Amd {
+            set_amd_topology(entries, &self.topology, index)?;
+        }
+        set_cache_topology(entries, &self.topology, self.vendor);
+        clear_power_management(entries);
+        if let Some(xcr0) = self.xcr0 {
+            cap_xsave(entries, xcr0);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::leaf;
+    use super::*;
+
+    fn intel_entries() -> Vec<kvm_cpuid_entry2> {
+        vec![
+            leaf(0x0, 0, [0xd, 0x756e_6547, 0x6c65_746e, 0x4965_6e69]),
+            leaf(0x1, 0, [0x5_0654, 0x0b10_0800, 0x7ffa_fbff, 0xbfeb_fbff]),
+            // L1d, L2 and L3.
+            leaf(0x4, 0, [0x1c00_4121, 0x01c0_003f, 0x3f, 0]),
+            leaf(0x4, 1, [0x1c00_4143, 0x03c0_003f, 0x3ff, 0]),
+            leaf(0x4, 2, [0x1c03_c163, 0x0280_003f, 0xdfff, 0x4]),
+            leaf(0x4, 3, [0, 0, 0, 0]),
+            leaf(0x5, 0, [0x40, 0x40, 0x3, 0x2020]),
+            leaf(0x6, 0, [0x77, 0x2, 0x9, 0]),
+            leaf(0xb, 0, [0x1, 0x2, 0x100, 0x17]),
+            leaf(0xb, 1, [0x5, 0x20, 0x201, 0x17]),
+            leaf(0xb, 2, [0, 0, 0x2, 0x17]),
+            leaf(0xd, 0, [0x2ff, 0xa88, 0xa88, 0]),
+            leaf(0xd, 1, [0xf, 0xa08, 0, 0]),
+            leaf(0xd, 2, [0x100, 0x240, 0, 0]),
+            leaf(0xd, 5, [0x40, 0x440, 0, 0]),
+            leaf(0xd, 6, [0x200, 0x480, 0, 0]),
+            leaf(0xd, 7, [0x400, 0x680, 0, 0]),
+            leaf(0xd, 9, [0x8, 0xa80, 0, 0]),
+            leaf(0x8000_0007, 0, [0x1, 0x2, 0x3, 0x100]),
+        ]
+    }
+
+    #[test]
+    fn test_topology() {
+        let topology = CpuTopology::new(2, 3, 2).unwrap();
+        assert_eq!(topology.vcpus(), 12);
+        assert_eq!(topology.thread_bits(), 1);
+        assert_eq!(topology.socket_bits(), 3);
+        assert_eq!(topology.apic_id(0), Ok(0));
+        assert_eq!(topology.apic_id(3), Ok(0x3));
+        // Core 2, thread 1 of socket 0, then the first vcpu of socket 1.
+        assert_eq!(topology.apic_id(5), Ok(0x5));
+        assert_eq!(topology.apic_id(6), Ok(0x8));
+        assert_eq!(topology.apic_id(11), Ok(0xd));
+        assert_eq!(topology.apic_id(12), Err(Error::InvalidVcpu(12)));
+
+        let flat = CpuTopology::new(1, 1, 1).unwrap();
+        assert_eq!(flat.socket_bits(), 0);
+        assert_eq!(flat.apic_id(0), Ok(0));
+
+        assert_eq!(CpuTopology::new(0, 1, 1), Err(Error::InvalidTopology));
+        assert_eq!(CpuTopology::new(1, 128, 2), Err(Error::InvalidTopology));
+        assert!(CpuTopology::new(1, 85, 3).is_ok());
+    }
+
+    #[test]
+    fn test_vendor() {
+        let entries = intel_entries();
+        assert_eq!(CpuVendor::from_entries(&entries), Some(CpuVendor::Intel));
+        let amd = [leaf(0x0, 0, [0x10, 0x6874_7541, 0x444d_4163, 0x6974_6e65])];
+        assert_eq!(CpuVendor::from_entries(&amd), Some(CpuVendor::Amd));
+        assert_eq!(CpuVendor::from_entries(&entries[1..]), None);
+    }
+
+    #[test]
+    fn test_apic_ids() {
+        let topology = CpuTopology::new(2, 2, 2).unwrap();
+        let mut entries = intel_entries();
+        set_apic_ids(&mut entries, &topology, 5).unwrap();
+        assert_eq!(entries.entry(0x1, 0).unwrap().ebx, 0x0504_0800);
+        assert_ne!(entries.entry(0x1, 0).unwrap().edx & LEAF1_EDX_HTT, 0);
+        let smt = entries.entry(0xb, 0).unwrap();
+        assert_eq!((smt.eax, smt.ebx, smt.ecx, smt.edx), (1, 2, 0x100, 5));
+        let core = entries.entry(0xb, 1).unwrap();
+        assert_eq!((core.eax, core.ebx, core.ecx, core.edx), (2, 4, 0x201, 5));
+        let invalid = entries.entry(0xb, 2).unwrap();
+        assert_eq!((invalid.eax, invalid.ebx, invalid.ecx), (0, 0, 0x2));
+
+        let single = CpuTopology::new(1, 1, 1).unwrap();
+        set_apic_ids(&mut entries, &single, 0).unwrap();
+        assert_eq!(entries.entry(0x1, 0).unwrap().ebx, 0x0001_0800);
+        assert_eq!(entries.entry(0x1, 0).unwrap().edx & LEAF1_EDX_HTT, 0);
+        assert_eq!(
+            set_apic_ids(&mut entries, &single, 1),
+            Err(Error::InvalidVcpu(1))
+        );
+        assert_eq!(
+            set_apic_ids(&mut entries[2..], &single, 0),
+            Err(Error::MissingLeaf {
+                function: 0x1,
+                index: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_cache_topology() {
+        let topology = CpuTopology::new(1, 4, 2).unwrap();
+        let mut entries = intel_entries();
+        set_cache_topology(&mut entries, &topology, CpuVendor::Intel);
+        // L1d shared by the 2 threads, L3 by the 8 vcpus, 4 cores per socket.
+        assert_eq!(entries.entry(0x4, 0).unwrap().eax, 0x0c00_4121);
+        assert_eq!(entries.entry(0x4, 1).unwrap().eax, 0x0c00_4143);
+        assert_eq!(entries.entry(0x4, 2).unwrap().eax, 0x0c01_c163);
+        assert_eq!(entries.entry(0x4, 3).unwrap().eax, 0);
+
+        let mut amd = vec![
+            leaf(0x8000_001d, 0, [0x0000_4121, 0x01c0_003f, 0x3f, 0]),
+            leaf(0x8000_001d, 1, [0x0000_4143, 0x01c0_003f, 0x3ff, 0x2]),
+            leaf(0x8000_001d, 2, [0x0003_c163, 0x03c0_003f, 0x3fff, 0x1]),
+        ];
+        let topology = CpuTopology::new(1, 3, 2).unwrap();
+        set_cache_topology(&mut amd, &topology, CpuVendor::Amd);
+        assert_eq!(amd[0].eax, 0x0000_4121);
+        assert_eq!(amd[2].eax, 0x0001_4163);
+    }
+
+    #[test]
+    fn test_amd_topology() {
+        let topology = CpuTopology::new(2, 3, 2).unwrap();
+        let mut entries = vec![
+            leaf(0x1, 0, [0x80_0f12, 0, 0, 0]),
+            leaf(0x8000_0008, 0, [0x3030, 0, 0x700f, 0]),
+            leaf(0x8000_001e, 0, [0, 0x100, 0, 0]),
+        ];
+        set_amd_topology(&mut entries, &topology, 9).unwrap();
+        assert_eq!(entries[1].ecx, 0x3005);
+        // vcpu 9 is thread 1 of core 1 of socket 1.
+        assert_eq!(
+            (entries[2].eax, entries[2].ebx, entries[2].ecx),
+            (0xb, 0x101, 0x1)
+        );
+    }
+
+    #[test]
+    fn test_power_management() {
+        let mut entries = intel_entries();
+        assert!(entries.has(Feature::Monitor));
+        clear_power_management(&mut entries);
+        assert!(!entries.has(Feature::Monitor));
+        let mwait = entries.entry(0x5, 0).unwrap();
+        assert_eq!((mwait.eax, mwait.ebx, mwait.ecx, mwait.edx), (0, 0, 0, 0));
+        assert_eq!(entries.entry(0x6, 0).unwrap().eax, 0);
+        let apm = entries.entry(0x8000_0007, 0).unwrap();
+        assert_eq!((apm.eax, apm.edx), (0, INVARIANT_TSC));
+    }
+
+    #[test]
+    fn test_cap_xsave() {
+        let mut entries = intel_entries();
+        // x87, SSE and AVX.
+        cap_xsave(&mut entries, 0x7);
+        let leaf = entries.entry(0xd, 0).unwrap();
+        assert_eq!((leaf.eax, leaf.ecx, leaf.edx), (0x7, 0x340, 0));
+        assert_eq!(entries.entry(0xd, 2).unwrap().eax, 0x100);
+        assert_eq!(entries.entry(0xd, 5).unwrap().eax, 0);
+        assert_eq!(entries.entry(0xd, 9).unwrap().ebx, 0);
+        // Subleaf 1 holds the XSAVE features and IA32_XSS.
+        assert_eq!(entries.entry(0xd, 1).unwrap().eax, 0xf);
+
+        let mut entries = intel_entries();
+        cap_xsave(&mut entries, 0x3);
+        assert_eq!(entries.entry(0xd, 0).unwrap().ecx, XSAVE_LEGACY_SIZE);
+    }
+
+    #[test]
+    fn test_normalizer() {
+        let mut entries = intel_entries();
+        let vendor = CpuVendor::from_entries(&entries).unwrap();
+        let topology = CpuTopology::new(1, 2, 1).unwrap();
+        CpuidNormalizer::new(vendor, topology)
+            .xcr0(0x7)
+            .normalize(&mut entries, 1)
+            .unwrap();
+        assert!(entries.has(Feature::Hypervisor));
+        assert_eq!(entries.entry(0xb, 0).unwrap().edx, 1);
+        assert_eq!(entries.entry(0x4, 2).unwrap().eax, 0x0400_4163);
+        assert_eq!(entries.entry(0x6, 0).unwrap().eax, 0);
+        assert_eq!(entries.entry(0xd, 0).unwrap().eax, 0x7);
+
+        assert_eq!(
+            CpuidNormalizer::new(vendor, topology).normalize(&mut entries, 2),
+            Err(Error::InvalidVcpu(2))
+        );
+    }
+}
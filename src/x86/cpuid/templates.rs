@@ -0,0 +1,744 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Named CPU models guests can be pinned to.
+//!
+//! A [`CpuTemplate`](struct.CpuTemplate.html) lists the CPUID features, the
+//! XSAVE state components and the MSR values of a CPU model. Applied to the
+//! CPUID of a host, it keeps only the feature bits of the model, so a guest
+//! keeps the same CPU on any host of the fleet, and reports what the host
+//! lacks. [`TEMPLATES`](constant.TEMPLATES.html) holds the known models.
+
+use std::fmt;
+
+use super::super::bindings::*;
+use super::normalize::{cap_xsave, CpuVendor};
+use super::{CpuidEntries, Feature, FeatureBit, Register};
+
+// MSRs set by the templates.
+const MSR_IA32_ARCH_CAPABILITIES: u32 = 0x10a;
+const MSR_IA32_MISC_ENABLE: u32 = 0x1a0;
+const MSR_AMD64_DE_CFG: u32 = 0xc001_1029;
+
+// IA32_MISC_ENABLE: fast string operations.
+const MISC_ENABLE_FAST_STRING: u64 = 1 << 0;
+// DE_CFG: LFENCE is dispatch serializing.
+const DE_CFG_LFENCE_SERIALIZE: u64 = 1 << 1;
+
+// The registers holding feature flags. The masks clear all their bits but the
+// features and the extra bits of the model.
+const FEATURE_REGISTERS: &[(u32, u32, Register)] = &[
+    (0x1, 0, Register::Ecx),
+    (0x1, 0, Register::Edx),
+    (0x7, 0, Register::Ebx),
+    (0x7, 0, Register::Ecx),
+    (0x7, 0, Register::Edx),
+    (0x7, 1, Register::Eax),
+    (0xd, 1, Register::Eax),
+    (0x8000_0001, 0, Register::Ecx),
+    (0x8000_0001, 0, Register::Edx),
+    (0x8000_0008, 0, Register::Ebx),
+];
+
+// The leaves enumerating a feature, cleared with all their subleaves when the
+// model lacks it: SGX, processor trace, and the AMX tiles and TMUL, which no
+// model has.
+const FEATURE_LEAVES: &[(u32, Option<Feature>)] = &[
+    (0x12, Some(Feature::Sgx)),
+    (0x14, Some(Feature::IntelPt)),
+    (0x1d, None),
+    (0x1e, None),
+];
+
+/// Errors associated with the CPU templates.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The template is for another CPU vendor than the host.
+    VendorMismatch {
+        expected: CpuVendor,
+        found: Option<CpuVendor>,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::VendorMismatch {
+                expected,
+                found: Some(found),
+            } => write!(f, "{:?} template for an {:?} host", expected, found),
+            Error::VendorMismatch {
+                expected,
+                found: None,
+            } => write!(f, "{:?} template for a host of unknown vendor", expected),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// The bits of a leaf a template keeps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LeafMask {
+    /// The leaf.
+    pub function: u32,
+    /// The subleaf.
+    pub index: u32,
+    /// The bits kept in `EAX`.
+    pub eax: u32,
+    /// The bits kept in `EBX`.
+    pub ebx: u32,
+    /// The bits kept in `ECX`.
+    pub ecx: u32,
+    /// The bits kept in `EDX`.
+    pub edx: u32,
+}
+
+impl LeafMask {
+    fn register(&self, register: Register) -> u32 {
+        match register {
+            Register::Eax => self.eax,
+            Register::Ebx => self.ebx,
+            Register::Ecx => self.ecx,
+            Register::Edx => self.edx,
+        }
+    }
+
+    fn register_mut(&mut self, register: Register) -> &mut u32 {
+        match register {
+            Register::Eax => &mut self.eax,
+            Register::Ebx => &mut self.ebx,
+            Register::Ecx => &mut self.ecx,
+            Register::Edx => &mut self.edx,
+        }
+    }
+
+    /// Clears the bits of `entry` outside of the mask.
+    pub fn apply(&self, entry: &mut kvm_cpuid_entry2) {
+        entry.eax &= self.eax;
+        entry.ebx &= self.ebx;
+        entry.ecx &= self.ecx;
+        entry.edx &= self.edx;
+    }
+}
+
+// Returns the mask of `register` in the leaf `function` and subleaf `index`,
+// adding a mask keeping the whole leaf if the leaf has none yet.
+fn register_mask(
+    masks: &mut Vec<LeafMask>,
+    function: u32,
+    index: u32,
+    register: Register,
+) -> &mut u32 {
+    let position = match masks
+        .iter()
+        .position(|m| m.function == function && m.index == index)
+    {
+        Some(position) => position,
+        None => {
+            masks.push(LeafMask {
+                function,
+                index,
+                eax: !0,
+                ebx: !0,
+                ecx: !0,
+                edx: !0,
+            });
+            masks.len() - 1
+        }
+    };
+    masks[position].register_mut(register)
+}
+
+/// The features of a template, once the ones of the host are masked.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TemplateReport {
+    /// The features of the host the template removed.
+    pub removed: Vec<Feature>,
+    /// The features of the template the host does not have. They stay clear.
+    pub missing: Vec<Feature>,
+}
+
+/// A CPU model.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CpuTemplate {
+    /// The name of the model, like `Skylake-Server`.
+    pub name: &'static str,
+    /// The vendor of the model.
+    pub vendor: CpuVendor,
+    /// The family, model and stepping in `EAX` of leaf 0x1.
+    pub signature: u32,
+    /// The features of the model, in groups shared between the models.
+    pub features: &'static [&'static [Feature]],
+    /// The bits of the feature registers the model has without a
+    /// [`Feature`](../enum.Feature.html).
+    pub extra_bits: &'static [LeafMask],
+    /// The XSAVE state components of the model.
+    pub xcr0: u64,
+    /// The MSR values the model needs, to set with `KVM_SET_MSRS`.
+    pub msrs: &'static [kvm_msr_entry],
+}
+
+impl CpuTemplate {
+    /// Returns the template named `name`, ignoring the case.
+    pub fn by_name(name: &str) -> Option<&'static CpuTemplate> {
+        TEMPLATES.iter().find(|t| t.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns `true` if the model has `feature`.
+    pub fn has(&self, feature: Feature) -> bool {
+        self.features.iter().any(|group| group.contains(&feature))
+    }
+
+    /// Returns the masks of the leaves with feature flags.
+    ///
+    /// The registers holding feature flags keep only the features of the
+    /// model and its [`extra_bits`](#structfield.extra_bits); the bits the
+    /// model does not name, like the features newer than the model, are
+    /// cleared. The other registers are kept.
+    pub fn masks(&self) -> Vec<LeafMask> {
+        let mut masks = Vec::new();
+        for &(function, index, register) in FEATURE_REGISTERS {
+            *register_mask(&mut masks, function, index, register) = 0;
+        }
+        for &feature in Feature::ALL.iter().filter(|&&f| self.has(f)) {
+            let FeatureBit {
+                function,
+                index,
+                register,
+                bit,
+            } = feature.location();
+            *register_mask(&mut masks, function, index, register) |= 1 << bit;
+        }
+        for extra in self.extra_bits {
+            for &register in &[Register::Eax, Register::Ebx, Register::Ecx, Register::Edx] {
+                *register_mask(&mut masks, extra.function, extra.index, register) |=
+                    extra.register(register);
+            }
+        }
+        masks
+    }
+
+    /// Masks the CPUID entries of the host with the model, and writes the
+    /// signature and the XSAVE state components of the model.
+    ///
+    /// The leaves enumerating SGX, processor trace or AMX are cleared when the
+    /// model lacks the feature.
+    ///
+    /// The MSRs of the model are not checked against the host; set them with
+    /// `KVM_SET_MSRS` after `KVM_SET_CPUID2`.
+    pub fn apply(&self, entries: &mut [kvm_cpuid_entry2]) -> Result<TemplateReport, Error> {
+        let found = CpuVendor::from_entries(entries);
+        if found != Some(self.vendor) {
+            return Err(Error::VendorMismatch {
+                expected: self.vendor,
+                found,
+            });
+        }
+
+        let mut report = TemplateReport::default();
+        for &feature in Feature::ALL {
+            match (entries.has(feature), self.has(feature)) {
+                (true, false) => report.removed.push(feature),
+                (false, true) => report.missing.push(feature),
+                _ => {}
+            }
+        }
+
+        for mask in self.masks() {
+            if let Some(entry) = entries.entry_mut(mask.function, mask.index) {
+                mask.apply(entry);
+            }
+        }
+        for &(function, feature) in FEATURE_LEAVES {
+            if feature.is_some_and(|f| self.has(f)) {
+                continue;
+            }
+            for entry in entries.iter_mut().filter(|e| e.function == function) {
+                entry.eax = 0;
+                entry.ebx = 0;
+                entry.ecx = 0;
+                entry.edx = 0;
+            }
+        }
+        if let Some(leaf) = entries.entry_mut(0x1, 0) {
+            leaf.eax = self.signature;
+        }
+        cap_xsave(entries, self.xcr0);
+        Ok(report)
+    }
+
+    /// Returns the MSR values of the model.
+    pub fn msr_entries(&self) -> Msrs {
+        Msrs::from_entries(self.msrs).expect("the templates have few MSRs")
+    }
+}
+
+// The features of every model: the x86-64 baseline, AVX2 and XSAVEOPT.
+const BASE: &[Feature] = &[
+    Feature::Sse3,
+    Feature::Pclmulqdq,
+    Feature::Ssse3,
+    Feature::Fma,
+    Feature::Cx16,
+    Feature::Sse4_1,
+    Feature::Sse4_2,
+    Feature::X2apic,
+    Feature::Movbe,
+    Feature::Popcnt,
+    Feature::Aes,
+    Feature::Xsave,
+    Feature::Osxsave,
+    Feature::Avx,
+    Feature::F16c,
+    Feature::Rdrand,
+    Feature::Hypervisor,
+    Feature::Fpu,
+    Feature::Vme,
+    Feature::De,
+    Feature::Pse,
+    Feature::Tsc,
+    Feature::Msr,
+    Feature::Pae,
+    Feature::Mce,
+    Feature::Cx8,
+    Feature::Apic,
+    Feature::Sep,
+    Feature::Mtrr,
+    Feature::Pge,
+    Feature::Mca,
+    Feature::Cmov,
+    Feature::Pat,
+    Feature::Pse36,
+    Feature::Clflush,
+    Feature::Mmx,
+    Feature::Fxsr,
+    Feature::Sse,
+    Feature::Sse2,
+    Feature::Fsgsbase,
+    Feature::Bmi1,
+    Feature::Avx2,
+    Feature::Smep,
+    Feature::Bmi2,
+    Feature::Xsaveopt,
+    Feature::LahfLm,
+    Feature::Abm,
+    Feature::Syscall,
+    Feature::Nx,
+    Feature::Pdpe1gb,
+    Feature::Rdtscp,
+    Feature::Lm,
+];
+
+// The Intel features since Haswell. The x87 FDP and FCS/FDS behaviors are
+// properties of the host FPU a guest cannot be given back once migrated.
+const INTEL: &[Feature] = &[
+    Feature::Pcid,
+    Feature::TscDeadlineTimer,
+    Feature::Erms,
+    Feature::Invpcid,
+    Feature::FdpExcptnOnly,
+    Feature::ZeroFcsFds,
+];
+
+// The Skylake server features, AVX-512 and the speculation controls.
+const SKYLAKE: &[Feature] = &[
+    Feature::Rdseed,
+    Feature::Adx,
+    Feature::Smap,
+    Feature::Clflushopt,
+    Feature::Clwb,
+    Feature::Avx512f,
+    Feature::Avx512dq,
+    Feature::Avx512cd,
+    Feature::Avx512bw,
+    Feature::Avx512vl,
+    Feature::Pku,
+    Feature::SpecCtrl,
+    Feature::SpecCtrlSsbd,
+    Feature::Xsavec,
+    Feature::Xgetbv1,
+    Feature::ThreeDNowPrefetch,
+];
+
+// TSX and MPX, gone since Icelake.
+const TSX_MPX: &[Feature] = &[Feature::Hle, Feature::Rtm, Feature::Mpx];
+
+const CASCADELAKE: &[Feature] = &[
+    Feature::Avx512Vnni,
+    Feature::MdClear,
+    Feature::ArchCapabilities,
+];
+
+const ICELAKE: &[Feature] = &[
+    Feature::Avx512ifma,
+    Feature::ShaNi,
+    Feature::Avx512vbmi,
+    Feature::Umip,
+    Feature::Avx512Vbmi2,
+    Feature::Gfni,
+    Feature::Vaes,
+    Feature::Vpclmulqdq,
+    Feature::Avx512Bitalg,
+    Feature::Avx512Vpopcntdq,
+    Feature::La57,
+    Feature::Rdpid,
+    Feature::Wbnoinvd,
+];
+
+const ROME: &[Feature] = &[
+    Feature::Rdseed,
+    Feature::Adx,
+    Feature::Smap,
+    Feature::Clflushopt,
+    Feature::Clwb,
+    Feature::ShaNi,
+    Feature::Umip,
+    Feature::Rdpid,
+    Feature::Xsavec,
+    Feature::Xgetbv1,
+    Feature::Cr8Legacy,
+    Feature::Sse4a,
+    Feature::Misalignsse,
+    Feature::ThreeDNowPrefetch,
+    Feature::Osvw,
+    Feature::Topoext,
+    Feature::PerfctrCore,
+    Feature::Mmxext,
+    Feature::FxsrOpt,
+    Feature::Clzero,
+    Feature::Xsaveerptr,
+    Feature::Wbnoinvd,
+    Feature::AmdIbpb,
+    Feature::AmdStibp,
+    Feature::AmdSsbd,
+];
+
+// The AMD copies of the leaf 0x1 EDX bits in leaf 0x80000001 EDX: FPU to
+// APIC, MTRR to PSE36, MMX and FXSR.
+const AMD_EDX_COPIES: LeafMask = LeafMask {
+    function: 0x8000_0001,
+    index: 0,
+    eax: 0,
+    ebx: 0,
+    ecx: 0,
+    edx: 0x0183_f3ff,
+};
+
+// The XSAVE state components: x87, SSE and AVX, then the MPX bound
+// registers, the AVX-512 registers and PKRU.
+const XCR0_AVX: u64 = 0x7;
+const XCR0_MPX: u64 = 0x18;
+const XCR0_AVX512: u64 = 0xe0;
+const XCR0_PKRU: u64 = 0x200;
+
+const FAST_STRING: kvm_msr_entry = kvm_msr_entry {
+    index: MSR_IA32_MISC_ENABLE,
+    reserved: 0,
+    data: MISC_ENABLE_FAST_STRING,
+};
+
+/// The known CPU models.
+pub const TEMPLATES: &[CpuTemplate] = &[
+    CpuTemplate {
+        name: "Skylake-Server",
+        vendor: CpuVendor::Intel,
+        signature: 0x5_0654,
+        features: &[BASE, INTEL, SKYLAKE, TSX_MPX],
+        extra_bits: &[],
+        xcr0: XCR0_AVX | XCR0_MPX | XCR0_AVX512 | XCR0_PKRU,
+        msrs: &[FAST_STRING],
+    },
+    CpuTemplate {
+        name: "Cascadelake-Server",
+        vendor: CpuVendor::Intel,
+        signature: 0x5_0657,
+        features: &[BASE, INTEL, SKYLAKE, TSX_MPX, CASCADELAKE],
+        extra_bits: &[],
+        xcr0: XCR0_AVX | XCR0_MPX | XCR0_AVX512 | XCR0_PKRU,
+        msrs: &[
+            FAST_STRING,
+            // RDCL_NO, IBRS_ALL, SKIP_L1DFL_VMENTRY and MDS_NO.
+            kvm_msr_entry {
+                index: MSR_IA32_ARCH_CAPABILITIES,
+                reserved: 0,
+                data: 0x2b,
+            },
+        ],
+    },
+    CpuTemplate {
+        name: "Icelake-Server",
+        vendor: CpuVendor::Intel,
+        signature: 0x6_06a6,
+        features: &[BASE, INTEL, SKYLAKE, CASCADELAKE, ICELAKE],
+        extra_bits: &[],
+        xcr0: XCR0_AVX | XCR0_AVX512 | XCR0_PKRU,
+        msrs: &[
+            FAST_STRING,
+            // Cascade Lake's, PSCHANGE_MC_NO and TAA_NO.
+            kvm_msr_entry {
+                index: MSR_IA32_ARCH_CAPABILITIES,
+                reserved: 0,
+                data: 0x16b,
+            },
+        ],
+    },
+    CpuTemplate {
+        name: "EPYC-Rome",
+        vendor: CpuVendor::Amd,
+        signature: 0x83_0f10,
+        features: &[BASE, ROME],
+        extra_bits: &[AMD_EDX_COPIES],
+        xcr0: XCR0_AVX,
+        msrs: &[kvm_msr_entry {
+            index: MSR_AMD64_DE_CFG,
+            reserved: 0,
+            data: DE_CFG_LFENCE_SERIALIZE,
+        }],
+    },
+    // A Haswell-like model without the features added since, for the widest
+    // range of Intel hosts.
+    CpuTemplate {
+        name: "T2",
+        vendor: CpuVendor::Intel,
+        signature: 0x3_06f0,
+        features: &[BASE, INTEL],
+        extra_bits: &[],
+        xcr0: XCR0_AVX,
+        msrs: &[FAST_STRING],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::super::leaf;
+    use super::*;
+
+    const INTEL_VENDOR: [u32; 4] = [0xd, 0x756e_6547, 0x6c65_746e, 0x4965_6e69];
+    const AMD_VENDOR: [u32; 4] = [0x10, 0x6874_7541, 0x444d_4163, 0x6974_6e65];
+
+    // An Intel host with the features of `template`.
+    fn host(template: &CpuTemplate) -> Vec<kvm_cpuid_entry2> {
+        host_of(INTEL_VENDOR, template)
+    }
+
+    // A host of the vendor of leaf 0x0 `vendor` with the features of
+    // `template`.
+    fn host_of(vendor: [u32; 4], template: &CpuTemplate) -> Vec<kvm_cpuid_entry2> {
+        let mut entries = vec![
+            leaf(0x0, 0, vendor),
+            leaf(0x1, 0, [0; 4]),
+            leaf(0x7, 0, [0x1, 0, 0, 0]),
+            leaf(0x7, 1, [0; 4]),
+            leaf(0xd, 0, [0x2ff, 0, 0, 0]),
+            leaf(0xd, 1, [0; 4]),
+            leaf(0x8000_0001, 0, [0; 4]),
+            leaf(0x8000_0008, 0, [0; 4]),
+        ];
+        for &feature in Feature::ALL {
+            if template.has(feature) {
+                entries.set(feature, true).unwrap();
+            }
+        }
+        entries
+    }
+
+    #[test]
+    fn test_catalogue() {
+        assert_eq!(CpuTemplate::by_name("t2").unwrap().name, "T2");
+        assert_eq!(
+            CpuTemplate::by_name("EPYC-Rome").unwrap().vendor,
+            CpuVendor::Amd
+        );
+        assert!(CpuTemplate::by_name("Pentium").is_none());
+        for (i, a) in TEMPLATES.iter().enumerate() {
+            assert!(TEMPLATES[i + 1..].iter().all(|b| b.name != a.name));
+        }
+
+        let skylake = CpuTemplate::by_name("Skylake-Server").unwrap();
+        let icelake = CpuTemplate::by_name("Icelake-Server").unwrap();
+        assert!(skylake.has(Feature::Avx512f));
+        assert!(skylake.has(Feature::Mpx));
+        assert!(!icelake.has(Feature::Mpx));
+        assert!(icelake.has(Feature::Avx512Vnni));
+        assert!(!CpuTemplate::by_name("T2").unwrap().has(Feature::Avx512f));
+    }
+
+    #[test]
+    fn test_masks() {
+        let t2 = CpuTemplate::by_name("T2").unwrap();
+        let masks = t2.masks();
+        let leaf7 = masks
+            .iter()
+            .find(|m| m.function == 0x7 && m.index == 0)
+            .unwrap();
+        // fsgsbase, bmi1, avx2, smep, fdp_excptn_only, bmi2, erms, invpcid and
+        // zero_fcs_fds.
+        assert_eq!(leaf7.ebx & 0xffff, 0x27e9);
+        // The unnamed bit 22 is cleared, the maximum subleaf kept.
+        assert_eq!(leaf7.ebx & (1 << 22), 0);
+        assert_eq!(leaf7.eax, !0);
+        // None of the features of leaf 0x7.1, like AVX-VNNI.
+        let leaf7_1 = masks
+            .iter()
+            .find(|m| m.function == 0x7 && m.index == 1)
+            .unwrap();
+        assert_eq!(leaf7_1.eax, 0);
+        assert_eq!(leaf7_1.ebx, !0);
+        for &feature in Feature::ALL {
+            let location = feature.location();
+            assert!(FEATURE_REGISTERS.contains(&(
+                location.function,
+                location.index,
+                location.register
+            )));
+            let mask = masks
+                .iter()
+                .find(|m| m.function == location.function && m.index == location.index)
+                .unwrap();
+            assert_eq!(
+                mask.register(location.register) & (1 << location.bit) != 0,
+                t2.has(feature)
+            );
+        }
+        // Only the bits of the features of the model are kept.
+        let named = |function, index, register| {
+            Feature::ALL
+                .iter()
+                .filter(|f| t2.has(**f))
+                .map(|f| f.location())
+                .filter(|l| l.function == function && l.index == index && l.register == register)
+                .fold(0, |bits, l| bits | 1 << l.bit)
+        };
+        for &(function, index, register) in FEATURE_REGISTERS {
+            let mask = masks
+                .iter()
+                .find(|m| m.function == function && m.index == index)
+                .unwrap();
+            assert_eq!(mask.register(register), named(function, index, register));
+        }
+    }
+
+    #[test]
+    fn test_apply() {
+        let cascadelake = CpuTemplate::by_name("Cascadelake-Server").unwrap();
+        let mut entries = host(cascadelake);
+        // An unnamed bit of a feature register, SERIALIZE and AMX-TILE, and
+        // AVX-VNNI and AVX512-BF16 in leaf 0x7.1.
+        entries.entry_mut(0x1, 0).unwrap().edx |= 1 << 10;
+        entries.entry_mut(0x7, 0).unwrap().edx |= (1 << 14) | (1 << 24);
+        entries.entry_mut(0x7, 1).unwrap().eax |= (1 << 4) | (1 << 5);
+        // The SGX and AMX leaves.
+        entries.push(leaf(0x12, 0, [0x3, 0, 0, 0x1f]));
+        entries.push(leaf(0x12, 2, [0x1, 0, 0x1, 0]));
+        entries.push(leaf(0x1d, 1, [0x2000, 0x8_0040, 0x10, 0]));
+
+        let skylake = CpuTemplate::by_name("Skylake-Server").unwrap();
+        let report = skylake.apply(&mut entries).unwrap();
+        assert_eq!(
+            report.removed,
+            vec![
+                Feature::Avx512Vnni,
+                Feature::MdClear,
+                Feature::ArchCapabilities
+            ]
+        );
+        assert!(report.missing.is_empty());
+        assert!(!entries.has(Feature::Avx512Vnni));
+        assert!(entries.has(Feature::Avx512bw));
+        assert_eq!(entries.entry(0x1, 0).unwrap().edx & (1 << 10), 0);
+        assert_eq!(entries.entry(0x7, 0).unwrap().edx & (1 << 14), 0);
+        assert_eq!(entries.entry(0x7, 0).unwrap().edx & (1 << 24), 0);
+        assert_eq!(entries.entry(0x7, 0).unwrap().eax, 0x1);
+        assert_eq!(entries.entry(0x7, 1).unwrap().eax, 0);
+        for entry in entries
+            .iter()
+            .filter(|e| e.function == 0x12 || e.function == 0x1d)
+        {
+            assert_eq!([entry.eax, entry.ebx, entry.ecx, entry.edx], [0; 4]);
+        }
+        assert_eq!(entries.entry(0x1, 0).unwrap().eax, 0x5_0654);
+
+        let mut entries = host(cascadelake);
+        let icelake = CpuTemplate::by_name("Icelake-Server").unwrap();
+        let report = icelake.apply(&mut entries).unwrap();
+        assert_eq!(
+            report.removed,
+            vec![Feature::Hle, Feature::Rtm, Feature::Mpx]
+        );
+        assert!(report.missing.contains(&Feature::Avx512ifma));
+        assert!(report.missing.contains(&Feature::Wbnoinvd));
+        assert!(!entries.has(Feature::Avx512ifma));
+        // The MPX state components are gone.
+        assert_eq!(entries.entry(0xd, 0).unwrap().eax, 0x2e7);
+    }
+
+    #[test]
+    fn test_apply_amd() {
+        let rome = CpuTemplate::by_name("EPYC-Rome").unwrap();
+        let mut entries = host_of(AMD_VENDOR, rome);
+        entries.set(Feature::Avx512f, true).unwrap();
+        // The copies of the FPU, TSC and MMX bits of leaf 0x1 EDX.
+        let copies = 1 | (1 << 4) | (1 << 23);
+        entries.entry_mut(0x8000_0001, 0).unwrap().edx |= copies;
+        // The unnamed bits past the named ones of leaf 0x80000008 EBX.
+        entries.entry_mut(0x8000_0008, 0).unwrap().ebx |= (1 << 19) | (1 << 27);
+
+        let report = rome.apply(&mut entries).unwrap();
+        assert_eq!(report.removed, vec![Feature::Avx512f]);
+        assert!(report.missing.is_empty());
+        assert!(!entries.has(Feature::Avx512f));
+        assert!(entries.has(Feature::Clzero));
+        assert!(entries.has(Feature::Lm));
+        assert_eq!(entries.entry(0x8000_0001, 0).unwrap().edx & copies, copies);
+        assert_eq!(
+            entries.entry(0x8000_0008, 0).unwrap().ebx & ((1 << 19) | (1 << 27)),
+            0
+        );
+        // The Intel models have no such copies.
+        let t2 = CpuTemplate::by_name("T2").unwrap();
+        let extended = t2
+            .masks()
+            .into_iter()
+            .find(|m| m.function == 0x8000_0001)
+            .unwrap();
+        assert_eq!(extended.edx & copies, 0);
+        assert_eq!(entries.entry(0x1, 0).unwrap().eax, 0x83_0f10);
+    }
+
+    #[test]
+    fn test_vendor_mismatch() {
+        let t2 = CpuTemplate::by_name("T2").unwrap();
+        let rome = CpuTemplate::by_name("EPYC-Rome").unwrap();
+        let mut entries = host(t2);
+        assert_eq!(
+            rome.apply(&mut entries),
+            Err(Error::VendorMismatch {
+                expected: CpuVendor::Amd,
+                found: Some(CpuVendor::Intel)
+            })
+        );
+        assert_eq!(
+            t2.apply(&mut entries[1..]),
+            Err(Error::VendorMismatch {
+                expected: CpuVendor::Intel,
+                found: None
+            })
+        );
+    }
+
+    #[test]
+    fn test_msrs() {
+        let msrs = CpuTemplate::by_name("Cascadelake-Server")
+            .unwrap()
+            .msr_entries();
+        assert_eq!(msrs.len(), 2);
+        assert_eq!(msrs.as_slice()[0].index, MSR_IA32_MISC_ENABLE);
+        assert_eq!(msrs.as_slice()[1].index, MSR_IA32_ARCH_CAPABILITIES);
+        assert_eq!(msrs.as_slice()[1].data, 0x2b);
+        assert_eq!(
+            CpuTemplate::by_name("EPYC-Rome").unwrap().msrs[0].index,
+            MSR_AMD64_DE_CFG
+        );
+    }
+}
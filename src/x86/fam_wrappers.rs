@@ -0,0 +1,148 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Maximum number of MSRs KVM accepts in one `KVM_GET_MSRS`/`KVM_SET_MSRS` call.
+pub const KVM_MAX_MSR_ENTRIES: usize = 256;
+/// Maximum number of GSI routes KVM accepts.
+pub const KVM_MAX_IRQ_ROUTES: usize = 4096;
+/// The kernel does not bound the register list; this keeps the allocation sane.
+pub const KVM_MAX_REG_LIST_ENTRIES: usize = 65536;
+/// Size in bytes of the kernel `sigset_t` expected by `KVM_SET_SIGNAL_MASK`.
+pub const KVM_SIGSET_SIZE: usize = 8;
+
+/// Implements `FamStruct` for the flexible array member structs of one bindings
+/// version and defines the wrappers over them. The bindings of that version must
+/// be in scope.
+///
+/// The maximum number of CPUID entries depends on the kernel version, so each
+/// version passes its own.
+macro_rules! fam_wrappers {
+    (max_cpuid_entries: $max_cpuid_entries:expr) => {
+        /// Maximum number of CPUID entries KVM accepts.
+        pub const KVM_MAX_CPUID_ENTRIES: usize = $max_cpuid_entries;
+
+        generate_fam_struct_impl!(
+            kvm_msrs,
+            kvm_msr_entry,
+            entries,
+            nmsrs,
+            __u32,
+            KVM_MAX_MSR_ENTRIES
+        );
+        generate_fam_struct_impl!(
+            kvm_msr_list,
+            __u32,
+            indices,
+            nmsrs,
+            __u32,
+            KVM_MAX_MSR_ENTRIES
+        );
+        generate_fam_struct_impl!(
+            kvm_cpuid2,
+            kvm_cpuid_entry2,
+            entries,
+            nent,
+            __u32,
+            KVM_MAX_CPUID_ENTRIES
+        );
+        generate_fam_struct_impl!(
+            kvm_cpuid,
+            kvm_cpuid_entry,
+            entries,
+            nent,
+            __u32,
+            KVM_MAX_CPUID_ENTRIES
+        );
+        generate_fam_struct_impl!(
+            kvm_irq_routing,
+            kvm_irq_routing_entry,
+            entries,
+            nr,
+            __u32,
+            KVM_MAX_IRQ_ROUTES
+        );
+        generate_fam_struct_impl!(kvm_reg_list, __u64, reg, n, __u64, KVM_MAX_REG_LIST_ENTRIES);
+        generate_fam_struct_impl!(kvm_signal_mask, __u8, sigset, len, __u32, KVM_SIGSET_SIZE);
+
+        /// Wrapper over `kvm_msrs`, used by `KVM_GET_MSRS` and `KVM_SET_MSRS`.
+        pub type Msrs = $crate::fam::FamStructWrapper<kvm_msrs>;
+        /// Wrapper over `kvm_msr_list`, used by `KVM_GET_MSR_INDEX_LIST`.
+        pub type MsrList = $crate::fam::FamStructWrapper<kvm_msr_list>;
+        /// Wrapper over `kvm_cpuid2`, used by `KVM_GET_SUPPORTED_CPUID` and `KVM_SET_CPUID2`.
+        pub type CpuId = $crate::fam::FamStructWrapper<kvm_cpuid2>;
+        /// Wrapper over `kvm_irq_routing`, used by `KVM_SET_GSI_ROUTING`.
+        pub type IrqRouting = $crate::fam::FamStructWrapper<kvm_irq_routing>;
+        /// Wrapper over `kvm_reg_list`, used by `KVM_GET_REG_LIST`.
+        pub type RegList = $crate::fam::FamStructWrapper<kvm_reg_list>;
+        /// Wrapper over `kvm_signal_mask`, used by `KVM_SET_SIGNAL_MASK`.
+        pub type SignalMask = $crate::fam::FamStructWrapper<kvm_signal_mask>;
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bindings::*;
+    use super::*;
+    use std::mem::align_of;
+
+    #[test]
+    fn test_msrs() {
+        let mut msrs = Msrs::new(0).unwrap();
+        let entry = kvm_msr_entry {
+            index: 0x174,
+            data: 0x10,
+            ..Default::default()
+        };
+        msrs.push(entry).unwrap();
+        assert_eq!(msrs.as_fam_struct_ref().nmsrs, 1);
+        assert_eq!(msrs.as_slice(), &[entry]);
+        assert_eq!(
+            msrs.as_fam_struct_ptr() as usize % align_of::<kvm_msrs>(),
+            0
+        );
+        assert!(Msrs::new(KVM_MAX_MSR_ENTRIES + 1).is_err());
+    }
+
+    #[test]
+    fn test_cpuid() {
+        let mut cpuid = CpuId::new(KVM_MAX_CPUID_ENTRIES).unwrap();
+        assert_eq!(cpuid.as_fam_struct_ref().nent, KVM_MAX_CPUID_ENTRIES as u32);
+        assert!(cpuid.push(kvm_cpuid_entry2::default()).is_err());
+        cpuid.retain(|e| e.function != 0);
+        assert!(cpuid.is_empty());
+        assert_eq!(cpuid.as_fam_struct_ref().nent, 0);
+    }
+
+    #[test]
+    fn test_msr_list_and_reg_list() {
+        let msr_list = MsrList::from_entries(&[0x10, 0x174]).unwrap();
+        assert_eq!(msr_list.as_fam_struct_ref().nmsrs, 2);
+        assert_eq!(msr_list.as_slice(), &[0x10, 0x174]);
+
+        let reg_list = RegList::new(3).unwrap();
+        assert_eq!(reg_list.as_fam_struct_ref().n, 3);
+    }
+
+    #[test]
+    fn test_irq_routing() {
+        let mut routing = IrqRouting::new(0).unwrap();
+        let mut entry = kvm_irq_routing_entry {
+            gsi: 5,
+            type_: KVM_IRQ_ROUTING_IRQCHIP,
+            flags: 0,
+            pad: 0,
+            u: unsafe { ::std::mem::zeroed() },
+        };
+        entry.u.irqchip = kvm_irq_routing_irqchip { irqchip: 0, pin: 5 };
+        routing.push(entry).unwrap();
+        assert_eq!(routing.as_fam_struct_ref().nr, 1);
+        assert_eq!(routing.as_slice()[0].gsi, 5);
+    }
+
+    #[test]
+    fn test_signal_mask() {
+        let mask = SignalMask::from_entries(&[0xff; KVM_SIGSET_SIZE]).unwrap();
+        assert_eq!(mask.as_fam_struct_ref().len, KVM_SIGSET_SIZE as u32);
+        assert!(SignalMask::new(KVM_SIGSET_SIZE + 1).is_err());
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Live migration compatibility of two x86 hosts.
+//!
+//! A guest migrated to another host keeps its CPUID, its MSRs, the KVM
+//! capabilities its VMM uses and its XSAVE area. [`check`](fn.check.html)
+//! compares the [`HostProfile`](struct.HostProfile.html) of the source and
+//! destination hosts and returns a
+//! [`MigrationReport`](struct.MigrationReport.html) of what the destination
+//! cannot provide.
+
+use std::fmt;
+
+use super::bindings::*;
+use super::cpuid::{CpuidEntries, Feature};
+
+/// What a host offers to its guests.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HostProfile<'a> {
+    /// The entries returned by `KVM_GET_SUPPORTED_CPUID`, or the CPUID of the
+    /// guest on the source host.
+    pub cpuid: &'a [kvm_cpuid_entry2],
+    /// The MSR indices returned by `KVM_GET_MSR_INDEX_LIST`.
+    pub msrs: &'a [u32],
+    /// The `KVM_CAP_*` capabilities, the ones the guest relies on for the
+    /// source host and the supported ones for the destination host.
+    pub caps: &'a [u32],
+    /// The size of the XSAVE area, from `ECX` of the CPUID leaf 0xD.0.
+    pub xsave_size: u32,
+}
+
+/// What the destination host of a migration lacks.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MigrationReport {
+    /// The features of the source missing on the destination.
+    pub missing_features: Vec<Feature>,
+    /// The CPUID leaves and subleaves of the source missing on the
+    /// destination.
+    pub missing_leaves: Vec<(u32, u32)>,
+    /// The MSRs of the source the destination cannot restore.
+    pub unrestorable_msrs: Vec<u32>,
+    /// The capabilities of the source missing on the destination.
+    pub missing_caps: Vec<u32>,
+    /// The source and destination XSAVE area sizes, if the destination one is
+    /// smaller.
+    pub xsave_size: Option<(u32, u32)>,
+}
+
+impl MigrationReport {
+    /// Returns `true` if the guest can migrate.
+    pub fn is_compatible(&self) -> bool {
+        *self == MigrationReport::default()
+    }
+}
+
+impl fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_compatible() {
+            return write!(f, "compatible");
+        }
+        let mut problems = Vec::new();
+        if !self.missing_features.is_empty() {
+            let names: Vec<&str> = self.missing_features.iter().map(|f| f.name()).collect();
+            problems.push(format!("missing features: {}", names.join(" ")));
+        }
+        if !self.missing_leaves.is_empty() {
+            let leaves: Vec<String> = self
+                .missing_leaves
+                .iter()
+                .map(|&(function, index)| format!("{:#x}.{}", function, index))
+                .collect();
+            problems.push(format!("missing CPUID leaves: {}", leaves.join(" ")));
+        }
+        if !self.unrestorable_msrs.is_empty() {
+            let msrs: Vec<String> = self
+                .unrestorable_msrs
+                .iter()
+                .map(|msr| format!("{:#x}", msr))
+                .collect();
+            problems.push(format!("unrestorable MSRs: {}", msrs.join(" ")));
+        }
+        if !self.missing_caps.is_empty() {
+            let caps: Vec<String> = self.missing_caps.iter().map(|c| c.to_string()).collect();
+            problems.push(format!("missing capabilities: {}", caps.join(" ")));
+        }
+        if let Some((source, destination)) = self.xsave_size {
+            problems.push(format!(
+                "XSAVE area of {} bytes larger than {}",
+                source, destination
+            ));
+        }
+        write!(f, "{}", problems.join("; "))
+    }
+}
+
+/// Checks that a guest of the `source` host can run on the `destination`
+/// host.
+pub fn check(source: &HostProfile, destination: &HostProfile) -> MigrationReport {
+    let missing_features = Feature::ALL
+        .iter()
+        .cloned()
+        .filter(|&f| source.cpuid.has(f) && !destination.cpuid.has(f))
+        .collect();
+    let missing_leaves = source
+        .cpuid
+        .iter()
+        .filter(|e| destination.cpuid.entry(e.function, e.index).is_none())
+        .map(|e| (e.function, e.index))
+        .collect();
+    let unrestorable_msrs = source
+        .msrs
+        .iter()
+        .cloned()
+        .filter(|msr| !destination.msrs.contains(msr))
+        .collect();
+    let missing_caps = source
+        .caps
+        .iter()
+        .cloned()
+        .filter(|cap| !destination.caps.contains(cap))
+        .collect();
+    let xsave_size = if source.xsave_size > destination.xsave_size {
+        Some((source.xsave_size, destination.xsave_size))
+    } else {
+        None
+    };
+    MigrationReport {
+        missing_features,
+        missing_leaves,
+        unrestorable_msrs,
+        missing_caps,
+        xsave_size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cpuid::leaf;
+    use super::*;
+
+    fn cpuid(features: &[Feature]) -> CpuId {
+        let mut cpuid = CpuId::from_entries(&[
+            leaf(0x1, 0, [0; 4]),
+            leaf(0x7, 0, [0; 4]),
+            leaf(0xd, 0, [0; 4]),
+            leaf(0xd, 1, [0; 4]),
+        ])
+        .unwrap();
+        for &feature in features {
+            cpuid.as_mut_slice().set(feature, true).unwrap();
+        }
+        cpuid
+    }
+
+    #[test]
+    fn test_compatible() {
+        let source_cpuid = cpuid(&[Feature::Avx, Feature::Avx2]);
+        let destination_cpuid = cpuid(&[Feature::Avx, Feature::Avx2, Feature::Avx512f]);
+        let msr_list = MsrList::from_entries(&[0x10, 0x174, 0x3a]).unwrap();
+        let source = HostProfile {
+            cpuid: source_cpuid.as_slice(),
+            msrs: &msr_list.as_slice()[..2],
+            caps: &[KVM_CAP_IRQCHIP, KVM_CAP_TSC_CONTROL],
+            xsave_size: 0x340,
+        };
+        let destination = HostProfile {
+            cpuid: destination_cpuid.as_slice(),
+            msrs: msr_list.as_slice(),
+            caps: &[KVM_CAP_TSC_CONTROL, KVM_CAP_IRQCHIP, KVM_CAP_XSAVE],
+            xsave_size: 0xa88,
+        };
+        let report = check(&source, &destination);
+        assert!(report.is_compatible());
+        assert_eq!(format!("{}", report), "compatible");
+        // The other way around, AVX-512, the MSR 0x3a and KVM_CAP_XSAVE are
+        // missing and the XSAVE area is too small.
+        assert!(!check(&destination, &source).is_compatible());
+    }
+
+    #[test]
+    fn test_incompatible() {
+        let source_cpuid = cpuid(&[Feature::Avx512f, Feature::Xsaves, Feature::X2apic]);
+        let mut destination_cpuid = cpuid(&[Feature::X2apic]);
+        destination_cpuid.retain(|e| !(e.function == 0xd && e.index == 1));
+        let source = HostProfile {
+            cpuid: source_cpuid.as_slice(),
+            msrs: &[0x10, 0x48, 0x10a],
+            caps: &[KVM_CAP_XSAVE, KVM_CAP_IRQCHIP],
+            xsave_size: 0xa88,
+        };
+        let destination = HostProfile {
+            cpuid: destination_cpuid.as_slice(),
+            msrs: &[0x10],
+            caps: &[KVM_CAP_IRQCHIP],
+            xsave_size: 0x340,
+        };
+        let report = check(&source, &destination);
+        assert_eq!(
+            report,
+            MigrationReport {
+                missing_features: vec![Feature::Avx512f, Feature::Xsaves],
+                missing_leaves: vec![(0xd, 1)],
+                unrestorable_msrs: vec![0x48, 0x10a],
+                missing_caps: vec![KVM_CAP_XSAVE],
+                xsave_size: Some((0xa88, 0x340)),
+            }
+        );
+        assert!(!report.is_compatible());
+        assert_eq!(
+            format!("{}", report),
+            format!(
+                "missing features: avx512f xsaves; missing CPUID leaves: 0xd.1; \
+                 unrestorable MSRs: 0x48 0x10a; missing capabilities: {}; \
+                 XSAVE area of 2696 bytes larger than 832",
+                KVM_CAP_XSAVE
+            )
+        );
+    }
+}
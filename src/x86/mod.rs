@@ -1,24 +1,417 @@
 // Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
+// Each `kvm-v*` feature compiles the bindings of one kernel version in their
+// own module, so several versions can be used side by side.
+//
+// Major hack to have a default version in case no feature is specified:
+// If no version is specified by using the features, just use the latest one
+// which currently is 6.6.
+
 #[cfg(feature = "kvm-v4_14_0")]
+#[allow(clippy::all)]
 mod bindings_v4_14_0;
+#[cfg(feature = "kvm-v4_14_0")]
+mod ioctls_v4_14_0;
+#[cfg(all(feature = "serde", feature = "kvm-v4_14_0"))]
+mod serialize_v4_14_0;
+
 #[cfg(feature = "kvm-v4_20_0")]
+#[allow(clippy::all)]
 mod bindings_v4_20_0;
+#[cfg(feature = "kvm-v4_20_0")]
+mod ioctls_v4_20_0;
+#[cfg(all(feature = "serde", feature = "kvm-v4_20_0"))]
+mod serialize_v4_20_0;
 
-// Major hack to have a default version in case no feature is specified:
-// If no version is specified by using the features, just use the latest one
-// which currently is 4.20.
-#[cfg(all(not(feature = "kvm-v4_14_0"), not(feature = "kvm-v4_20_0")))]
-mod bindings_v4_20_0;
+#[cfg(feature = "kvm-v5_4_0")]
+#[allow(clippy::all)]
+mod bindings_v5_4_0;
+#[cfg(feature = "kvm-v5_4_0")]
+mod ioctls_v5_4_0;
+#[cfg(all(feature = "serde", feature = "kvm-v5_4_0"))]
+mod serialize_v5_4_0;
 
-pub mod bindings {
-    #[cfg(feature = "kvm-v4_14_0")]
+#[cfg(feature = "kvm-v5_10_0")]
+#[allow(clippy::all)]
+mod bindings_v5_10_0;
+#[cfg(feature = "kvm-v5_10_0")]
+mod ioctls_v5_10_0;
+#[cfg(all(feature = "serde", feature = "kvm-v5_10_0"))]
+mod serialize_v5_10_0;
+
+#[cfg(feature = "kvm-v5_15_0")]
+#[allow(clippy::all)]
+mod bindings_v5_15_0;
+#[cfg(feature = "kvm-v5_15_0")]
+mod ioctls_v5_15_0;
+#[cfg(all(feature = "serde", feature = "kvm-v5_15_0"))]
+mod serialize_v5_15_0;
+
+#[cfg(feature = "kvm-v6_1_0")]
+#[allow(clippy::all)]
+mod bindings_v6_1_0;
+#[cfg(feature = "kvm-v6_1_0")]
+mod ioctls_v6_1_0;
+#[cfg(all(feature = "serde", feature = "kvm-v6_1_0"))]
+mod serialize_v6_1_0;
+
+#[cfg(any(
+    feature = "kvm-v6_6_0",
+    not(any(
+        feature = "kvm-v4_14_0",
+        feature = "kvm-v4_20_0",
+        feature = "kvm-v5_4_0",
+        feature = "kvm-v5_10_0",
+        feature = "kvm-v5_15_0",
+        feature = "kvm-v6_1_0"
+    ))
+))]
+#[allow(clippy::all)]
+mod bindings_v6_6_0;
+#[cfg(any(
+    feature = "kvm-v6_6_0",
+    not(any(
+        feature = "kvm-v4_14_0",
+        feature = "kvm-v4_20_0",
+        feature = "kvm-v5_4_0",
+        feature = "kvm-v5_10_0",
+        feature = "kvm-v5_15_0",
+        feature = "kvm-v6_1_0"
+    ))
+))]
+mod ioctls_v6_6_0;
+#[cfg(all(
+    feature = "serde",
+    any(
+        feature = "kvm-v6_6_0",
+        not(any(
+            feature = "kvm-v4_14_0",
+            feature = "kvm-v4_20_0",
+            feature = "kvm-v5_4_0",
+            feature = "kvm-v5_10_0",
+            feature = "kvm-v5_15_0",
+            feature = "kvm-v6_1_0"
+        ))
+    )
+))]
+mod serialize_v6_6_0;
+
+#[macro_use]
+mod fam_wrappers;
+// The layouts are only used by `kernel_abi` for the host architecture.
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64")),
+    allow(dead_code)
+)]
+pub(crate) mod layouts;
+
+pub mod cpuid;
+pub mod migration;
+
+// The C types of the x86_64 ABI. The bindings are generated with
+// `--ctypes-prefix super::raw` so their layout does not depend on the host.
+mod raw {
+    pub type c_char = i8;
+    pub type c_schar = i8;
+    pub type c_uchar = u8;
+    pub type c_short = i16;
+    pub type c_ushort = u16;
+    pub type c_int = i32;
+    pub type c_uint = u32;
+    pub type c_long = i64;
+    pub type c_ulong = u64;
+    pub type c_longlong = i64;
+    pub type c_ulonglong = u64;
+    pub use std::os::raw::c_void;
+}
+
+/// Bindings for Linux 4.14.
+#[cfg(feature = "kvm-v4_14_0")]
+pub mod v4_14_0 {
     pub use super::bindings_v4_14_0::*;
+    pub use super::fam_wrappers::*;
 
-    #[cfg(feature = "kvm-v4_20_0")]
-    pub use super::bindings_v4_20_0::*;
+    fam_wrappers!(max_cpuid_entries: 80);
 
-    #[cfg(all(not(feature = "kvm-v4_14_0"), not(feature = "kvm-v4_20_0")))]
+    /// Ioctl request numbers for Linux 4.14.
+    pub mod ioctls {
+        pub use super::super::ioctls_v4_14_0::*;
+    }
+}
+
+/// Bindings for Linux 4.20.
+#[cfg(feature = "kvm-v4_20_0")]
+pub mod v4_20_0 {
     pub use super::bindings_v4_20_0::*;
+    pub use super::fam_wrappers::*;
+
+    fam_wrappers!(max_cpuid_entries: 80);
+
+    /// Ioctl request numbers for Linux 4.20.
+    pub mod ioctls {
+        pub use super::super::ioctls_v4_20_0::*;
+    }
+}
+
+/// Bindings for Linux 5.4.
+#[cfg(feature = "kvm-v5_4_0")]
+pub mod v5_4_0 {
+    pub use super::bindings_v5_4_0::*;
+    pub use super::fam_wrappers::*;
+
+    fam_wrappers!(max_cpuid_entries: 80);
+
+    /// Ioctl request numbers for Linux 5.4.
+    pub mod ioctls {
+        pub use super::super::ioctls_v5_4_0::*;
+    }
+}
+
+/// Bindings for Linux 5.10.
+#[cfg(feature = "kvm-v5_10_0")]
+pub mod v5_10_0 {
+    pub use super::bindings_v5_10_0::*;
+    pub use super::fam_wrappers::*;
+
+    fam_wrappers!(max_cpuid_entries: 80);
+
+    /// Ioctl request numbers for Linux 5.10.
+    pub mod ioctls {
+        pub use super::super::ioctls_v5_10_0::*;
+    }
+}
+
+/// Bindings for Linux 5.15.
+#[cfg(feature = "kvm-v5_15_0")]
+pub mod v5_15_0 {
+    pub use super::bindings_v5_15_0::*;
+    pub use super::fam_wrappers::*;
+
+    fam_wrappers!(max_cpuid_entries: 256);
+
+    /// Ioctl request numbers for Linux 5.15.
+    pub mod ioctls {
+        pub use super::super::ioctls_v5_15_0::*;
+    }
+}
+
+/// Bindings for Linux 6.1.
+#[cfg(feature = "kvm-v6_1_0")]
+pub mod v6_1_0 {
+    pub use super::bindings_v6_1_0::*;
+    pub use super::fam_wrappers::*;
+
+    fam_wrappers!(max_cpuid_entries: 256);
+
+    /// Ioctl request numbers for Linux 6.1.
+    pub mod ioctls {
+        pub use super::super::ioctls_v6_1_0::*;
+    }
+}
+
+/// Bindings for Linux 6.6.
+#[cfg(any(
+    feature = "kvm-v6_6_0",
+    not(any(
+        feature = "kvm-v4_14_0",
+        feature = "kvm-v4_20_0",
+        feature = "kvm-v5_4_0",
+        feature = "kvm-v5_10_0",
+        feature = "kvm-v5_15_0",
+        feature = "kvm-v6_1_0"
+    ))
+))]
+pub mod v6_6_0 {
+    pub use super::bindings_v6_6_0::*;
+    pub use super::fam_wrappers::*;
+
+    fam_wrappers!(max_cpuid_entries: 256);
+
+    /// Ioctl request numbers for Linux 6.6.
+    pub mod ioctls {
+        pub use super::super::ioctls_v6_6_0::*;
+    }
+}
+
+/// The default bindings: the newest version compiled in.
+pub mod bindings {
+    #[cfg(all(
+        feature = "kvm-v4_14_0",
+        not(any(
+            feature = "kvm-v4_20_0",
+            feature = "kvm-v5_4_0",
+            feature = "kvm-v5_10_0",
+            feature = "kvm-v5_15_0",
+            feature = "kvm-v6_1_0",
+            feature = "kvm-v6_6_0"
+        ))
+    ))]
+    pub use super::v4_14_0::*;
+
+    #[cfg(all(
+        feature = "kvm-v4_20_0",
+        not(any(
+            feature = "kvm-v5_4_0",
+            feature = "kvm-v5_10_0",
+            feature = "kvm-v5_15_0",
+            feature = "kvm-v6_1_0",
+            feature = "kvm-v6_6_0"
+        ))
+    ))]
+    pub use super::v4_20_0::*;
+
+    #[cfg(all(
+        feature = "kvm-v5_4_0",
+        not(any(
+            feature = "kvm-v5_10_0",
+            feature = "kvm-v5_15_0",
+            feature = "kvm-v6_1_0",
+            feature = "kvm-v6_6_0"
+        ))
+    ))]
+    pub use super::v5_4_0::*;
+
+    #[cfg(all(
+        feature = "kvm-v5_10_0",
+        not(any(
+            feature = "kvm-v5_15_0",
+            feature = "kvm-v6_1_0",
+            feature = "kvm-v6_6_0"
+        ))
+    ))]
+    pub use super::v5_10_0::*;
+
+    #[cfg(all(
+        feature = "kvm-v5_15_0",
+        not(any(feature = "kvm-v6_1_0", feature = "kvm-v6_6_0"))
+    ))]
+    pub use super::v5_15_0::*;
+
+    #[cfg(all(feature = "kvm-v6_1_0", not(feature = "kvm-v6_6_0")))]
+    pub use super::v6_1_0::*;
+
+    #[cfg(any(
+        feature = "kvm-v6_6_0",
+        not(any(
+            feature = "kvm-v4_14_0",
+            feature = "kvm-v4_20_0",
+            feature = "kvm-v5_4_0",
+            feature = "kvm-v5_10_0",
+            feature = "kvm-v5_15_0",
+            feature = "kvm-v6_1_0"
+        ))
+    ))]
+    pub use super::v6_6_0::*;
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(all(feature = "kvm-v4_14_0", feature = "kvm-v4_20_0"))]
+    #[test]
+    fn test_versions_side_by_side() {
+        use std::mem::size_of;
+
+        assert_eq!(size_of::<super::v4_14_0::kvm_run>(), 2352);
+        assert_eq!(size_of::<super::v4_20_0::kvm_run>(), 2352);
+        assert_eq!(
+            super::v4_14_0::ioctls::KVM_RUN,
+            super::v4_20_0::ioctls::KVM_RUN
+        );
+        assert_eq!(super::v4_20_0::ioctls::KVM_GET_NESTED_STATE, 0xc080_aebe);
+        assert_eq!(size_of::<super::v4_20_0::kvm_nested_state>(), 128);
+    }
+
+    #[cfg(all(feature = "kvm-v5_4_0", feature = "kvm-v6_1_0"))]
+    #[test]
+    fn test_lts_versions_side_by_side() {
+        use std::mem::size_of;
+
+        assert_eq!(size_of::<super::v5_4_0::kvm_hyperv_exit>(), 40);
+        assert_eq!(size_of::<super::v6_1_0::kvm_hyperv_exit>(), 56);
+        assert_eq!(
+            super::v5_4_0::ioctls::KVM_GET_XSAVE,
+            super::v6_1_0::ioctls::KVM_GET_XSAVE
+        );
+        assert_eq!(super::v6_1_0::ioctls::KVM_GET_XSAVE2, 0x9000_aecf);
+        assert_eq!(super::v5_4_0::KVM_MAX_CPUID_ENTRIES, 80);
+        assert_eq!(super::v6_1_0::KVM_MAX_CPUID_ENTRIES, 256);
+    }
+
+    #[cfg(all(feature = "kvm-v5_10_0", feature = "kvm-v5_15_0"))]
+    #[test]
+    fn test_5_10_and_5_15_side_by_side() {
+        assert_eq!(
+            super::v5_10_0::ioctls::KVM_X86_SET_MSR_FILTER,
+            super::v5_15_0::ioctls::KVM_X86_SET_MSR_FILTER
+        );
+        assert_eq!(super::v5_15_0::ioctls::KVM_GET_SREGS2, 0x8140_aecc);
+        assert_eq!(super::v5_10_0::KVM_MAX_CPUID_ENTRIES, 80);
+        assert_eq!(super::v5_15_0::KVM_MAX_CPUID_ENTRIES, 256);
+    }
+
+    #[test]
+    fn test_default_version() {
+        #[cfg(any(
+            feature = "kvm-v6_6_0",
+            not(any(
+                feature = "kvm-v4_14_0",
+                feature = "kvm-v4_20_0",
+                feature = "kvm-v5_4_0",
+                feature = "kvm-v5_10_0",
+                feature = "kvm-v5_15_0",
+                feature = "kvm-v6_1_0"
+            ))
+        ))]
+        assert_eq!(super::bindings::KVM_CAP_ARM_SUPPORTED_BLOCK_SIZES, 229);
+        #[cfg(all(feature = "kvm-v6_1_0", not(feature = "kvm-v6_6_0")))]
+        assert_eq!(super::bindings::KVM_CAP_DIRTY_LOG_RING_ACQ_REL, 223);
+        #[cfg(all(
+            feature = "kvm-v5_15_0",
+            not(any(feature = "kvm-v6_1_0", feature = "kvm-v6_6_0"))
+        ))]
+        assert_eq!(super::bindings::KVM_CAP_ARM_MTE, 205);
+        #[cfg(all(
+            feature = "kvm-v5_10_0",
+            not(any(
+                feature = "kvm-v5_15_0",
+                feature = "kvm-v6_1_0",
+                feature = "kvm-v6_6_0"
+            ))
+        ))]
+        assert_eq!(super::bindings::KVM_CAP_ENFORCE_PV_FEATURE_CPUID, 190);
+        #[cfg(all(
+            feature = "kvm-v5_4_0",
+            not(any(
+                feature = "kvm-v5_10_0",
+                feature = "kvm-v5_15_0",
+                feature = "kvm-v6_1_0",
+                feature = "kvm-v6_6_0"
+            ))
+        ))]
+        assert_eq!(super::bindings::KVM_CAP_HYPERV_DIRECT_TLBFLUSH, 175);
+        #[cfg(all(
+            feature = "kvm-v4_20_0",
+            not(any(
+                feature = "kvm-v5_4_0",
+                feature = "kvm-v5_10_0",
+                feature = "kvm-v5_15_0",
+                feature = "kvm-v6_1_0",
+                feature = "kvm-v6_6_0"
+            ))
+        ))]
+        assert_eq!(super::bindings::KVM_CAP_NESTED_STATE, 157);
+        #[cfg(all(
+            feature = "kvm-v4_14_0",
+            not(any(
+                feature = "kvm-v4_20_0",
+                feature = "kvm-v5_4_0",
+                feature = "kvm-v5_10_0",
+                feature = "kvm-v5_15_0",
+                feature = "kvm-v6_1_0",
+                feature = "kvm-v6_6_0"
+            ))
+        ))]
+        assert_eq!(super::bindings::KVM_CAP_HYPERV_VP_INDEX, 149);
+    }
 }